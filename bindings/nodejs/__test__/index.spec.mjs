@@ -2,8 +2,86 @@ import test from 'ava'
 
 import glaredb from '../glaredb.js'
 
+// Collect the results of a query as plain objects. 64 bit integers are read
+// as bigints, convert those to numbers to keep comparisons simple.
+async function rows(output) {
+  const table = await output.toArrow()
+  return table.toArray().map((row) =>
+    Object.fromEntries(
+      Object.entries(row.toJSON()).map(([k, v]) => [k, typeof v === 'bigint' ? Number(v) : v]),
+    ),
+  )
+}
+
 test('able to connect', async (t) => {
   t.notThrows(async () => {
     await glaredb.connect()
   })
 })
+
+test('query with positional params', async (t) => {
+  const con = await glaredb.connect()
+  await con.execute('create table params_test (a int, b text)')
+  await t.notThrowsAsync(con.execute('insert into params_test values ($1, $2)', [1, 'one']))
+  const cursor = await con.sql('select b from params_test where a = $1', [1])
+  t.deepEqual(await rows(cursor), [{ b: 'one' }])
+})
+
+test('query with named params', async (t) => {
+  const con = await glaredb.connect()
+  const cursor = await con.sql('select :a + 1 as a, :b as b', { a: 41, b: 'hello' })
+  t.deepEqual(await rows(cursor), [{ a: 42, b: 'hello' }])
+})
+
+test('query with invalid params', async (t) => {
+  const con = await glaredb.connect()
+  await t.throwsAsync(con.sql('select $1, $2', [1]), { message: /expected 2 values, got 1/ })
+  await t.throwsAsync(con.sql('select :a', [1]), { message: /values must be provided by name/ })
+  await t.throwsAsync(con.sql('select $1', 1), { message: /params must be an array or an object/ })
+})
+
+test('query registered objects', async (t) => {
+  const con = await glaredb.connect()
+  con.register('people', [
+    { name: 'alice', age: 31 },
+    { name: 'bob', age: 42 },
+  ])
+  const cursor = await con.sql('select name from people where age > 40')
+  t.deepEqual(await rows(cursor), [{ name: 'bob' }])
+
+  // Registered tables aren't shared between connections.
+  const other = await glaredb.connect()
+  await t.throwsAsync(other.sql('select * from people'))
+})
+
+test('register invalid data', async (t) => {
+  const con = await glaredb.connect()
+  t.throws(() => con.register('empty', []), { message: /empty array/ })
+  t.throws(() => con.register('not_rows', [1, 2]))
+  t.throws(() => con.register('not_data', 'hello'), { instanceOf: TypeError })
+})
+
+test('query registered ipc data', async (t) => {
+  const con = await glaredb.connect()
+  const ipc = await (await con.sql('select * from generate_series(1, 10) as s(a)')).toIpc()
+  con.registerIpc('from_ipc', ipc)
+  const cursor = await con.sql('select sum(a) as total from from_ipc')
+  t.deepEqual(await rows(cursor), [{ total: 55 }])
+})
+
+test('stream results as ipc', async (t) => {
+  const con = await glaredb.connect()
+  const stream = await (await con.sql('select * from generate_series(1, 10) as s(a)')).toIpcStream()
+
+  const chunks = []
+  let chunk
+  while ((chunk = await stream.next()) !== null) {
+    chunks.push(chunk)
+  }
+  t.true(chunks.length >= 2)
+  t.is(await stream.next(), null)
+
+  con.registerIpc('from_stream', Buffer.concat(chunks))
+  const expected = Array.from({ length: 10 }, (_, i) => ({ a: i + 1 }))
+  t.deepEqual(await rows(await con.sql('select * from from_stream order by a')), expected)
+})
@@ -21,6 +21,40 @@ Object.assign(glaredb.JsExecutionOutput.prototype, {
     } catch (e) {
       throw new Error("apache-arrow is not installed, please run `npm install apache-arrow`")
     }
+  },
+  async toArrowStream() {
+    let arrow
+    try {
+      arrow = require("apache-arrow")
+    } catch (e) {
+      throw new Error("apache-arrow is not installed, please run `npm install apache-arrow`")
+    }
+    const stream = await this.toIpcStream()
+    async function* chunks() {
+      let chunk
+      while ((chunk = await stream.next()) !== null) {
+        yield chunk
+      }
+    }
+    return arrow.RecordBatchReader.from(chunks())
+  }
+});
+
+Object.assign(glaredb.Connection.prototype, {
+  register(name, data) {
+    if (Array.isArray(data)) {
+      return this.registerObjects(name, data)
+    }
+    let arrow
+    try {
+      arrow = require("apache-arrow")
+    } catch (e) {
+      throw new TypeError("data must be an array of objects, or an apache-arrow Table")
+    }
+    if (data instanceof arrow.Table) {
+      return this.registerIpc(name, arrow.tableToIPC(data, "stream"))
+    }
+    throw new TypeError("data must be an array of objects, or an apache-arrow Table")
   }
 });
 
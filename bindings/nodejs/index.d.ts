@@ -60,8 +60,19 @@ export class Connection {
    * con = glaredb.connect()
    * await con.sql('create table my_table (a int)').then(cursor => cursor.execute())
    * ```
+   *
+   * Pass values for query parameters with `params`, either as an array for
+   * positional parameters, or as an object for named parameters.
+   *
+   * ```javascript
+   * import glaredb from "@glaredb/glaredb"
+   *
+   * let con = glaredb.connect()
+   * await (await con.sql('select * from my_table where a = $1', [1])).show()
+   * await (await con.sql('select * from my_table where a = :a', { a: 1 })).show()
+   * ```
    */
-  sql(query: string): Promise<JsExecutionOutput>
+  sql(query: string, params?: Array<any> | Record<string, any>): Promise<JsExecutionOutput>
   /**
    * Run a PRQL query against a GlareDB database. Does not change
    * the state or dialect of the connection object.
@@ -91,8 +102,44 @@ export class Connection {
    * con = glaredb.connect()
    * con.execute('create table my_table (a int)')
    * ```
+   *
+   * Inserting values passed as parameters.
+   *
+   * ```js
+   * import glaredb from "@glaredb/glaredb"
+   *
+   * con = glaredb.connect()
+   * con.execute('insert into my_table values ($1)', [1])
+   * ```
    */
-  execute(query: string): Promise<void>
+  execute(query: string, params?: Array<any> | Record<string, any>): Promise<void>
+  /**
+   * Register in-memory data as a table that can be queried by name.
+   *
+   * Data is either an "apache-arrow" Table, or an array of plain objects
+   * with one object per row. The schema of an array of objects is
+   * inferred from its values. Registering data with the name of a
+   * previously registered table replaces it.
+   *
+   * ```javascript
+   * import glaredb from "@glaredb/glaredb"
+   *
+   * let con = await glaredb.connect()
+   * con.register('people', [{ name: 'alice', age: 31 }, { name: 'bob', age: 42 }])
+   * await (await con.sql('select name from people where age > 40')).show()
+   * ```
+   */
+  register(name: string, data: arrow.Table<any> | Array<Record<string, any>>): void
+  /**
+   * Register arrow IPC data, in either the stream or file format, as a
+   * table that can be queried by name.
+   */
+  registerIpc(name: string, data: Uint8Array): void
+  /**
+   * Register an array of plain objects, one per row, as a table that can
+   * be queried by name.
+   */
+  registerObjects(name: string, rows: Array<any>): void
   /** Close the current session. */
   close(): Promise<void>
 }
@@ -101,6 +148,11 @@ export class JsExecutionOutput {
   show(): Promise<void>
   execute(): Promise<void>
   toIpc(): Promise<Buffer>
+  /**
+   * Stream the results as chunks of arrow IPC data in the stream format.
+   * Chunks are produced as batches of results are computed.
+   */
+  toIpcStream(): Promise<JsIpcStream>
   /**
    * Convert to a Polars DataFrame.
    * "nodejs-polars" must be installed as a peer dependency.
@@ -113,4 +165,20 @@ export class JsExecutionOutput {
    * See https://www.npmjs.com/package/apache-arrow
    */
   toArrow(): arrow.Table<any>
+  /**
+   * Stream the results as "apache-arrow" record batches, computing them
+   * as they're read.
+   * "apache-arrow" must be installed as a peer dependency.
+   * See https://www.npmjs.com/package/apache-arrow
+   */
+  toArrowStream(): arrow.AsyncRecordBatchStreamReader<any>
+}
+/** Results of a query as chunks of arrow IPC data. */
+export class JsIpcStream {
+  /**
+   * Compute the next batch of results and return it as IPC data, or
+   * `null` once the stream is exhausted. The first chunk includes the
+   * schema, and the last chunk marks the end of the stream.
+   */
+  next(): Promise<Buffer | null>
 }
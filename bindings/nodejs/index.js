@@ -252,8 +252,9 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { connect, Connection, JsExecutionOutput } = nativeBinding
+const { connect, Connection, JsExecutionOutput, JsIpcStream } = nativeBinding
 
 module.exports.connect = connect
 module.exports.Connection = Connection
 module.exports.JsExecutionOutput = JsExecutionOutput
+module.exports.JsIpcStream = JsIpcStream
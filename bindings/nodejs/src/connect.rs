@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::connection::Connection;
+use crate::environment::JsEnvironmentReader;
 use crate::error::JsDatabaseError;
 
 #[napi(object)]
@@ -48,9 +49,12 @@ pub async fn connect(
         .map_err(glaredb::DatabaseError::from)
         .map_err(JsDatabaseError::from)?;
 
+    let env = JsEnvironmentReader::default();
     options.connection_target = data_dir_or_cloud_url;
+    options.environment_reader = Some(Arc::new(env.clone()));
 
     Ok(Connection {
         inner: Arc::new(options.connect().await.map_err(JsDatabaseError::from)?),
+        env,
     })
 }
@@ -1,15 +1,20 @@
 use std::sync::Arc;
 
 use async_once_cell::OnceCell;
+use napi::bindgen_prelude::Uint8Array;
 
+use crate::environment::{table_from_ipc, table_from_objects, JsEnvironmentReader};
 use crate::error::JsDatabaseError;
 use crate::execution::JsExecutionOutput;
+use crate::params::params_from_js;
 
 /// A connected session to a GlareDB database.
 #[napi]
 #[derive(Clone)]
 pub struct Connection {
     pub(crate) inner: Arc<glaredb::Connection>,
+    /// Tables registered with `register`.
+    pub(crate) env: JsEnvironmentReader,
 }
 
 #[napi]
@@ -25,14 +30,17 @@ impl Connection {
 
         Ok(DEFAULT_CON
             .get_or_try_init(async {
+                let env = JsEnvironmentReader::default();
                 Ok::<_, JsDatabaseError>(Connection {
                     inner: Arc::new(
                         glaredb::ConnectOptionsBuilder::new_in_memory()
+                            .environment_reader(Arc::new(env.clone()))
                             .build()
                             .map_err(glaredb::DatabaseError::from)?
                             .connect()
                             .await?,
                     ),
+                    env,
                 })
             })
             .await?
@@ -76,15 +84,32 @@ impl Connection {
     /// con = glaredb.connect()
     /// await con.sql('create table my_table (a int)').then(cursor => cursor.execute())
     /// ```
-    #[napi(catch_unwind)]
-    pub async fn sql(&self, query: String) -> napi::Result<JsExecutionOutput> {
-        Ok(self
-            .inner
-            .sql(query)
-            .evaluate()
-            .await
-            .map_err(JsDatabaseError::from)?
-            .into())
+    ///
+    /// Pass values for query parameters with `params`, either as an array for
+    /// positional parameters, or as an object for named parameters.
+    ///
+    /// ```javascript
+    /// import glaredb from "@glaredb/glaredb"
+    ///
+    /// let con = glaredb.connect()
+    /// await (await con.sql('select * from my_table where a = $1', [1])).show()
+    /// await (await con.sql('select * from my_table where a = :a', { a: 1 })).show()
+    /// ```
+    #[napi(
+        catch_unwind,
+        ts_args_type = "query: string, params?: Array<any> | Record<string, any>"
+    )]
+    pub async fn sql(
+        &self,
+        query: String,
+        params: Option<serde_json::Value>,
+    ) -> napi::Result<JsExecutionOutput> {
+        let mut op = self.inner.sql(query);
+        if let Some(params) = params_from_js(params)? {
+            op = op.with_params(params);
+        }
+
+        Ok(op.evaluate().await.map_err(JsDatabaseError::from)?.into())
     }
 
     /// Run a PRQL query against a GlareDB database. Does not change
@@ -123,15 +148,75 @@ impl Connection {
     /// con = glaredb.connect()
     /// con.execute('create table my_table (a int)')
     /// ```
+    ///
+    /// Inserting values passed as parameters.
+    ///
+    /// ```js
+    /// import glaredb from "@glaredb/glaredb"
+    ///
+    /// con = glaredb.connect()
+    /// con.execute('insert into my_table values ($1)', [1])
+    /// ```
+    #[napi(
+        catch_unwind,
+        ts_args_type = "query: string, params?: Array<any> | Record<string, any>"
+    )]
+    pub async fn execute(
+        &self,
+        query: String,
+        params: Option<serde_json::Value>,
+    ) -> napi::Result<()> {
+        let mut op = self.inner.execute(query);
+        if let Some(params) = params_from_js(params)? {
+            op = op.with_params(params);
+        }
+
+        op.call().check().await.map_err(JsDatabaseError::from)?;
+
+        Ok(())
+    }
+
+    /// Register in-memory data as a table that can be queried by name.
+    ///
+    /// Data is either an "apache-arrow" Table, or an array of plain objects
+    /// with one object per row. The schema of an array of objects is
+    /// inferred from its values. Registering data with the name of a
+    /// previously registered table replaces it.
+    ///
+    /// ```javascript
+    /// import glaredb from "@glaredb/glaredb"
+    ///
+    /// let con = await glaredb.connect()
+    /// con.register('people', [{ name: 'alice', age: 31 }, { name: 'bob', age: 42 }])
+    /// await (await con.sql('select name from people where age > 40')).show()
+    /// ```
+    #[napi(ts_args_type = "name: string, data: arrow.Table<any> | Array<Record<string, any>>")]
+    pub fn register(&self) -> napi::Result<()> {
+        // TODO: implement this in rust if possible?
+        // Currently, this is monkeypatched in glaredb.js to detect arrow
+        // tables, which are passed to `registerIpc`.
+        unimplemented!("register")
+    }
+
+    /// Register arrow IPC data, in either the stream or file format, as a
+    /// table that can be queried by name.
     #[napi(catch_unwind)]
-    pub async fn execute(&self, query: String) -> napi::Result<()> {
-        self.inner
-            .execute(query)
-            .call()
-            .check()
-            .await
+    pub fn register_ipc(&self, name: String, data: Uint8Array) -> napi::Result<()> {
+        let table = table_from_ipc(&data)
+            .map_err(glaredb::DatabaseError::from)
             .map_err(JsDatabaseError::from)?;
+        self.env.register(name, table);
+        Ok(())
+    }
 
+    /// Register an array of plain objects, one per row, as a table that can
+    /// be queried by name.
+    #[napi(catch_unwind)]
+    pub fn register_objects(&self, name: String, rows: Vec<serde_json::Value>) -> napi::Result<()> {
+        let table = table_from_objects(&rows)
+            .map_err(glaredb::DatabaseError::from)
+            .map_err(JsDatabaseError::from)?;
+        self.env.register(name, table);
         Ok(())
     }
 
@@ -0,0 +1,95 @@
+//! Tables registered from javascript.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+
+use glaredb::ext::datafusion::arrow::datatypes::SchemaRef;
+use glaredb::ext::datafusion::arrow::error::ArrowError;
+use glaredb::ext::datafusion::arrow::ipc::reader::{FileReader, StreamReader};
+use glaredb::ext::datafusion::arrow::json::reader::{
+    infer_json_schema_from_iterator,
+    ReaderBuilder,
+};
+use glaredb::ext::datafusion::datasource::{MemTable, TableProvider};
+use glaredb::ext::{EnvironmentReader, RecordBatch};
+
+/// Magic bytes at the start of data in the arrow IPC file format.
+const ARROW_FILE_MAGIC: &[u8] = b"ARROW1";
+
+/// Number of rows per record batch when converting arrays of objects.
+const BATCH_SIZE: usize = 8192;
+
+/// Resolve tables registered with a connection by name.
+#[derive(Clone, Default)]
+pub struct JsEnvironmentReader {
+    tables: Arc<Mutex<HashMap<String, Arc<dyn TableProvider>>>>,
+}
+
+impl JsEnvironmentReader {
+    /// Register a table, replacing any table previously registered with the
+    /// same name.
+    pub fn register(&self, name: String, table: Arc<dyn TableProvider>) {
+        self.tables.lock().unwrap().insert(name, table);
+    }
+}
+
+impl EnvironmentReader for JsEnvironmentReader {
+    fn resolve_table(
+        &self,
+        name: &str,
+    ) -> Result<Option<Arc<dyn TableProvider>>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(self.tables.lock().unwrap().get(name).cloned())
+    }
+}
+
+/// Read a table from arrow IPC data, in either the stream or file format.
+pub fn table_from_ipc(data: &[u8]) -> Result<Arc<dyn TableProvider>, ArrowError> {
+    let (schema, batches) = if data.starts_with(ARROW_FILE_MAGIC) {
+        let reader = FileReader::try_new(Cursor::new(data), None)?;
+        let schema = reader.schema();
+        (schema, reader.collect::<Result<Vec<_>, _>>()?)
+    } else {
+        let reader = StreamReader::try_new(Cursor::new(data), None)?;
+        let schema = reader.schema();
+        (schema, reader.collect::<Result<Vec<_>, _>>()?)
+    };
+
+    mem_table(schema, batches)
+}
+
+/// Convert an array of objects into a table, one row per object. The schema
+/// is inferred from the values of the objects.
+pub fn table_from_objects(
+    rows: &[serde_json::Value],
+) -> Result<Arc<dyn TableProvider>, ArrowError> {
+    if rows.is_empty() {
+        return Err(ArrowError::SchemaError(
+            "cannot infer a schema from an empty array".to_string(),
+        ));
+    }
+
+    let schema = Arc::new(infer_json_schema_from_iterator(rows.iter().map(Ok))?);
+    let mut decoder = ReaderBuilder::new(schema.clone())
+        .with_batch_size(BATCH_SIZE)
+        .build_decoder()?;
+
+    let mut batches = Vec::new();
+    for chunk in rows.chunks(BATCH_SIZE) {
+        decoder.serialize(chunk)?;
+        if let Some(batch) = decoder.flush()? {
+            batches.push(batch);
+        }
+    }
+
+    mem_table(schema, batches)
+}
+
+fn mem_table(
+    schema: SchemaRef,
+    batches: Vec<RecordBatch>,
+) -> Result<Arc<dyn TableProvider>, ArrowError> {
+    let table = MemTable::try_new(schema, vec![batches])
+        .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+    Ok(Arc::new(table))
+}
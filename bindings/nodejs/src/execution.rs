@@ -1,7 +1,8 @@
 use std::sync::{Arc, Mutex};
 
+use futures::lock::Mutex as AsyncMutex;
 use futures::stream::StreamExt;
-use glaredb::ext::datafusion::arrow::ipc::writer::FileWriter;
+use glaredb::ext::datafusion::arrow::ipc::writer::{FileWriter, StreamWriter};
 use glaredb::ext::SendableRecordBatchStream;
 use glaredb::{DatabaseError, RecordStream};
 
@@ -70,6 +71,21 @@ impl JsExecutionOutput {
         Ok(self.to_arrow_inner().await?.into())
     }
 
+    /// Stream the results as chunks of arrow IPC data in the stream format.
+    /// Chunks are produced as batches of results are computed.
+    #[napi(catch_unwind)]
+    pub async fn to_ipc_stream(&self) -> napi::Result<JsIpcStream> {
+        let mut op = self.op.lock().unwrap().clone();
+        let stream = op.resolve().await.map_err(JsDatabaseError::from)?;
+        let writer = StreamWriter::try_new(Vec::new(), stream.schema().as_ref())
+            .map_err(DatabaseError::from)
+            .map_err(JsDatabaseError::from)?;
+
+        Ok(JsIpcStream {
+            state: Arc::new(AsyncMutex::new(Some(IpcStreamState { stream, writer }))),
+        })
+    }
+
     #[napi(ts_return_type = "pl.DataFrame")]
     /// Convert to a Polars DataFrame.
     /// "nodejs-polars" must be installed as a peer dependency.
@@ -89,6 +105,71 @@ impl JsExecutionOutput {
         // Currently, this is monkeypatched in glaredb.js
         unimplemented!("to_arrow")
     }
+
+    #[napi(ts_return_type = "arrow.AsyncRecordBatchStreamReader<any>")]
+    /// Stream the results as "apache-arrow" record batches, computing them
+    /// as they're read.
+    /// "apache-arrow" must be installed as a peer dependency.
+    /// See https://www.npmjs.com/package/apache-arrow
+    pub async fn to_arrow_stream(&self) -> napi::Result<()> {
+        // TODO: implement this in rust if possible?
+        // Currently, this is monkeypatched in glaredb.js
+        unimplemented!("to_arrow_stream")
+    }
+}
+
+struct IpcStreamState {
+    stream: SendableRecordBatchStream,
+    writer: StreamWriter<Vec<u8>>,
+}
+
+/// Results of a query as chunks of arrow IPC data.
+#[napi]
+pub struct JsIpcStream {
+    /// `None` once the end of the stream has been written.
+    state: Arc<AsyncMutex<Option<IpcStreamState>>>,
+}
+
+#[napi]
+impl JsIpcStream {
+    /// Compute the next batch of results and return it as IPC data, or
+    /// `null` once the stream is exhausted. The first chunk includes the
+    /// schema, and the last chunk marks the end of the stream.
+    #[napi(catch_unwind)]
+    pub async fn next(&self) -> napi::Result<Option<napi::bindgen_prelude::Buffer>> {
+        let mut state = self.state.lock().await;
+        let IpcStreamState { stream, writer } = match state.as_mut() {
+            Some(state) => state,
+            None => return Ok(None),
+        };
+
+        let done = match stream.next().await {
+            Some(batch) => {
+                let batch = batch
+                    .map_err(DatabaseError::from)
+                    .map_err(JsDatabaseError::from)?;
+                writer
+                    .write(&batch)
+                    .map_err(DatabaseError::from)
+                    .map_err(JsDatabaseError::from)?;
+                false
+            }
+            None => {
+                writer
+                    .finish()
+                    .map_err(DatabaseError::from)
+                    .map_err(JsDatabaseError::from)?;
+                true
+            }
+        };
+
+        let chunk = std::mem::take(writer.get_mut());
+        if done {
+            *state = None;
+        }
+
+        Ok(Some(chunk.into()))
+    }
 }
 
 async fn print_record_batches(stream: SendableRecordBatchStream) -> Result<(), JsDatabaseError> {
@@ -1,7 +1,9 @@
 #![allow(clippy::wrong_self_convention)]
 pub mod connect;
 pub mod connection;
+pub mod environment;
 pub mod error;
 pub mod execution;
+pub mod params;
 #[macro_use]
 extern crate napi_derive;
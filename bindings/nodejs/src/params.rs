@@ -0,0 +1,44 @@
+use glaredb::ext::{ParamValues, ScalarValue};
+use serde_json::Value;
+
+use crate::error::JsDatabaseError;
+
+/// Convert query parameters passed from javascript.
+///
+/// An array provides values for positional parameters (`$1`, `$2`, ...), and
+/// an object provides values for named parameters (`:name`).
+pub fn params_from_js(params: Option<Value>) -> Result<Option<ParamValues>, JsDatabaseError> {
+    Ok(match params {
+        None | Some(Value::Null) => None,
+        Some(Value::Array(values)) => Some(ParamValues::List(
+            values
+                .into_iter()
+                .map(scalar_from_js)
+                .collect::<Result<_, _>>()?,
+        )),
+        Some(Value::Object(values)) => Some(ParamValues::Map(
+            values
+                .into_iter()
+                .map(|(name, value)| Ok((name, scalar_from_js(value)?)))
+                .collect::<Result<_, JsDatabaseError>>()?,
+        )),
+        Some(_) => return Err(JsDatabaseError::new("params must be an array or an object")),
+    })
+}
+
+fn scalar_from_js(value: Value) -> Result<ScalarValue, JsDatabaseError> {
+    Ok(match value {
+        Value::Null => ScalarValue::Null,
+        Value::Bool(v) => ScalarValue::Boolean(Some(v)),
+        Value::Number(v) => match v.as_i64() {
+            Some(v) => ScalarValue::Int64(Some(v)),
+            None => ScalarValue::Float64(v.as_f64()),
+        },
+        Value::String(v) => ScalarValue::Utf8(Some(v)),
+        Value::Array(_) | Value::Object(_) => {
+            return Err(JsDatabaseError::new(format!(
+                "unsupported parameter type: {value}"
+            )))
+        }
+    })
+}
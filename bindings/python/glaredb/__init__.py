@@ -1,10 +1,112 @@
 # pylint: disable-all
-from .glaredb import connect, sql, prql, execute, __runtime
+import datetime
+import time
+
+from .glaredb import (
+    connect,
+    connect_async,
+    sql,
+    prql,
+    execute,
+    __runtime,
+    apilevel,
+    threadsafety,
+    paramstyle,
+    Connection,
+    Cursor,
+    AsyncConnection,
+    AsyncExecutionOutput,
+    Warning,
+    Error,
+    InterfaceError,
+    DatabaseError,
+    DataError,
+    OperationalError,
+    IntegrityError,
+    InternalError,
+    ProgrammingError,
+    NotSupportedError,
+)
+
+
+# DB-API (PEP 249) type objects and constructors.
+#
+# The type code of a column in `Cursor.description` is the name of its arrow
+# type, which compares equal to the type object for its category.
+
+
+class _DBAPITypeObject:
+    def __init__(self, *prefixes):
+        self.prefixes = prefixes
+
+    def __eq__(self, other):
+        return isinstance(other, str) and other.startswith(self.prefixes)
+
+    def __ne__(self, other):
+        return not self.__eq__(other)
+
+    def __hash__(self):
+        return hash(self.prefixes)
+
+
+STRING = _DBAPITypeObject("Utf8", "LargeUtf8")
+BINARY = _DBAPITypeObject("Binary", "LargeBinary", "FixedSizeBinary")
+NUMBER = _DBAPITypeObject("Int8", "Int16", "Int32", "Int64", "UInt", "Float", "Decimal")
+DATETIME = _DBAPITypeObject("Date", "Time", "Timestamp", "Interval", "Duration")
+ROWID = _DBAPITypeObject()
+
+Date = datetime.date
+Time = datetime.time
+Timestamp = datetime.datetime
+Binary = bytes
+
+
+def DateFromTicks(ticks):
+    return Date(*time.localtime(ticks)[:3])
+
+
+def TimeFromTicks(ticks):
+    return Time(*time.localtime(ticks)[3:6])
+
+
+def TimestampFromTicks(ticks):
+    return Timestamp(*time.localtime(ticks)[:6])
+
 
 __all__ = [
     "connect",
+    "connect_async",
     "sql",
     "prql",
     "execute",
     "__runtime",
+    "apilevel",
+    "threadsafety",
+    "paramstyle",
+    "Connection",
+    "Cursor",
+    "AsyncConnection",
+    "AsyncExecutionOutput",
+    "Warning",
+    "Error",
+    "InterfaceError",
+    "DatabaseError",
+    "DataError",
+    "OperationalError",
+    "IntegrityError",
+    "InternalError",
+    "ProgrammingError",
+    "NotSupportedError",
+    "STRING",
+    "BINARY",
+    "NUMBER",
+    "DATETIME",
+    "ROWID",
+    "Date",
+    "Time",
+    "Timestamp",
+    "Binary",
+    "DateFromTicks",
+    "TimeFromTicks",
+    "TimestampFromTicks",
 ]
@@ -0,0 +1,273 @@
+//! Asyncio versions of connections and query results.
+//!
+//! Everything that would block on a query in the synchronous API returns an
+//! awaitable instead, with the query running on the Tokio runtime.
+//!
+//! Since queries aren't planned on the calling thread, tables can't be
+//! resolved from variables in the caller's scope as they are with
+//! `Connection`.
+
+use std::sync::{Arc, Mutex};
+
+use futures::lock::Mutex as AsyncMutex;
+use futures::StreamExt;
+use glaredb::ext::datafusion::arrow::datatypes::Schema;
+use glaredb::ext::datafusion::arrow::pyarrow::ToPyArrow;
+use glaredb::ext::RecordBatch;
+use glaredb::{DatabaseError, Operation, RecordStream};
+use pyo3::exceptions::PyStopAsyncIteration;
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+
+use crate::error::PyDatabaseError;
+use crate::params::params_from_py;
+use crate::runtime::future_into_py;
+
+/// A connected session to a GlareDB database, for use with asyncio.
+#[pyclass]
+#[derive(Clone)]
+pub struct AsyncConnection {
+    inner: Arc<glaredb::Connection>,
+}
+
+impl AsyncConnection {
+    pub fn new(inner: Arc<glaredb::Connection>) -> Self {
+        AsyncConnection { inner }
+    }
+}
+
+#[pymethods]
+impl AsyncConnection {
+    fn __aenter__<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        let con = self.clone();
+        future_into_py(py, async move { Ok(con) })
+    }
+
+    fn __aexit__<'py>(
+        &self,
+        py: Python<'py>,
+        _exc_type: Option<&PyAny>,
+        _exc_value: Option<&PyAny>,
+        _traceback: Option<&PyAny>,
+    ) -> PyResult<&'py PyAny> {
+        self.close(py)
+    }
+
+    /// Run a SQL operation against a GlareDB database.
+    ///
+    /// Returns an awaitable that resolves once the query is planned. As with
+    /// `Connection.sql`, operations that write or modify data are executed
+    /// directly, and queries run when their results are processed.
+    ///
+    /// ```python
+    /// import glaredb
+    ///
+    /// con = await glaredb.connect_async()
+    /// res = await con.sql('select * from my_table where a = :a', params={'a': 1})
+    /// table = await res.to_arrow()
+    /// ```
+    #[pyo3(signature = (query, params = None))]
+    pub fn sql<'py>(
+        &self,
+        py: Python<'py>,
+        query: String,
+        params: Option<&PyAny>,
+    ) -> PyResult<&'py PyAny> {
+        let params = params_from_py(params)?;
+        let conn = self.inner.clone();
+        future_into_py(py, async move {
+            let mut op = conn.sql(query);
+            if let Some(params) = params {
+                op = op.with_params(params);
+            }
+            let op = op.evaluate().await.map_err(PyDatabaseError::from)?;
+            Ok(AsyncExecutionOutput::from(op))
+        })
+    }
+
+    /// Run a PRQL query against a GlareDB database.
+    ///
+    /// Returns an awaitable that resolves once the query is planned.
+    pub fn prql<'py>(&self, py: Python<'py>, query: String) -> PyResult<&'py PyAny> {
+        let conn = self.inner.clone();
+        future_into_py(py, async move {
+            let op = conn
+                .prql(query)
+                .evaluate()
+                .await
+                .map_err(PyDatabaseError::from)?;
+            Ok(AsyncExecutionOutput::from(op))
+        })
+    }
+
+    /// Execute a SQL query.
+    ///
+    /// Returns an awaitable that resolves once the query has run.
+    ///
+    /// ```python
+    /// import glaredb
+    ///
+    /// con = await glaredb.connect_async()
+    /// await con.execute('insert into my_table values ($1)', params=[1])
+    /// ```
+    #[pyo3(signature = (query, params = None))]
+    pub fn execute<'py>(
+        &self,
+        py: Python<'py>,
+        query: String,
+        params: Option<&PyAny>,
+    ) -> PyResult<&'py PyAny> {
+        let params = params_from_py(params)?;
+        let conn = self.inner.clone();
+        future_into_py(py, async move {
+            let mut op = conn.execute(query);
+            if let Some(params) = params {
+                op = op.with_params(params);
+            }
+            let op = op.evaluate().await.map_err(PyDatabaseError::from)?;
+            Ok(AsyncExecutionOutput::from(op))
+        })
+    }
+
+    /// Close the current session.
+    pub fn close<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        future_into_py(py, async move { Ok(()) })
+    }
+}
+
+/// State of iterating over the batches of a result.
+enum BatchIter {
+    NotStarted,
+    Streaming(RecordStream),
+    Done,
+}
+
+/// The results of a query on an `AsyncConnection`.
+///
+/// Results can be collected with `to_arrow`, `to_pandas` or `to_polars`, or
+/// iterated over as `pyarrow.RecordBatch`es with `async for`.
+#[pyclass]
+pub struct AsyncExecutionOutput {
+    op: Arc<Mutex<Operation>>,
+    batches: Arc<AsyncMutex<BatchIter>>,
+}
+
+impl From<Operation> for AsyncExecutionOutput {
+    fn from(op: Operation) -> Self {
+        AsyncExecutionOutput {
+            op: Arc::new(Mutex::new(op)),
+            batches: Arc::new(AsyncMutex::new(BatchIter::NotStarted)),
+        }
+    }
+}
+
+#[pymethods]
+impl AsyncExecutionOutput {
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("AsyncExecution{:#?}", self.op))
+    }
+
+    /// Collect the results into a `pyarrow.Table`.
+    pub fn to_arrow<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        let stream = self.op.lock().unwrap().call();
+        future_into_py(py, async move {
+            let batches = collect_batches(stream).await?;
+            Python::with_gil(|py| Ok(arrow_table(py, batches)?.to_object(py)))
+        })
+    }
+
+    /// Collect the results into a pandas data frame.
+    pub fn to_pandas<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        let stream = self.op.lock().unwrap().call();
+        future_into_py(py, async move {
+            let batches = collect_batches(stream).await?;
+            Python::with_gil(|py| {
+                let table = arrow_table(py, batches)?;
+                Ok(table.call_method0("to_pandas")?.to_object(py))
+            })
+        })
+    }
+
+    /// Collect the results into a polars data frame.
+    pub fn to_polars<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        let stream = self.op.lock().unwrap().call();
+        future_into_py(py, async move {
+            let batches = collect_batches(stream).await?;
+            Python::with_gil(|py| {
+                let table = arrow_table(py, batches)?;
+                let df = py
+                    .import("polars")?
+                    .getattr("DataFrame")?
+                    .call1(PyTuple::new(py, [table]))?;
+                Ok(df.to_object(py))
+            })
+        })
+    }
+
+    /// Run the query to completion, discarding the results.
+    pub fn execute<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        let stream = self.op.lock().unwrap().call();
+        future_into_py(py, async move {
+            collect_batches(stream).await?;
+            Ok(())
+        })
+    }
+
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Option<&'py PyAny>> {
+        let op = self.op.clone();
+        let batches = self.batches.clone();
+        let next = future_into_py(py, async move {
+            let mut batches = batches.lock().await;
+            if let BatchIter::NotStarted = *batches {
+                *batches = BatchIter::Streaming(op.lock().unwrap().call());
+            }
+
+            let batch = match &mut *batches {
+                BatchIter::Streaming(stream) => stream.next().await,
+                _ => None,
+            };
+
+            match batch {
+                Some(batch) => {
+                    let batch = batch
+                        .map_err(DatabaseError::from)
+                        .map_err(PyDatabaseError::from)?;
+                    Python::with_gil(|py| batch.to_pyarrow(py))
+                }
+                None => {
+                    *batches = BatchIter::Done;
+                    Err(PyStopAsyncIteration::new_err(()))
+                }
+            }
+        })?;
+
+        Ok(Some(next))
+    }
+}
+
+async fn collect_batches(mut stream: RecordStream) -> Result<Vec<RecordBatch>, PyDatabaseError> {
+    let mut batches = Vec::new();
+    while let Some(batch) = stream.next().await {
+        batches.push(batch.map_err(DatabaseError::from)?);
+    }
+    Ok(batches)
+}
+
+fn arrow_table(py: Python, batches: Vec<RecordBatch>) -> PyResult<&PyAny> {
+    let schema = match batches.first() {
+        Some(batch) => batch.schema(),
+        None => Arc::new(Schema::empty()),
+    };
+    let batches = batches
+        .iter()
+        .map(|batch| batch.to_pyarrow(py))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    py.import("pyarrow")?
+        .getattr("Table")?
+        .call_method1("from_batches", (batches, schema.to_pyarrow(py)?))
+}
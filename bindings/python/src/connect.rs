@@ -8,10 +8,11 @@ use std::sync::Arc;
 
 use pyo3::prelude::*;
 
+use crate::asyncio::AsyncConnection;
 use crate::connection::Connection;
 use crate::environment::PyEnvironmentReader;
 use crate::error::PyDatabaseError;
-use crate::runtime::wait_for_future;
+use crate::runtime::{future_into_py, wait_for_future};
 
 /// Connect to a GlareDB database.
 ///
@@ -52,25 +53,87 @@ pub fn connect(
     location: Option<String>,
     storage_options: Option<HashMap<String, String>>,
 ) -> PyResult<Connection> {
+    let options = connect_options(
+        data_dir_or_cloud_url,
+        spill_path,
+        disable_tls,
+        cloud_addr,
+        location,
+        storage_options,
+    )
+    .environment_reader(Arc::new(PyEnvironmentReader))
+    .build()
+    .map_err(glaredb::DatabaseError::from)
+    .map_err(PyDatabaseError::from)?;
+
     wait_for_future(py, async move {
         Ok(Connection {
-            inner: Arc::new(
-                glaredb::ConnectOptionsBuilder::default()
-                    .connection_target(data_dir_or_cloud_url.clone())
-                    .set_storage_options(storage_options)
-                    .location(location)
-                    .spill_path(spill_path)
-                    .cloud_addr(cloud_addr)
-                    .disable_tls(disable_tls)
-                    .client_type(glaredb::ClientType::Python)
-                    .environment_reader(Arc::new(PyEnvironmentReader))
-                    .build()
-                    .map_err(glaredb::DatabaseError::from)
-                    .map_err(PyDatabaseError::from)?
-                    .connect()
-                    .await
-                    .map_err(PyDatabaseError::from)?,
-            ),
+            inner: Arc::new(options.connect().await.map_err(PyDatabaseError::from)?),
         })
     })
 }
+
+/// Connect to a GlareDB database for use with asyncio.
+///
+/// Takes the same arguments as `connect`, but returns an awaitable that
+/// resolves to an `AsyncConnection`. Queries on an `AsyncConnection` run
+/// without blocking the event loop.
+///
+/// Queries run outside of the calling frame, so unlike `connect`, data frames
+/// in scope can't be referenced by name in queries.
+///
+/// ```python
+/// import glaredb
+///
+/// con = await glaredb.connect_async()
+/// table = await (await con.sql('select 1')).to_arrow()
+/// ```
+#[pyfunction]
+#[pyo3(signature = (data_dir_or_cloud_url = None, /, *, spill_path = None, disable_tls = false, cloud_addr = String::from("https://console.glaredb.com"), location = None, storage_options = None))]
+pub fn connect_async(
+    py: Python,
+    data_dir_or_cloud_url: Option<String>,
+    spill_path: Option<String>,
+    disable_tls: bool,
+    cloud_addr: String,
+    location: Option<String>,
+    storage_options: Option<HashMap<String, String>>,
+) -> PyResult<&PyAny> {
+    let options = connect_options(
+        data_dir_or_cloud_url,
+        spill_path,
+        disable_tls,
+        cloud_addr,
+        location,
+        storage_options,
+    )
+    .build()
+    .map_err(glaredb::DatabaseError::from)
+    .map_err(PyDatabaseError::from)?;
+
+    future_into_py(py, async move {
+        Ok(AsyncConnection::new(Arc::new(
+            options.connect().await.map_err(PyDatabaseError::from)?,
+        )))
+    })
+}
+
+fn connect_options(
+    data_dir_or_cloud_url: Option<String>,
+    spill_path: Option<String>,
+    disable_tls: bool,
+    cloud_addr: String,
+    location: Option<String>,
+    storage_options: Option<HashMap<String, String>>,
+) -> glaredb::ConnectOptionsBuilder {
+    let mut builder = glaredb::ConnectOptionsBuilder::default();
+    builder
+        .connection_target(data_dir_or_cloud_url)
+        .set_storage_options(storage_options)
+        .location(location)
+        .spill_path(spill_path)
+        .cloud_addr(cloud_addr)
+        .disable_tls(disable_tls)
+        .client_type(glaredb::ClientType::Python);
+    builder
+}
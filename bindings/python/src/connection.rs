@@ -1,13 +1,19 @@
 use std::sync::Arc;
 
+use glaredb::ext::datafusion::arrow::datatypes::DataType;
+use glaredb::ext::datafusion::arrow::pyarrow::PyArrowType;
 use once_cell::sync::OnceCell;
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::types::PyType;
 
+use crate::cursor::Cursor;
 use crate::environment::PyEnvironmentReader;
-use crate::error::PyDatabaseError;
+use crate::error::{NotSupportedError, PyDatabaseError};
 use crate::execution::PyExecutionOutput;
+use crate::params::params_from_py;
 use crate::runtime::wait_for_future;
+use crate::udf::create_python_udf;
 
 /// A connected session to a GlareDB database.
 #[pyclass]
@@ -101,15 +107,31 @@ impl Connection {
     /// con = glaredb.connect()
     /// con.sql('create table my_table (a int)').execute()
     /// ```
-    pub fn sql(&self, py: Python<'_>, query: &str) -> PyResult<PyExecutionOutput> {
+    ///
+    /// Pass values for query parameters with `params`, either as a list for
+    /// positional parameters, or as a dict for named parameters.
+    ///
+    /// ```python
+    /// import glaredb
+    ///
+    /// con = glaredb.connect()
+    /// con.sql('select * from my_table where a = $1', params=[1]).show()
+    /// con.sql('select * from my_table where a = :a', params={'a': 1}).show()
+    /// ```
+    #[pyo3(signature = (query, params = None))]
+    pub fn sql(
+        &self,
+        py: Python<'_>,
+        query: &str,
+        params: Option<&PyAny>,
+    ) -> PyResult<PyExecutionOutput> {
+        let params = params_from_py(params)?;
         wait_for_future(py, async move {
-            Ok(self
-                .inner
-                .sql(query)
-                .evaluate()
-                .await
-                .map_err(PyDatabaseError::from)?
-                .into())
+            let mut op = self.inner.sql(query);
+            if let Some(params) = params {
+                op = op.with_params(params);
+            }
+            Ok(op.evaluate().await.map_err(PyDatabaseError::from)?.into())
         })
     }
 
@@ -150,18 +172,106 @@ impl Connection {
     /// con = glaredb.connect()
     /// con.execute('create table my_table (a int)')
     /// ```
-    pub fn execute(&self, py: Python<'_>, query: &str) -> PyResult<PyExecutionOutput> {
+    ///
+    /// Inserting values passed as parameters.
+    ///
+    /// ```python
+    /// import glaredb
+    ///
+    /// con = glaredb.connect()
+    /// con.execute('insert into my_table values ($1)', params=[1])
+    /// ```
+    #[pyo3(signature = (query, params = None))]
+    pub fn execute(
+        &self,
+        py: Python<'_>,
+        query: &str,
+        params: Option<&PyAny>,
+    ) -> PyResult<PyExecutionOutput> {
+        let params = params_from_py(params)?;
         wait_for_future(py, async move {
-            Ok(self
-                .inner
-                .execute(query)
-                .evaluate()
-                .await
-                .map_err(PyDatabaseError::from)?
-                .into())
+            let mut op = self.inner.execute(query);
+            if let Some(params) = params {
+                op = op.with_params(params);
+            }
+            Ok(op.evaluate().await.map_err(PyDatabaseError::from)?.into())
         })
     }
 
+    /// Register a python function as a SQL function for this connection.
+    ///
+    /// Argument and return types are pyarrow data types. By default the
+    /// function is vectorized, it's called with a pyarrow array per argument
+    /// and must return an array (or anything that can be converted to one)
+    /// with a value for each row. With `vectorized=False`, the function is
+    /// called once per row with python values instead.
+    ///
+    /// The function is only available to this connection, and isn't
+    /// persisted in the catalog.
+    ///
+    /// ```python
+    /// import glaredb
+    /// import pyarrow as pa
+    /// import pyarrow.compute as pc
+    ///
+    /// con = glaredb.connect()
+    /// con.register_udf('add_one', lambda a: pc.add(a, 1), [pa.int64()], pa.int64())
+    /// con.register_udf('shout', lambda s: s.upper(), [pa.string()], pa.string(), vectorized=False)
+    /// con.sql("select add_one(41), shout('hello')").show()
+    /// ```
+    #[pyo3(signature = (name, func, arg_types, return_type, vectorized = true))]
+    pub fn register_udf(
+        &self,
+        py: Python<'_>,
+        name: &str,
+        func: PyObject,
+        arg_types: Vec<PyArrowType<DataType>>,
+        return_type: PyArrowType<DataType>,
+        vectorized: bool,
+    ) -> PyResult<()> {
+        if !func.as_ref(py).is_callable() {
+            return Err(PyTypeError::new_err("func must be callable"));
+        }
+
+        let arg_types = arg_types.into_iter().map(|typ| typ.0).collect();
+        let udf = create_python_udf(name, func, arg_types, return_type.0, vectorized);
+        wait_for_future(py, async move {
+            self.inner
+                .register_udf(udf)
+                .await
+                .map_err(PyDatabaseError::from)
+        })?;
+        Ok(())
+    }
+
+    /// Create a new DB-API cursor for this connection.
+    ///
+    /// ```python
+    /// import glaredb
+    ///
+    /// con = glaredb.connect()
+    /// cur = con.cursor()
+    /// cur.execute('select 1')
+    /// print(cur.fetchone())
+    /// ```
+    pub fn cursor(&self) -> Cursor {
+        Cursor::new(self.inner.clone())
+    }
+
+    /// Commit any pending transaction.
+    ///
+    /// Statements are always committed as they're executed, so this does
+    /// nothing.
+    pub fn commit(&self) {}
+
+    /// Roll back any pending transaction. Not supported since statements are
+    /// always committed as they're executed.
+    pub fn rollback(&self) -> PyResult<()> {
+        Err(NotSupportedError::new_err(
+            "transactions are not supported, statements are committed as they're executed",
+        ))
+    }
+
     /// Close the current session.
     pub fn close(&self, _py: Python<'_>) -> PyResult<()> {
         // TODO: Remove this method. No longer required.
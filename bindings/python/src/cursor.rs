@@ -0,0 +1,290 @@
+//! Cursors as defined by the DB-API (PEP 249).
+//!
+//! Query results are streamed, only the record batch currently being
+//! fetched from is held in memory.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use glaredb::ext::datafusion::arrow::datatypes::Schema;
+use glaredb::ext::datafusion::arrow::pyarrow::ToPyArrow;
+use glaredb::ext::RecordBatch;
+use glaredb::{DatabaseError, RecordStream};
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PyTuple};
+
+use crate::error::{ProgrammingError, PyDatabaseError};
+use crate::params::params_from_py;
+use crate::runtime::wait_for_future;
+
+/// A cursor for executing queries and fetching their results.
+#[pyclass]
+pub struct Cursor {
+    conn: Arc<glaredb::Connection>,
+    /// Remaining results of the last query. `None` if the last operation
+    /// didn't produce a result set, or the results have been exhausted.
+    stream: Option<RecordStream>,
+    /// Rows of the current batch that haven't been fetched yet.
+    rows: VecDeque<PyObject>,
+    description: Option<PyObject>,
+    rowcount: i64,
+    /// Number of rows `fetchmany` returns by default.
+    #[pyo3(get, set)]
+    arraysize: usize,
+    closed: bool,
+}
+
+impl Cursor {
+    pub fn new(conn: Arc<glaredb::Connection>) -> Self {
+        Cursor {
+            conn,
+            stream: None,
+            rows: VecDeque::new(),
+            description: None,
+            rowcount: -1,
+            arraysize: 1,
+            closed: false,
+        }
+    }
+
+    fn check_open(&self) -> PyResult<()> {
+        if self.closed {
+            return Err(ProgrammingError::new_err(
+                "cannot operate on a closed cursor",
+            ));
+        }
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.stream = None;
+        self.rows.clear();
+        self.description = None;
+        self.rowcount = -1;
+    }
+
+    /// Pull batches from the result stream until at least `n` rows are
+    /// buffered, or the stream is exhausted.
+    fn fill(&mut self, py: Python, n: usize) -> PyResult<()> {
+        if self.stream.is_none() && self.description.is_none() {
+            return Err(ProgrammingError::new_err(
+                "no results to fetch, execute a query first",
+            ));
+        }
+
+        while self.rows.len() < n {
+            let stream = match self.stream.as_mut() {
+                Some(stream) => stream,
+                None => break,
+            };
+
+            let batch = wait_for_future(py, async move { stream.next().await });
+            match batch {
+                Some(batch) => {
+                    let batch = batch
+                        .map_err(DatabaseError::from)
+                        .map_err(PyDatabaseError::from)?;
+                    self.rows.extend(batch_to_rows(py, &batch)?);
+                }
+                None => self.stream = None,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fetch(&mut self, py: Python, n: usize) -> PyResult<PyObject> {
+        self.check_open()?;
+        self.fill(py, n)?;
+        let n = n.min(self.rows.len());
+        Ok(PyList::new(py, self.rows.drain(..n)).into())
+    }
+}
+
+#[pymethods]
+impl Cursor {
+    /// Describes the columns of the current result set, or `None` if the last
+    /// operation didn't return rows.
+    ///
+    /// Each column is described by a 7 item tuple of `(name, type_code,
+    /// display_size, internal_size, precision, scale, null_ok)`. The type
+    /// code is the name of the column's arrow type.
+    #[getter]
+    fn description(&self, py: Python) -> PyObject {
+        match &self.description {
+            Some(description) => description.clone_ref(py),
+            None => py.None(),
+        }
+    }
+
+    /// The number of rows modified by the last operation, or -1 if the
+    /// operation didn't modify rows.
+    #[getter]
+    fn rowcount(&self) -> i64 {
+        self.rowcount
+    }
+
+    /// Execute a query, optionally with values for its parameters.
+    ///
+    /// Statements that modify data or the catalog run immediately. Rows of
+    /// queries are streamed as they're fetched.
+    ///
+    /// ```python
+    /// import glaredb
+    ///
+    /// con = glaredb.connect()
+    /// cur = con.cursor()
+    /// cur.execute('select * from my_table where a = :a', {'a': 1})
+    /// print(cur.fetchall())
+    /// ```
+    #[pyo3(signature = (operation, parameters = None))]
+    fn execute<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        py: Python<'py>,
+        operation: String,
+        parameters: Option<&PyAny>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        slf.check_open()?;
+        slf.reset();
+
+        let params = params_from_py(parameters)?;
+        let conn = slf.conn.clone();
+        let mut op = wait_for_future(py, async move {
+            let mut op = conn.sql(operation);
+            if let Some(params) = params {
+                op = op.with_params(params);
+            }
+            op.evaluate().await
+        })
+        .map_err(PyDatabaseError::from)?;
+
+        if let Some(rows) = op.rows_affected() {
+            slf.rowcount = rows as i64;
+        }
+        if op.returns_rows() {
+            let schema = op.schema().unwrap_or_else(|| Arc::new(Schema::empty()));
+            slf.description = Some(describe(py, &schema));
+            slf.stream = Some(op.call());
+        }
+
+        Ok(slf)
+    }
+
+    /// Execute a query once for each set of parameters. Any result sets are
+    /// discarded.
+    fn executemany<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        py: Python<'py>,
+        operation: String,
+        seq_of_parameters: &PyAny,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let mut rowcount = 0;
+        for parameters in seq_of_parameters.iter()? {
+            slf = Self::execute(slf, py, operation.clone(), Some(parameters?))?;
+            if rowcount >= 0 && slf.rowcount >= 0 {
+                rowcount += slf.rowcount;
+            } else {
+                rowcount = -1;
+            }
+        }
+
+        slf.reset();
+        slf.rowcount = rowcount;
+        Ok(slf)
+    }
+
+    /// Fetch the next row of the result set, or `None` if there are no more
+    /// rows.
+    fn fetchone(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        self.check_open()?;
+        self.fill(py, 1)?;
+        Ok(self.rows.pop_front())
+    }
+
+    /// Fetch the next `size` rows of the result set. Defaults to `arraysize`
+    /// rows.
+    #[pyo3(signature = (size = None))]
+    fn fetchmany(&mut self, py: Python, size: Option<usize>) -> PyResult<PyObject> {
+        let size = size.unwrap_or(self.arraysize);
+        self.fetch(py, size)
+    }
+
+    /// Fetch all remaining rows of the result set.
+    fn fetchall(&mut self, py: Python) -> PyResult<PyObject> {
+        self.fetch(py, usize::MAX)
+    }
+
+    /// Close the cursor, discarding any remaining results.
+    fn close(&mut self) {
+        self.reset();
+        self.closed = true;
+    }
+
+    fn setinputsizes(&self, _sizes: &PyAny) {}
+
+    #[pyo3(signature = (_size, _column = None))]
+    fn setoutputsize(&self, _size: &PyAny, _column: Option<&PyAny>) {}
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        self.fetchone(py)
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(
+        &mut self,
+        _exc_type: Option<&PyAny>,
+        _exc_value: Option<&PyAny>,
+        _traceback: Option<&PyAny>,
+    ) {
+        self.close();
+    }
+}
+
+fn describe(py: Python, schema: &Schema) -> PyObject {
+    let columns: Vec<PyObject> = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            (
+                field.name(),
+                field.data_type().to_string(),
+                py.None(),
+                py.None(),
+                py.None(),
+                py.None(),
+                field.is_nullable(),
+            )
+                .to_object(py)
+        })
+        .collect();
+    PyList::new(py, columns).into()
+}
+
+/// Convert a record batch into a tuple per row.
+fn batch_to_rows(py: Python, batch: &RecordBatch) -> PyResult<Vec<PyObject>> {
+    let batch = batch.to_pyarrow(py)?;
+    let columns = (0..batch
+        .as_ref(py)
+        .getattr("num_columns")?
+        .extract::<usize>()?)
+        .map(|idx| {
+            batch
+                .call_method1(py, "column", (idx,))?
+                .call_method0(py, "to_pylist")?
+                .extract::<Vec<PyObject>>(py)
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let num_rows = columns.first().map(|col| col.len()).unwrap_or(0);
+    Ok((0..num_rows)
+        .map(|row| PyTuple::new(py, columns.iter().map(|col| col[row].clone_ref(py))).into())
+        .collect())
+}
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use pyo3::exceptions::{PyException, PyRuntimeError};
+use pyo3::prelude::*;
 use pyo3::{create_exception, PyErr};
 
 #[derive(Debug, thiserror::Error)]
@@ -43,8 +44,47 @@ impl From<PyDatabaseError> for PyErr {
     }
 }
 
-create_exception!(exceptions, ArrowErrorException, PyException);
-create_exception!(exceptions, ConfigurationException, PyException);
-create_exception!(exceptions, DataFusionErrorException, PyException);
-create_exception!(exceptions, ExecutionException, PyException);
-create_exception!(exceptions, MetastoreException, PyException);
+// Exception hierarchy as defined by the DB-API (PEP 249).
+create_exception!(exceptions, Warning, PyException);
+create_exception!(exceptions, Error, PyException);
+create_exception!(exceptions, InterfaceError, Error);
+create_exception!(exceptions, DatabaseError, Error);
+create_exception!(exceptions, DataError, DatabaseError);
+create_exception!(exceptions, OperationalError, DatabaseError);
+create_exception!(exceptions, IntegrityError, DatabaseError);
+create_exception!(exceptions, InternalError, DatabaseError);
+create_exception!(exceptions, ProgrammingError, DatabaseError);
+create_exception!(exceptions, NotSupportedError, DatabaseError);
+
+create_exception!(exceptions, ArrowErrorException, DatabaseError);
+create_exception!(exceptions, ConfigurationException, InterfaceError);
+create_exception!(exceptions, DataFusionErrorException, DatabaseError);
+create_exception!(exceptions, ExecutionException, DatabaseError);
+create_exception!(exceptions, MetastoreException, DatabaseError);
+
+/// Add the exception types to the module.
+pub fn add_exceptions(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add("Warning", py.get_type::<Warning>())?;
+    m.add("Error", py.get_type::<Error>())?;
+    m.add("InterfaceError", py.get_type::<InterfaceError>())?;
+    m.add("DatabaseError", py.get_type::<DatabaseError>())?;
+    m.add("DataError", py.get_type::<DataError>())?;
+    m.add("OperationalError", py.get_type::<OperationalError>())?;
+    m.add("IntegrityError", py.get_type::<IntegrityError>())?;
+    m.add("InternalError", py.get_type::<InternalError>())?;
+    m.add("ProgrammingError", py.get_type::<ProgrammingError>())?;
+    m.add("NotSupportedError", py.get_type::<NotSupportedError>())?;
+
+    m.add("ArrowErrorException", py.get_type::<ArrowErrorException>())?;
+    m.add(
+        "ConfigurationException",
+        py.get_type::<ConfigurationException>(),
+    )?;
+    m.add(
+        "DataFusionErrorException",
+        py.get_type::<DataFusionErrorException>(),
+    )?;
+    m.add("ExecutionException", py.get_type::<ExecutionException>())?;
+    m.add("MetastoreException", py.get_type::<MetastoreException>())?;
+    Ok(())
+}
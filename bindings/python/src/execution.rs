@@ -1,11 +1,15 @@
 use std::any::Any;
+use std::ffi::CString;
 use std::fmt::Debug;
 use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 use futures::StreamExt;
 use glaredb::ext::datafusion::arrow::datatypes::{Schema, SchemaRef};
-use glaredb::ext::datafusion::arrow::pyarrow::ToPyArrow;
+use glaredb::ext::datafusion::arrow::error::ArrowError;
+use glaredb::ext::datafusion::arrow::ffi_stream::FFI_ArrowArrayStream;
+use glaredb::ext::datafusion::arrow::pyarrow::{IntoPyArrow, ToPyArrow};
+use glaredb::ext::datafusion::arrow::record_batch::RecordBatchReader;
 use glaredb::ext::datafusion::datasource::TableProvider;
 use glaredb::ext::datafusion::error::DataFusionError;
 use glaredb::ext::datafusion::execution::context::SessionState;
@@ -16,13 +20,14 @@ use glaredb::ext::datafusion::physical_plan::streaming::{PartitionStream, Stream
 use glaredb::ext::datafusion::physical_plan::ExecutionPlan;
 use glaredb::ext::datafusion::prelude::Expr;
 use glaredb::ext::{RecordBatch, SendableRecordBatchStream};
-use glaredb::{DatabaseError, Operation};
+use glaredb::{DatabaseError, Operation, RecordStream};
 use pyo3::exceptions::PyRuntimeError;
 use pyo3::prelude::*;
-use pyo3::types::PyTuple;
+use pyo3::types::{PyCapsule, PyTuple};
+use tokio::runtime::Handle;
 
 use crate::error::PyDatabaseError;
-use crate::runtime::wait_for_future;
+use crate::runtime::{get_tokio_runtime, wait_for_future};
 use crate::util::pyprint;
 
 #[pyclass]
@@ -87,6 +92,32 @@ impl PyExecutionOutput {
         })
     }
 
+    /// Convert to a `pyarrow.RecordBatchReader`.
+    ///
+    /// Unlike `to_arrow`, batches are only produced as they're read from the
+    /// reader, and aren't all held in memory at once.
+    pub fn to_arrow_reader(&self, py: Python) -> PyResult<PyObject> {
+        let reader: Box<dyn RecordBatchReader + Send> = Box::new(self.batch_reader(py)?);
+        reader.into_pyarrow(py)
+    }
+
+    /// Export the results through the Arrow PyCapsule interface as a C
+    /// stream. Batches are produced as they're read from the stream.
+    ///
+    /// The requested schema is ignored, consumers are expected to cast the
+    /// results if they need to.
+    #[pyo3(signature = (requested_schema = None))]
+    pub fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<&'py PyCapsule> {
+        let _ = requested_schema;
+        let stream = FFI_ArrowArrayStream::new(Box::new(self.batch_reader(py)?));
+        let name = CString::new("arrow_array_stream").unwrap();
+        PyCapsule::new(py, stream, Some(name))
+    }
+
     pub fn execute(&self, py: Python) -> PyResult<()> {
         let mut stream = self.op.lock().unwrap().call();
 
@@ -142,6 +173,32 @@ impl PyExecutionOutput {
         Ok((schema, batches))
     }
 
+    /// Start streaming the results into a synchronous batch reader.
+    fn batch_reader(&self, py: Python) -> Result<BlockingBatchReader, PyDatabaseError> {
+        let (mut stream, schema) = {
+            let mut op = self.op.lock().unwrap();
+            (op.call(), op.schema())
+        };
+
+        // The results of statements that have already run don't necessarily
+        // match the schema of the plan, so read the schema from the first
+        // batch if there is one.
+        let first = wait_for_future(py, async { stream.next().await })
+            .transpose()
+            .map_err(DatabaseError::from)?;
+        let schema = match &first {
+            Some(batch) => batch.schema(),
+            None => schema.unwrap_or_else(|| Arc::new(Schema::empty())),
+        };
+
+        Ok(BlockingBatchReader {
+            schema,
+            first,
+            stream,
+            runtime: get_tokio_runtime(py).0.handle().clone(),
+        })
+    }
+
     fn resolve_batches(&self, py: Python) -> Result<Vec<RecordBatch>, PyDatabaseError> {
         let mut stream = self.op.lock().unwrap().call();
 
@@ -222,3 +279,34 @@ impl PartitionStream for PyPartition {
         ))
     }
 }
+
+/// Adapts the stream of results to arrow's synchronous `RecordBatchReader`
+/// for exporting through the C stream interface. Each read blocks on
+/// the next batch of the stream.
+struct BlockingBatchReader {
+    schema: SchemaRef,
+    /// Batch read ahead to determine the schema.
+    first: Option<RecordBatch>,
+    stream: RecordStream,
+    runtime: Handle,
+}
+
+impl Iterator for BlockingBatchReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(batch) = self.first.take() {
+            return Some(Ok(batch));
+        }
+
+        self.runtime
+            .block_on(self.stream.next())
+            .map(|batch| batch.map_err(|e| ArrowError::ExternalError(Box::new(e))))
+    }
+}
+
+impl RecordBatchReader for BlockingBatchReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
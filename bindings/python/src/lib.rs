@@ -1,11 +1,15 @@
 #![allow(clippy::wrong_self_convention)] // this is consistent with other python API's
 
+mod asyncio;
 mod connect;
 mod connection;
+mod cursor;
 mod environment;
 mod error;
 mod execution;
+mod params;
 mod runtime;
+mod udf;
 mod util;
 
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -18,7 +22,7 @@ use tokio::runtime::Builder;
 
 /// A Python module implemented in Rust.
 #[pymodule]
-fn glaredb(_py: Python, m: &PyModule) -> PyResult<()> {
+fn glaredb(py: Python, m: &PyModule) -> PyResult<()> {
     // add the Tokio runtime to the module so we can access it later
     let runtime = Builder::new_multi_thread()
         .thread_name_fn(move || {
@@ -37,6 +41,17 @@ fn glaredb(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(execute, m)?)?;
 
     m.add_function(wrap_pyfunction!(connect::connect, m)?)?;
+    m.add_function(wrap_pyfunction!(connect::connect_async, m)?)?;
+    m.add_class::<asyncio::AsyncConnection>()?;
+    m.add_class::<asyncio::AsyncExecutionOutput>()?;
+
+    // DB-API (PEP 249) globals.
+    m.add("apilevel", "2.0")?;
+    m.add("threadsafety", 1)?;
+    m.add("paramstyle", "named")?;
+    m.add_class::<Connection>()?;
+    m.add_class::<cursor::Cursor>()?;
+    error::add_exceptions(py, m)?;
 
     Ok(())
 }
@@ -46,8 +61,9 @@ fn glaredb(_py: Python, m: &PyModule) -> PyResult<()> {
 /// with the same underlying connection object and therefore access
 /// the same data and database.
 #[pyfunction]
-pub fn sql(py: Python, query: &str) -> PyResult<PyExecutionOutput> {
-    Connection::default_in_memory(py)?.sql(py, query)
+#[pyo3(signature = (query, params = None))]
+pub fn sql(py: Python, query: &str, params: Option<&PyAny>) -> PyResult<PyExecutionOutput> {
+    Connection::default_in_memory(py)?.sql(py, query, params)
 }
 
 /// Run a PRQL query against the default in-memory GlareDB
@@ -64,6 +80,7 @@ pub fn prql(py: Python, query: &str) -> PyResult<PyExecutionOutput> {
 /// with the same underlying connection object and therefore access
 /// the same data and database.
 #[pyfunction]
-pub fn execute(py: Python, query: &str) -> PyResult<PyExecutionOutput> {
-    Connection::default_in_memory(py)?.execute(py, query)
+#[pyo3(signature = (query, params = None))]
+pub fn execute(py: Python, query: &str, params: Option<&PyAny>) -> PyResult<PyExecutionOutput> {
+    Connection::default_in_memory(py)?.execute(py, query, params)
 }
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use glaredb::ext::datafusion::arrow::datatypes::{DataType, TimeUnit};
+use glaredb::ext::{ParamValues, ScalarValue};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyList, PyLong, PyString, PyTuple};
+
+/// Convert query parameters passed from python.
+///
+/// A list or tuple provides values for positional parameters (`$1`, `$2`,
+/// ...), and a dict provides values for named parameters (`:name`).
+pub fn params_from_py(params: Option<&PyAny>) -> PyResult<Option<ParamValues>> {
+    let params = match params {
+        Some(params) if !params.is_none() => params,
+        _ => return Ok(None),
+    };
+
+    if let Ok(dict) = params.downcast::<PyDict>() {
+        let mut values = HashMap::with_capacity(dict.len());
+        for (key, value) in dict.iter() {
+            let name: String = key
+                .extract()
+                .map_err(|_| PyTypeError::new_err("parameter names must be strings"))?;
+            values.insert(name, scalar_from_py(value)?);
+        }
+        return Ok(Some(ParamValues::Map(values)));
+    }
+
+    if params.is_instance_of::<PyList>() || params.is_instance_of::<PyTuple>() {
+        let values = params
+            .iter()?
+            .map(|value| scalar_from_py(value?))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(Some(ParamValues::List(values)));
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "params must be a list, tuple, or dict, got {}",
+        params.get_type().name()?
+    )))
+}
+
+fn scalar_from_py(value: &PyAny) -> PyResult<ScalarValue> {
+    if value.is_none() {
+        return Ok(ScalarValue::Null);
+    }
+    // Check bool before int, bool is a subclass of int in python.
+    if let Ok(v) = value.downcast::<PyBool>() {
+        return Ok(ScalarValue::Boolean(Some(v.is_true())));
+    }
+    if value.is_instance_of::<PyLong>() {
+        let v: i64 = value.extract()?;
+        return Ok(ScalarValue::Int64(Some(v)));
+    }
+    if value.is_instance_of::<PyFloat>() {
+        let v: f64 = value.extract()?;
+        return Ok(ScalarValue::Float64(Some(v)));
+    }
+    if value.is_instance_of::<PyString>() {
+        let v: String = value.extract()?;
+        return Ok(ScalarValue::Utf8(Some(v)));
+    }
+    if let Ok(v) = value.downcast::<PyBytes>() {
+        return Ok(ScalarValue::Binary(Some(v.as_bytes().to_vec())));
+    }
+
+    // Dates and times go through their ISO 8601 representation. Check
+    // datetime before date, datetime is a subclass of date.
+    let datetime = value.py().import("datetime")?;
+    for (class, data_type) in [
+        ("datetime", DataType::Timestamp(TimeUnit::Microsecond, None)),
+        ("date", DataType::Date32),
+        ("time", DataType::Time64(TimeUnit::Microsecond)),
+    ] {
+        if value.is_instance(datetime.getattr(class)?)? {
+            let iso: String = value.call_method0("isoformat")?.extract()?;
+            return ScalarValue::Utf8(Some(iso.clone()))
+                .cast_to(&data_type)
+                .map_err(|e| PyValueError::new_err(format!("invalid {class} {iso}: {e}")));
+        }
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "unsupported parameter type: {}",
+        value.get_type().name()?
+    )))
+}
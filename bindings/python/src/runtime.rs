@@ -1,7 +1,7 @@
 use std::future::Future;
 
 use pyo3::prelude::*;
-use pyo3::{PyRef, Python};
+use pyo3::{wrap_pyfunction, PyRef, Python};
 use tokio::runtime::Runtime;
 
 #[pyclass]
@@ -21,3 +21,63 @@ where
     let runtime: &Runtime = &get_tokio_runtime(py).0;
     py.allow_threads(|| runtime.block_on(f))
 }
+
+/// Run a rust future on the Tokio runtime, returning an asyncio future for
+/// its output.
+///
+/// Unlike `wait_for_future`, the calling thread isn't blocked, the event
+/// loop keeps running while the rust future is polled. Must be called from
+/// a thread with a running event loop.
+pub fn future_into_py<F, T>(py: Python, f: F) -> PyResult<&PyAny>
+where
+    F: Future<Output = PyResult<T>> + Send + 'static,
+    T: IntoPy<PyObject> + Send + 'static,
+{
+    let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+    let py_fut = event_loop.call_method0("create_future")?;
+
+    let event_loop: PyObject = event_loop.into();
+    let fut: PyObject = py_fut.into();
+    get_tokio_runtime(py).0.spawn(async move {
+        let result = f.await;
+
+        Python::with_gil(|py| {
+            let completed = match result {
+                Ok(value) => wrap_pyfunction!(set_future_result, py)
+                    .map(|set_result| (set_result, value.into_py(py))),
+                Err(err) => wrap_pyfunction!(set_future_exception, py)
+                    .map(|set_exception| (set_exception, err.into_value(py).into())),
+            };
+
+            // The future is completed from the event loop's thread. This only
+            // fails if the event loop has been closed in the meantime, and
+            // there's no one left to report to.
+            let scheduled = completed.and_then(|(callback, value)| {
+                event_loop.call_method1(py, "call_soon_threadsafe", (callback, fut, value))
+            });
+            if let Err(err) = scheduled {
+                err.print(py);
+            }
+        });
+    });
+
+    Ok(py_fut)
+}
+
+/// Complete an asyncio future, unless it's been cancelled.
+#[pyfunction]
+fn set_future_result(fut: &PyAny, value: PyObject) -> PyResult<()> {
+    if !fut.call_method0("done")?.is_true()? {
+        fut.call_method1("set_result", (value,))?;
+    }
+    Ok(())
+}
+
+/// Fail an asyncio future, unless it's been cancelled.
+#[pyfunction]
+fn set_future_exception(fut: &PyAny, err: PyObject) -> PyResult<()> {
+    if !fut.call_method0("done")?.is_true()? {
+        fut.call_method1("set_exception", (err,))?;
+    }
+    Ok(())
+}
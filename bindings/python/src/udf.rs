@@ -0,0 +1,143 @@
+//! Python functions as SQL UDFs.
+//!
+//! Functions are called from DataFusion's execution threads, which don't
+//! hold the GIL. The GIL is acquired for every call, so all entry points that
+//! execute queries must release it while waiting on results (see
+//! `wait_for_future`).
+
+use std::sync::Arc;
+
+use glaredb::ext::datafusion::arrow::array::{make_array, Array, ArrayData, ArrayRef};
+use glaredb::ext::datafusion::arrow::datatypes::DataType;
+use glaredb::ext::datafusion::arrow::pyarrow::{PyArrowType, ToPyArrow};
+use glaredb::ext::datafusion::error::DataFusionError;
+use glaredb::ext::datafusion::logical_expr::{create_udf, ColumnarValue, ScalarUDF, Volatility};
+use glaredb::ext::ScalarValue;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PyTuple};
+
+/// A python callable invoked by a UDF.
+struct PythonFunction {
+    name: String,
+    func: PyObject,
+    return_type: DataType,
+    /// Whether the function is called once with a pyarrow array per
+    /// argument, or once per row with python values.
+    vectorized: bool,
+}
+
+impl PythonFunction {
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue, DataFusionError> {
+        // Functions called with only scalar arguments (or none at all) are
+        // invoked on a single row and produce a scalar.
+        let num_rows = args
+            .iter()
+            .find_map(|arg| match arg {
+                ColumnarValue::Array(arr) => Some(arr.len()),
+                ColumnarValue::Scalar(_) => None,
+            })
+            .unwrap_or(1);
+        let all_scalars = args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+
+        let arrays = args
+            .iter()
+            .map(|arg| match arg {
+                ColumnarValue::Array(arr) => Ok(arr.clone()),
+                ColumnarValue::Scalar(scalar) => scalar.to_array_of_size(num_rows),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let out = Python::with_gil(|py| self.call(py, &arrays, num_rows)).map_err(|e| {
+            DataFusionError::Execution(format!("Python function '{}' failed: {e}", self.name))
+        })?;
+
+        if all_scalars {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(&out, 0)?))
+        } else {
+            Ok(ColumnarValue::Array(out))
+        }
+    }
+
+    fn call(&self, py: Python, arrays: &[ArrayRef], num_rows: usize) -> PyResult<ArrayRef> {
+        let pyarrow = py.import("pyarrow")?;
+        let return_type = self.return_type.to_pyarrow(py)?;
+
+        let args = arrays
+            .iter()
+            .map(|arr| arr.to_data().to_pyarrow(py))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let result = if self.vectorized {
+            self.func.as_ref(py).call1(PyTuple::new(py, args))?
+        } else {
+            let columns = args
+                .iter()
+                .map(|arr| {
+                    arr.call_method0(py, "to_pylist")?
+                        .extract::<Vec<PyObject>>(py)
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+
+            let results = (0..num_rows)
+                .map(|row| {
+                    let row_args =
+                        PyTuple::new(py, columns.iter().map(|col| col[row].clone_ref(py)));
+                    self.func.as_ref(py).call1(row_args)
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            PyList::new(py, results).as_ref()
+        };
+
+        // Accept anything pyarrow can turn into an array, e.g. lists or numpy
+        // arrays, and make sure it's of the declared return type.
+        let result = if result.is_instance(pyarrow.getattr("Array")?)? {
+            result
+        } else {
+            pyarrow.call_method1("array", (result, return_type.clone_ref(py)))?
+        };
+        let result = if result.getattr("type")?.eq(&return_type)? {
+            result
+        } else {
+            result.call_method1("cast", (return_type,))?
+        };
+
+        let out = make_array(result.extract::<PyArrowType<ArrayData>>()?.0);
+        if out.len() != num_rows {
+            return Err(PyValueError::new_err(format!(
+                "expected {num_rows} values, got {}",
+                out.len()
+            )));
+        }
+
+        Ok(out)
+    }
+}
+
+/// Create a UDF calling a python function.
+pub fn create_python_udf(
+    name: &str,
+    func: PyObject,
+    arg_types: Vec<DataType>,
+    return_type: DataType,
+    vectorized: bool,
+) -> ScalarUDF {
+    let func = Arc::new(PythonFunction {
+        name: name.to_string(),
+        func,
+        return_type: return_type.clone(),
+        vectorized,
+    });
+
+    // Python functions may have side effects, never evaluate them during
+    // planning.
+    create_udf(
+        name,
+        arg_types,
+        Arc::new(return_type),
+        Volatility::Volatile,
+        Arc::new(move |args: &[ColumnarValue]| func.invoke(args)),
+    )
+}
@@ -0,0 +1,44 @@
+import glaredb
+import pyarrow as pa
+import pyarrow.parquet as pq
+
+
+def test_to_arrow_reader():
+    con = glaredb.connect()
+    reader = con.sql("select * from generate_series(1, 10000) as s(a)").to_arrow_reader()
+
+    assert isinstance(reader, pa.RecordBatchReader)
+    assert reader.schema.names == ["a"]
+
+    total = 0
+    for batch in reader:
+        total += batch.num_rows
+    assert total == 10000
+
+
+def test_arrow_c_stream():
+    con = glaredb.connect()
+    out = con.sql("select 1 as a, 'hello' as b")
+
+    table = pa.RecordBatchReader.from_stream(out).read_all()
+    assert table.to_pydict() == {"a": [1], "b": ["hello"]}
+
+
+def test_arrow_reader_empty_result():
+    con = glaredb.connect()
+    reader = con.sql("select 1 as a where false").to_arrow_reader()
+
+    assert reader.schema.names == ["a"]
+    assert reader.read_all().num_rows == 0
+
+
+def test_arrow_reader_to_parquet(tmp_path):
+    con = glaredb.connect()
+    reader = con.sql("select * from generate_series(1, 100) as s(a)").to_arrow_reader()
+
+    path = tmp_path / "out.parquet"
+    with pq.ParquetWriter(path, reader.schema) as writer:
+        for batch in reader:
+            writer.write_batch(batch)
+
+    assert pq.read_table(path).num_rows == 100
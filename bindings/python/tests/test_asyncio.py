@@ -0,0 +1,81 @@
+import asyncio
+
+import glaredb
+import pyarrow as pa
+import pytest
+
+
+def test_sql():
+    async def run():
+        con = await glaredb.connect_async()
+        res = await con.sql("select 1 as a, 'hello' as b")
+        return await res.to_arrow()
+
+    table = asyncio.run(run())
+    assert table.to_pydict() == {"a": [1], "b": ["hello"]}
+
+
+def test_execute_with_params():
+    async def run():
+        async with await glaredb.connect_async() as con:
+            await con.execute("create table async_test (a int, b text)")
+            await con.execute("insert into async_test values (:a, :b)", params={"a": 1, "b": "one"})
+            await con.execute("insert into async_test values ($1, $2)", params=[2, "two"])
+            res = await con.sql("select * from async_test order by a")
+            return await res.to_pandas()
+
+    df = asyncio.run(run())
+    assert df["a"].tolist() == [1, 2]
+    assert df["b"].tolist() == ["one", "two"]
+
+
+def test_iterate_batches():
+    async def run():
+        con = await glaredb.connect_async()
+        res = await con.sql("select * from generate_series(1, 10000) as s(a)")
+        batches = []
+        async for batch in res:
+            batches.append(batch)
+        return batches
+
+    batches = asyncio.run(run())
+    assert all(isinstance(batch, pa.RecordBatch) for batch in batches)
+    assert sum(batch.num_rows for batch in batches) == 10000
+
+
+def test_concurrent_queries():
+    async def query(con, n):
+        res = await con.sql(f"select count(*) as c from generate_series(1, {n})")
+        return (await res.to_arrow()).to_pydict()["c"][0]
+
+    async def run():
+        con = await glaredb.connect_async()
+        return await asyncio.gather(*(query(con, n) for n in [10, 100, 1000]))
+
+    assert asyncio.run(run()) == [10, 100, 1000]
+
+
+def test_errors():
+    async def run():
+        con = await glaredb.connect_async()
+        await con.sql("select * from missing_table")
+
+    with pytest.raises(glaredb.DatabaseError):
+        asyncio.run(run())
+
+
+def test_no_environment_tables():
+    import pandas as pd
+
+    async def run(df):
+        con = await glaredb.connect_async()
+        await con.sql("select * from df")
+
+    df = pd.DataFrame({"a": [1, 2, 3]})
+    with pytest.raises(glaredb.DatabaseError):
+        asyncio.run(run(df))
+
+
+def test_requires_running_loop():
+    with pytest.raises(RuntimeError):
+        glaredb.connect_async()
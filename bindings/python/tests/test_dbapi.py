@@ -0,0 +1,87 @@
+import datetime
+
+import glaredb
+import pytest
+
+
+def test_module_globals():
+    assert glaredb.apilevel == "2.0"
+    assert glaredb.threadsafety == 1
+    assert glaredb.paramstyle == "named"
+    assert issubclass(glaredb.ProgrammingError, glaredb.DatabaseError)
+    assert issubclass(glaredb.DatabaseError, glaredb.Error)
+
+
+def test_fetch():
+    con = glaredb.connect()
+    cur = con.cursor()
+
+    cur.execute("select * from generate_series(1, 5) as s(a);")
+    assert cur.description[0][0] == "a"
+    assert cur.description[0][1] == glaredb.NUMBER
+    assert cur.rowcount == -1
+
+    assert cur.fetchone() == (1,)
+    assert cur.fetchmany(2) == [(2,), (3,)]
+    assert cur.fetchall() == [(4,), (5,)]
+    assert cur.fetchone() is None
+    assert cur.fetchall() == []
+
+
+def test_iterate():
+    con = glaredb.connect()
+    cur = con.cursor()
+
+    cur.execute("select 'a' as s union all select 'b';")
+    assert cur.description[0][1] == glaredb.STRING
+    assert sorted(cur) == [("a",), ("b",)]
+
+
+def test_execute_and_executemany():
+    con = glaredb.connect()
+    cur = con.cursor()
+
+    cur.execute("create table dbapi_test (a int, b text, c date);")
+    assert cur.description is None
+
+    cur.execute(
+        "insert into dbapi_test values (:a, :b, :c);",
+        {"a": 1, "b": "one", "c": datetime.date(2024, 1, 1)},
+    )
+    assert cur.rowcount == 1
+    assert cur.description is None
+
+    cur.executemany("insert into dbapi_test values ($1, $2, $3);", [(2, "two", None), (3, "three", None)])
+    assert cur.rowcount == 2
+
+    cur.execute("select a, b, c from dbapi_test order by a;")
+    assert cur.fetchall() == [
+        (1, "one", datetime.date(2024, 1, 1)),
+        (2, "two", None),
+        (3, "three", None),
+    ]
+
+    cur.execute("drop table dbapi_test;")
+
+
+def test_errors():
+    con = glaredb.connect()
+    cur = con.cursor()
+
+    with pytest.raises(glaredb.ProgrammingError):
+        cur.fetchone()
+
+    with pytest.raises(glaredb.DatabaseError):
+        cur.execute("select * from missing_table;")
+
+    with pytest.raises(glaredb.NotSupportedError):
+        con.rollback()
+
+    cur.close()
+    with pytest.raises(glaredb.ProgrammingError):
+        cur.execute("select 1;")
+
+
+def test_commit_is_noop():
+    con = glaredb.connect()
+    con.commit()
@@ -0,0 +1,48 @@
+import glaredb
+import pytest
+
+
+def test_positional_params():
+    con = glaredb.connect()
+
+    con.execute("create table tblparams (a int, b text);")
+    con.execute("insert into tblparams values ($1, $2);", params=[1, "one"])
+    con.execute("insert into tblparams values ($1, $2);", params=(2, "two"))
+
+    out = con.sql("select b from tblparams where a = $1;", params=[2]).to_arrow().to_pydict()
+    assert out == {"b": ["two"]}
+
+    con.execute("drop table tblparams;")
+
+
+def test_named_params():
+    con = glaredb.connect()
+
+    out = con.sql("select :a + 1 as a, :b as b;", params={"a": 41, "b": "hello"}).to_arrow().to_pydict()
+    assert out == {"a": [42], "b": ["hello"]}
+
+
+def test_null_and_bool_params():
+    con = glaredb.connect()
+
+    out = con.sql("select $1 as a, $2 as b;", params=[None, True]).to_arrow().to_pydict()
+    assert out == {"a": [None], "b": [True]}
+
+
+def test_invalid_params():
+    con = glaredb.connect()
+
+    with pytest.raises(Exception, match="expected 2 values, got 1"):
+        con.sql("select $1, $2;", params=[1])
+
+    with pytest.raises(Exception, match="missing value for :b"):
+        con.sql("select :a, :b;", params={"a": 1})
+
+    with pytest.raises(Exception, match="values must be provided by name"):
+        con.sql("select :a;", params=[1])
+
+    with pytest.raises(TypeError, match="unsupported parameter type"):
+        con.sql("select $1;", params=[object()])
+
+    with pytest.raises(TypeError, match="params must be a list, tuple, or dict"):
+        con.sql("select $1;", params=1)
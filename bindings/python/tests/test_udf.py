@@ -0,0 +1,75 @@
+import glaredb
+import pyarrow as pa
+import pyarrow.compute as pc
+import pytest
+
+
+def test_vectorized_udf():
+    con = glaredb.connect()
+    con.register_udf("add_one", lambda a: pc.add(a, 1), [pa.int64()], pa.int64())
+
+    out = con.sql("select add_one(a) as b from generate_series(1, 3) as s(a)").to_arrow()
+    assert out.to_pydict() == {"b": [2, 3, 4]}
+
+    out = con.sql("select add_one(41) as b").to_arrow()
+    assert out.to_pydict() == {"b": [42]}
+
+
+def test_row_udf():
+    con = glaredb.connect()
+    con.register_udf(
+        "shout",
+        lambda s: None if s is None else s.upper() + "!",
+        [pa.string()],
+        pa.string(),
+        vectorized=False,
+    )
+
+    out = con.sql("select shout(s) as s from (values ('hello'), (null)) as t(s)").to_arrow()
+    assert out.to_pydict() == {"s": ["HELLO!", None]}
+
+
+def test_udf_return_converted():
+    con = glaredb.connect()
+    con.register_udf("lengths", lambda a: [len(s) for s in a.to_pylist()], [pa.string()], pa.int32())
+
+    out = con.sql("select lengths(s) as n from (values ('a'), ('abc')) as t(s)").to_arrow()
+    assert out.to_pydict() == {"n": [1, 3]}
+    assert out.schema.field("n").type == pa.int32()
+
+
+def test_udf_replace():
+    con = glaredb.connect()
+    con.register_udf("answer", lambda: pa.array([1]), [], pa.int64())
+    con.register_udf("answer", lambda: pa.array([42]), [], pa.int64())
+
+    out = con.sql("select answer() as a").to_arrow()
+    assert out.to_pydict() == {"a": [42]}
+
+
+def test_udf_errors():
+    con = glaredb.connect()
+
+    with pytest.raises(TypeError):
+        con.register_udf("not_callable", 1, [pa.int64()], pa.int64())
+
+    # Builtin functions can't be replaced.
+    with pytest.raises(glaredb.DatabaseError):
+        con.register_udf("abs", lambda a: a, [pa.int64()], pa.int64())
+
+    def fails(a):
+        raise ValueError("oops")
+
+    con.register_udf("fails", fails, [pa.int64()], pa.int64())
+    with pytest.raises(Exception, match="oops"):
+        con.sql("select fails(a) from generate_series(1, 3) as s(a)").to_arrow()
+
+
+def test_udf_not_shared_between_connections():
+    con = glaredb.connect()
+    con.register_udf("only_here", lambda a: a, [pa.int64()], pa.int64())
+    con.sql("select only_here(1)").to_arrow()
+
+    other = glaredb.connect()
+    with pytest.raises(Exception):
+        other.sql("select only_here(1)").to_arrow()
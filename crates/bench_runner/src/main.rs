@@ -6,7 +6,7 @@ use anyhow::Result;
 use clap::Parser;
 use cli::server::ComputeServer;
 use glob::glob;
-use pgsrv::auth::SingleUserAuthenticator;
+use pgsrv::auth::{PasswordMode, SingleUserAuthenticator};
 use tokio::net::TcpListener;
 use tokio::runtime::Builder;
 use tokio::sync::oneshot;
@@ -60,6 +60,7 @@ fn main() -> Result<()> {
             .with_authenticator(SingleUserAuthenticator {
                 user: "glaredb".to_string(),
                 password: "glaredb".to_string(),
+                password_mode: PasswordMode::RequireCleartext,
             })
             .with_pg_listener(pg_listener)
             .connect()
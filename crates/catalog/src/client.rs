@@ -4,6 +4,7 @@ use std::time::Duration;
 
 use protogen::metastore::types::catalog::CatalogState;
 use protogen::metastore::types::service::Mutation;
+use protogen::metastore::types::storage::CatalogVersionInfo;
 use tokio::sync::{mpsc, oneshot};
 use tracing::error;
 
@@ -64,6 +65,30 @@ impl MetastoreClientHandle {
             .and_then(std::convert::identity) // Flatten
     }
 
+    /// Get the state of the catalog at an older version.
+    ///
+    /// This does not touch the cached state.
+    pub async fn get_state_at_version(&self, version: u64) -> Result<Arc<CatalogState>> {
+        let (tx, rx) = oneshot::channel();
+        self.send(
+            ClientRequest::GetStateAtVersion {
+                version,
+                response: tx,
+            },
+            rx,
+        )
+        .await
+        .and_then(std::convert::identity) // Flatten
+    }
+
+    /// Get the retained versions of the catalog, oldest first.
+    pub async fn get_catalog_history(&self) -> Result<Vec<CatalogVersionInfo>> {
+        let (tx, rx) = oneshot::channel();
+        self.send(ClientRequest::GetCatalogHistory { response: tx }, rx)
+            .await
+            .and_then(std::convert::identity) // Flatten
+    }
+
     pub async fn commit_state(
         &self,
         version: u64,
@@ -178,6 +203,17 @@ pub enum ClientRequest {
 
     /// Refresh the cached catalog state from persistence for some database
     RefreshCachedState { response: oneshot::Sender<()> },
+
+    /// Get an older version of the catalog state.
+    GetStateAtVersion {
+        version: u64,
+        response: oneshot::Sender<Result<Arc<CatalogState>>>,
+    },
+
+    /// Get the retained version history of the catalog.
+    GetCatalogHistory {
+        response: oneshot::Sender<Result<Vec<CatalogVersionInfo>>>,
+    },
 }
 
 impl ClientRequest {
@@ -188,6 +224,8 @@ impl ClientRequest {
             ClientRequest::GetCachedState { .. } => "get_cached_state",
             ClientRequest::ExecMutations { .. } => "exec_mutations",
             ClientRequest::RefreshCachedState { .. } => "refresh_cached_state",
+            ClientRequest::GetStateAtVersion { .. } => "get_state_at_version",
+            ClientRequest::GetCatalogHistory { .. } => "get_catalog_history",
         }
     }
 }
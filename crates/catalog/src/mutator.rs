@@ -1,29 +1,43 @@
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::time::Instant;
 
 use protogen::metastore::strategy::ResolveErrorStrategy;
-use protogen::metastore::types::catalog::CatalogState;
-use protogen::metastore::types::service::Mutation;
+use protogen::metastore::types::catalog::{CatalogEntry, CatalogState, SequenceEntry};
+use protogen::metastore::types::service::{AdvanceSequence, Mutation, SetSequenceValue};
+use telemetry::metrics::{CATALOG_COMMIT_CONFLICTS_TOTAL, CATALOG_COMMIT_DURATION_SECONDS};
+use tokio::sync::Mutex;
 use tracing::debug;
 
 use super::client::MetastoreClientHandle;
 use crate::errors::{CatalogError, Result};
 
+/// How many times to retry mutating a sequence when racing with other
+/// sessions modifying the catalog.
+const MAX_SEQUENCE_MUTATION_ATTEMPTS: usize = 5;
+
 /// Wrapper around a metastore client for mutating the catalog.
 #[derive(Clone)]
 pub struct CatalogMutator {
     pub client: Option<MetastoreClientHandle>,
+    /// Sequence values allocated by this session that haven't been handed
+    /// out yet, keyed by sequence id.
+    sequence_cache: Arc<Mutex<HashMap<u32, VecDeque<i64>>>>,
 }
 
 impl CatalogMutator {
     pub fn empty() -> Self {
-        CatalogMutator { client: None }
+        Self::new(None)
     }
 
     pub fn is_empty(&self) -> bool {
         self.client.is_none()
     }
     pub fn new(client: Option<MetastoreClientHandle>) -> Self {
-        CatalogMutator { client }
+        CatalogMutator {
+            client,
+            sequence_cache: Arc::default(),
+        }
     }
 
     pub fn get_metastore_client(&self) -> Option<&MetastoreClientHandle> {
@@ -84,6 +98,7 @@ impl CatalogMutator {
                 // here should be semantically equivalent to manually refreshing
                 // the catalog and rerunning and replanning the query.
                 debug!(error_message = msg, "retrying mutations");
+                CATALOG_COMMIT_CONFLICTS_TOTAL.inc();
 
                 client.refresh_cached_state().await?;
                 let state = client.get_cached_state().await?;
@@ -108,16 +123,133 @@ impl CatalogMutator {
         catalog_version: u64,
         mutations: impl IntoIterator<Item = Mutation>,
     ) -> Result<Arc<CatalogState>> {
+        let start = Instant::now();
         let state = self.mutate(catalog_version, mutations).await?;
-        self.commit_state(catalog_version, state.as_ref().clone())
-            .await
+        let state = self
+            .commit_state(catalog_version, state.as_ref().clone())
+            .await?;
+        CATALOG_COMMIT_DURATION_SECONDS.observe(start.elapsed().as_secs_f64());
+        Ok(state)
+    }
+
+    /// Hand out the next `count` values of a sequence.
+    ///
+    /// Values are allocated from the catalog in blocks of the sequence's
+    /// `CACHE` size, and handed out from memory until the block runs out. Like
+    /// in Postgres, values allocated but never handed out by a session are
+    /// lost.
+    ///
+    /// Sequences aren't transactional. Values are never handed out twice,
+    /// even if whatever used them ends up failing.
+    pub async fn advance_sequence(&self, sequence_id: u32, count: u64) -> Result<Vec<i64>> {
+        let mut cache = self.sequence_cache.lock().await;
+        let cached = cache.entry(sequence_id).or_default();
+
+        let needed = count.saturating_sub(cached.len() as u64);
+        if needed > 0 {
+            let values = self
+                .mutate_sequence(sequence_id, |seq| {
+                    let block = seq.options.cache.max(1) as u64;
+                    let values = seq.allocate(needed, block).ok_or_else(|| {
+                        CatalogError::new(format!(
+                            "sequence {} has reached its limit",
+                            seq.meta.name
+                        ))
+                    })?;
+                    let mutation = Mutation::AdvanceSequence(AdvanceSequence {
+                        sequence_id,
+                        count: values.len() as u64,
+                    });
+                    Ok((mutation, values))
+                })
+                .await?;
+            cached.extend(values);
+        }
+
+        Ok(cached.drain(..count as usize).collect())
+    }
+
+    /// Set the current value of a sequence.
+    pub async fn set_sequence_value(
+        &self,
+        sequence_id: u32,
+        value: i64,
+        is_called: bool,
+    ) -> Result<()> {
+        // Values cached before the reset shouldn't be handed out after it.
+        let mut cache = self.sequence_cache.lock().await;
+        cache.remove(&sequence_id);
+
+        self.mutate_sequence(sequence_id, |seq| {
+            if seq.set_value(value, is_called) {
+                let mutation = Mutation::SetSequenceValue(SetSequenceValue {
+                    sequence_id,
+                    value,
+                    is_called,
+                });
+                Ok((mutation, ()))
+            } else {
+                Err(CatalogError::new(format!(
+                    "value {value} is out of bounds for sequence {}",
+                    seq.meta.name
+                )))
+            }
+        })
+        .await
+    }
+
+    /// Commit a mutation of a sequence on top of the latest catalog state,
+    /// retrying on conflicts.
+    ///
+    /// `apply` is run against the sequence as it exists at the version the
+    /// mutation is committed on, and returns the mutation that metastore
+    /// mirrors it with.
+    async fn mutate_sequence<T>(
+        &self,
+        sequence_id: u32,
+        apply: impl Fn(&mut SequenceEntry) -> Result<(Mutation, T)>,
+    ) -> Result<T> {
+        let client = match &self.client {
+            Some(client) => client,
+            None => return Err(CatalogError::new("metastore client not configured")),
+        };
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let state = client.get_cached_state().await?;
+
+            let mut seq = match state.entries.get(&sequence_id) {
+                Some(CatalogEntry::Sequence(seq)) => seq.clone(),
+                _ => {
+                    return Err(CatalogError::new(format!(
+                        "missing sequence with id {sequence_id}"
+                    )))
+                }
+            };
+            let (mutation, out) = apply(&mut seq)?;
+
+            match client
+                .try_mutate_and_commit(state.version, vec![mutation])
+                .await
+            {
+                Ok(_) => return Ok(out),
+                Err(CatalogError {
+                    msg,
+                    strategy: Some(ResolveErrorStrategy::FetchCatalogAndRetry),
+                }) if attempt < MAX_SEQUENCE_MUTATION_ATTEMPTS => {
+                    debug!(error_message = msg, %sequence_id, "retrying sequence mutation");
+                    CATALOG_COMMIT_CONFLICTS_TOTAL.inc();
+                    client.refresh_cached_state().await?;
+                }
+                Err(e) => return Err(e),
+            }
+        }
     }
 }
 
 impl From<MetastoreClientHandle> for CatalogMutator {
     fn from(value: MetastoreClientHandle) -> Self {
-        CatalogMutator {
-            client: Some(value),
-        }
+        Self::new(Some(value))
     }
 }
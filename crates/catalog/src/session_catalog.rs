@@ -1,7 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
-use datafusion::datasource::{MemTable, TableProvider};
+use datafusion::datasource::TableProvider;
 use parking_lot::Mutex;
 use protogen::metastore::types::catalog::{
     CatalogEntry,
@@ -13,6 +13,8 @@ use protogen::metastore::types::catalog::{
     EntryType,
     FunctionEntry,
     FunctionType,
+    Privilege,
+    RoleEntry,
     SchemaEntry,
     SourceAccessMode,
     TableEntry,
@@ -53,6 +55,8 @@ pub struct SessionCatalog {
     tunnel_names: HashMap<String, u32>,
     /// Map credentials names to their ids.
     credentials_names: HashMap<String, u32>,
+    /// Map role names to their ids.
+    role_names: HashMap<String, u32>,
     /// Map schema names to their ids.
     schema_names: HashMap<String, u32>,
     /// Map schema IDs to objects in the schema.
@@ -72,6 +76,7 @@ impl SessionCatalog {
             database_names: HashMap::new(),
             tunnel_names: HashMap::new(),
             credentials_names: HashMap::new(),
+            role_names: HashMap::new(),
             schema_names: HashMap::new(),
             schema_objects: HashMap::new(),
             resolve_conf,
@@ -95,6 +100,10 @@ impl SessionCatalog {
         self.alias.as_deref()
     }
 
+    pub fn resolve_config(&self) -> ResolveConfig {
+        self.resolve_conf
+    }
+
     /// Get the version of this catalog state.
     pub fn version(&self) -> u64 {
         self.state.version
@@ -200,6 +209,127 @@ impl SessionCatalog {
         }
     }
 
+    /// Resolve a role by name.
+    pub fn resolve_role(&self, name: &str) -> Option<&RoleEntry> {
+        let id = self.role_names.get(name)?;
+        let ent = self
+            .state
+            .entries
+            .get(id)
+            .expect("role name points to invalid id");
+
+        match ent {
+            CatalogEntry::Role(ent) => Some(ent),
+            _ => panic!(
+                "entry type not role; name: {}, id: {}, type: {:?}",
+                name,
+                id,
+                ent.entry_type(),
+            ),
+        }
+    }
+
+    /// Check if a role has a privilege on an object.
+    ///
+    /// `role` is the catalog role for the session. Sessions without one (e.g.
+    /// the user configured on the command line) are unmanaged and allowed
+    /// everything. Roles that don't exist in the catalog (e.g. dropped after
+    /// the session started) are allowed nothing.
+    ///
+    /// Superusers are allowed everything, and builtin and temporary objects
+    /// can be read by everyone. Table privileges granted on a schema apply to
+    /// all objects within it. Privileges are inherited through role
+    /// membership.
+    pub fn role_has_privilege(
+        &self,
+        role: Option<&str>,
+        meta: &EntryMeta,
+        privilege: Privilege,
+    ) -> bool {
+        let role = match role {
+            Some(role) => role,
+            None => return true,
+        };
+        let role = match self.resolve_role(role) {
+            Some(role) => role,
+            None => return false,
+        };
+
+        if meta.is_temp
+            || (meta.builtin && matches!(privilege, Privilege::Select | Privilege::Usage))
+        {
+            return true;
+        }
+
+        self.inherited_roles(role).into_iter().any(|role| {
+            role.superuser
+                || role.has_grant(meta.id, privilege)
+                || role.has_grant(meta.parent, privilege)
+        })
+    }
+
+    /// Check if a role is a superuser, either directly or through role
+    /// membership.
+    ///
+    /// Sessions without a catalog role are treated as superusers, roles that
+    /// don't exist in the catalog are not.
+    pub fn role_is_superuser(&self, role: Option<&str>) -> bool {
+        let role = match role {
+            Some(role) => role,
+            None => return true,
+        };
+        match self.resolve_role(role) {
+            Some(role) => self
+                .inherited_roles(role)
+                .into_iter()
+                .any(|role| role.superuser),
+            None => false,
+        }
+    }
+
+    /// Check if a role owns an object, either directly or through role
+    /// membership.
+    ///
+    /// Sessions without a catalog role and superusers are treated as owning
+    /// everything. Objects without an owner are only owned by those.
+    pub fn role_owns(&self, role: Option<&str>, meta: &EntryMeta) -> bool {
+        let role = match role {
+            Some(role) => role,
+            None => return true,
+        };
+        let role = match self.resolve_role(role) {
+            Some(role) => role,
+            None => return false,
+        };
+
+        if meta.is_temp {
+            return true;
+        }
+
+        self.inherited_roles(role)
+            .into_iter()
+            .any(|role| role.superuser || (meta.owner != 0 && role.meta.id == meta.owner))
+    }
+
+    /// Get a role along with all roles it's a (possibly indirect) member of.
+    fn inherited_roles<'a>(&'a self, role: &'a RoleEntry) -> Vec<&'a RoleEntry> {
+        let mut roles: Vec<&RoleEntry> = Vec::new();
+        let mut stack = vec![role];
+        while let Some(role) = stack.pop() {
+            if roles.iter().any(|r| r.meta.id == role.meta.id) {
+                continue;
+            }
+            roles.push(role);
+            stack.extend(role.member_of.iter().filter_map(
+                |oid| match self.state.entries.get(oid) {
+                    Some(CatalogEntry::Role(role)) => Some(role),
+                    _ => None,
+                },
+            ));
+        }
+        roles
+    }
+
     /// Resolve a schema by name.
     pub fn resolve_schema(&self, name: &str) -> Option<&SchemaEntry> {
         // Similar invariants as `resolve_database`. If we find an entry in the
@@ -343,13 +473,15 @@ impl SessionCatalog {
     fn as_namespaced_entry<'a>(&'a self, ent: &'a CatalogEntry) -> NamespacedCatalogEntry<'a> {
         let parent_entry = match ent {
             // Explicitly mention all the options to accidentally not leave anything here.
-            CatalogEntry::Database(_) | CatalogEntry::Tunnel(_) | CatalogEntry::Credentials(_) => {
-                None
-            }
+            CatalogEntry::Database(_)
+            | CatalogEntry::Tunnel(_)
+            | CatalogEntry::Credentials(_)
+            | CatalogEntry::Role(_) => None,
             CatalogEntry::Schema(_)
             | CatalogEntry::Table(_)
             | CatalogEntry::View(_)
-            | CatalogEntry::Function(_) => {
+            | CatalogEntry::Function(_)
+            | CatalogEntry::Sequence(_) => {
                 Some(self.state.entries.get(&ent.get_meta().parent).unwrap()) // Bug if it doesn't exist.
             }
         };
@@ -365,6 +497,7 @@ impl SessionCatalog {
         self.database_names.clear();
         self.tunnel_names.clear();
         self.credentials_names.clear();
+        self.role_names.clear();
         self.schema_names.clear();
         self.schema_objects.clear();
 
@@ -381,10 +514,16 @@ impl SessionCatalog {
                 CatalogEntry::Credentials(_) => {
                     self.credentials_names.insert(name, *id);
                 }
+                CatalogEntry::Role(_) => {
+                    self.role_names.insert(name, *id);
+                }
                 CatalogEntry::Schema(_) => {
                     self.schema_names.insert(name, *id);
                 }
-                CatalogEntry::Table(_) | CatalogEntry::View(_) | CatalogEntry::Function(_) => {
+                CatalogEntry::Table(_)
+                | CatalogEntry::View(_)
+                | CatalogEntry::Function(_)
+                | CatalogEntry::Sequence(_) => {
                     let schema_id = ent.get_meta().parent;
                     let ent = self.schema_objects.entry(schema_id).or_default();
                     ent.objects.insert(name, *id);
@@ -440,9 +579,17 @@ impl TempCatalog {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Default)]
 struct TempObjectsInner {
-    tables: HashMap<String, Arc<MemTable>>,
+    tables: HashMap<String, Arc<dyn TableProvider>>,
+}
+
+impl std::fmt::Debug for TempObjectsInner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TempObjectsInner")
+            .field("tables", &self.tables.keys())
+            .finish()
+    }
 }
 
 impl TempCatalog {
@@ -473,24 +620,36 @@ impl TempCatalog {
                     builtin: false,
                     external: false,
                     is_temp: true,
+                    comment: None,
+                    owner: 0,
                 },
                 options: TableOptionsInternal {
                     columns: columns.clone(),
+                    maintenance: None,
+                    partition_columns: Vec::new(),
+                    identity_columns: Vec::new(),
                 }
                 .into(),
                 tunnel_id: None,
                 access_mode: SourceAccessMode::ReadWrite,
                 columns: Some(columns),
+                statistics: None,
+                column_comments: BTreeMap::new(),
             }
         })
     }
 
-    pub fn put_temp_table(&self, name: String, table: Arc<MemTable>) {
+    /// Put a table in the temp catalog, replacing any existing table with
+    /// the same name.
+    ///
+    /// Tables created with `CREATE TEMP TABLE` are memory tables, but tables
+    /// registered through the SDK may be any table provider.
+    pub fn put_temp_table(&self, name: String, table: Arc<dyn TableProvider>) {
         let mut inner = self.inner.lock();
         inner.tables.insert(name, table);
     }
 
-    pub fn get_temp_table_provider(&self, name: &str) -> Option<Arc<MemTable>> {
+    pub fn get_temp_table_provider(&self, name: &str) -> Option<Arc<dyn TableProvider>> {
         self.inner.lock().tables.get(name).cloned()
     }
 
@@ -518,14 +677,21 @@ impl TempCatalog {
                     builtin: false,
                     external: false,
                     is_temp: true,
+                    comment: None,
+                    owner: 0,
                 },
                 options: TableOptionsInternal {
                     columns: Vec::new(),
+                    maintenance: None,
+                    partition_columns: Vec::new(),
+                    identity_columns: Vec::new(),
                 }
                 .into(),
                 tunnel_id: None,
                 access_mode: SourceAccessMode::ReadWrite,
                 columns: None,
+                statistics: None,
+                column_comments: BTreeMap::new(),
             });
         }
 
@@ -0,0 +1,43 @@
+use clap::Args;
+
+use super::parse_key_value_pair;
+use crate::args::{LocalClientOpts, PathBuf, StorageConfigArgs};
+
+#[derive(Args, Debug)]
+pub struct BackupArgs {
+    #[clap(flatten)]
+    pub opts: LocalClientOpts,
+
+    /// Local path or object store URL to write the backup to.
+    ///
+    /// The location must be empty.
+    #[arg(long)]
+    pub to: String,
+
+    /// Options for accessing the backup location, e.g.
+    /// `--to-option access_key_id=...`.
+    #[arg(long = "to-option", value_parser = parse_key_value_pair)]
+    pub to_options: Vec<(String, String)>,
+}
+
+#[derive(Args, Debug)]
+pub struct RestoreArgs {
+    /// Local path or object store URL of the backup to restore.
+    #[arg(long)]
+    pub from: String,
+
+    /// Options for accessing the backup location, e.g.
+    /// `--from-option access_key_id=...`.
+    #[arg(long = "from-option", value_parser = parse_key_value_pair)]
+    pub from_options: Vec<(String, String)>,
+
+    /// Data directory to restore the backup into.
+    ///
+    /// The directory must not contain a database yet, and must not be in use
+    /// by a running instance.
+    #[arg(short = 'f', long, value_parser, conflicts_with = "location")]
+    pub data_dir: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub storage_config: StorageConfigArgs,
+}
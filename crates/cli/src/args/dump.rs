@@ -0,0 +1,21 @@
+use clap::Args;
+
+use crate::args::{LocalClientOpts, PathBuf};
+
+#[derive(Args, Debug)]
+pub struct DumpArgs {
+    #[clap(flatten)]
+    pub opts: LocalClientOpts,
+
+    /// Include secrets (passwords, keys, tokens) in the output.
+    ///
+    /// Secrets are otherwise written as `SECRET <name>` references, which are
+    /// read from `GLAREDB_SECRET_<NAME>` environment variables when the dump
+    /// is replayed.
+    #[arg(long)]
+    pub include_secrets: bool,
+
+    /// File to write the statements to instead of stdout.
+    #[arg(long, value_parser)]
+    pub output: Option<PathBuf>,
+}
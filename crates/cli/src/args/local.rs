@@ -15,6 +15,13 @@ pub struct LocalArgs {
     #[arg(short, long, value_parser)]
     pub query: Option<String>,
 
+    /// Execute a script, exiting upon completion.
+    ///
+    /// Scripts may contain client commands (e.g. `\set`) on their own lines.
+    /// Execution stops at the first error, exiting with a non-zero status.
+    #[arg(long, value_parser, conflicts_with = "query")]
+    pub file: Option<PathBuf>,
+
     #[clap(flatten)]
     pub opts: LocalClientOpts,
 
@@ -104,7 +111,7 @@ impl LocalClientOpts {
             ("\\help", "Show this help text"),
             (
                 "\\mode MODE",
-                "Set the output mode [table, json, ndjson, csv]",
+                "Set the output mode [table, json, ndjson, csv, markdown, html, line, arrow, parquet]",
             ),
             ("\\max-rows NUM", "Max number of rows to display"),
             (
@@ -113,6 +120,29 @@ impl LocalClientOpts {
             ),
             ("\\open PATH", "Open a database at the given path"),
             ("\\timing", "Toggle query execution runtime display"),
+            ("\\i FILE", "Execute commands from a file"),
+            (
+                "\\o [FILE]",
+                "Send query results to a file, or to stdout if no file is given",
+            ),
+            (
+                "\\set [NAME [VALUE]]",
+                "Set a variable referenced as :NAME in queries, or list all variables",
+            ),
+            ("\\unset NAME", "Unset a variable"),
+            (
+                "\\d [PATTERN]",
+                "List relations, or describe the columns of relations matching PATTERN",
+            ),
+            ("\\dt [PATTERN]", "List tables"),
+            ("\\dv [PATTERN]", "List views"),
+            ("\\dn [PATTERN]", "List schemas"),
+            ("\\df [PATTERN]", "List functions"),
+            ("\\dx [PATTERN]", "List tunnels and credentials"),
+            (
+                "\\l [PATTERN]",
+                "List databases, including external databases",
+            ),
             ("\\quit", "Quit this session"),
         ];
 
@@ -5,9 +5,13 @@ use clap::{Parser, ValueEnum};
 
 use crate::proxy::TLSMode;
 
+pub mod backup;
+pub mod dump;
 pub mod local;
 pub mod server;
 pub mod slt;
+pub use backup::*;
+pub use dump::*;
 pub use local::*;
 pub use server::*;
 pub use slt::*;
@@ -18,6 +22,21 @@ pub enum OutputMode {
     Json,
     Ndjson,
     Csv,
+    Markdown,
+    Html,
+    /// One value per line, for rows too wide to display as a table.
+    Line,
+    /// Arrow IPC stream.
+    Arrow,
+    Parquet,
+}
+
+impl OutputMode {
+    /// Check if results are written in a binary format, which can only hold
+    /// a single result set and no other text.
+    pub fn is_binary(&self) -> bool {
+        matches!(self, OutputMode::Arrow | OutputMode::Parquet)
+    }
 }
 
 #[derive(Parser)]
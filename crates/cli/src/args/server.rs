@@ -1,4 +1,4 @@
-use clap::Args;
+use clap::{Args, ValueEnum};
 
 use crate::args::{PathBuf, StorageConfigArgs};
 
@@ -18,6 +18,12 @@ pub struct ServerArgs {
     #[arg(id= "RPC_PORT", long="rpc-bind", value_parser, aliases=&["flight-bind"])]
     pub rpc_bind: Option<String>,
 
+    /// TCP address to bind to for serving Prometheus metrics over HTTP.
+    ///
+    /// Metrics are served from the `/metrics` path. Disabled if not provided.
+    #[arg(long, value_parser)]
+    pub metrics_bind: Option<String>,
+
     /// Set the user used for authentication.
     ///
     /// Only has an affect if a password is also provided. If a password is
@@ -31,6 +37,22 @@ pub struct ServerArgs {
     #[arg(short, long, value_parser)]
     pub password: Option<String>,
 
+    /// Set how clients send the password for the Postgres interface.
+    ///
+    /// Only has an affect if a password is also provided.
+    #[arg(long, value_enum, default_value_t = PasswordAuthMethod::Cleartext, requires = "password")]
+    pub password_auth: PasswordAuthMethod,
+
+    /// Path to a SSL/TLS server certificate for the Postgres interface.
+    ///
+    /// Enables channel binding when using SCRAM-SHA-256 authentication.
+    #[arg(long, value_parser, requires = "ssl_server_key")]
+    pub ssl_server_cert: Option<String>,
+
+    /// Path to the SSL/TLS server key for the Postgres interface.
+    #[arg(long, value_parser, requires = "ssl_server_cert")]
+    pub ssl_server_key: Option<String>,
+
     /// Optional file path for persisting data.
     ///
     /// Catalog data and user data will be stored in this directory.
@@ -106,3 +128,15 @@ pub struct ServerArgs {
     #[clap(long, value_parser)]
     pub metastore_bucket: Option<String>,
 }
+
+/// Password authentication methods for the Postgres interface.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PasswordAuthMethod {
+    /// Password sent in cleartext. Should only be used with TLS.
+    Cleartext,
+    /// Password hashed with MD5 and a random salt.
+    Md5,
+    /// SASL authentication using SCRAM-SHA-256.
+    #[value(name = "scram-sha-256")]
+    ScramSha256,
+}
@@ -5,7 +5,7 @@ use std::time::Duration;
 
 use anyhow::{anyhow, Result};
 use clap::Args;
-use pgsrv::auth::SingleUserAuthenticator;
+use pgsrv::auth::{PasswordMode, SingleUserAuthenticator};
 use slt::clients::flightsql::FlightSqlTestClient;
 use slt::clients::postgres::PgTestClient;
 use slt::clients::rpc::RpcTestClient;
@@ -195,6 +195,7 @@ impl SltArgs {
                     .with_authenticator(SingleUserAuthenticator {
                         user: "glaredb".to_string(),
                         password: "glaredb".to_string(),
+                        password_mode: PasswordMode::RequireCleartext,
                     })
                     .with_pg_listener_opt(pg_listener)
                     .with_rpc_listener_opt(rpc_listener)
@@ -34,6 +34,11 @@ struct Cli {
     #[clap(long, value_enum)]
     log_mode: Option<LoggingMode>,
 
+    /// Export tracing spans to an OpenTelemetry collector listening on this
+    /// OTLP/gRPC endpoint (e.g. 'http://localhost:4317').
+    #[clap(long, value_parser, global = true)]
+    otlp_endpoint: Option<String>,
+
     #[clap(subcommand)]
     command: Option<Commands>,
 
@@ -66,12 +71,20 @@ fn main() -> Result<()> {
             // Use JSON logging by default when writing to a file.
             cli.log_mode.unwrap_or(LoggingMode::Json).into(),
             Some(log_file),
+            cli.otlp_endpoint.as_deref(),
         ),
         // Disable logging when running locally since it'll clobber the repl
         // _unless_ the user specified a logging related option.
-        (Commands::Local { .. }, None, 0) => (),
-        _ => logutil::init(cli.verbose, cli.log_mode.unwrap_or_default().into(), None),
+        (Commands::Local { .. }, None, 0) if cli.otlp_endpoint.is_none() => (),
+        _ => logutil::init(
+            cli.verbose,
+            cli.log_mode.unwrap_or_default().into(),
+            None,
+            cli.otlp_endpoint.as_deref(),
+        ),
     }
 
-    command.run()
+    let result = command.run();
+    logutil::otlp::shutdown();
+    result
 }
@@ -5,7 +5,16 @@ use std::sync::Arc;
 use anyhow::{anyhow, Result};
 use atty::Stream;
 use clap::Subcommand;
-use pgsrv::auth::{LocalAuthenticator, PasswordlessAuthenticator, SingleUserAuthenticator};
+use datafusion_ext::vars::SessionVars;
+use parser::options::{OptionValue, StatementOptions};
+use parser::BackupDatabaseStmt;
+use pgsrv::auth::{
+    LocalAuthenticator,
+    PasswordMode,
+    PasswordlessAuthenticator,
+    SingleUserAuthenticator,
+};
+use pgsrv::ssl::SslConfig;
 use slt::discovery::SltDiscovery;
 use slt::hooks::{
     AllTestsHook,
@@ -14,12 +23,28 @@ use slt::hooks::{
     SqliteTestsHook,
     SshTunnelHook,
 };
-use slt::tests::{PgBinaryEncoding, SshKeysTest};
+use slt::tests::{
+    AnalyzeJoinOrderTest,
+    CatalogAsOfVersionTest,
+    PgBinaryEncoding,
+    RolesTest,
+    SshKeysTest,
+};
+use sqlexec::backup::{open_backup_location, restore_database};
+use sqlexec::ddl::DdlOptions;
 use tokio::net::TcpListener;
 use tokio::runtime::{Builder, Runtime};
 
-use crate::args::server::ServerArgs;
-use crate::args::{LocalArgs, PgProxyArgs, RpcProxyArgs, SltArgs};
+use crate::args::server::{PasswordAuthMethod, ServerArgs};
+use crate::args::{
+    BackupArgs,
+    DumpArgs,
+    LocalArgs,
+    PgProxyArgs,
+    RestoreArgs,
+    RpcProxyArgs,
+    SltArgs,
+};
 use crate::built_info;
 use crate::local::LocalSession;
 use crate::proxy::{PgProxy, RpcProxy};
@@ -31,6 +56,12 @@ pub enum Commands {
     Local(LocalArgs),
     /// Starts the sql server portion of GlareDB.
     Server(ServerArgs),
+    /// Prints the statements that recreate the objects in the catalog.
+    Dump(DumpArgs),
+    /// Writes a backup of the catalog and native tables.
+    Backup(BackupArgs),
+    /// Restores a backup into an empty data directory or location.
+    Restore(RestoreArgs),
     /// Starts an instance of the pgsrv proxy.
     #[clap(hide = true)]
     PgProxy(PgProxyArgs),
@@ -47,6 +78,9 @@ impl Commands {
         match self {
             Commands::Local(local) => local.run(),
             Commands::Server(server) => server.run(),
+            Commands::Dump(dump) => dump.run(),
+            Commands::Backup(backup) => backup.run(),
+            Commands::Restore(restore) => restore.run(),
             Commands::PgProxy(pg_proxy) => pg_proxy.run(),
             Commands::RpcProxy(rpc_proxy) => rpc_proxy.run(),
             Commands::SqlLogicTests(slt) => slt.run(),
@@ -77,6 +111,11 @@ impl RunCommand for LocalArgs {
 
         let runtime = build_runtime("local")?;
         runtime.block_on(async move {
+            if let Some(file) = self.file {
+                let local = LocalSession::connect(self.opts).await?;
+                return local.run_file(&file).await;
+            }
+
             let query = match self.query {
                 Some(q) if q.to_ascii_lowercase() == "version" => {
                     return Err(anyhow!(
@@ -130,6 +169,74 @@ impl RunCommand for LocalArgs {
     }
 }
 
+impl RunCommand for DumpArgs {
+    fn run(self) -> Result<()> {
+        let runtime = build_runtime("dump")?;
+        runtime.block_on(async move {
+            let local = LocalSession::connect(self.opts).await?;
+            let dump = local.dump_catalog(DdlOptions {
+                include_secrets: self.include_secrets,
+            });
+
+            match self.output {
+                Some(path) => tokio::fs::write(path, dump).await?,
+                None => print!("{dump}"),
+            }
+            Ok(())
+        })
+    }
+}
+
+impl RunCommand for BackupArgs {
+    fn run(self) -> Result<()> {
+        let runtime = build_runtime("backup")?;
+        runtime.block_on(async move {
+            let options = self
+                .to_options
+                .into_iter()
+                .map(|(k, v)| (k, OptionValue::QuotedLiteral(v)))
+                .collect();
+            let stmt = BackupDatabaseStmt {
+                location: self.to,
+                options: StatementOptions::new(options),
+            };
+
+            let mut local = LocalSession::connect(self.opts).await?;
+            local.execute(&stmt.to_string()).await
+        })
+    }
+}
+
+impl RunCommand for RestoreArgs {
+    fn run(self) -> Result<()> {
+        let runtime = build_runtime("restore")?;
+        runtime.block_on(async move {
+            let dest = match (&self.storage_config.location, &self.data_dir) {
+                (Some(location), _) => open_backup_location(
+                    location,
+                    HashMap::from_iter(self.storage_config.storage_options.clone()),
+                )?,
+                (_, Some(path)) => open_backup_location(&path.to_string_lossy(), HashMap::new())?,
+                (None, None) => {
+                    return Err(anyhow!(
+                        "A data directory or location to restore into is required"
+                    ))
+                }
+            };
+            let src = open_backup_location(&self.from, HashMap::from_iter(self.from_options))?;
+
+            let db_id = SessionVars::default().database_id();
+            let manifest = restore_database(src, dest, db_id).await?;
+            println!(
+                "Restored catalog version {} with {} native tables",
+                manifest.catalog_version,
+                manifest.tables.len()
+            );
+            Ok(())
+        })
+    }
+}
+
 impl RunCommand for ServerArgs {
     fn run(self) -> Result<()> {
         // Map an empty string to None. Makes writing the terraform easier.
@@ -151,6 +258,11 @@ impl RunCommand for ServerArgs {
             Some(password) => Box::new(SingleUserAuthenticator {
                 user: self.user,
                 password,
+                password_mode: match self.password_auth {
+                    PasswordAuthMethod::Cleartext => PasswordMode::RequireCleartext,
+                    PasswordAuthMethod::Md5 => PasswordMode::RequireMd5,
+                    PasswordAuthMethod::ScramSha256 => PasswordMode::RequireScramSha256,
+                },
             }),
             None => Box::new(PasswordlessAuthenticator {
                 drop_auth_messages: self.ignore_pg_auth,
@@ -165,6 +277,10 @@ impl RunCommand for ServerArgs {
                 None if self.disable_postgres_api => None,
                 None => Some(TcpListener::bind(DEFAULT_PG_BIND_ADDR).await?),
             };
+            let ssl_conf = match (self.ssl_server_cert, self.ssl_server_key) {
+                (Some(cert), Some(key)) => Some(SslConfig::new(cert, key).await?),
+                _ => None,
+            };
             let rpc_listener = match self.rpc_bind {
                 Some(bind) => Some(TcpListener::bind(bind).await?),
                 None if self.enable_flight_api => {
@@ -172,11 +288,17 @@ impl RunCommand for ServerArgs {
                 }
                 None => None,
             };
+            let metrics_listener = match self.metrics_bind {
+                Some(bind) => Some(TcpListener::bind(bind).await?),
+                None => None,
+            };
 
             let server = ComputeServer::builder()
                 .with_authenticator(auth)
+                .with_ssl_conf_opt(ssl_conf)
                 .with_pg_listener_opt(pg_listener)
                 .with_rpc_listener_opt(rpc_listener)
+                .with_metrics_listener_opt(metrics_listener)
                 .with_segment_key_opt(segment_key)
                 .with_data_dir_opt(self.data_dir)
                 .with_service_account_path_opt(self.service_account_path)
@@ -243,6 +365,15 @@ impl RunCommand for SltArgs {
             // Rust tests
             .test("sqllogictests/ssh_keys", Box::new(SshKeysTest))?
             .test("pgproto/binary_encoding", Box::new(PgBinaryEncoding))?
+            .test("sqllogictests/roles_login", Box::new(RolesTest))?
+            .test(
+                "sqllogictests/catalog_history_pinned",
+                Box::new(CatalogAsOfVersionTest),
+            )?
+            .test(
+                "sqllogictests/analyze_join_order",
+                Box::new(AnalyzeJoinOrderTest),
+            )?
             // Add hooks
             .hook("*", Arc::new(AllTestsHook))?
             // Sqlite tests
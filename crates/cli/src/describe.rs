@@ -0,0 +1,511 @@
+//! psql style client commands for describing objects in the catalog.
+//!
+//! Each command is turned into a query against the `glare_catalog` tables, or
+//! the `list_*` functions for objects in external databases.
+
+use anyhow::{anyhow, Result};
+
+/// Name of the database holding all native objects.
+const DEFAULT_DATABASE: &str = "default";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DescribeKind {
+    /// `\d`: List relations, or describe the columns of matching relations
+    /// when given a pattern.
+    Relations,
+    /// `\dt`
+    Tables,
+    /// `\dv`
+    Views,
+    /// `\dn`
+    Schemas,
+    /// `\df`
+    Functions,
+    /// `\l`
+    Databases,
+    /// `\dx`: Tunnels and credentials.
+    Connections,
+}
+
+impl DescribeKind {
+    /// Max number of dot separated parts in a pattern for this kind of
+    /// object.
+    fn max_pattern_parts(&self) -> usize {
+        match self {
+            Self::Relations | Self::Tables | Self::Views => 3,
+            Self::Schemas => 2,
+            Self::Functions | Self::Databases | Self::Connections => 1,
+        }
+    }
+
+    fn plural(&self) -> &'static str {
+        match self {
+            Self::Relations => "relations",
+            Self::Tables => "tables",
+            Self::Views => "views",
+            Self::Schemas => "schemas",
+            Self::Functions => "functions",
+            Self::Databases => "databases",
+            Self::Connections => "tunnels or credentials",
+        }
+    }
+}
+
+/// A single dot separated part of a pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PatternPart {
+    /// Anchored regular expression matching the part.
+    regex: String,
+    /// The name to match if the part doesn't contain any wildcards.
+    literal: Option<String>,
+}
+
+impl PatternPart {
+    /// Parse a part of a pattern.
+    ///
+    /// Follows psql's rules: `*` matches any sequence of characters and `?`
+    /// matches a single character. Unquoted characters are folded to lower
+    /// case, while double quoted characters are matched as is with wildcards
+    /// taken literally.
+    fn parse(s: &str) -> Self {
+        let mut regex = String::from("^(");
+        let mut literal = String::new();
+        let mut has_wildcards = false;
+        let mut in_quotes = false;
+
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if in_quotes && chars.peek() == Some(&'"') => {
+                    chars.next();
+                    push_regex_literal(&mut regex, '"');
+                    literal.push('"');
+                }
+                '"' => in_quotes = !in_quotes,
+                '*' if !in_quotes => {
+                    has_wildcards = true;
+                    regex.push_str(".*");
+                }
+                '?' if !in_quotes => {
+                    has_wildcards = true;
+                    regex.push('.');
+                }
+                c if in_quotes => {
+                    push_regex_literal(&mut regex, c);
+                    literal.push(c);
+                }
+                c => {
+                    for c in c.to_lowercase() {
+                        push_regex_literal(&mut regex, c);
+                        literal.push(c);
+                    }
+                }
+            }
+        }
+        regex.push_str(")$");
+
+        PatternPart {
+            regex,
+            literal: if has_wildcards { None } else { Some(literal) },
+        }
+    }
+
+    /// Condition matching `column` against this part.
+    fn condition(&self, column: &str) -> String {
+        format!("{column} ~ {}", quote_literal(&self.regex))
+    }
+
+    fn require_literal(&self, what: &str) -> Result<&str> {
+        self.literal.as_deref().ok_or_else(|| {
+            anyhow!("Wildcards are not supported in {what} names of external databases")
+        })
+    }
+}
+
+fn push_regex_literal(regex: &mut String, c: char) {
+    if "\\.+*?()|[]{}^$".contains(c) {
+        regex.push('\\');
+    }
+    regex.push(c);
+}
+
+/// A pattern of the form `[[database.]schema.]name`.
+///
+/// Empty parts match everything, e.g. `myschema.` matches all objects in
+/// `myschema`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Pattern {
+    database: Option<PatternPart>,
+    schema: Option<PatternPart>,
+    name: Option<PatternPart>,
+}
+
+impl Pattern {
+    fn parse(s: &str, max_parts: usize) -> Result<Self> {
+        let mut parts = split_pattern(s)
+            .into_iter()
+            .map(|part| {
+                if part.is_empty() {
+                    None
+                } else {
+                    Some(PatternPart::parse(&part))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if parts.len() > max_parts {
+            return Err(anyhow!(
+                "Improper qualified name (too many dotted names): {s}"
+            ));
+        }
+
+        let name = parts.pop().flatten();
+        let schema = parts.pop().flatten();
+        let database = parts.pop().flatten();
+
+        Ok(Pattern {
+            database,
+            schema,
+            name,
+        })
+    }
+
+    /// Get the external database this pattern refers to, if any.
+    fn external_database(&self) -> Result<Option<&str>> {
+        match &self.database {
+            Some(part) => {
+                let database = part
+                    .literal
+                    .as_deref()
+                    .ok_or_else(|| anyhow!("Wildcards are not supported in database names"))?;
+                if database == DEFAULT_DATABASE {
+                    Ok(None)
+                } else {
+                    Ok(Some(database))
+                }
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// Split a pattern on dots outside of double quotes.
+fn split_pattern(s: &str) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut in_quotes = false;
+    for c in s.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                parts.last_mut().unwrap().push(c);
+            }
+            '.' if !in_quotes => parts.push(String::new()),
+            c => parts.last_mut().unwrap().push(c),
+        }
+    }
+    parts
+}
+
+/// A psql style command for describing objects, e.g. `\dt public.*`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DescribeCommand {
+    kind: DescribeKind,
+    /// Include builtin objects (commands suffixed with `S`).
+    builtin: bool,
+    /// Pattern as provided by the user.
+    input: Option<String>,
+    pattern: Option<Pattern>,
+}
+
+impl DescribeCommand {
+    /// Parse a describe command and its optional pattern.
+    ///
+    /// Returns `None` if the command isn't a describe command.
+    pub fn parse(cmd: &str, pattern: Option<&str>) -> Result<Option<Self>> {
+        let (kind, builtin) = match cmd {
+            "\\d" => (DescribeKind::Relations, false),
+            "\\dS" => (DescribeKind::Relations, true),
+            "\\dt" => (DescribeKind::Tables, false),
+            "\\dtS" => (DescribeKind::Tables, true),
+            "\\dv" => (DescribeKind::Views, false),
+            "\\dvS" => (DescribeKind::Views, true),
+            "\\dn" => (DescribeKind::Schemas, false),
+            "\\dnS" => (DescribeKind::Schemas, true),
+            // Almost all functions are builtin, always list them.
+            "\\df" | "\\dfS" => (DescribeKind::Functions, true),
+            "\\l" => (DescribeKind::Databases, true),
+            "\\dx" => (DescribeKind::Connections, false),
+            "\\dxS" => (DescribeKind::Connections, true),
+            _ => return Ok(None),
+        };
+
+        let parsed = pattern
+            .map(|p| Pattern::parse(p, kind.max_pattern_parts()))
+            .transpose()?
+            .map(|mut p| {
+                // Schemas are only qualified by their database.
+                if kind == DescribeKind::Schemas {
+                    p.database = p.schema.take();
+                }
+                p
+            });
+
+        Ok(Some(DescribeCommand {
+            kind,
+            builtin,
+            input: pattern.map(|p| p.to_string()),
+            pattern: parsed,
+        }))
+    }
+
+    /// Message to print if the query returned no rows.
+    pub fn not_found_message(&self) -> String {
+        match (&self.kind, &self.input) {
+            (DescribeKind::Relations, Some(input)) => {
+                format!("Did not find any relation named \"{input}\".")
+            }
+            (kind, Some(input)) => {
+                format!("Did not find any {} matching \"{input}\".", kind.plural())
+            }
+            (kind, None) => format!("Did not find any {}.", kind.plural()),
+        }
+    }
+
+    /// Build the query for this command.
+    pub fn to_sql(&self) -> Result<String> {
+        let external = match &self.pattern {
+            Some(pattern) => pattern.external_database()?,
+            None => None,
+        };
+        if let Some(database) = external {
+            return self.external_sql(database);
+        }
+
+        let schema = self.pattern.as_ref().and_then(|p| p.schema.as_ref());
+        let name = self.pattern.as_ref().and_then(|p| p.name.as_ref());
+
+        let sql = match self.kind {
+            DescribeKind::Relations if self.pattern.is_some() => {
+                let mut conds = vec![format!("s.database_name = '{DEFAULT_DATABASE}'")];
+                conds.extend(schema.map(|p| p.condition("s.schema_name")));
+                conds.extend(name.map(|p| p.condition("c.table_name")));
+                format!(
+                    "SELECT s.schema_name, c.table_name, c.column_name, c.data_type, c.is_nullable, c.comment \
+                     FROM glare_catalog.columns AS c \
+                     JOIN glare_catalog.schemas AS s ON c.schema_oid = s.oid{} \
+                     ORDER BY s.schema_name, c.table_name, c.column_ordinal",
+                    where_clause(conds),
+                )
+            }
+            DescribeKind::Relations => {
+                let filter = where_clause(self.builtin_filter());
+                format!(
+                    "SELECT schema_name, table_name AS name, 'table' AS kind FROM glare_catalog.tables{filter} \
+                     UNION ALL \
+                     SELECT schema_name, view_name AS name, 'view' AS kind FROM glare_catalog.views{filter} \
+                     ORDER BY schema_name, name"
+                )
+            }
+            DescribeKind::Tables => {
+                let mut conds = self.builtin_filter();
+                conds.extend(schema.map(|p| p.condition("schema_name")));
+                conds.extend(name.map(|p| p.condition("table_name")));
+                format!(
+                    "SELECT schema_name, table_name AS name, external, datasource, access_mode, comment \
+                     FROM glare_catalog.tables{} \
+                     ORDER BY schema_name, name",
+                    where_clause(conds),
+                )
+            }
+            DescribeKind::Views => {
+                let mut conds = self.builtin_filter();
+                conds.extend(schema.map(|p| p.condition("schema_name")));
+                conds.extend(name.map(|p| p.condition("view_name")));
+                format!(
+                    "SELECT schema_name, view_name AS name, sql, comment \
+                     FROM glare_catalog.views{} \
+                     ORDER BY schema_name, name",
+                    where_clause(conds),
+                )
+            }
+            DescribeKind::Schemas => {
+                let mut conds = vec![format!("database_name = '{DEFAULT_DATABASE}'")];
+                conds.extend(self.builtin_filter());
+                conds.extend(name.map(|p| p.condition("schema_name")));
+                format!(
+                    "SELECT schema_name AS name, builtin, comment \
+                     FROM glare_catalog.schemas{} \
+                     ORDER BY name",
+                    where_clause(conds),
+                )
+            }
+            DescribeKind::Functions => {
+                let conds = name.map(|p| p.condition("function_name"));
+                format!(
+                    "SELECT function_name AS name, function_type AS kind, parameters, description \
+                     FROM glare_catalog.functions{} \
+                     ORDER BY name",
+                    where_clause(conds),
+                )
+            }
+            DescribeKind::Databases => {
+                let conds = name.map(|p| p.condition("database_name"));
+                format!(
+                    "SELECT database_name AS name, external, datasource, access_mode, comment \
+                     FROM glare_catalog.databases{} \
+                     ORDER BY name",
+                    where_clause(conds),
+                )
+            }
+            DescribeKind::Connections => {
+                let mut tunnel_conds = self.builtin_filter();
+                tunnel_conds.extend(name.map(|p| p.condition("tunnel_name")));
+                let mut credentials_conds = self.builtin_filter();
+                credentials_conds.extend(name.map(|p| p.condition("credentials_name")));
+                format!(
+                    "SELECT 'tunnel' AS kind, tunnel_name AS name, tunnel_type AS provider, CAST(NULL AS TEXT) AS comment \
+                     FROM glare_catalog.tunnels{} \
+                     UNION ALL \
+                     SELECT 'credentials' AS kind, credentials_name AS name, provider, comment \
+                     FROM glare_catalog.credentials{} \
+                     ORDER BY kind, name",
+                    where_clause(tunnel_conds),
+                    where_clause(credentials_conds),
+                )
+            }
+        };
+
+        Ok(sql)
+    }
+
+    /// Build the query for objects in an external database.
+    fn external_sql(&self, database: &str) -> Result<String> {
+        // Only reachable with a pattern containing a database.
+        let pattern = self.pattern.as_ref().unwrap();
+
+        let sql = match self.kind {
+            DescribeKind::Schemas => {
+                let conds = pattern.name.as_ref().map(|p| p.condition("schema_name"));
+                format!(
+                    "SELECT schema_name AS name FROM list_schemas({}){} ORDER BY name",
+                    quote_literal(database),
+                    where_clause(conds),
+                )
+            }
+            DescribeKind::Relations | DescribeKind::Tables => {
+                let schema = pattern
+                    .schema
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("A schema is required for external databases"))?
+                    .require_literal("schema")?;
+
+                match (&self.kind, &pattern.name) {
+                    (DescribeKind::Relations, Some(name)) => format!(
+                        "SELECT column_name, data_type, nullable FROM list_columns({}, {}, {})",
+                        quote_literal(database),
+                        quote_literal(schema),
+                        quote_literal(name.require_literal("table")?),
+                    ),
+                    (_, name) => format!(
+                        "SELECT table_name AS name FROM list_tables({}, {}){} ORDER BY name",
+                        quote_literal(database),
+                        quote_literal(schema),
+                        where_clause(name.as_ref().map(|p| p.condition("table_name"))),
+                    ),
+                }
+            }
+            kind => {
+                return Err(anyhow!(
+                    "Listing {} is not supported for external databases",
+                    kind.plural()
+                ))
+            }
+        };
+
+        Ok(sql)
+    }
+
+    fn builtin_filter(&self) -> Vec<String> {
+        if self.builtin {
+            Vec::new()
+        } else {
+            vec!["NOT builtin".to_string()]
+        }
+    }
+}
+
+fn where_clause(conds: impl IntoIterator<Item = String>) -> String {
+    let conds = conds.into_iter().collect::<Vec<_>>();
+    if conds.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", conds.join(" AND "))
+    }
+}
+
+fn quote_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pattern_part() {
+        let part = PatternPart::parse("My_Table");
+        assert_eq!("^(my_table)$", part.regex);
+        assert_eq!(Some("my_table".to_string()), part.literal);
+
+        let part = PatternPart::parse("t*.?");
+        assert_eq!("^(t.*\\..)$", part.regex);
+        assert_eq!(None, part.literal);
+
+        let part = PatternPart::parse("\"My*\"\"Table\"");
+        assert_eq!("^(My\\*\"Table)$", part.regex);
+        assert_eq!(Some("My*\"Table".to_string()), part.literal);
+    }
+
+    #[test]
+    fn parse_qualified_pattern() {
+        let pattern = Pattern::parse("db.\"a.b\".t*", 3).unwrap();
+        assert_eq!(Some("db"), pattern.database.unwrap().literal.as_deref());
+        assert_eq!(Some("a.b"), pattern.schema.unwrap().literal.as_deref());
+        assert_eq!(None, pattern.name.unwrap().literal);
+
+        let pattern = Pattern::parse("public.", 3).unwrap();
+        assert!(pattern.schema.is_some());
+        assert!(pattern.name.is_none());
+
+        Pattern::parse("a.b", 1).unwrap_err();
+    }
+
+    #[test]
+    fn not_describe_command() {
+        assert_eq!(None, DescribeCommand::parse("\\timing", None).unwrap());
+    }
+
+    #[test]
+    fn external_queries() {
+        let cmd = DescribeCommand::parse("\\dt", Some("my_pg.public.*"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            "SELECT table_name AS name FROM list_tables('my_pg', 'public') WHERE table_name ~ '^(.*)$' ORDER BY name",
+            cmd.to_sql().unwrap()
+        );
+
+        let cmd = DescribeCommand::parse("\\d", Some("my_pg.public.users"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            "SELECT column_name, data_type, nullable FROM list_columns('my_pg', 'public', 'users')",
+            cmd.to_sql().unwrap()
+        );
+
+        let cmd = DescribeCommand::parse("\\d", Some("my_pg.pub*.users"))
+            .unwrap()
+            .unwrap();
+        cmd.to_sql().unwrap_err();
+    }
+}
@@ -0,0 +1,216 @@
+//! Text and binary output formats for query results.
+
+use std::io::Write;
+
+use anyhow::Result;
+use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::ipc::writer::StreamWriter;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::arrow::util::display::{ArrayFormatter, FormatOptions};
+use datafusion::parquet::arrow::ArrowWriter;
+
+fn format_options() -> FormatOptions<'static> {
+    FormatOptions::default()
+        .with_display_error(true)
+        .with_null("NULL")
+}
+
+/// Format every value of a batch as strings, row by row.
+fn format_rows(batch: &RecordBatch) -> Result<Vec<Vec<String>>> {
+    let opts = format_options();
+    let formatters = batch
+        .columns()
+        .iter()
+        .map(|col| ArrayFormatter::try_new(col.as_ref(), &opts))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let rows = (0..batch.num_rows())
+        .map(|row| {
+            formatters
+                .iter()
+                .map(|f| f.value(row).to_string())
+                .collect()
+        })
+        .collect();
+    Ok(rows)
+}
+
+/// Write batches as a GitHub flavored markdown table.
+///
+/// Numeric columns are right aligned.
+pub fn write_markdown(out: &mut dyn Write, schema: &Schema, batches: &[RecordBatch]) -> Result<()> {
+    fn escape(s: &str) -> String {
+        s.replace('|', "\\|").replace('\n', "<br>")
+    }
+
+    let names = schema
+        .fields()
+        .iter()
+        .map(|f| escape(f.name()))
+        .collect::<Vec<_>>();
+    writeln!(out, "| {} |", names.join(" | "))?;
+
+    let aligns = schema
+        .fields()
+        .iter()
+        .map(|f| {
+            if f.data_type().is_numeric() {
+                "---:"
+            } else {
+                "---"
+            }
+        })
+        .collect::<Vec<_>>();
+    writeln!(out, "|{}|", aligns.join("|"))?;
+
+    for batch in batches {
+        for row in format_rows(batch)? {
+            let row = row.iter().map(|v| escape(v)).collect::<Vec<_>>();
+            writeln!(out, "| {} |", row.join(" | "))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write batches as an HTML table.
+pub fn write_html(out: &mut dyn Write, schema: &Schema, batches: &[RecordBatch]) -> Result<()> {
+    fn escape(s: &str) -> String {
+        let mut escaped = String::with_capacity(s.len());
+        for c in s.chars() {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&#39;"),
+                c => escaped.push(c),
+            }
+        }
+        escaped
+    }
+
+    writeln!(out, "<table>")?;
+    write!(out, "<tr>")?;
+    for field in schema.fields() {
+        write!(out, "<th>{}</th>", escape(field.name()))?;
+    }
+    writeln!(out, "</tr>")?;
+
+    for batch in batches {
+        for row in format_rows(batch)? {
+            write!(out, "<tr>")?;
+            for val in row {
+                write!(out, "<td>{}</td>", escape(&val))?;
+            }
+            writeln!(out, "</tr>")?;
+        }
+    }
+    writeln!(out, "</table>")?;
+
+    Ok(())
+}
+
+/// Write batches with one value per line, and a blank line between rows.
+///
+/// Useful for rows too wide to display as a table.
+pub fn write_line(out: &mut dyn Write, schema: &Schema, batches: &[RecordBatch]) -> Result<()> {
+    let width = schema
+        .fields()
+        .iter()
+        .map(|f| f.name().chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut first = true;
+    for batch in batches {
+        for row in format_rows(batch)? {
+            if !first {
+                writeln!(out)?;
+            }
+            first = false;
+
+            for (field, val) in schema.fields().iter().zip(row) {
+                writeln!(out, "{: >width$} = {val}", field.name())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Write batches in the Arrow IPC stream format.
+pub fn write_arrow(out: &mut dyn Write, schema: &Schema, batches: &[RecordBatch]) -> Result<()> {
+    let mut writer = StreamWriter::try_new(out, schema)?;
+    for batch in batches {
+        writer.write(batch)?;
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+/// Write batches as a Parquet file.
+pub fn write_parquet(out: &mut dyn Write, schema: &Schema, batches: &[RecordBatch]) -> Result<()> {
+    // The parquet writer requires a `Send` writer, buffer the file in memory.
+    let mut buf = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buf, schema.clone().into(), None)?;
+    for batch in batches {
+        writer.write(batch)?;
+    }
+    writer.close()?;
+
+    out.write_all(&buf)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use datafusion::arrow::array::{Int64Array, StringArray};
+    use datafusion::arrow::datatypes::{DataType, Field};
+
+    use super::*;
+
+    fn test_batch() -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("a|<b>"), None])),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn render(
+        f: fn(&mut dyn Write, &Schema, &[RecordBatch]) -> Result<()>,
+        batch: RecordBatch,
+    ) -> String {
+        let mut buf = Vec::new();
+        f(&mut buf, &batch.schema(), &[batch]).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn markdown() {
+        let expected = "| id | name |\n|---:|---|\n| 1 | a\\|<b> |\n| 2 | NULL |\n";
+        assert_eq!(expected, render(write_markdown, test_batch()));
+    }
+
+    #[test]
+    fn html() {
+        let expected = "<table>\n<tr><th>id</th><th>name</th></tr>\n<tr><td>1</td><td>a|&lt;b&gt;</td></tr>\n<tr><td>2</td><td>NULL</td></tr>\n</table>\n";
+        assert_eq!(expected, render(write_html, test_batch()));
+    }
+
+    #[test]
+    fn line() {
+        let expected = "  id = 1\nname = a|<b>\n\n  id = 2\nname = NULL\n";
+        assert_eq!(expected, render(write_line, test_batch()));
+    }
+}
@@ -1,9 +1,12 @@
 pub mod args;
 pub mod commands;
+mod describe;
+mod format;
 mod highlighter;
 pub mod local;
 mod prompt;
 pub mod proxy;
+mod script;
 pub mod server;
 
 pub mod built_info {
@@ -1,7 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
-use std::io::Write;
-use std::path::PathBuf;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use anyhow::{anyhow, Result};
@@ -9,6 +11,7 @@ use arrow_util::pretty;
 use clap::ValueEnum;
 use colored::Colorize;
 use datafusion::arrow::csv::writer::WriterBuilder as CsvWriterBuilder;
+use datafusion::arrow::datatypes::SchemaRef;
 use datafusion::arrow::error::ArrowError;
 use datafusion::arrow::json::writer::{
     JsonFormat,
@@ -22,14 +25,18 @@ use futures::StreamExt;
 use pgrepr::format::Format;
 use pgrepr::notice::NoticeSeverity;
 use reedline::{FileBackedHistory, Reedline, Signal};
+use sqlexec::ddl::DdlOptions;
 use sqlexec::engine::{Engine, SessionStorageConfig, TrackedSession};
 use sqlexec::remote::client::{RemoteClient, RemoteClientType};
 use sqlexec::session::ExecutionResult;
 use url::Url;
 
 use crate::args::{LocalClientOpts, OutputMode, StorageConfigArgs};
+use crate::describe::DescribeCommand;
+use crate::format;
 use crate::highlighter::{SQLHighlighter, SQLHinter, SQLValidator};
 use crate::prompt::SQLPrompt;
+use crate::script::{is_variable_char, split_script, substitute_variables, ScriptItem};
 
 #[derive(Debug, Clone, Copy)]
 enum ClientCommandResult {
@@ -39,10 +46,56 @@ enum ClientCommandResult {
     Continue,
 }
 
+/// Variable controlling whether scripts stop at the first error.
+const ON_ERROR_STOP: &str = "ON_ERROR_STOP";
+
+/// Destination for query results, changed with `\o`.
+#[derive(Debug, Default)]
+struct Output {
+    file: Option<File>,
+    /// Whether a binary result set has already been written to this
+    /// destination.
+    wrote_binary: bool,
+}
+
+impl Output {
+    /// Check if results are written to an interactive terminal.
+    fn is_terminal(&self) -> bool {
+        self.file.is_none() && atty::is(atty::Stream::Stdout)
+    }
+
+    /// Change where results are written to.
+    fn set_file(&mut self, file: Option<File>) -> std::io::Result<()> {
+        self.flush()?;
+        self.file = file;
+        self.wrote_binary = false;
+        Ok(())
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match &mut self.file {
+            Some(file) => file.write(buf),
+            None => std::io::stdout().write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match &mut self.file {
+            Some(file) => file.flush(),
+            None => std::io::stdout().flush(),
+        }
+    }
+}
+
 pub struct LocalSession {
     sess: TrackedSession,
     _engine: Engine,
     opts: LocalClientOpts,
+    /// Variables set with `\set`, substituted into queries.
+    variables: BTreeMap<String, String>,
+    output: Output,
 }
 
 impl LocalSession {
@@ -96,7 +149,7 @@ impl LocalSession {
             sess.attach_remote_session(exec_client.clone(), None)
                 .await?;
 
-            println!("{}", info_msg);
+            print_status(opts.mode, info_msg);
 
             sess
         } else {
@@ -109,9 +162,16 @@ impl LocalSession {
             sess,
             _engine: engine,
             opts,
+            variables: BTreeMap::new(),
+            output: Output::default(),
         })
     }
 
+    /// Render the statements recreating the objects in the catalog.
+    pub fn dump_catalog(&self, opts: DdlOptions) -> String {
+        sqlexec::ddl::dump_catalog(self.sess.get_session_catalog(), opts)
+    }
+
     pub async fn run(mut self, query: Option<String>) -> Result<()> {
         if let Some(query) = query {
             self.execute_one(&query).await
@@ -120,6 +180,66 @@ impl LocalSession {
         }
     }
 
+    /// Run a script non-interactively, stopping at the first error.
+    pub async fn run_file(mut self, path: &Path) -> Result<()> {
+        self.variables
+            .insert(ON_ERROR_STOP.to_string(), "on".to_string());
+        self.run_script(path).await?;
+        Ok(())
+    }
+
+    /// Execute the commands and statements from a script file.
+    ///
+    /// Errors are printed and execution continues, unless `ON_ERROR_STOP` is
+    /// set, in which case the error is returned.
+    async fn run_script(&mut self, path: &Path) -> Result<ClientCommandResult> {
+        let script = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Unable to read '{}': {e}", path.display()))?;
+
+        for (line, item) in split_script(&script) {
+            let result = match item {
+                ScriptItem::Command(cmd) => self.handle_client_cmd(&cmd).await,
+                ScriptItem::Sql(sql) => {
+                    let result = self.execute(&sql).await;
+                    self.print_notices();
+                    result.map(|_| ClientCommandResult::Continue)
+                }
+            };
+
+            match result {
+                Ok(ClientCommandResult::Continue) => (),
+                Ok(ClientCommandResult::Exit) => return Ok(ClientCommandResult::Exit),
+                Err(e) if self.on_error_stop() => {
+                    return Err(anyhow!("{}:{line}: {e}", path.display()))
+                }
+                Err(e) => eprintln!("{}:{line}: Error: {e}", path.display()),
+            }
+        }
+
+        Ok(ClientCommandResult::Continue)
+    }
+
+    fn on_error_stop(&self) -> bool {
+        self.variables
+            .get(ON_ERROR_STOP)
+            .map(|v| matches!(v.to_lowercase().as_str(), "on" | "true" | "1"))
+            .unwrap_or(false)
+    }
+
+    /// Print out notices from the session.
+    fn print_notices(&mut self) {
+        for notice in self.sess.take_notices() {
+            eprintln!(
+                "{}: {}",
+                match notice.severity {
+                    s @ (NoticeSeverity::Warning | NoticeSeverity::Error) => s.to_string().red(),
+                    other => other.to_string().blue(),
+                },
+                notice.message
+            );
+        }
+    }
+
     async fn run_interactive(&mut self) -> Result<()> {
         match (&self.opts.storage_config, &self.opts.data_dir) {
             (
@@ -128,12 +248,21 @@ impl LocalSession {
                     ..
                 },
                 _,
-            ) => println!("Persisting database at location: {location}"),
-            (_, Some(path)) => println!("Persisting database at path: {}", path.display()),
+            ) => print_status(
+                self.opts.mode,
+                format!("Persisting database at location: {location}"),
+            ),
+            (_, Some(path)) => print_status(
+                self.opts.mode,
+                format!("Persisting database at path: {}", path.display()),
+            ),
             _ => (),
         };
 
-        println!("Type {} for help.", "\\help".bold().italic());
+        print_status(
+            self.opts.mode,
+            format!("Type {} for help.", "\\help".bold().italic()),
+        );
 
         let history = Box::new(
             FileBackedHistory::with_file(10000, get_history_path())
@@ -172,17 +301,7 @@ impl LocalSession {
                         // non-interactive fashion which and having notice
                         // messages interspersed with the output would be
                         // annoying.
-                        for notice in self.sess.take_notices() {
-                            eprintln!(
-                                "{}: {}",
-                                match notice.severity {
-                                    s @ (NoticeSeverity::Warning | NoticeSeverity::Error) =>
-                                        s.to_string().red(),
-                                    other => other.to_string().blue(),
-                                },
-                                notice.message
-                            );
-                        }
+                        self.print_notices();
                     }
                 },
                 Ok(Signal::CtrlD) => break,
@@ -214,7 +333,8 @@ impl LocalSession {
 
         const UNNAMED: String = String::new();
 
-        let statements = self.sess.parse_query(text)?;
+        let text = self.substitute_variables(text);
+        let statements = self.sess.parse_query(&text)?;
         for stmt in statements {
             self.sess
                 .prepare_statement(UNNAMED, stmt, Vec::new())
@@ -233,6 +353,7 @@ impl LocalSession {
             match stream {
                 ExecutionResult::Query { stream, .. } => {
                     print_stream(
+                        &mut self.output,
                         stream,
                         self.opts.mode,
                         self.opts.max_width,
@@ -245,19 +366,37 @@ impl LocalSession {
                 | ExecutionResult::DeleteSuccess { .. }
                 | ExecutionResult::InsertSuccess { .. }
                 | ExecutionResult::UpdateSuccess { .. }) => {
-                    println!("{}", res);
-                    print_time_elapsed(now);
+                    self.write_status(res)?;
+                    print_time_elapsed(self.opts.mode, now);
                 }
 
                 other => {
-                    println!("{}", other);
+                    self.write_status(other)?;
                 }
             }
         }
         Ok(())
     }
 
+    /// Write a command completion message to the output.
+    ///
+    /// Binary output modes only contain result sets, so the message is
+    /// printed to stderr instead.
+    fn write_status(&mut self, msg: impl Display) -> Result<()> {
+        if self.opts.mode.is_binary() {
+            eprintln!("{msg}");
+        } else {
+            writeln!(self.output, "{msg}")?;
+        }
+        Ok(())
+    }
+
+    fn substitute_variables(&self, text: &str) -> String {
+        substitute_variables(text, &self.variables)
+    }
+
     async fn handle_client_cmd(&mut self, text: &str) -> Result<ClientCommandResult> {
+        let text = self.substitute_variables(text.trim());
         let mut ss = text.split_whitespace();
         let cmd = ss.next().unwrap();
         let val = ss.next();
@@ -266,6 +405,38 @@ impl LocalSession {
             ("\\help", None) => {
                 print!("{}", LocalClientOpts::help_string()?);
             }
+            ("\\set", None) => {
+                for (name, value) in &self.variables {
+                    print_status(self.opts.mode, format!("{name} = '{value}'"));
+                }
+            }
+            ("\\set", Some(name)) => {
+                if !name.chars().all(is_variable_char) {
+                    return Err(anyhow!("Invalid variable name: {name}"));
+                }
+                // Everything after the name is the value.
+                let value = text[cmd.len()..].trim_start()[name.len()..].trim();
+                let value = value
+                    .strip_prefix('\'')
+                    .and_then(|v| v.strip_suffix('\''))
+                    .map(|v| v.replace("''", "'"))
+                    .unwrap_or_else(|| value.to_string());
+                self.variables.insert(name.to_string(), value);
+            }
+            ("\\unset", Some(name)) => {
+                self.variables.remove(name);
+            }
+            ("\\i" | "\\include", Some(path)) => {
+                return Box::pin(self.run_script(Path::new(path))).await;
+            }
+            ("\\o" | "\\out", None) => {
+                self.output.set_file(None)?;
+            }
+            ("\\o" | "\\out", Some(path)) => {
+                let file = File::create(path)
+                    .map_err(|e| anyhow!("Unable to open output file '{path}': {e}"))?;
+                self.output.set_file(Some(file))?;
+            }
             ("\\mode", Some(val)) => {
                 self.opts.mode = OutputMode::from_str(val, true)
                     .map_err(|s| anyhow!("Unable to set output mode: {s}"))?;
@@ -280,7 +451,7 @@ impl LocalSession {
                         ..self.opts.clone()
                     };
                     let new_sess = LocalSession::connect(new_opts).await?;
-                    *self = new_sess;
+                    self.replace_session(new_sess);
                 } else {
                     let new_opts = LocalClientOpts {
                         data_dir: Some(PathBuf::from(path)),
@@ -288,21 +459,62 @@ impl LocalSession {
                         ..self.opts.clone()
                     };
                     let new_sess = LocalSession::connect(new_opts).await?;
-                    *self = new_sess;
+                    self.replace_session(new_sess);
                 }
             }
             ("\\timing", None) => {
                 self.opts.timing = !self.opts.timing;
-                println!("Timing is {}", if self.opts.timing { "on" } else { "off" })
+                print_status(
+                    self.opts.mode,
+                    format!("Timing is {}", if self.opts.timing { "on" } else { "off" }),
+                )
             }
             ("\\quit", None) | ("\\q", None) | ("exit", None) => {
                 return Ok(ClientCommandResult::Exit)
             }
-            (cmd, _) => return Err(anyhow!("Unable to handle client command: {cmd}")),
+            (cmd, pattern) => match DescribeCommand::parse(cmd, pattern)? {
+                Some(describe) => self.describe(&describe).await?,
+                None => return Err(anyhow!("Unable to handle client command: {cmd}")),
+            },
         }
 
         Ok(ClientCommandResult::Continue)
     }
+
+    /// Replace the current session with a new one, keeping variables and
+    /// the output destination.
+    fn replace_session(&mut self, new_sess: LocalSession) {
+        let variables = std::mem::take(&mut self.variables);
+        let output = std::mem::take(&mut self.output);
+        *self = LocalSession {
+            variables,
+            output,
+            ..new_sess
+        };
+    }
+
+    /// Run a describe command, printing the matching objects.
+    async fn describe(&mut self, cmd: &DescribeCommand) -> Result<()> {
+        let stream = self.sess.execute_sql(&cmd.to_sql()?).await?;
+        let schema = stream.schema();
+        let batches = process_stream(stream).await?;
+
+        // Only print a message in table mode to keep other formats parseable.
+        if matches!(self.opts.mode, OutputMode::Table) && batches.iter().all(|b| b.num_rows() == 0)
+        {
+            writeln!(self.output, "{}", cmd.not_found_message())?;
+            return Ok(());
+        }
+
+        print_batches(
+            &mut self.output,
+            &schema,
+            &batches,
+            self.opts.mode,
+            self.opts.max_width,
+            self.opts.max_rows,
+        )
+    }
 }
 
 async fn process_stream(stream: SendableRecordBatchStream) -> Result<Vec<RecordBatch>> {
@@ -315,6 +527,7 @@ async fn process_stream(stream: SendableRecordBatchStream) -> Result<Vec<RecordB
 }
 
 async fn print_stream(
+    out: &mut Output,
     stream: SendableRecordBatchStream,
     mode: OutputMode,
     max_width: Option<usize>,
@@ -323,10 +536,22 @@ async fn print_stream(
 ) -> Result<()> {
     let schema = stream.schema();
     let batches = process_stream(stream).await?;
+    print_batches(out, &schema, &batches, mode, max_width, max_rows)?;
+    print_time_elapsed(mode, maybe_now);
 
-    fn write_json<F: JsonFormat>(batches: &[RecordBatch]) -> Result<()> {
-        let stdout = std::io::stdout();
-        let buf = std::io::BufWriter::new(stdout);
+    Ok(())
+}
+
+fn print_batches(
+    out: &mut Output,
+    schema: &SchemaRef,
+    batches: &[RecordBatch],
+    mode: OutputMode,
+    max_width: Option<usize>,
+    max_rows: Option<usize>,
+) -> Result<()> {
+    fn write_json<F: JsonFormat>(out: &mut dyn Write, batches: &[RecordBatch]) -> Result<()> {
+        let buf = BufWriter::new(out);
         let mut writer = JsonWriter::<_, F>::new(buf);
         for batch in batches {
             writer.write(batch)?;
@@ -342,28 +567,59 @@ async fn print_stream(
             // If width not explicitly set by the user, try to get the width of ther
             // terminal.
             let width = max_width.unwrap_or(terminal_util::term_width());
-            let disp = pretty::pretty_format_batches(&schema, &batches, Some(width), max_rows)?;
-            println!("{disp}");
+            let disp = pretty::pretty_format_batches(schema, batches, Some(width), max_rows)?;
+            writeln!(out, "{disp}")?;
         }
         OutputMode::Csv => {
-            let stdout = std::io::stdout();
-            let buf = std::io::BufWriter::new(stdout);
+            let buf = BufWriter::new(out);
             let mut writer = CsvWriterBuilder::new().with_header(true).build(buf);
             for batch in batches {
-                writer.write(&batch)?; // CSV writer flushes per write.
+                writer.write(batch)?; // CSV writer flushes per write.
             }
         }
-        OutputMode::Json => write_json::<JsonArrayNewLines>(&batches)?,
-        OutputMode::Ndjson => write_json::<JsonLineDelimted>(&batches)?,
+        OutputMode::Json => write_json::<JsonArrayNewLines>(out, batches)?,
+        OutputMode::Ndjson => write_json::<JsonLineDelimted>(out, batches)?,
+        OutputMode::Markdown => format::write_markdown(out, schema, batches)?,
+        OutputMode::Html => format::write_html(out, schema, batches)?,
+        OutputMode::Line => format::write_line(out, schema, batches)?,
+        OutputMode::Arrow | OutputMode::Parquet if out.is_terminal() => {
+            return Err(anyhow!(
+                "Refusing to write binary output to a terminal, redirect stdout or use \\o to write to a file"
+            ))
+        }
+        OutputMode::Arrow | OutputMode::Parquet if out.wrote_binary => {
+            return Err(anyhow!(
+                "Binary output only supports a single result set, use \\o to write each result to its own file"
+            ))
+        }
+        OutputMode::Arrow => {
+            format::write_arrow(out, schema, batches)?;
+            out.wrote_binary = true;
+        }
+        OutputMode::Parquet => {
+            format::write_parquet(out, schema, batches)?;
+            out.wrote_binary = true;
+        }
     }
-    print_time_elapsed(maybe_now);
 
     Ok(())
 }
 
-pub(crate) fn print_time_elapsed(maybe_now: Option<Instant>) {
+pub(crate) fn print_time_elapsed(mode: OutputMode, maybe_now: Option<Instant>) {
     if let Some(now) = maybe_now {
-        println!("Time: {:.3}s", now.elapsed().as_secs_f64());
+        print_status(mode, format!("Time: {:.3}s", now.elapsed().as_secs_f64()));
+    }
+}
+
+/// Print a message that isn't part of a result set.
+///
+/// Binary output modes may be written to stdout, so messages are printed to
+/// stderr instead to keep the output parseable.
+fn print_status(mode: OutputMode, msg: impl Display) {
+    if mode.is_binary() {
+        eprintln!("{msg}");
+    } else {
+        println!("{msg}");
     }
 }
 
@@ -0,0 +1,240 @@
+//! Support for running scripts and substituting variables, similar to psql.
+
+use std::collections::HashMap;
+
+/// A single item of a script to execute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptItem {
+    /// A client command, e.g. `\set foo bar`.
+    Command(String),
+    /// SQL text containing one or more statements.
+    Sql(String),
+}
+
+/// Tracks quoting state across lines of a script.
+#[derive(Debug, Default, Clone, Copy)]
+struct ScanState {
+    in_string: bool,
+    in_identifier: bool,
+    in_block_comment: bool,
+}
+
+impl ScanState {
+    fn is_quoted(&self) -> bool {
+        self.in_string || self.in_identifier || self.in_block_comment
+    }
+
+    /// Scan a line, returning if the line ends a statement, i.e. the last
+    /// character outside of quotes and comments is a semicolon.
+    fn scan_line(&mut self, line: &str) -> bool {
+        let mut ends_statement = false;
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            if self.in_block_comment {
+                if c == '*' && chars.peek() == Some(&'/') {
+                    chars.next();
+                    self.in_block_comment = false;
+                }
+                continue;
+            }
+            if self.in_string {
+                // Escaped quotes ('') toggle twice.
+                if c == '\'' {
+                    self.in_string = false;
+                }
+                continue;
+            }
+            if self.in_identifier {
+                if c == '"' {
+                    self.in_identifier = false;
+                }
+                continue;
+            }
+
+            match c {
+                '-' if chars.peek() == Some(&'-') => break,
+                '/' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    self.in_block_comment = true;
+                }
+                '\'' => {
+                    self.in_string = true;
+                    ends_statement = false;
+                }
+                '"' => {
+                    self.in_identifier = true;
+                    ends_statement = false;
+                }
+                ';' => ends_statement = true,
+                c if c.is_whitespace() => (),
+                _ => ends_statement = false,
+            }
+        }
+        ends_statement && !self.is_quoted()
+    }
+}
+
+/// Split a script into client commands and SQL statements, along with the
+/// (1-based) line number each item starts on.
+///
+/// Client commands must be on their own line, and are only recognized
+/// outside of SQL statements.
+pub fn split_script(script: &str) -> Vec<(usize, ScriptItem)> {
+    let mut items = Vec::new();
+    let mut state = ScanState::default();
+    let mut buf = String::new();
+    let mut start = 0;
+
+    for (idx, line) in script.lines().enumerate() {
+        let line_num = idx + 1;
+
+        if buf.trim().is_empty() {
+            let trimmed = line.trim();
+            if trimmed.starts_with('\\') {
+                items.push((line_num, ScriptItem::Command(trimmed.to_string())));
+                continue;
+            }
+            buf.clear();
+            start = line_num;
+        }
+
+        buf.push_str(line);
+        buf.push('\n');
+
+        if state.scan_line(line) {
+            items.push((start, ScriptItem::Sql(std::mem::take(&mut buf))));
+        }
+    }
+
+    if !buf.trim().is_empty() {
+        items.push((start, ScriptItem::Sql(buf)));
+    }
+
+    items
+}
+
+/// Substitute variables in some text.
+///
+/// `:name` is replaced with the value of the variable as is, `:'name'` with
+/// the value as a string literal, and `:"name"` with the value as a quoted
+/// identifier. References to variables that aren't set, and anything inside
+/// quotes are left as is.
+pub fn substitute_variables(text: &str, vars: &HashMap<String, String>) -> String {
+    if vars.is_empty() {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut quote: Option<char> = None;
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            out.push(c);
+            continue;
+        }
+
+        match c {
+            '\'' | '"' => {
+                quote = Some(c);
+                out.push(c);
+            }
+            // Casts, e.g. `'1'::int`.
+            ':' if rest.starts_with(':') => {
+                out.push_str("::");
+                rest = &rest[1..];
+            }
+            ':' => match parse_variable_ref(rest) {
+                Some((name, wrap, len)) if vars.contains_key(name) => {
+                    let value = &vars[name];
+                    match wrap {
+                        Some('\'') => out.push_str(&format!("'{}'", value.replace('\'', "''"))),
+                        Some('"') => out.push_str(&format!("\"{}\"", value.replace('"', "\"\""))),
+                        _ => out.push_str(value),
+                    }
+                    rest = &rest[len..];
+                }
+                _ => out.push(c),
+            },
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Parse a variable reference following a colon, returning the name, the
+/// quote character wrapping the name if any, and the number of bytes
+/// consumed.
+fn parse_variable_ref(s: &str) -> Option<(&str, Option<char>, usize)> {
+    let (wrap, inner) = match s.chars().next()? {
+        q @ ('\'' | '"') => (Some(q), &s[1..]),
+        _ => (None, s),
+    };
+
+    let len = inner
+        .find(|c: char| !is_variable_char(c))
+        .unwrap_or(inner.len());
+    if len == 0 {
+        return None;
+    }
+    let name = &inner[..len];
+
+    match wrap {
+        Some(q) if inner[len..].starts_with(q) => Some((name, wrap, len + 2)),
+        Some(_) => None,
+        None => Some((name, None, len)),
+    }
+}
+
+/// Check if a character is valid in a variable name.
+pub fn is_variable_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_statements_and_commands() {
+        let script = "\\set a 1\nselect 1;\nselect\n  'a;\nb'; -- comment;\n\\o out.txt\nselect 2";
+        let items = split_script(script);
+
+        assert_eq!(
+            vec![
+                (1, ScriptItem::Command("\\set a 1".to_string())),
+                (2, ScriptItem::Sql("select 1;\n".to_string())),
+                (
+                    3,
+                    ScriptItem::Sql("select\n  'a;\nb'; -- comment;\n".to_string())
+                ),
+                (6, ScriptItem::Command("\\o out.txt".to_string())),
+                (7, ScriptItem::Sql("select 2\n".to_string())),
+            ],
+            items
+        );
+    }
+
+    #[test]
+    fn substitute() {
+        let vars = HashMap::from([
+            ("tbl".to_string(), "my_table".to_string()),
+            ("name".to_string(), "it's".to_string()),
+        ]);
+
+        assert_eq!(
+            "select * from my_table where a = 'it''s' and b = ':tbl'",
+            substitute_variables("select * from :tbl where a = :'name' and b = ':tbl'", &vars)
+        );
+        assert_eq!(
+            "select \"my_table\", '1'::int, :missing",
+            substitute_variables("select :\"tbl\", '1'::int, :missing", &vars)
+        );
+    }
+}
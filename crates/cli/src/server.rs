@@ -1,20 +1,26 @@
 use std::collections::HashMap;
+use std::convert::Infallible;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::{env, fs};
 
 use anyhow::{anyhow, Result};
+use hyper::header::CONTENT_TYPE;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, StatusCode};
 use ioutil::ensure_dir;
 use metastore::local::start_inprocess;
 use object_store_util::conf::StorageConfig;
 use pgsrv::auth::LocalAuthenticator;
 use pgsrv::handler::{ProtocolHandler, ProtocolHandlerConfig};
+use pgsrv::ssl::SslConfig;
 use protogen::gen::rpcsrv::service::execution_service_server::ExecutionServiceServer;
 use protogen::gen::rpcsrv::simple::simple_service_server::SimpleServiceServer;
 use rpcsrv::flight::handler::{FlightServiceServer, FlightSessionHandler};
 use rpcsrv::handler::RpcHandler;
 use rpcsrv::simple::SimpleHandler;
 use sqlexec::engine::{Engine, EngineStorageConfig};
+use telemetry::metrics::{gather_text, ACTIVE_SESSIONS, TEXT_CONTENT_TYPE};
 use telemetry::{SegmentTracker, Tracker};
 use tokio::net::TcpListener;
 use tokio::signal;
@@ -40,6 +46,7 @@ pub struct ComputeServer {
     engine: Arc<Engine>,
     pg_config: Option<PostgresProtocolConfig>,
     rpc_listener: Option<TcpListener>,
+    metrics_listener: Option<TcpListener>,
 }
 
 pub struct ComputeServerBuilder {
@@ -47,8 +54,11 @@ pub struct ComputeServerBuilder {
     pg_listener: Option<TcpListener>,
     /// Listener to use for rpc handler.
     rpc_listener: Option<TcpListener>,
+    /// Listener to use for serving prometheus metrics.
+    metrics_listener: Option<TcpListener>,
     segment_key: Option<String>,
     authenticator: Option<Box<dyn LocalAuthenticator>>,
+    ssl_conf: Option<SslConfig>,
     data_dir: Option<PathBuf>,
     service_account_path: Option<String>,
     location: Option<String>,
@@ -66,8 +76,10 @@ impl ComputeServerBuilder {
         ComputeServerBuilder {
             pg_listener: None,
             rpc_listener: None,
+            metrics_listener: None,
             segment_key: None,
             authenticator: None,
+            ssl_conf: None,
             data_dir: None,
             service_account_path: None,
             location: None,
@@ -87,6 +99,12 @@ impl ComputeServerBuilder {
         self
     }
 
+    /// Optionally set the SSL/TLS configuration to use for the pg handler.
+    pub fn with_ssl_conf_opt(mut self, ssl_conf: Option<SslConfig>) -> Self {
+        self.ssl_conf = ssl_conf;
+        self
+    }
+
     /// Add a tcp listener to use for serving over the pg protocol.
     pub fn with_pg_listener(mut self, pg_listener: TcpListener) -> Self {
         self.pg_listener = Some(pg_listener);
@@ -111,6 +129,12 @@ impl ComputeServerBuilder {
         self
     }
 
+    /// Optionally add a tcp listener to use for serving prometheus metrics.
+    pub fn with_metrics_listener_opt(mut self, metrics_listener: Option<TcpListener>) -> Self {
+        self.metrics_listener = metrics_listener;
+        self
+    }
+
     pub fn with_segment_key(mut self, segment_key: String) -> Self {
         self.segment_key = Some(segment_key);
         self
@@ -225,10 +249,7 @@ impl ComputeServerBuilder {
         let pg_config = if let Some(listener) = self.pg_listener {
             let handler_conf = ProtocolHandlerConfig {
                 authenticator: self.authenticator.unwrap(),
-                // TODO: Allow specifying SSL/TLS on the GlareDB side as well. I
-                // want to hold off on doing that until we have a shared config
-                // between the proxy and GlareDB.
-                ssl_conf: None,
+                ssl_conf: self.ssl_conf,
                 integration_testing: self.integration_testing,
             };
             let pg_handler = Arc::new(ProtocolHandler::new(engine.clone(), handler_conf));
@@ -249,6 +270,7 @@ impl ComputeServerBuilder {
             pg_config,
             engine,
             rpc_listener: self.rpc_listener,
+            metrics_listener: self.metrics_listener,
         })
     }
 
@@ -385,10 +407,19 @@ impl ComputeServer {
             "".to_string()
         };
 
+        let metrics_msg = if let Some(listener) = &self.metrics_listener {
+            format!(
+                "Metrics available at: http://{}/metrics",
+                listener.local_addr()?
+            )
+        } else {
+            "".to_string()
+        };
+
         info!(
             "Starting GlareDB {}\n{}",
             env!("CARGO_PKG_VERSION"),
-            [rpc_msg, pg_msg].join("\n"),
+            [rpc_msg, pg_msg, metrics_msg].join("\n"),
         );
 
         // Shutdown handler.
@@ -396,6 +427,16 @@ impl ComputeServer {
         let engine = self.engine.clone();
         let mut rx = spawn_shutdown_handler(engine, self.integration_testing);
 
+        // Start metrics service.
+        if let Some(listener) = self.metrics_listener {
+            let engine = self.engine.clone();
+            tokio::spawn(async move {
+                if let Err(e) = serve_metrics(listener, engine).await {
+                    error!(%e, "metrics service died");
+                }
+            });
+        }
+
         // Start rpc service.
         if self.rpc_listener.is_some() {
             let server = self.build_rpc_service();
@@ -447,6 +488,41 @@ impl ComputeServer {
     }
 }
 
+/// Serve prometheus metrics over HTTP on the `/metrics` path.
+async fn serve_metrics(listener: TcpListener, engine: Arc<Engine>) -> Result<()> {
+    let make_service = make_service_fn(move |_conn| {
+        let engine = engine.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |req| {
+                let engine = engine.clone();
+                async move { Ok::<_, Infallible>(metrics_response(&req, &engine)) }
+            }))
+        }
+    });
+
+    hyper::Server::from_tcp(listener.into_std()?)?
+        .serve(make_service)
+        .await?;
+    Ok(())
+}
+
+fn metrics_response(req: &Request<Body>, engine: &Engine) -> Response<Body> {
+    if req.method() != Method::GET || req.uri().path() != "/metrics" {
+        return Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Body::empty())
+            .unwrap();
+    }
+
+    // Gauges derived from engine state are updated on scrape.
+    ACTIVE_SESSIONS.set(engine.session_count() as i64);
+
+    Response::builder()
+        .header(CONTENT_TYPE, TEXT_CONTENT_TYPE)
+        .body(Body::from(gather_text()))
+        .unwrap()
+}
+
 fn spawn_shutdown_handler(
     engine: Arc<Engine>,
     is_integration_testing: bool,
@@ -489,7 +565,9 @@ fn spawn_shutdown_handler(
 mod tests {
     use std::time::Duration;
 
-    use pgsrv::auth::SingleUserAuthenticator;
+    use pgsrv::auth::{PasswordMode, SingleUserAuthenticator};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
     use tokio_postgres::{Config as ClientConfig, NoTls};
 
     use super::*;
@@ -515,6 +593,7 @@ mod tests {
             .with_authenticator(SingleUserAuthenticator {
                 user: "glaredb".to_string(),
                 password: "glaredb".to_string(),
+                password_mode: PasswordMode::RequireCleartext,
             })
             .with_pg_listener(pg_listener)
             .with_rpc_listener(rpc_listener)
@@ -546,4 +625,119 @@ mod tests {
             .unwrap() // Timeout error
             .unwrap(); // Query error
     }
+
+    #[tokio::test]
+    async fn serve_prometheus_metrics() {
+        let pg_listener = TcpListener::bind("localhost:0").await.unwrap();
+        let metrics_listener = TcpListener::bind("localhost:0").await.unwrap();
+
+        let pg_addr = pg_listener.local_addr().unwrap();
+        let metrics_addr = metrics_listener.local_addr().unwrap();
+
+        let server = ComputeServer::builder()
+            .with_authenticator(SingleUserAuthenticator {
+                user: "glaredb".to_string(),
+                password: "glaredb".to_string(),
+                password_mode: PasswordMode::RequireCleartext,
+            })
+            .with_pg_listener(pg_listener)
+            .with_metrics_listener_opt(Some(metrics_listener))
+            .connect()
+            .await
+            .unwrap();
+
+        tokio::spawn(server.serve());
+
+        let (client, conn) = ClientConfig::new()
+            .user("glaredb")
+            .password("glaredb")
+            .dbname("glaredb")
+            .host("localhost")
+            .port(pg_addr.port())
+            .connect(NoTls)
+            .await
+            .unwrap();
+        tokio::spawn(conn);
+        client.simple_query("select 1").await.unwrap();
+
+        let get = |path: &'static str| async move {
+            let mut stream = TcpStream::connect(metrics_addr).await.unwrap();
+            stream
+                .write_all(format!("GET {path} HTTP/1.0\r\n\r\n").as_bytes())
+                .await
+                .unwrap();
+            let mut resp = String::new();
+            stream.read_to_string(&mut resp).await.unwrap();
+            resp
+        };
+
+        let resp = tokio::time::timeout(Duration::from_secs(5), get("/metrics"))
+            .await
+            .unwrap();
+        assert!(resp.starts_with("HTTP/1.0 200"), "response: {resp}");
+        assert!(
+            resp.contains("glaredb_active_sessions 1"),
+            "response: {resp}"
+        );
+        assert!(
+            resp.contains(r#"glaredb_queries_total{result_type="query",status="success"}"#),
+            "response: {resp}"
+        );
+
+        let resp = tokio::time::timeout(Duration::from_secs(5), get("/missing"))
+            .await
+            .unwrap();
+        assert!(resp.starts_with("HTTP/1.0 404"), "response: {resp}");
+    }
+
+    #[tokio::test]
+    async fn password_auth_modes() {
+        for mode in [
+            PasswordMode::RequireCleartext,
+            PasswordMode::RequireMd5,
+            PasswordMode::RequireScramSha256,
+        ] {
+            let pg_listener = TcpListener::bind("localhost:0").await.unwrap();
+            let pg_addr = pg_listener.local_addr().unwrap();
+
+            let server = ComputeServer::builder()
+                .with_authenticator(SingleUserAuthenticator {
+                    user: "glaredb".to_string(),
+                    password: "glaredb".to_string(),
+                    password_mode: mode,
+                })
+                .with_pg_listener(pg_listener)
+                .connect()
+                .await
+                .unwrap();
+
+            tokio::spawn(server.serve());
+
+            let connect = |password: &'static str| async move {
+                let mut config = ClientConfig::new();
+                config
+                    .user("glaredb")
+                    .password(password)
+                    .dbname("glaredb")
+                    .host("localhost")
+                    .port(pg_addr.port());
+                tokio::time::timeout(Duration::from_secs(5), config.connect(NoTls)).await
+            };
+
+            let result = connect("wrong").await.unwrap(); // Timeout error
+            assert!(result.is_err(), "connected with wrong password ({mode:?})");
+
+            let (client, conn) = connect("glaredb")
+                .await
+                .unwrap() // Timeout error
+                .unwrap(); // Connect error
+
+            tokio::spawn(conn);
+
+            tokio::time::timeout(Duration::from_secs(5), client.simple_query("select 1"))
+                .await
+                .unwrap() // Timeout error
+                .unwrap(); // Query error
+        }
+    }
 }
@@ -1,7 +1,9 @@
+use std::collections::HashMap;
 use std::fmt::{self, Display};
 
 use async_trait::async_trait;
 use catalog::session_catalog::SessionCatalog;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use datafusion::arrow::datatypes::{Field, Fields};
 use datafusion::execution::context::SessionState;
 use datafusion::prelude::SessionContext;
@@ -526,3 +528,170 @@ impl TryFrom<FuncParamValue> for Decimal128 {
         }
     }
 }
+
+/// A previous version of a table to read (time travel).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TableVersion {
+    /// A specific commit version.
+    Version(i64),
+    /// The latest version committed at or before this timestamp.
+    Timestamp(DateTime<Utc>),
+}
+
+impl TableVersion {
+    /// Named argument selecting a commit version, e.g. `version => 12`.
+    pub const VERSION_OPT: &'static str = "version";
+    /// Named argument selecting a timestamp, e.g. `as_of => '2024-01-01'`.
+    pub const AS_OF_OPT: &'static str = "as_of";
+
+    /// Create a version from a parameter value holding a commit version.
+    pub fn try_from_version(value: FuncParamValue) -> Result<Self> {
+        let version: i64 = value.try_into()?;
+        if version < 0 {
+            return Err(ExtensionError::InvalidParamValue {
+                param: version.to_string(),
+                expected: "non-negative table version",
+            });
+        }
+        Ok(Self::Version(version))
+    }
+
+    /// Create a version from a parameter value holding a timestamp, either
+    /// as a timestamp scalar or a string.
+    pub fn try_from_timestamp(value: FuncParamValue) -> Result<Self> {
+        let invalid = |param: String| ExtensionError::InvalidParamValue {
+            param,
+            expected: "timestamp",
+        };
+
+        let ts = match value {
+            FuncParamValue::Scalar(ScalarValue::Utf8(Some(s)))
+            | FuncParamValue::Scalar(ScalarValue::LargeUtf8(Some(s))) => {
+                parse_timestamp(&s).ok_or_else(|| invalid(s))?
+            }
+            FuncParamValue::Scalar(ScalarValue::TimestampSecond(Some(v), _)) => {
+                DateTime::from_timestamp(v, 0).ok_or_else(|| invalid(v.to_string()))?
+            }
+            FuncParamValue::Scalar(ScalarValue::TimestampMillisecond(Some(v), _)) => {
+                DateTime::from_timestamp_millis(v).ok_or_else(|| invalid(v.to_string()))?
+            }
+            FuncParamValue::Scalar(ScalarValue::TimestampMicrosecond(Some(v), _)) => {
+                DateTime::from_timestamp_micros(v).ok_or_else(|| invalid(v.to_string()))?
+            }
+            FuncParamValue::Scalar(ScalarValue::TimestampNanosecond(Some(v), _)) => {
+                DateTime::from_timestamp_nanos(v)
+            }
+            other => return Err(invalid(other.to_string())),
+        };
+
+        Ok(Self::Timestamp(ts))
+    }
+
+    /// Remove the version from a function's named arguments, if one was
+    /// provided.
+    pub fn take_from_opts(opts: &mut HashMap<String, FuncParamValue>) -> Result<Option<Self>> {
+        let version = opts.remove(Self::VERSION_OPT);
+        let as_of = opts.remove(Self::AS_OF_OPT);
+
+        match (version, as_of) {
+            (None, None) => Ok(None),
+            (Some(version), None) => Self::try_from_version(version).map(Some),
+            (None, Some(as_of)) => Self::try_from_timestamp(as_of).map(Some),
+            (Some(_), Some(_)) => Err(ExtensionError::String(format!(
+                "Only one of '{}' or '{}' may be provided",
+                Self::VERSION_OPT,
+                Self::AS_OF_OPT
+            ))),
+        }
+    }
+
+    /// Named arguments representing this version.
+    ///
+    /// Used to send the version along with table references to remote nodes.
+    pub fn into_opts(self) -> HashMap<String, FuncParamValue> {
+        let (key, value) = match self {
+            Self::Version(v) => (Self::VERSION_OPT, ScalarValue::Int64(Some(v))),
+            Self::Timestamp(ts) => (Self::AS_OF_OPT, ScalarValue::Utf8(Some(ts.to_rfc3339()))),
+        };
+        HashMap::from([(key.to_string(), FuncParamValue::Scalar(value))])
+    }
+}
+
+impl fmt::Display for TableVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Version(v) => write!(f, "version {v}"),
+            Self::Timestamp(ts) => write!(f, "timestamp '{}'", ts.to_rfc3339()),
+        }
+    }
+}
+
+/// Parse a timestamp string. Timestamps without an offset are treated as UTC.
+fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    let s = s.trim();
+    if let Ok(ts) = DateTime::parse_from_rfc3339(s) {
+        return Some(ts.with_timezone(&Utc));
+    }
+    for fmt in ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"] {
+        if let Ok(ts) = NaiveDateTime::parse_from_str(s, fmt) {
+            return Some(ts.and_utc());
+        }
+    }
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|ts| ts.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_version_from_opts() {
+        let mut opts = HashMap::new();
+        assert_eq!(None, TableVersion::take_from_opts(&mut opts).unwrap());
+
+        let version = TableVersion::Version(12);
+        let mut opts = version.into_opts();
+        assert_eq!(
+            Some(version),
+            TableVersion::take_from_opts(&mut opts).unwrap()
+        );
+        assert!(opts.is_empty());
+
+        let ts = parse_timestamp("2024-01-02 03:04:05").unwrap();
+        let version = TableVersion::Timestamp(ts);
+        let mut opts = version.into_opts();
+        assert_eq!(
+            Some(version),
+            TableVersion::take_from_opts(&mut opts).unwrap()
+        );
+
+        let mut opts = HashMap::from([
+            (
+                "version".to_string(),
+                FuncParamValue::Scalar(ScalarValue::Int64(Some(1))),
+            ),
+            (
+                "as_of".to_string(),
+                FuncParamValue::Scalar(ScalarValue::Utf8(Some("2024-01-01".to_string()))),
+            ),
+        ]);
+        TableVersion::take_from_opts(&mut opts).unwrap_err();
+    }
+
+    #[test]
+    fn parse_timestamps() {
+        let expected = NaiveDate::from_ymd_opt(2024, 1, 2)
+            .unwrap()
+            .and_hms_opt(3, 4, 5)
+            .unwrap()
+            .and_utc();
+
+        assert_eq!(Some(expected), parse_timestamp("2024-01-02 03:04:05"));
+        assert_eq!(Some(expected), parse_timestamp("2024-01-02T03:04:05Z"));
+        assert_eq!(Some(expected), parse_timestamp("2024-01-02T05:04:05+02:00"));
+        assert_eq!(None, parse_timestamp("yesterday"));
+    }
+}
@@ -472,6 +472,8 @@ pub struct AggregatedMetrics {
     pub bytes_read: u64,
     /// Total bytes written.
     pub bytes_written: Option<u64>,
+    /// Total bytes spilled to disk.
+    pub spilled_bytes: u64,
 }
 
 impl AggregatedMetrics {
@@ -484,6 +486,7 @@ impl AggregatedMetrics {
             elapsed_compute_ns: 0,
             bytes_read: 0,
             bytes_written: None,
+            spilled_bytes: 0,
         };
         agg.aggregate_recurse(plan);
         agg
@@ -496,6 +499,7 @@ impl AggregatedMetrics {
                 .sum_by_name(BYTES_READ_GAUGE_NAME)
                 .map(|m| m.as_usize() as u64)
                 .unwrap_or_default();
+            self.spilled_bytes += metrics.spilled_bytes().unwrap_or_default() as u64;
 
             if self.bytes_written.is_none() {
                 // Only count bytes written if they were not counted before.
@@ -88,8 +88,10 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
     /// Create a placeholder expression
     /// This is the same as Postgres's prepare statement syntax in which a placeholder starts with `$` sign and then
     /// number 1, 2, ... etc. For example, `$1` is the first placeholder; $2 is the second one and so on.
+    ///
+    /// Named placeholders (`:name` or `$name`) are also accepted. Their type
+    /// is only ever inferred from how they're used.
     fn create_placeholder_expr(param: String, param_data_types: &[DataType]) -> Result<Expr> {
-        // Parse the placeholder as a number because it is the only support from sqlparser and postgres
         let index = param[1..].parse::<usize>();
         let idx = match index {
             Ok(0) => {
@@ -97,15 +99,16 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                     "Invalid placeholder, zero is not a valid index: {param}"
                 )));
             }
-            Ok(index) => index - 1,
+            Ok(index) => Some(index - 1),
+            Err(_) if is_placeholder_name(&param[1..]) => None,
             Err(_) => {
                 return Err(DataFusionError::Plan(format!(
-                    "Invalid placeholder, not a number: {param}"
+                    "Invalid placeholder, not a number or name: {param}"
                 )));
             }
         };
         // Check if the placeholder is in the parameter list
-        let param_type = param_data_types.get(idx);
+        let param_type = idx.and_then(|idx| param_data_types.get(idx));
 
         Ok(Expr::Placeholder(Placeholder::new(
             param,
@@ -342,3 +345,9 @@ fn has_units(val: &str) -> bool {
         || val.ends_with("nanosecond")
         || val.ends_with("nanoseconds")
 }
+
+fn is_placeholder_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -58,7 +58,7 @@ use parser::sqlparser::ast::{
 };
 
 use crate::conversion::convert;
-use crate::functions::FuncParamValue;
+use crate::functions::{FuncParamValue, TableVersion};
 use crate::utils::make_decimal_type;
 
 #[derive(Debug)]
@@ -92,6 +92,13 @@ pub trait AsyncContextProvider: Send + Sync {
     /// Getter for a datasource
     async fn get_table_source(&mut self, name: TableReference<'_>) -> Result<Arc<dyn TableSource>>;
 
+    /// Getter for a datasource as of a previous version of the table.
+    async fn get_table_source_at_version(
+        &mut self,
+        name: TableReference<'_>,
+        version: TableVersion,
+    ) -> Result<Arc<dyn TableSource>>;
+
     /// Get a table returning function.
     ///
     /// Note that this accepts a table reference since these functions are
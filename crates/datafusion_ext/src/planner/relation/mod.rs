@@ -26,7 +26,7 @@ use datafusion::scalar::ScalarValue;
 use datafusion::sql::planner::PlannerContext;
 use parser::sqlparser::ast;
 
-use crate::functions::FuncParamValue;
+use crate::functions::{FuncParamValue, TableVersion};
 use crate::planner::{AsyncContextProvider, SqlQueryPlanner};
 
 mod join;
@@ -44,9 +44,22 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                 mut name,
                 alias,
                 args,
+                version,
                 ..
             } => {
+                if version.is_some() && args.is_some() {
+                    return Err(DataFusionError::Plan(
+                        "AS OF cannot be used with table functions".to_string(),
+                    ));
+                }
+
                 if name.0.len() == 1 && name.0[0].quote_style == Some('\'') {
+                    if version.is_some() {
+                        return Err(DataFusionError::Plan(format!(
+                            "AS OF cannot be used when reading files directly: {name}"
+                        )));
+                    }
+
                     // SELECT * FROM './my/file.csv'
                     //
                     // Infer the table function to use based on a file path.
@@ -107,16 +120,30 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                             let table_name = table_ref.to_string();
 
                             let cte = planner_context.get_cte(&table_name);
-                            let plan = if let Some(cte_plan) = cte {
-                                cte_plan.clone()
-                            } else {
-                                let provider = self
-                                    .context_provider
-                                    .get_table_source(table_ref.clone())
-                                    .await?;
-                                let plan_builder =
-                                    LogicalPlanBuilder::scan(table_ref, provider, None)?;
-                                plan_builder.build()?
+                            let plan = match (cte, version) {
+                                (Some(_), Some(_)) => {
+                                    return Err(DataFusionError::Plan(format!(
+                                        "AS OF cannot be used with common table expression '{table_name}'"
+                                    )));
+                                }
+                                (Some(cte_plan), None) => cte_plan.clone(),
+                                (None, Some(version)) => {
+                                    let version = self.table_version(version)?;
+                                    let provider = self
+                                        .context_provider
+                                        .get_table_source_at_version(table_ref.clone(), version)
+                                        .await?;
+                                    LogicalPlanBuilder::scan(table_ref, provider, None)?.build()?
+                                }
+                                (None, None) => {
+                                    let provider = self
+                                        .context_provider
+                                        .get_table_source(table_ref.clone())
+                                        .await?;
+                                    let plan_builder =
+                                        LogicalPlanBuilder::scan(table_ref, provider, None)?;
+                                    plan_builder.build()?
+                                }
                             };
                             (plan, alias)
                         }
@@ -153,6 +180,24 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
         }
     }
 
+    /// Get the table version from an `AS OF` clause.
+    ///
+    /// Numbers are treated as commit versions, everything else is expected to
+    /// be a timestamp.
+    fn table_version(&self, version: ast::TableVersion) -> Result<TableVersion> {
+        let ast::TableVersion::ForSystemTimeAsOf(expr) = version;
+        let version = match expr {
+            ast::Expr::Value(ast::Value::Number(..)) => {
+                TableVersion::try_from_version(self.get_param_val(expr)?)
+            }
+            ast::Expr::TypedString { value, .. } => TableVersion::try_from_timestamp(
+                FuncParamValue::Scalar(ScalarValue::Utf8(Some(value))),
+            ),
+            other => TableVersion::try_from_timestamp(self.get_param_val(other)?),
+        };
+        version.map_err(|e| DataFusionError::Plan(format!("Invalid AS OF clause: {e}")))
+    }
+
     /// Get a constant expression literal from a function argument.
     ///
     /// Returns an optional name for the argument.
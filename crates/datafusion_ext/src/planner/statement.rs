@@ -126,6 +126,14 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
             for row in rows.iter() {
                 for (idx, val) in row.iter().enumerate() {
                     if let ast::Expr::Value(Value::Placeholder(name)) = val {
+                        // Named placeholders don't take part in positional
+                        // type inference, the projection below casts them to
+                        // the column's type.
+                        if name.starts_with(':')
+                            || name[1..].starts_with(|c: char| !c.is_ascii_digit())
+                        {
+                            continue;
+                        }
                         let name =
                             name.replace('$', "").parse::<usize>().map_err(|_| {
                                 plan_datafusion_err!("Can't parse placeholder: {name}")
@@ -0,0 +1,188 @@
+//! Execution plan wrapper for emitting tracing spans for each operator.
+use std::any::Any;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::error::Result;
+use datafusion::execution::TaskContext;
+use datafusion::physical_expr::{PhysicalSortExpr, PhysicalSortRequirement};
+use datafusion::physical_plan::metrics::MetricsSet;
+use datafusion::physical_plan::{
+    displayable,
+    DisplayAs,
+    DisplayFormatType,
+    ExecutionPlan,
+    Partitioning,
+    RecordBatchStream,
+    SendableRecordBatchStream,
+    Statistics,
+};
+use futures::{Stream, StreamExt};
+use tracing::{info_span, Span};
+
+/// Wraps an operator so that its output streams are polled inside of a span.
+///
+/// Everything other than execution is forwarded to the wrapped operator
+/// (including display, metrics, and children), so a wrapped plan looks
+/// identical to the original plan in EXPLAIN output.
+///
+/// Wrapping should happen immediately before execution, since optimizer rules
+/// and the extension codec look at concrete operator types.
+#[derive(Debug)]
+pub struct TracedExec {
+    inner: Arc<dyn ExecutionPlan>,
+}
+
+impl TracedExec {
+    /// Wrap every operator in a plan.
+    ///
+    /// `descend` is called on each operator to determine if its children
+    /// should be wrapped as well. Operators that serialize their children
+    /// (e.g. for remote execution) must return false.
+    pub fn wrap_plan(
+        plan: Arc<dyn ExecutionPlan>,
+        descend: &dyn Fn(&dyn ExecutionPlan) -> bool,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let children = plan.children();
+        let plan = if !children.is_empty() && descend(plan.as_ref()) {
+            let children = children
+                .into_iter()
+                .map(|child| Self::wrap_plan(child, descend))
+                .collect::<Result<Vec<_>>>()?;
+            plan.with_new_children(children)?
+        } else {
+            plan
+        };
+        Ok(Arc::new(TracedExec { inner: plan }))
+    }
+
+    /// Name of the wrapped operator, e.g. "ProjectionExec".
+    fn operator_name(&self) -> String {
+        let line = displayable(self.inner.as_ref()).one_line().to_string();
+        match line.split_once(':') {
+            Some((name, _)) => name.trim().to_string(),
+            None => line.trim().to_string(),
+        }
+    }
+}
+
+impl ExecutionPlan for TracedExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        self.inner.output_partitioning()
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        self.inner.output_ordering()
+    }
+
+    fn required_input_ordering(&self) -> Vec<Option<Vec<PhysicalSortRequirement>>> {
+        self.inner.required_input_ordering()
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        self.inner.children()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(TracedExec {
+            inner: self.inner.clone().with_new_children(children)?,
+        }))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let span = info_span!("operator", name = %self.operator_name(), partition);
+        let stream = span.in_scope(|| self.inner.execute(partition, context))?;
+        Ok(Box::pin(TracedStream { stream, span }))
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        self.inner.statistics()
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        self.inner.metrics()
+    }
+}
+
+impl DisplayAs for TracedExec {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.fmt_as(t, f)
+    }
+}
+
+/// Stream that enters a span every time it's polled. The span is closed once
+/// the stream is dropped.
+struct TracedStream {
+    stream: SendableRecordBatchStream,
+    span: Span,
+}
+
+impl Stream for TracedStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let span = self.span.clone();
+        let _entered = span.enter();
+        self.stream.poll_next_unpin(cx)
+    }
+}
+
+impl RecordBatchStream for TracedStream {
+    fn schema(&self) -> SchemaRef {
+        self.stream.schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::datatypes::Schema;
+    use datafusion::physical_plan::coalesce_partitions::CoalescePartitionsExec;
+    use datafusion::physical_plan::empty::EmptyExec;
+
+    use super::*;
+
+    fn test_plan() -> Arc<dyn ExecutionPlan> {
+        let empty = Arc::new(EmptyExec::new(Arc::new(Schema::empty())));
+        Arc::new(CoalescePartitionsExec::new(empty))
+    }
+
+    #[test]
+    fn wrapped_plan_displays_as_original() {
+        let plan = test_plan();
+        let wrapped = TracedExec::wrap_plan(plan.clone(), &|_| true).unwrap();
+
+        assert!(wrapped.as_any().is::<TracedExec>());
+        assert!(wrapped.children()[0].as_any().is::<TracedExec>());
+        assert_eq!(
+            displayable(plan.as_ref()).indent(true).to_string(),
+            displayable(wrapped.as_ref()).indent(true).to_string(),
+        );
+    }
+
+    #[test]
+    fn wrap_plan_skips_children() {
+        let wrapped = TracedExec::wrap_plan(test_plan(), &|_| false).unwrap();
+
+        assert!(wrapped.as_any().is::<TracedExec>());
+        assert!(wrapped.children()[0].as_any().is::<EmptyExec>());
+    }
+}
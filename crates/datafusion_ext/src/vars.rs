@@ -90,6 +90,7 @@ impl SessionVars {
      remote_session_id: Option<Uuid>,
      user_id: Uuid,
      user_name: String,
+     is_catalog_role: bool,
      database_name: String,
      max_datasource_count: Option<usize>,
      memory_limit_bytes: Option<usize>,
@@ -98,6 +99,7 @@ impl SessionVars {
      is_cloud_instance: bool,
      dialect: Dialect,
      enable_experimental_scheduler: bool,
+     catalog_as_of_version: Option<u64>,
     }
 }
 
@@ -114,6 +116,18 @@ impl SessionVars {
         self.inner.write()
     }
 
+    /// The catalog role to check privileges against for this session.
+    ///
+    /// Sessions for users without a role in the catalog (e.g. the user
+    /// configured for the server) aren't subject to privilege checks.
+    pub fn catalog_role(&self) -> Option<String> {
+        if self.is_catalog_role() {
+            Some(self.user_name())
+        } else {
+            None
+        }
+    }
+
     /// Iterate over the implicit search path. This will have all implicit
     /// schemas prepended to the iterator.
     ///
@@ -192,6 +206,9 @@ impl SessionVars {
     pub fn with_user_name(self, value: impl AsRef<str>, setter: VarType) -> Self {
         with_property!(self, user_name, setter, value.as_ref())
     }
+    pub fn with_is_catalog_role(self, value: bool, setter: VarType) -> Self {
+        with_property!(self, is_catalog_role, setter, value)
+    }
     pub fn with_database_name(self, value: impl AsRef<str>, setter: VarType) -> Self {
         with_property!(self, database_name, setter, value.as_ref())
     }
@@ -210,6 +227,9 @@ impl SessionVars {
     pub fn with_is_cloud_instance(self, value: bool, setter: VarType) -> Self {
         with_property!(self, is_cloud_instance, setter, value)
     }
+    pub fn with_catalog_as_of_version(self, value: Option<u64>, setter: VarType) -> Self {
+        with_property!(self, catalog_as_of_version, setter, value)
+    }
 }
 
 impl ConfigExtension for SessionVars {
@@ -158,6 +158,15 @@ pub(super) const USER_NAME: ServerVar<str> = ServerVar {
     description: "User name",
 };
 
+pub(super) const IS_CATALOG_ROLE: ServerVar<bool> = ServerVar {
+    name: "is_catalog_role",
+    value: &false,
+    group: "glaredb",
+    user_configurable: false,
+    description:
+        "If the session's user is a role stored in the catalog, and so subject to its privileges",
+};
+
 pub(super) const DATABASE_NAME: ServerVar<str> = ServerVar {
     name: "database_name",
     value: "",
@@ -222,6 +231,14 @@ pub(super) const ENABLE_EXPERIMENTAL_SCHEDULER: ServerVar<bool> = ServerVar {
     description: "If the experimental query scheduler should be enabled",
 };
 
+pub(super) const CATALOG_AS_OF_VERSION: ServerVar<Option<u64>> = ServerVar {
+    name: "catalog_as_of_version",
+    value: &None,
+    group: "glaredb",
+    user_configurable: true,
+    description: "Pin the session to an older version of the catalog, making it read-only (superusers only)",
+};
+
 /// Note that these are not normally shown in the search path.
 pub(super) const IMPLICIT_SCHEMAS: [&str; 2] = [
     POSTGRES_SCHEMA,
@@ -13,6 +13,7 @@ use uuid::Uuid;
 
 use super::constants::{
     APPLICATION_NAME,
+    CATALOG_AS_OF_VERSION,
     CLIENT_ENCODING,
     CLIENT_MIN_MESSAGES,
     CONNECTION_ID,
@@ -25,6 +26,7 @@ use super::constants::{
     EXTRA_FLOAT_DIGITS,
     FORCE_CATALOG_REFRESH,
     GLAREDB_VERSION,
+    IS_CATALOG_ROLE,
     IS_CLOUD_INSTANCE,
     MAX_CREDENTIALS_COUNT,
     MAX_DATASOURCE_COUNT,
@@ -72,6 +74,7 @@ pub struct SessionVarsInner {
     pub remote_session_id: SessionVar<Option<Uuid>>,
     pub user_id: SessionVar<Uuid>,
     pub user_name: SessionVar<str>,
+    pub is_catalog_role: SessionVar<bool>,
     pub database_name: SessionVar<str>,
     pub max_datasource_count: SessionVar<Option<usize>>,
     pub memory_limit_bytes: SessionVar<Option<usize>>,
@@ -80,6 +83,7 @@ pub struct SessionVarsInner {
     pub is_cloud_instance: SessionVar<bool>,
     pub dialect: SessionVar<Dialect>,
     pub enable_experimental_scheduler: SessionVar<bool>,
+    pub catalog_as_of_version: SessionVar<Option<u64>>,
 }
 
 impl SessionVarsInner {
@@ -134,6 +138,8 @@ impl SessionVarsInner {
             Ok(&self.remote_session_id)
         } else if name.eq_ignore_ascii_case(USER_NAME.name) {
             Ok(&self.user_name)
+        } else if name.eq_ignore_ascii_case(IS_CATALOG_ROLE.name) {
+            Ok(&self.is_catalog_role)
         } else if name.eq_ignore_ascii_case(DATABASE_NAME.name) {
             Ok(&self.database_name)
         } else if name.eq_ignore_ascii_case(MAX_DATASOURCE_COUNT.name) {
@@ -150,6 +156,8 @@ impl SessionVarsInner {
             Ok(&self.dialect)
         } else if name.eq_ignore_ascii_case(ENABLE_EXPERIMENTAL_SCHEDULER.name) {
             Ok(&self.enable_experimental_scheduler)
+        } else if name.eq_ignore_ascii_case(CATALOG_AS_OF_VERSION.name) {
+            Ok(&self.catalog_as_of_version)
         } else {
             Err(VarError::UnknownVariable(name.to_string()).into())
         }
@@ -209,6 +217,8 @@ impl SessionVarsInner {
             self.dialect.set_from_str(val, setter)
         } else if name.eq_ignore_ascii_case(ENABLE_EXPERIMENTAL_SCHEDULER.name) {
             self.enable_experimental_scheduler.set_from_str(val, setter)
+        } else if name.eq_ignore_ascii_case(CATALOG_AS_OF_VERSION.name) {
+            self.catalog_as_of_version.set_from_str(val, setter)
         } else {
             Err(VarError::UnknownVariable(name.to_string()).into())
         }
@@ -232,6 +242,7 @@ impl SessionVarsInner {
             self.connection_id.config_entry(),
             self.remote_session_id.config_entry(),
             self.user_name.config_entry(),
+            self.is_catalog_role.config_entry(),
             self.database_name.config_entry(),
             self.max_datasource_count.config_entry(),
             self.memory_limit_bytes.config_entry(),
@@ -239,6 +250,7 @@ impl SessionVarsInner {
             self.max_credentials_count.config_entry(),
             self.is_cloud_instance.config_entry(),
             self.dialect.config_entry(),
+            self.catalog_as_of_version.config_entry(),
         ]
     }
 }
@@ -264,6 +276,7 @@ impl Default for SessionVarsInner {
             connection_id: SessionVar::new(&CONNECTION_ID),
             remote_session_id: SessionVar::new(&REMOTE_SESSION_ID),
             user_name: SessionVar::new(&USER_NAME),
+            is_catalog_role: SessionVar::new(&IS_CATALOG_ROLE),
             database_name: SessionVar::new(&DATABASE_NAME),
             max_datasource_count: SessionVar::new(&MAX_DATASOURCE_COUNT),
             memory_limit_bytes: SessionVar::new(&MEMORY_LIMIT_BYTES),
@@ -272,6 +285,7 @@ impl Default for SessionVarsInner {
             is_cloud_instance: SessionVar::new(&IS_CLOUD_INSTANCE),
             dialect: SessionVar::new(&DIALECT),
             enable_experimental_scheduler: SessionVar::new(&ENABLE_EXPERIMENTAL_SCHEDULER),
+            catalog_as_of_version: SessionVar::new(&CATALOG_AS_OF_VERSION),
         }
     }
 }
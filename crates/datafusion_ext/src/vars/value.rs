@@ -61,6 +61,16 @@ impl Value for usize {
     }
 }
 
+impl Value for u64 {
+    fn try_parse(s: &str) -> Option<Self::Owned> {
+        s.parse().ok()
+    }
+
+    fn format(&self) -> String {
+        self.to_string()
+    }
+}
+
 impl Value for Uuid {
     fn try_parse(s: &str) -> Option<Self::Owned> {
         s.parse().ok()
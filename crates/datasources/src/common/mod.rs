@@ -14,6 +14,7 @@ use datafusion::prelude::Expr;
 pub mod errors;
 pub mod sink;
 pub mod ssh;
+pub mod statistics;
 pub mod url;
 pub mod util;
 
@@ -0,0 +1,321 @@
+//! Reporting table statistics collected by `ANALYZE`.
+
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::arrow::datatypes::{Schema, SchemaRef};
+use datafusion::common::stats::Precision;
+use datafusion::common::{ColumnStatistics, Constraints, ScalarValue, Statistics};
+use datafusion::datasource::TableProvider;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::context::SessionState;
+use datafusion::execution::TaskContext;
+use datafusion::logical_expr::{LogicalPlan, TableProviderFilterPushDown, TableType};
+use datafusion::physical_expr::{PhysicalSortExpr, PhysicalSortRequirement};
+use datafusion::physical_plan::{
+    DisplayAs,
+    DisplayFormatType,
+    ExecutionPlan,
+    Partitioning,
+    SendableRecordBatchStream,
+};
+use datafusion::prelude::Expr;
+use protogen::metastore::types::catalog::TableStatistics;
+
+/// Convert statistics collected by `ANALYZE` to statistics for `schema`.
+///
+/// Everything is reported as inexact since the table may have changed since
+/// it was analyzed.
+pub fn analyzed_statistics(stats: &TableStatistics, schema: &Schema) -> Statistics {
+    let column_statistics = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let col = match stats.columns.iter().find(|col| &col.name == field.name()) {
+                Some(col) => col,
+                None => return ColumnStatistics::new_unknown(),
+            };
+
+            let parse = |value: &Option<String>| match value {
+                Some(value) => ScalarValue::try_from_string(value.clone(), field.data_type())
+                    .map(Precision::Inexact)
+                    .unwrap_or(Precision::Absent),
+                None => Precision::Absent,
+            };
+
+            ColumnStatistics {
+                null_count: Precision::Inexact(col.null_count as usize),
+                max_value: parse(&col.max_value),
+                min_value: parse(&col.min_value),
+                distinct_count: col
+                    .distinct_count
+                    .map(|count| Precision::Inexact(count as usize))
+                    .unwrap_or(Precision::Absent),
+            }
+        })
+        .collect();
+
+    Statistics {
+        num_rows: Precision::Inexact(stats.num_rows as usize),
+        total_byte_size: Precision::Absent,
+        column_statistics,
+    }
+}
+
+/// Fill in statistics the source doesn't know with ones from `ANALYZE`.
+fn merge_statistics(source: Statistics, analyzed: &Statistics) -> Statistics {
+    fn or<T: fmt::Debug + Clone + PartialEq + Eq + PartialOrd>(
+        source: Precision<T>,
+        analyzed: &Precision<T>,
+    ) -> Precision<T> {
+        match source {
+            Precision::Absent => analyzed.clone(),
+            source => source,
+        }
+    }
+
+    let column_statistics = source
+        .column_statistics
+        .into_iter()
+        .zip(analyzed.column_statistics.iter())
+        .map(|(source, analyzed)| ColumnStatistics {
+            null_count: or(source.null_count, &analyzed.null_count),
+            max_value: or(source.max_value, &analyzed.max_value),
+            min_value: or(source.min_value, &analyzed.min_value),
+            distinct_count: or(source.distinct_count, &analyzed.distinct_count),
+        })
+        .collect();
+
+    Statistics {
+        num_rows: or(source.num_rows, &analyzed.num_rows),
+        total_byte_size: or(source.total_byte_size, &analyzed.total_byte_size),
+        column_statistics,
+    }
+}
+
+/// Wraps a table provider to report statistics collected by `ANALYZE`.
+///
+/// Statistics the underlying provider already knows take precedence, the
+/// analyzed ones only fill in the gaps.
+pub struct AnalyzedTableProvider {
+    inner: Arc<dyn TableProvider>,
+    statistics: TableStatistics,
+}
+
+impl AnalyzedTableProvider {
+    pub fn new(inner: Arc<dyn TableProvider>, statistics: TableStatistics) -> Self {
+        AnalyzedTableProvider { inner, statistics }
+    }
+}
+
+#[async_trait]
+impl TableProvider for AnalyzedTableProvider {
+    fn as_any(&self) -> &dyn Any {
+        self.inner.as_any()
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+
+    fn constraints(&self) -> Option<&Constraints> {
+        self.inner.constraints()
+    }
+
+    fn table_type(&self) -> TableType {
+        self.inner.table_type()
+    }
+
+    fn get_table_definition(&self) -> Option<&str> {
+        self.inner.get_table_definition()
+    }
+
+    fn get_logical_plan(&self) -> Option<&LogicalPlan> {
+        self.inner.get_logical_plan()
+    }
+
+    async fn scan(
+        &self,
+        state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let plan = self.inner.scan(state, projection, filters, limit).await?;
+        let mut statistics = analyzed_statistics(&self.statistics, &plan.schema());
+        if let Some(limit) = limit {
+            statistics.num_rows = statistics.num_rows.min(&Precision::Inexact(limit));
+        }
+        Ok(Arc::new(AnalyzedScanExec {
+            input: plan,
+            statistics,
+        }))
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        self.inner.supports_filters_pushdown(filters)
+    }
+
+    fn statistics(&self) -> Option<Statistics> {
+        let analyzed = analyzed_statistics(&self.statistics, &self.schema());
+        Some(match self.inner.statistics() {
+            Some(source) => merge_statistics(source, &analyzed),
+            None => analyzed,
+        })
+    }
+
+    async fn insert_into(
+        &self,
+        state: &SessionState,
+        input: Arc<dyn ExecutionPlan>,
+        overwrite: bool,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.inner.insert_into(state, input, overwrite).await
+    }
+}
+
+/// Reports analyzed statistics for a table scan.
+#[derive(Debug)]
+struct AnalyzedScanExec {
+    input: Arc<dyn ExecutionPlan>,
+    /// Analyzed statistics for the scan's output.
+    statistics: Statistics,
+}
+
+impl ExecutionPlan for AnalyzedScanExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.input.schema()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        self.input.output_partitioning()
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        self.input.output_ordering()
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.input.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if children.len() != 1 {
+            return Err(DataFusionError::Plan(
+                "AnalyzedScanExec wrong number of children".to_string(),
+            ));
+        }
+        Ok(Arc::new(AnalyzedScanExec {
+            input: children[0].clone(),
+            statistics: self.statistics.clone(),
+        }))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        self.input.execute(partition, context)
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        Ok(merge_statistics(self.input.statistics()?, &self.statistics))
+    }
+
+    fn required_input_ordering(&self) -> Vec<Option<Vec<PhysicalSortRequirement>>> {
+        // Keep the ordering of the scan, this exec doesn't change it.
+        vec![self
+            .output_ordering()
+            .map(PhysicalSortRequirement::from_sort_exprs)]
+    }
+}
+
+impl DisplayAs for AnalyzedScanExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AnalyzedScanExec")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::datatypes::{DataType, Field};
+    use protogen::metastore::types::catalog::ColumnStatistics as AnalyzedColumn;
+
+    use super::*;
+
+    #[test]
+    fn analyzed_statistics_follow_schema() {
+        let stats = TableStatistics {
+            num_rows: 10,
+            columns: vec![
+                AnalyzedColumn {
+                    name: "a".to_string(),
+                    null_count: 1,
+                    min_value: Some("1".to_string()),
+                    max_value: Some("9".to_string()),
+                    distinct_count: Some(8),
+                },
+                AnalyzedColumn {
+                    name: "b".to_string(),
+                    null_count: 0,
+                    min_value: None,
+                    max_value: None,
+                    distinct_count: None,
+                },
+            ],
+            analyzed_at: 0,
+        };
+
+        // Projected and reordered, with a column that wasn't analyzed.
+        let schema = Schema::new(vec![
+            Field::new("c", DataType::Utf8, true),
+            Field::new("a", DataType::Int32, true),
+        ]);
+        let out = analyzed_statistics(&stats, &schema);
+
+        assert_eq!(Precision::Inexact(10), out.num_rows);
+        assert_eq!(ColumnStatistics::new_unknown(), out.column_statistics[0]);
+        assert_eq!(
+            ColumnStatistics {
+                null_count: Precision::Inexact(1),
+                max_value: Precision::Inexact(ScalarValue::Int32(Some(9))),
+                min_value: Precision::Inexact(ScalarValue::Int32(Some(1))),
+                distinct_count: Precision::Inexact(8),
+            },
+            out.column_statistics[1]
+        );
+
+        // Known source statistics win.
+        let source = Statistics {
+            num_rows: Precision::Exact(12),
+            total_byte_size: Precision::Absent,
+            column_statistics: vec![
+                ColumnStatistics::new_unknown(),
+                ColumnStatistics {
+                    null_count: Precision::Exact(0),
+                    ..ColumnStatistics::new_unknown()
+                },
+            ],
+        };
+        let merged = merge_statistics(source, &out);
+        assert_eq!(Precision::Exact(12), merged.num_rows);
+        assert_eq!(Precision::Exact(0), merged.column_statistics[1].null_count);
+        assert_eq!(
+            Precision::Inexact(8),
+            merged.column_statistics[1].distinct_count
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use deltalake::DeltaTable;
+use datafusion_ext::functions::TableVersion;
+use deltalake::{DeltaTable, DeltaTableBuilder};
 use protogen::metastore::types::options::{
     DeltaLakeCatalog,
     DeltaLakeUnityCatalog,
@@ -68,3 +69,19 @@ pub async fn load_table_direct(location: &str, opts: StorageOptions) -> Result<D
     // during execution.
     Ok(table)
 }
+
+/// Loads a previous version of the table at the given location.
+pub async fn load_table_direct_at_version(
+    location: &str,
+    opts: StorageOptions,
+    version: TableVersion,
+) -> Result<DeltaTable> {
+    let opts = HashMap::from_iter(opts.inner.into_iter());
+    let builder = DeltaTableBuilder::from_uri(location).with_storage_options(opts);
+    let builder = match version {
+        TableVersion::Version(version) => builder.with_version(version),
+        TableVersion::Timestamp(ts) => builder.with_timestamp(ts),
+    };
+    let table = builder.load().await?;
+    Ok(table)
+}
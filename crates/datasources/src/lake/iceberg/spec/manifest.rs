@@ -297,13 +297,13 @@ pub struct DataFile {
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BinaryEntry {
-    key: i32,
+    pub key: i32,
     #[serde_as(as = "Bytes")]
-    value: Vec<u8>,
+    pub value: Vec<u8>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct I64Entry {
-    key: i32,
-    value: i64,
+    pub key: i32,
+    pub value: i64,
 }
@@ -4,7 +4,14 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use datafusion::arrow::datatypes::{Schema as ArrowSchema, SchemaRef as ArrowSchemaRef};
+use datafusion::arrow::datatypes::{
+    DataType,
+    Schema as ArrowSchema,
+    SchemaRef as ArrowSchemaRef,
+    TimeUnit,
+};
+use datafusion::common::stats::Precision;
+use datafusion::common::{ColumnStatistics, ScalarValue};
 use datafusion::datasource::file_format::parquet::ParquetFormat;
 use datafusion::datasource::file_format::FileFormat;
 use datafusion::datasource::listing::PartitionedFile;
@@ -28,10 +35,16 @@ use object_store::path::Path as ObjectPath;
 use object_store::{ObjectMeta, ObjectStore};
 
 use super::spec::{
+    AnyType,
+    BinaryEntry,
+    DataFile,
+    I64Entry,
     Manifest,
     ManifestContent,
     ManifestEntryStatus,
     ManifestList,
+    PrimitiveType,
+    Schema,
     Snapshot,
     TableMetadata,
 };
@@ -73,10 +86,14 @@ impl IcebergTable {
 
     pub async fn table_reader(&self) -> Result<Arc<dyn TableProvider>> {
         let schema = self.table_arrow_schema()?;
+        let data_files = self.state.read_data_files().await?;
+        let statistics = data_files_statistics(self.state.current_schema()?, &schema, &data_files);
 
         Ok(Arc::new(IcebergTableReader {
             schema: Arc::new(schema),
             state: self.state.clone(),
+            data_files,
+            statistics,
         }))
     }
 }
@@ -210,11 +227,10 @@ impl TableState {
         Ok(current_snapshot)
     }
 
-    fn table_arrow_schema(&self) -> Result<ArrowSchema> {
+    fn current_schema(&self) -> Result<&Schema> {
         // TODO: v1: Read `schema` (deprecated format).
 
-        let schema = self
-            .metadata
+        self.metadata
             .schemas
             .iter()
             .find(|s| s.schema_id == self.metadata.current_schema_id)
@@ -223,9 +239,38 @@ impl TableState {
                     "Missing schema for id: {}",
                     self.metadata.current_schema_id
                 ))
-            })?;
+            })
+    }
 
-        schema.to_arrow_schema()
+    fn table_arrow_schema(&self) -> Result<ArrowSchema> {
+        self.current_schema()?.to_arrow_schema()
+    }
+
+    /// Read the data files for the current snapshot.
+    ///
+    /// TODO: Handle "delete" content and also pull out partition
+    /// information.
+    async fn read_data_files(&self) -> Result<Vec<DataFile>> {
+        let manifests = self.read_manifests().await?;
+
+        // Get only data files with "data" content.
+        let data_files = manifests
+            .into_iter()
+            .filter(|m| matches!(m.metadata.content, ManifestContent::Data))
+            .flat_map(|m| {
+                m.entries.into_iter().filter_map(|ent| {
+                    let ent_status: ManifestEntryStatus = ent.status.try_into().unwrap_or_default();
+                    if ent_status.is_deleted() {
+                        // Ignore deleted entries during table scans.
+                        None
+                    } else {
+                        Some(ent.data_file)
+                    }
+                })
+            })
+            .collect();
+
+        Ok(data_files)
     }
 
     async fn read_manifests(&self) -> Result<Vec<Manifest>> {
@@ -302,6 +347,10 @@ impl PathResolver {
 pub struct IcebergTableReader {
     schema: Arc<ArrowSchema>,
     state: TableState,
+    /// Data files in the snapshot being read.
+    data_files: Vec<DataFile>,
+    /// Statistics from the metrics recorded for each data file.
+    statistics: Statistics,
 }
 
 #[async_trait]
@@ -325,6 +374,10 @@ impl TableProvider for IcebergTableReader {
         Ok(TableProviderFilterPushDown::Inexact)
     }
 
+    fn statistics(&self) -> Option<Statistics> {
+        Some(self.statistics.clone())
+    }
+
     async fn scan(
         &self,
         ctx: &SessionState,
@@ -351,35 +404,8 @@ impl TableProvider for IcebergTableReader {
         // TODO: Use provided filters to prune out partitions and/or data files
         // (since the metadata will have some info about file content).
 
-        // TODO: Collect statistics and pass to exec.
-
-        let manifests = self
-            .state
-            .read_manifests()
-            .await
-            .map_err(|e| DataFusionError::External(Box::new(e)))?;
-
-        // Get only data files with "data" content.
-        //
-        // TODO: Handle "delete" content and also pull out partition
-        // information.
-        let data_files: Vec<_> = manifests
-            .into_iter()
-            .filter(|m| matches!(m.metadata.content, ManifestContent::Data))
-            .flat_map(|m| {
-                m.entries.into_iter().filter_map(|ent| {
-                    let ent_status: ManifestEntryStatus = ent.status.try_into().unwrap_or_default();
-                    if ent_status.is_deleted() {
-                        // Ignore deleted entries during table scans.
-                        None
-                    } else {
-                        Some(ent.data_file)
-                    }
-                })
-            })
-            .collect();
-
-        let partitioned_files = data_files
+        let partitioned_files = self
+            .data_files
             .iter()
             .map(|f| {
                 let path = self.state.resolver.relative_path(&f.file_path);
@@ -401,14 +427,11 @@ impl TableProvider for IcebergTableReader {
             .collect::<Result<Vec<PartitionedFile>>>()
             .map_err(|e| DataFusionError::External(Box::new(e)))?;
 
-        let file_schema = self.schema();
-        let statistics = Statistics::new_unknown(file_schema.as_ref());
-
         let conf = FileScanConfig {
             object_store_url: object_url,
-            file_schema,
+            file_schema: self.schema(),
             projection: projection.cloned(),
-            statistics,
+            statistics: self.statistics.clone(),
             file_groups: vec![partitioned_files],
             limit,
             table_partition_cols: Vec::new(),
@@ -480,7 +503,7 @@ impl ExecutionPlan for IcebergTableScan {
     }
 
     fn statistics(&self) -> DataFusionResult<Statistics> {
-        Ok(Statistics::new_unknown(self.schema().as_ref()))
+        self.parquet_scan.statistics()
     }
 }
 
@@ -492,6 +515,141 @@ impl DisplayAs for IcebergTableScan {
     }
 }
 
+/// Compute table statistics from the metrics recorded for each data file.
+///
+/// Everything is reported as inexact since deletes aren't accounted for, and
+/// bounds for strings may be truncated. Column statistics are only reported if
+/// every file has them.
+fn data_files_statistics(
+    schema: &Schema,
+    arrow_schema: &ArrowSchema,
+    data_files: &[DataFile],
+) -> Statistics {
+    let num_rows: i64 = data_files.iter().map(|f| f.record_count).sum();
+
+    let column_statistics = schema
+        .fields
+        .iter()
+        .zip(arrow_schema.fields())
+        .map(|(field, arrow_field)| {
+            let primitive = match &field.r#type {
+                AnyType::Primitive(primitive) => *primitive,
+                _ => return ColumnStatistics::new_unknown(),
+            };
+
+            let null_count: Option<i64> = data_files
+                .iter()
+                .map(|f| find_i64_entry(&f.null_value_counts, field.id))
+                .sum();
+
+            let bounds = |get: fn(&DataFile) -> &Option<Vec<BinaryEntry>>| {
+                data_files
+                    .iter()
+                    .map(|f| {
+                        let value = find_binary_entry(get(f), field.id)?;
+                        decode_bound(primitive, arrow_field.data_type(), value)
+                    })
+                    .collect::<Option<Vec<_>>>()
+            };
+            let min_value = bounds(|f| &f.lower_bounds)
+                .and_then(|values| values.into_iter().reduce(|a, b| if b < a { b } else { a }));
+            let max_value = bounds(|f| &f.upper_bounds)
+                .and_then(|values| values.into_iter().reduce(|a, b| if b > a { b } else { a }));
+
+            ColumnStatistics {
+                null_count: null_count
+                    .map(|count| Precision::Inexact(count as usize))
+                    .unwrap_or(Precision::Absent),
+                max_value: max_value
+                    .map(Precision::Inexact)
+                    .unwrap_or(Precision::Absent),
+                min_value: min_value
+                    .map(Precision::Inexact)
+                    .unwrap_or(Precision::Absent),
+                distinct_count: Precision::Absent,
+            }
+        })
+        .collect();
+
+    Statistics {
+        num_rows: Precision::Inexact(num_rows as usize),
+        total_byte_size: Precision::Absent,
+        column_statistics,
+    }
+}
+
+fn find_i64_entry(entries: &Option<Vec<I64Entry>>, field_id: i32) -> Option<i64> {
+    entries
+        .as_ref()?
+        .iter()
+        .find(|ent| ent.key == field_id)
+        .map(|ent| ent.value)
+}
+
+fn find_binary_entry(entries: &Option<Vec<BinaryEntry>>, field_id: i32) -> Option<&[u8]> {
+    entries
+        .as_ref()?
+        .iter()
+        .find(|ent| ent.key == field_id)
+        .map(|ent| ent.value.as_slice())
+}
+
+/// Decode a lower or upper bound using iceberg's single value serialization.
+///
+/// See <https://iceberg.apache.org/spec/#binary-single-value-serialization>
+fn decode_bound(
+    primitive: PrimitiveType,
+    data_type: &DataType,
+    value: &[u8],
+) -> Option<ScalarValue> {
+    Some(match (primitive, data_type) {
+        (PrimitiveType::Boolean, _) => ScalarValue::Boolean(Some(*value.first()? != 0)),
+        (PrimitiveType::Int, _) => {
+            ScalarValue::Int32(Some(i32::from_le_bytes(value.try_into().ok()?)))
+        }
+        (PrimitiveType::Date, _) => {
+            ScalarValue::Date32(Some(i32::from_le_bytes(value.try_into().ok()?)))
+        }
+        // Columns promoted from int may still have 4 byte bounds.
+        (PrimitiveType::Long, _) => ScalarValue::Int64(Some(match value.len() {
+            4 => i32::from_le_bytes(value.try_into().ok()?) as i64,
+            _ => i64::from_le_bytes(value.try_into().ok()?),
+        })),
+        (PrimitiveType::Float, _) => {
+            ScalarValue::Float32(Some(f32::from_le_bytes(value.try_into().ok()?)))
+        }
+        // Columns promoted from float may still have 4 byte bounds.
+        (PrimitiveType::Double, _) => ScalarValue::Float64(Some(match value.len() {
+            4 => f32::from_le_bytes(value.try_into().ok()?) as f64,
+            _ => f64::from_le_bytes(value.try_into().ok()?),
+        })),
+        (
+            PrimitiveType::Timestamp | PrimitiveType::Timestamptz,
+            DataType::Timestamp(TimeUnit::Microsecond, tz),
+        ) => ScalarValue::TimestampMicrosecond(
+            Some(i64::from_le_bytes(value.try_into().ok()?)),
+            tz.clone(),
+        ),
+        (PrimitiveType::String, DataType::Utf8) => {
+            ScalarValue::Utf8(Some(String::from_utf8(value.to_vec()).ok()?))
+        }
+        // Big endian two's complement using the minimum number of bytes.
+        (PrimitiveType::Decimal { p, s }, _) => {
+            if value.is_empty() || value.len() > 16 {
+                return None;
+            }
+            let mut buf = if value[0] & 0x80 != 0 {
+                [0xff; 16]
+            } else {
+                [0; 16]
+            };
+            buf[16 - value.len()..].copy_from_slice(value);
+            ScalarValue::Decimal128(Some(i128::from_be_bytes(buf)), p, s as i8)
+        }
+        _ => return None,
+    })
+}
+
 /// Formats an object path depending on if it's a url (for real object stores),
 /// or if it's a local path.
 fn format_object_path(
@@ -579,4 +737,51 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_decode_bound() {
+        let test_cases = [
+            (
+                PrimitiveType::Int,
+                DataType::Int32,
+                (-5i32).to_le_bytes().to_vec(),
+                Some(ScalarValue::Int32(Some(-5))),
+            ),
+            (
+                PrimitiveType::Long,
+                DataType::Int64,
+                7i32.to_le_bytes().to_vec(),
+                Some(ScalarValue::Int64(Some(7))),
+            ),
+            (
+                PrimitiveType::Double,
+                DataType::Float64,
+                1.5f64.to_le_bytes().to_vec(),
+                Some(ScalarValue::Float64(Some(1.5))),
+            ),
+            (
+                PrimitiveType::String,
+                DataType::Utf8,
+                b"abc".to_vec(),
+                Some(ScalarValue::Utf8(Some("abc".to_string()))),
+            ),
+            (
+                PrimitiveType::Decimal { p: 15, s: 2 },
+                DataType::Decimal128(15, 2),
+                vec![0xff, 0x38],
+                Some(ScalarValue::Decimal128(Some(-200), 15, 2)),
+            ),
+            // Wrong number of bytes.
+            (PrimitiveType::Int, DataType::Int32, vec![1, 2], None),
+            (PrimitiveType::Binary, DataType::Binary, vec![1, 2], None),
+        ];
+
+        for (primitive, data_type, value, expected) in test_cases {
+            assert_eq!(
+                expected,
+                decode_bound(primitive, &data_type, &value),
+                "primitive: {primitive:?}"
+            );
+        }
+    }
 }
@@ -15,12 +15,14 @@ use datafusion::physical_plan::empty::EmptyExec;
 use datafusion::physical_plan::projection::ProjectionExec;
 use datafusion::physical_plan::{ExecutionPlan, Statistics};
 use datafusion::prelude::Expr;
+use datafusion_ext::functions::TableVersion;
 use datafusion_ext::metrics::ReadOnlyDataSourceMetricsExecAdapter;
 use deltalake::delta_datafusion::DataFusionMixins;
-use deltalake::kernel::{ArrayType, DataType as DeltaDataType};
-use deltalake::logstore::{default_logstore, logstores, LogStore, LogStoreFactory};
+use deltalake::kernel::{Action, ArrayType, DataType as DeltaDataType};
+use deltalake::logstore::{default_logstore, get_actions, logstores, LogStore, LogStoreFactory};
 use deltalake::operations::create::CreateBuilder;
 use deltalake::operations::delete::DeleteBuilder;
+use deltalake::operations::restore::RestoreBuilder;
 use deltalake::operations::update::UpdateBuilder;
 pub use deltalake::protocol::SaveMode;
 use deltalake::storage::{factories, ObjectStoreFactory, ObjectStoreRef, StorageOptions};
@@ -31,13 +33,20 @@ use object_store::prefix::PrefixStore;
 use object_store::ObjectStore;
 use object_store_util::shared::SharedObjectStore;
 use protogen::metastore::types::catalog::TableEntry;
-use protogen::metastore::types::options::{TableOptionsInternal, TableOptionsV0};
+use protogen::metastore::types::options::{
+    IdentityColumn,
+    NativeMaintenancePolicy,
+    TableOptionsInternal,
+    TableOptionsV0,
+};
 use serde_json::{json, Value};
 use url::Url;
 use uuid::Uuid;
 
 use crate::native::errors::{NativeError, Result};
+use crate::native::identity::FillIdentityExec;
 use crate::native::insert::NativeTableInsertExec;
+use crate::native::maintenance::{self, OptimizeMetrics};
 
 #[derive(Debug, Clone)]
 pub struct NativeTableStorage {
@@ -213,7 +222,8 @@ impl NativeTableStorage {
             let mut builder = CreateBuilder::new()
                 .with_save_mode(save_mode)
                 .with_table_name(&table.meta.name)
-                .with_log_store(delta_store);
+                .with_log_store(delta_store)
+                .with_partition_columns(opts.partition_columns.clone());
 
             for col in &opts.columns {
                 let delta_col = arrow_to_delta_safe(&col.arrow_type)?;
@@ -226,8 +236,7 @@ impl NativeTableStorage {
             }
 
             let delta_table = builder.await?;
-            // TODO: Partitioning
-            NativeTable::new(delta_table)
+            NativeTable::new(delta_table).with_identity_columns(opts.identity_columns.clone())
         };
 
         Ok(tbl)
@@ -237,16 +246,166 @@ impl NativeTableStorage {
     ///
     /// Errors if the table is not the correct type.
     pub async fn load_table(&self, table: &TableEntry) -> Result<NativeTable> {
-        let _ = Self::opts_from_ent(table)?; // Check that this is the correct table type.
+        let opts = Self::opts_from_ent(table)?; // Check that this is the correct table type.
 
         let delta_store = self.create_delta_store_for_table(table);
         let mut table = DeltaTable::new(delta_store, DeltaTableConfig::default());
 
         table.load().await?;
 
+        Ok(NativeTable::new(table)
+            .with_maintenance(opts.maintenance)
+            .with_identity_columns(opts.identity_columns))
+    }
+
+    /// Load a native table as it existed at a previous version.
+    ///
+    /// Errors if the table is not the correct type, or if the requested
+    /// version doesn't exist.
+    pub async fn load_table_at_version(
+        &self,
+        table: &TableEntry,
+        version: TableVersion,
+    ) -> Result<NativeTable> {
+        let _ = Self::opts_from_ent(table)?; // Check that this is the correct table type.
+
+        let delta_store = self.create_delta_store_for_table(table);
+        let mut table = DeltaTable::new(delta_store, DeltaTableConfig::default());
+
+        match version {
+            TableVersion::Version(version) => table.load_version(version).await?,
+            TableVersion::Timestamp(ts) => table.load_with_datetime(ts).await?,
+        }
+
         Ok(NativeTable::new(table))
     }
 
+    /// List the commits made to a native table, newest first.
+    ///
+    /// Stops early if older commits have been removed from the log.
+    pub async fn table_history(
+        &self,
+        table: &TableEntry,
+        limit: Option<usize>,
+    ) -> Result<Vec<NativeTableCommit>> {
+        let table = self.load_table(table).await?;
+        let log_store = table.delta.log_store();
+
+        let mut commits = Vec::new();
+        for version in (0..=table.delta.version()).rev() {
+            if limit.is_some_and(|limit| commits.len() >= limit) {
+                break;
+            }
+
+            let bytes = match log_store.read_commit_entry(version).await? {
+                Some(bytes) => bytes,
+                None => break,
+            };
+            let actions = get_actions(version, bytes).await?;
+            commits.push(NativeTableCommit::from_actions(version, actions));
+        }
+
+        Ok(commits)
+    }
+
+    /// Compact the small files of a native table into larger ones.
+    ///
+    /// Rows are clustered by `zorder_columns` if any are provided.
+    pub async fn optimize_table(
+        &self,
+        table: &TableEntry,
+        zorder_columns: Vec<String>,
+    ) -> Result<OptimizeMetrics> {
+        let table = self.load_table(table).await?;
+        let (_, metrics) = maintenance::optimize(table.delta, zorder_columns).await?;
+        Ok(metrics)
+    }
+
+    /// Delete files that are no longer referenced by a native table.
+    ///
+    /// Returns the paths of the deleted files, or the files that would be
+    /// deleted if `dry_run` is set.
+    pub async fn vacuum_table(
+        &self,
+        table: &TableEntry,
+        retain_hours: Option<u64>,
+        dry_run: bool,
+    ) -> Result<Vec<String>> {
+        let table = self.load_table(table).await?;
+        let (_, deleted) = maintenance::vacuum(table.delta, retain_hours, dry_run).await?;
+        Ok(deleted)
+    }
+
+    /// Roll a native table back to a previous version.
+    ///
+    /// This adds a new commit to the table, so versions after the restored
+    /// one can still be read with `AS OF` (until vacuumed).
+    pub async fn restore_table(
+        &self,
+        table: &TableEntry,
+        version: TableVersion,
+    ) -> Result<NativeTableRestore> {
+        let table = self.load_table(table).await?;
+        let builder = RestoreBuilder::new(table.delta.log_store(), table.delta.state.unwrap());
+        let builder = match version {
+            TableVersion::Version(version) => builder.with_version_to_restore(version),
+            TableVersion::Timestamp(ts) => builder.with_datetime_to_restore(ts),
+        };
+        let (table, metrics) = builder.await?;
+
+        Ok(NativeTableRestore {
+            version: table.version(),
+            files_removed: metrics.num_removed_file as u64,
+            files_restored: metrics.num_restored_file as u64,
+        })
+    }
+
+    /// Copy a native table as of its latest version into another store.
+    ///
+    /// Only the log entries up to that version and the data files that make up
+    /// that version are copied, so the copy reads as exactly that version
+    /// regardless of any commits made while copying. Objects keep their paths
+    /// relative to the store root.
+    ///
+    /// Returns the version that was copied.
+    pub async fn backup_table(&self, table: &TableEntry, dest: &dyn ObjectStore) -> Result<i64> {
+        let prefix = self.table_prefix(table.meta.id);
+        let table = self.load_table(table).await?;
+        let version = table.delta.version();
+
+        let log_prefix: ObjectStorePath = format!("{prefix}/_delta_log").into();
+        let mut paths = Vec::new();
+        let mut objects = self.store.list(Some(&log_prefix));
+        while let Some(meta) = objects.next().await {
+            let meta = meta?;
+            // Commits and checkpoints are named after the version they're for.
+            // Anything else (e.g. `_last_checkpoint`) may point past the
+            // version being copied, and is skipped.
+            let log_version = meta
+                .location
+                .filename()
+                .and_then(|name| name.split('.').next())
+                .and_then(|v| v.parse::<i64>().ok());
+            if log_version.is_some_and(|v| v <= version) {
+                paths.push(meta.location);
+            }
+        }
+
+        paths.extend(
+            table
+                .delta
+                .get_files_iter()?
+                .map(|path| ObjectStorePath::from(format!("{prefix}/{path}"))),
+        );
+
+        for path in paths {
+            let bytes = self.store.get(&path).await?.bytes().await?;
+            dest.put(&path, bytes).await?;
+        }
+
+        Ok(version)
+    }
+
     pub async fn delete_table(&self, table: &TableEntry) -> Result<()> {
         let prefix = self.table_prefix(table.meta.id);
         let mut x = self.store.list(Some(&prefix.into()));
@@ -328,24 +487,126 @@ impl NativeTableStorage {
     }
 }
 
+/// Outcome of restoring a native table to a previous version.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NativeTableRestore {
+    /// Version of the commit made by the restore.
+    pub version: i64,
+    pub files_removed: u64,
+    pub files_restored: u64,
+}
+
+/// A single commit in a native table's log.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NativeTableCommit {
+    pub version: i64,
+    /// Commit time in milliseconds since the epoch.
+    pub timestamp: Option<i64>,
+    /// Operation that produced the commit, e.g. "WRITE" or "DELETE".
+    pub operation: Option<String>,
+    /// Parameters of the operation, serialized as JSON.
+    pub operation_parameters: Option<String>,
+    pub files_added: u64,
+    pub files_removed: u64,
+    /// Rows in the added files. `None` if any added file is missing stats.
+    pub rows_added: Option<u64>,
+}
+
+impl NativeTableCommit {
+    fn from_actions(version: i64, actions: Vec<Action>) -> Self {
+        let mut commit = NativeTableCommit {
+            version,
+            rows_added: Some(0),
+            ..Default::default()
+        };
+
+        for action in actions {
+            match action {
+                Action::CommitInfo(info) => {
+                    commit.timestamp = info.timestamp;
+                    commit.operation = info.operation;
+                    commit.operation_parameters = info
+                        .operation_parameters
+                        .and_then(|params| serde_json::to_string(&params).ok());
+                }
+                Action::Add(add) => {
+                    commit.files_added += 1;
+                    let num_records = add.get_stats().ok().flatten().map(|s| s.num_records);
+                    commit.rows_added = match (commit.rows_added, num_records) {
+                        (Some(total), Some(n)) => Some(total + n as u64),
+                        _ => None,
+                    };
+                }
+                Action::Remove(_) => commit.files_removed += 1,
+                _ => (),
+            }
+        }
+
+        commit
+    }
+}
+
 #[derive(Debug)]
 pub struct NativeTable {
     delta: DeltaTable,
+    /// Maintenance to run in the background after inserts.
+    maintenance: Option<NativeMaintenancePolicy>,
+    /// Identity columns to fill in for inserted rows.
+    identity_columns: Vec<IdentityColumn>,
 }
 
 impl NativeTable {
     pub fn new(delta: DeltaTable) -> Self {
-        NativeTable { delta }
+        NativeTable {
+            delta,
+            maintenance: None,
+            identity_columns: Vec::new(),
+        }
+    }
+
+    pub fn with_maintenance(mut self, maintenance: Option<NativeMaintenancePolicy>) -> Self {
+        self.maintenance = maintenance;
+        self
+    }
+
+    pub fn with_identity_columns(mut self, identity_columns: Vec<IdentityColumn>) -> Self {
+        self.identity_columns = identity_columns;
+        self
     }
 
     pub fn storage_location(&self) -> String {
         self.delta.table_uri()
     }
 
+    /// Returns the version of the table that was loaded.
+    pub fn version(&self) -> i64 {
+        self.delta.version()
+    }
+
     pub fn into_table_provider(self) -> Arc<dyn TableProvider> {
         Arc::new(self)
     }
 
+    /// Returns the index of each column of the table in the schema delta
+    /// scans with.
+    ///
+    /// Delta moves partition columns to the end of its schema, so these only
+    /// differ from the table's own column positions for partitioned tables.
+    fn delta_column_indices(&self) -> Vec<usize> {
+        let snapshot = self.delta.snapshot().unwrap();
+        let arrow_schema = snapshot.arrow_schema().unwrap();
+        let table_schema = ArrowSchema::try_from(snapshot.schema()).unwrap();
+        table_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                arrow_schema
+                    .index_of(field.name())
+                    .expect("delta schema is missing a column")
+            })
+            .collect()
+    }
+
     /// Create a new execution plan for inserting `input` into the table.
     pub fn insert_exec(
         &self,
@@ -358,6 +619,8 @@ impl NativeTable {
             SaveMode::Append
         };
 
+        let input = FillIdentityExec::wrap(input, &self.identity_columns);
+
         let store = self.delta.log_store();
         let snapshot = self.delta.state.clone();
         Arc::new(NativeTableInsertExec::new(
@@ -365,6 +628,7 @@ impl NativeTable {
             store,
             snapshot.unwrap(),
             save_mode,
+            self.maintenance.clone(),
         ))
     }
 }
@@ -383,8 +647,9 @@ impl TableProvider for NativeTable {
         let mut fields = vec![];
         let arrow_schema = self.delta.snapshot().unwrap().arrow_schema().unwrap();
 
-        for col in arrow_schema.fields() {
-            let mut field = col.clone();
+        for idx in self.delta_column_indices() {
+            let col = arrow_schema.field(idx);
+            let mut field = Arc::new(col.clone());
             let metadata = col.metadata();
 
             // If the field requires conversion, we need to use the original arrow type
@@ -398,6 +663,13 @@ impl TableProvider for NativeTable {
                     serde_json::from_str(&s).expect("metadata was not correctly written");
 
                 field = Arc::new(Field::new(col.name(), arrow_type, col.is_nullable()));
+            } else if let DataType::Dictionary(_, value_type) = col.data_type() {
+                // Partition columns are dictionary encoded when scanned.
+                field = Arc::new(Field::new(
+                    col.name(),
+                    value_type.as_ref().clone(),
+                    col.is_nullable(),
+                ));
             }
             fields.push(field);
         }
@@ -433,7 +705,17 @@ impl TableProvider for NativeTable {
             let schema = self.schema();
             Ok(Arc::new(EmptyExec::new(schema)))
         } else {
-            let plan = self.delta.scan(session, projection, filters, limit).await?;
+            // Project in the table's column order, delta would otherwise
+            // return partition columns last.
+            let indices = self.delta_column_indices();
+            let delta_projection: Vec<usize> = match projection {
+                Some(projection) => projection.iter().map(|idx| indices[*idx]).collect(),
+                None => indices,
+            };
+            let plan = self
+                .delta
+                .scan(session, Some(&delta_projection), filters, limit)
+                .await?;
             let output_schema = plan.schema();
             let mut schema = self.schema();
             if let Some(projection) = projection {
@@ -480,7 +762,15 @@ impl TableProvider for NativeTable {
     }
 
     fn statistics(&self) -> Option<Statistics> {
-        self.delta.statistics()
+        let mut stats = self.delta.statistics()?;
+        let indices = self.delta_column_indices();
+        if stats.column_statistics.len() == indices.len() {
+            stats.column_statistics = indices
+                .iter()
+                .map(|idx| stats.column_statistics[*idx].clone())
+                .collect();
+        }
+        Some(stats)
     }
 
     async fn insert_into(
@@ -495,7 +785,16 @@ impl TableProvider for NativeTable {
 
 #[cfg(test)]
 mod tests {
-    use datafusion::arrow::datatypes::DataType;
+    use std::collections::BTreeMap;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    use datafusion::arrow::array::Int32Array;
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+    use datafusion::arrow::record_batch::RecordBatch;
+    use datafusion::datasource::TableProvider;
+    use datafusion_ext::functions::TableVersion;
+    use deltalake::operations::write::WriteBuilder;
     use deltalake::protocol::SaveMode;
     use object_store_util::conf::StorageConfig;
     use protogen::metastore::types::catalog::{EntryMeta, EntryType, SourceAccessMode, TableEntry};
@@ -506,21 +805,20 @@ mod tests {
 
     use crate::native::access::NativeTableStorage;
 
-    #[tokio::test]
-    async fn test_delete_table() {
-        let db_id = Uuid::new_v4();
-        let dir = tempdir().unwrap();
+    fn test_storage(path: &Path) -> NativeTableStorage {
         let conf = StorageConfig::Local {
-            path: dir.path().to_path_buf(),
+            path: path.to_path_buf(),
         };
 
-        let storage = NativeTableStorage::new(
-            db_id,
-            Url::from_file_path(dir.path()).unwrap(),
+        NativeTableStorage::new(
+            Uuid::new_v4(),
+            Url::from_file_path(path).unwrap(),
             conf.new_object_store().unwrap(),
-        );
+        )
+    }
 
-        let entry = TableEntry {
+    fn test_entry() -> TableEntry {
+        TableEntry {
             meta: EntryMeta {
                 entry_type: EntryType::Table,
                 id: 12345,
@@ -529,6 +827,8 @@ mod tests {
                 builtin: false,
                 external: false,
                 is_temp: false,
+                comment: None,
+                owner: 0,
             },
             options: TableOptionsInternal {
                 columns: vec![InternalColumnDefinition {
@@ -536,12 +836,24 @@ mod tests {
                     nullable: true,
                     arrow_type: DataType::Int32,
                 }],
+                maintenance: None,
+                partition_columns: Vec::new(),
+                identity_columns: Vec::new(),
             }
             .into(),
             tunnel_id: None,
             access_mode: SourceAccessMode::ReadOnly,
             columns: None,
-        };
+            statistics: None,
+            column_comments: BTreeMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_table() {
+        let dir = tempdir().unwrap();
+        let storage = test_storage(dir.path());
+        let entry = test_entry();
 
         // Create a table, load it, delete it and load it again!
         storage
@@ -558,4 +870,238 @@ mod tests {
             .unwrap_err();
         assert_eq!(err, "Error loading table");
     }
+
+    #[tokio::test]
+    async fn test_create_partitioned_table() {
+        let dir = tempdir().unwrap();
+        let storage = test_storage(dir.path());
+        let mut entry = test_entry();
+        entry.options = TableOptionsInternal {
+            columns: vec![
+                InternalColumnDefinition {
+                    name: "day".to_string(),
+                    nullable: true,
+                    arrow_type: DataType::Utf8,
+                },
+                InternalColumnDefinition {
+                    name: "id".to_string(),
+                    nullable: true,
+                    arrow_type: DataType::Int32,
+                },
+            ],
+            maintenance: None,
+            partition_columns: vec!["day".to_string()],
+            identity_columns: Vec::new(),
+        }
+        .into();
+
+        storage
+            .create_table(&entry, SaveMode::ErrorIfExists)
+            .await
+            .unwrap();
+
+        let table = storage.load_table(&entry).await.unwrap();
+        assert_eq!(
+            vec!["day".to_string()],
+            table.delta.metadata().unwrap().partition_columns
+        );
+
+        // Columns are kept in declaration order even though delta puts
+        // partition columns last.
+        let schema = table.schema();
+        let names: Vec<_> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(vec!["day", "id"], names);
+        assert_eq!(&DataType::Utf8, schema.field(0).data_type());
+    }
+
+    #[tokio::test]
+    async fn test_load_table_at_version() {
+        let dir = tempdir().unwrap();
+        let storage = test_storage(dir.path());
+        let entry = test_entry();
+
+        storage
+            .create_table(&entry, SaveMode::ErrorIfExists)
+            .await
+            .unwrap();
+
+        let table = storage.load_table(&entry).await.unwrap();
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, true)]));
+        let batch =
+            RecordBatch::try_new(schema, vec![Arc::new(Int32Array::from(vec![1, 2, 3]))]).unwrap();
+        WriteBuilder::new(table.delta.log_store(), table.delta.state.clone())
+            .with_input_batches([batch])
+            .await
+            .unwrap();
+
+        assert_eq!(1, storage.load_table(&entry).await.unwrap().version());
+
+        let table = storage
+            .load_table_at_version(&entry, TableVersion::Version(0))
+            .await
+            .unwrap();
+        assert_eq!(0, table.version());
+
+        storage
+            .load_table_at_version(&entry, TableVersion::Version(2))
+            .await
+            .unwrap_err();
+
+        let history = storage.table_history(&entry, None).await.unwrap();
+        let versions: Vec<_> = history.iter().map(|c| c.version).collect();
+        assert_eq!(vec![1, 0], versions);
+        assert_eq!(Some("WRITE"), history[0].operation.as_deref());
+        assert_eq!(Some(3), history[0].rows_added);
+        assert_eq!(1, history[0].files_added);
+
+        let history = storage.table_history(&entry, Some(1)).await.unwrap();
+        assert_eq!(1, history.len());
+    }
+
+    #[tokio::test]
+    async fn test_optimize_and_vacuum_table() {
+        let dir = tempdir().unwrap();
+        let storage = test_storage(dir.path());
+        let entry = test_entry();
+
+        storage
+            .create_table(&entry, SaveMode::ErrorIfExists)
+            .await
+            .unwrap();
+
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, true)]));
+        for i in 0..3 {
+            let table = storage.load_table(&entry).await.unwrap();
+            let batch =
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![i]))])
+                    .unwrap();
+            WriteBuilder::new(table.delta.log_store(), table.delta.state.clone())
+                .with_input_batches([batch])
+                .await
+                .unwrap();
+        }
+
+        let metrics = storage.optimize_table(&entry, Vec::new()).await.unwrap();
+        assert_eq!(1, metrics.files_added);
+        assert_eq!(3, metrics.files_removed);
+
+        // Nothing left to compact.
+        let metrics = storage.optimize_table(&entry, Vec::new()).await.unwrap();
+        assert_eq!(0, metrics.files_removed);
+
+        // Removed files are kept around for the default retention period.
+        let deleted = storage.vacuum_table(&entry, None, false).await.unwrap();
+        assert!(deleted.is_empty());
+
+        let deleted = storage.vacuum_table(&entry, Some(0), true).await.unwrap();
+        assert_eq!(3, deleted.len());
+        let deleted = storage.vacuum_table(&entry, Some(0), false).await.unwrap();
+        assert_eq!(3, deleted.len());
+        let deleted = storage.vacuum_table(&entry, Some(0), false).await.unwrap();
+        assert!(deleted.is_empty());
+
+        // Latest version is still readable.
+        storage.load_table(&entry).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_backup_table() {
+        let dir = tempdir().unwrap();
+        let storage = test_storage(dir.path());
+        let entry = test_entry();
+
+        storage
+            .create_table(&entry, SaveMode::ErrorIfExists)
+            .await
+            .unwrap();
+
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, true)]));
+        for i in 0..2 {
+            let table = storage.load_table(&entry).await.unwrap();
+            let batch =
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![i]))])
+                    .unwrap();
+            WriteBuilder::new(table.delta.log_store(), table.delta.state.clone())
+                .with_input_batches([batch])
+                .await
+                .unwrap();
+        }
+        // Compacted files are no longer part of the latest version.
+        storage.optimize_table(&entry, Vec::new()).await.unwrap();
+
+        let backup_dir = tempdir().unwrap();
+        let conf = StorageConfig::Local {
+            path: backup_dir.path().to_path_buf(),
+        };
+        let backup_store = conf.new_object_store().unwrap();
+
+        let version = storage
+            .backup_table(&entry, backup_store.as_ref())
+            .await
+            .unwrap();
+        assert_eq!(3, version);
+
+        let backup = NativeTableStorage::new(
+            storage.db_id(),
+            Url::from_file_path(backup_dir.path()).unwrap(),
+            backup_store,
+        );
+        let table = backup.load_table(&entry).await.unwrap();
+        assert_eq!(3, table.version());
+        assert_eq!(1, table.delta.get_files_count());
+
+        // Only the files for the latest version were copied.
+        let history = backup.table_history(&entry, None).await.unwrap();
+        assert_eq!(4, history.len());
+        backup
+            .load_table_at_version(&entry, TableVersion::Version(1))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_restore_table() {
+        let dir = tempdir().unwrap();
+        let storage = test_storage(dir.path());
+        let entry = test_entry();
+
+        storage
+            .create_table(&entry, SaveMode::ErrorIfExists)
+            .await
+            .unwrap();
+
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, true)]));
+        for i in 0..2 {
+            let table = storage.load_table(&entry).await.unwrap();
+            let batch =
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![i]))])
+                    .unwrap();
+            WriteBuilder::new(table.delta.log_store(), table.delta.state.clone())
+                .with_input_batches([batch])
+                .await
+                .unwrap();
+        }
+
+        let restore = storage
+            .restore_table(&entry, TableVersion::Version(1))
+            .await
+            .unwrap();
+        assert_eq!(3, restore.version);
+        assert_eq!(1, restore.files_removed);
+        assert_eq!(0, restore.files_restored);
+
+        // Restoring is itself a commit, so we can go back to before it.
+        let restore = storage
+            .restore_table(&entry, TableVersion::Version(2))
+            .await
+            .unwrap();
+        assert_eq!(4, restore.version);
+        assert_eq!(0, restore.files_removed);
+        assert_eq!(1, restore.files_restored);
+
+        storage
+            .restore_table(&entry, TableVersion::Version(10))
+            .await
+            .unwrap_err();
+    }
 }
@@ -0,0 +1,202 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use catalog::mutator::CatalogMutator;
+use datafusion::arrow::array::{Array, ArrayRef, AsArray, Int64Array};
+use datafusion::arrow::compute::{cast, cast_with_options, CastOptions};
+use datafusion::arrow::datatypes::{DataType, Int64Type, SchemaRef};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::error::{DataFusionError, Result as DataFusionResult};
+use datafusion::execution::TaskContext;
+use datafusion::physical_expr::PhysicalSortExpr;
+use datafusion::physical_plan::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::{
+    DisplayAs,
+    DisplayFormatType,
+    Distribution,
+    ExecutionPlan,
+    Partitioning,
+    SendableRecordBatchStream,
+    Statistics,
+};
+use futures::StreamExt;
+use protogen::metastore::types::options::IdentityColumn;
+
+/// Fills in identity columns of rows being inserted into a native table.
+///
+/// Rows that didn't provide a value for an identity column (which arrive here
+/// as nulls) get the next value from the column's sequence.
+#[derive(Debug)]
+pub struct FillIdentityExec {
+    input: Arc<dyn ExecutionPlan>,
+    /// Identity columns along with their index in the input schema.
+    columns: Vec<(usize, IdentityColumn)>,
+    metrics: ExecutionPlanMetricsSet,
+}
+
+impl FillIdentityExec {
+    /// Wrap `input` to fill the given identity columns. Returns the input
+    /// unchanged if none of the identity columns are part of its schema.
+    pub fn wrap(
+        input: Arc<dyn ExecutionPlan>,
+        identity_columns: &[IdentityColumn],
+    ) -> Arc<dyn ExecutionPlan> {
+        let schema = input.schema();
+        let columns: Vec<_> = identity_columns
+            .iter()
+            .filter_map(|col| {
+                schema
+                    .index_of(&col.name)
+                    .ok()
+                    .map(|idx| (idx, col.clone()))
+            })
+            .collect();
+
+        if columns.is_empty() {
+            return input;
+        }
+
+        Arc::new(FillIdentityExec {
+            input,
+            columns,
+            metrics: ExecutionPlanMetricsSet::new(),
+        })
+    }
+}
+
+impl ExecutionPlan for FillIdentityExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.input.schema()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        self.input.output_partitioning()
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        None
+    }
+
+    fn required_input_distribution(&self) -> Vec<Distribution> {
+        vec![Distribution::UnspecifiedDistribution]
+    }
+
+    fn maintains_input_order(&self) -> Vec<bool> {
+        vec![true]
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.input.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(Self {
+            input: children[0].clone(),
+            columns: self.columns.clone(),
+            metrics: ExecutionPlanMetricsSet::new(),
+        }))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> DataFusionResult<SendableRecordBatchStream> {
+        let mutator = context
+            .session_config()
+            .get_extension::<CatalogMutator>()
+            .ok_or_else(|| {
+                DataFusionError::Execution(
+                    "missing catalog mutator for filling identity columns".to_string(),
+                )
+            })?;
+
+        let baseline = BaselineMetrics::new(&self.metrics, partition);
+        let columns = self.columns.clone();
+        let stream = self.input.execute(partition, context)?.then(move |batch| {
+            let mutator = mutator.clone();
+            let columns = columns.clone();
+            let baseline = baseline.clone();
+            async move {
+                let batch = fill_identity_columns(batch?, &columns, &mutator).await?;
+                baseline.record_output(batch.num_rows());
+                Ok(batch)
+            }
+        });
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema(),
+            stream,
+        )))
+    }
+
+    fn statistics(&self) -> DataFusionResult<Statistics> {
+        self.input.statistics()
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+}
+
+impl DisplayAs for FillIdentityExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let names: Vec<_> = self.columns.iter().map(|(_, col)| &col.name).collect();
+        write!(f, "FillIdentityExec: columns={names:?}")
+    }
+}
+
+async fn fill_identity_columns(
+    batch: RecordBatch,
+    columns: &[(usize, IdentityColumn)],
+    mutator: &CatalogMutator,
+) -> DataFusionResult<RecordBatch> {
+    let mut arrays = batch.columns().to_vec();
+
+    for (idx, col) in columns {
+        let array = &arrays[*idx];
+        let null_count = array.null_count();
+
+        if col.always && null_count != array.len() {
+            return Err(DataFusionError::Execution(format!(
+                "cannot insert a non-DEFAULT value into column \"{}\"; it is GENERATED ALWAYS AS IDENTITY",
+                col.name
+            )));
+        }
+        if null_count == 0 {
+            continue;
+        }
+
+        let mut values = mutator
+            .advance_sequence(col.sequence_id, null_count as u64)
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))?
+            .into_iter();
+
+        let provided = cast(array, &DataType::Int64)?;
+        let filled: Int64Array = provided
+            .as_primitive::<Int64Type>()
+            .iter()
+            .map(|v| v.or_else(|| values.next()))
+            .collect();
+
+        // Identity sequences are bounded by the range of the column's type,
+        // so this cast shouldn't fail. Error instead of wrapping if it does.
+        let opts = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let filled: ArrayRef = cast_with_options(&filled, array.data_type(), &opts)?;
+        arrays[*idx] = filled;
+    }
+
+    Ok(RecordBatch::try_new(batch.schema(), arrays)?)
+}
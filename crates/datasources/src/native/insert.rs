@@ -25,8 +25,10 @@ use deltalake::operations::write::WriteBuilder;
 use deltalake::protocol::SaveMode;
 use deltalake::table::state::DeltaTableState;
 use futures::StreamExt;
+use protogen::metastore::types::options::NativeMaintenancePolicy;
 
 use crate::common::util::{create_count_record_batch, COUNT_SCHEMA};
+use crate::native::maintenance::maybe_spawn_maintenance;
 
 /// An execution plan for inserting data into a delta table.
 #[derive(Debug)]
@@ -35,6 +37,8 @@ pub struct NativeTableInsertExec {
     store: Arc<dyn LogStore>,
     snapshot: DeltaTableState,
     save_mode: SaveMode,
+    /// Maintenance to kick off once the insert has been committed.
+    maintenance: Option<NativeMaintenancePolicy>,
 }
 
 impl NativeTableInsertExec {
@@ -43,12 +47,14 @@ impl NativeTableInsertExec {
         store: Arc<dyn LogStore>,
         snapshot: DeltaTableState,
         save_mode: SaveMode,
+        maintenance: Option<NativeMaintenancePolicy>,
     ) -> Self {
         NativeTableInsertExec {
             input,
             store,
             snapshot,
             save_mode,
+            maintenance,
         }
     }
 }
@@ -91,6 +97,7 @@ impl ExecutionPlan for NativeTableInsertExec {
             store: self.store.clone(),
             snapshot: self.snapshot.clone(),
             save_mode: self.save_mode,
+            maintenance: self.maintenance.clone(),
         }))
     }
 
@@ -165,11 +172,16 @@ impl ExecutionPlan for NativeTableInsertExec {
             .with_save_mode(self.save_mode)
             .with_input_execution_plan(input.clone());
 
+        let maintenance = self.maintenance.clone();
         let output = futures::stream::once(async move {
-            let _ = builder
+            let table = builder
                 .await
                 .map_err(|e| DataFusionError::External(Box::new(e)))?;
 
+            if let Some(policy) = maintenance {
+                maybe_spawn_maintenance(table, policy);
+            }
+
             let count = input
                 .metrics()
                 .map(|metrics| metrics.output_rows().unwrap_or_default())
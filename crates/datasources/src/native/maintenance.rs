@@ -0,0 +1,140 @@
+//! Compaction and cleanup of the files backing native tables.
+//!
+//! Every insert into a native table writes at least one new file, so tables
+//! that are appended to frequently end up with many small files. Optimizing
+//! rewrites those into fewer, larger files. The replaced files stay around
+//! for time travel until they're vacuumed.
+use std::collections::HashSet;
+
+use deltalake::operations::optimize::{OptimizeBuilder, OptimizeType};
+use deltalake::operations::vacuum::VacuumBuilder;
+use deltalake::DeltaTable;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use protogen::metastore::types::options::NativeMaintenancePolicy;
+use tracing::{debug, warn};
+
+use crate::native::errors::{NativeError, Result};
+
+/// Locations of tables that currently have background maintenance running.
+static MAINTENANCE_IN_FLIGHT: Lazy<Mutex<HashSet<String>>> =
+    Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Summary of an optimize run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OptimizeMetrics {
+    pub files_added: u64,
+    pub files_removed: u64,
+    pub partitions_optimized: u64,
+    /// Files that were looked at, including ones that didn't need rewriting.
+    pub files_considered: u64,
+    pub files_skipped: u64,
+}
+
+/// Rewrite the table's small files into larger ones.
+///
+/// Rows are clustered by `zorder_columns` if any are provided, otherwise
+/// files are only bin-packed.
+pub async fn optimize(
+    table: DeltaTable,
+    zorder_columns: Vec<String>,
+) -> Result<(DeltaTable, OptimizeMetrics)> {
+    let optimize_type = if zorder_columns.is_empty() {
+        OptimizeType::Compact
+    } else {
+        OptimizeType::ZOrder(zorder_columns)
+    };
+
+    let (table, metrics) = OptimizeBuilder::new(table.log_store(), table.state.unwrap())
+        .with_type(optimize_type)
+        .await?;
+
+    let metrics = OptimizeMetrics {
+        files_added: metrics.num_files_added,
+        files_removed: metrics.num_files_removed,
+        partitions_optimized: metrics.partitions_optimized,
+        files_considered: metrics.total_considered_files as u64,
+        files_skipped: metrics.total_files_skipped as u64,
+    };
+
+    Ok((table, metrics))
+}
+
+/// Delete files no longer referenced by the table, returning their paths.
+///
+/// Files removed within the last `retain_hours` are kept so that recent
+/// versions can still be read with `AS OF`. Without an explicit retention,
+/// the table's default (7 days) is used.
+pub async fn vacuum(
+    table: DeltaTable,
+    retain_hours: Option<u64>,
+    dry_run: bool,
+) -> Result<(DeltaTable, Vec<String>)> {
+    let mut builder =
+        VacuumBuilder::new(table.log_store(), table.state.unwrap()).with_dry_run(dry_run);
+
+    if let Some(hours) = retain_hours {
+        let retention = i64::try_from(hours)
+            .ok()
+            .and_then(chrono::Duration::try_hours)
+            .ok_or(NativeError::Static("Vacuum retention period is too large"))?;
+        // The retention was explicitly asked for, don't second guess it.
+        builder = builder
+            .with_retention_period(retention)
+            .with_enforce_retention_duration(false);
+    }
+
+    let (table, metrics) = builder.await?;
+
+    Ok((table, metrics.files_deleted))
+}
+
+/// Run the maintenance policy in the background if the table has
+/// accumulated enough files.
+///
+/// Errors are logged, the insert that triggered maintenance has already been
+/// committed. At most one maintenance run is in flight per table, inserts
+/// that land while one is running don't start another.
+pub fn maybe_spawn_maintenance(table: DeltaTable, policy: NativeMaintenancePolicy) {
+    let num_files = table.get_files_count() as u64;
+    if num_files < policy.optimize_min_files {
+        return;
+    }
+
+    let location = table.table_uri();
+    if !MAINTENANCE_IN_FLIGHT.lock().insert(location.clone()) {
+        debug!(%location, "native table maintenance already running");
+        return;
+    }
+    let guard = InFlightGuard(location.clone());
+
+    tokio::spawn(async move {
+        let _guard = guard;
+        debug!(%location, %num_files, "running native table maintenance");
+        if let Err(e) = run_maintenance(table, policy).await {
+            warn!(%e, %location, "native table maintenance failed");
+        }
+    });
+}
+
+/// Clears a table's in-flight marker once its maintenance task finishes,
+/// including when the task panics.
+struct InFlightGuard(String);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        MAINTENANCE_IN_FLIGHT.lock().remove(&self.0);
+    }
+}
+
+async fn run_maintenance(table: DeltaTable, policy: NativeMaintenancePolicy) -> Result<()> {
+    let (table, metrics) = optimize(table, policy.zorder_columns).await?;
+    debug!(?metrics, "optimized native table");
+
+    if let Some(hours) = policy.vacuum_retain_hours {
+        let (_, deleted) = vacuum(table, Some(hours), false).await?;
+        debug!(num_deleted = %deleted.len(), "vacuumed native table");
+    }
+
+    Ok(())
+}
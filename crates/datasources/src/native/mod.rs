@@ -3,4 +3,6 @@
 //! "Just" another data source that we happen to manage.
 pub mod access;
 pub mod errors;
+pub mod identity;
 pub mod insert;
+pub mod maintenance;
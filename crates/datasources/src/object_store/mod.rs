@@ -5,7 +5,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use datafusion::arrow::datatypes::SchemaRef;
-use datafusion::common::FileType;
+use datafusion::common::{FileType, Statistics};
 use datafusion::datasource::file_format::FileFormat;
 use datafusion::datasource::listing::PartitionedFile;
 use datafusion::datasource::physical_plan::FileScanConfig;
@@ -21,7 +21,7 @@ use datafusion::physical_plan::{project_schema, ExecutionPlan};
 use datafusion::prelude::Expr;
 use datafusion_ext::metrics::ReadOnlyDataSourceMetricsExecAdapter;
 use errors::{ObjectStoreSourceError, Result};
-use futures::StreamExt;
+use futures::{Stream, StreamExt, TryStreamExt};
 use glob::{MatchOptions, Pattern};
 use object_store::path::Path as ObjectStorePath;
 use object_store::{ObjectMeta, ObjectStore};
@@ -249,13 +249,11 @@ impl ObjStoreAccessor {
             .infer_schema(&store, state, file_format.as_ref(), &objects)
             .await?;
         let base_url = self.access.base_url()?;
-        Ok(Arc::new(ObjStoreTableProvider {
-            store,
-            arrow_schema,
-            base_url,
-            objects,
-            file_format,
-        }))
+        let provider =
+            ObjStoreTableProvider::new(store, arrow_schema, base_url, objects, file_format)
+                .with_file_statistics(state)
+                .await?;
+        Ok(Arc::new(provider))
     }
 
     /// Take the accessor and return the underlying object store.
@@ -271,6 +269,9 @@ pub struct ObjStoreTableProvider {
     base_url: ObjectStoreUrl,
     objects: Vec<ObjectMeta>,
     file_format: Arc<dyn FileFormat>,
+    /// Statistics for each object, and for all of them combined, if they've
+    /// been read ahead of scanning.
+    file_statistics: Option<(Vec<Statistics>, Statistics)>,
 }
 
 impl ObjStoreTableProvider {
@@ -287,8 +288,32 @@ impl ObjStoreTableProvider {
             base_url,
             objects,
             file_format,
+            file_statistics: None,
         }
     }
+
+    /// Read statistics for every object so that they can be reported before
+    /// the table is scanned.
+    ///
+    /// These are only as good as what the file format knows, e.g. parquet
+    /// footers include row counts and min/max values while csv files have
+    /// nothing.
+    pub async fn with_file_statistics(mut self, state: &SessionState) -> DatafusionResult<Self> {
+        let file_statistics: Vec<_> = futures::stream::iter(&self.objects)
+            .map(|object| {
+                self.file_format
+                    .infer_stats(state, &self.store, self.schema(), object)
+            })
+            .buffered(state.config_options().execution.meta_fetch_concurrency)
+            .try_collect()
+            .await?;
+
+        let files = files_with_statistics(&self.objects, &file_statistics);
+        let (_, statistics) = get_statistics_with_limit(files, self.schema(), None, true).await?;
+
+        self.file_statistics = Some((file_statistics, statistics));
+        Ok(self)
+    }
 }
 
 #[async_trait]
@@ -313,19 +338,26 @@ impl TableProvider for ObjStoreTableProvider {
         limit: Option<usize>,
     ) -> DatafusionResult<Arc<dyn ExecutionPlan>> {
         // See datafusion's `ListingTable::list_files_for_scan`.
-        let files = futures::stream::iter(&self.objects)
-            .map(|object| async {
-                let file: PartitionedFile = object.clone().into();
-                let stats = self
-                    .file_format
-                    .infer_stats(ctx, &self.store, self.schema(), object)
-                    .await?;
-                Ok((file, stats))
-            })
-            .boxed()
-            .buffered(ctx.config_options().execution.meta_fetch_concurrency);
-        let (files, statistics) =
-            get_statistics_with_limit(files, self.schema(), limit, true).await?;
+        let (files, statistics) = match &self.file_statistics {
+            Some((file_statistics, _)) => {
+                let files = files_with_statistics(&self.objects, file_statistics);
+                get_statistics_with_limit(files, self.schema(), limit, true).await?
+            }
+            None => {
+                let files = futures::stream::iter(&self.objects)
+                    .map(|object| async {
+                        let file: PartitionedFile = object.clone().into();
+                        let stats = self
+                            .file_format
+                            .infer_stats(ctx, &self.store, self.schema(), object)
+                            .await?;
+                        Ok((file, stats))
+                    })
+                    .boxed()
+                    .buffered(ctx.config_options().execution.meta_fetch_concurrency);
+                get_statistics_with_limit(files, self.schema(), limit, true).await?
+            }
+        };
 
         // If there are no files, return an empty exec plan.
         if files.is_empty() {
@@ -359,6 +391,12 @@ impl TableProvider for ObjStoreTableProvider {
         Ok(Arc::new(ReadOnlyDataSourceMetricsExecAdapter::new(plan)))
     }
 
+    fn statistics(&self) -> Option<Statistics> {
+        self.file_statistics
+            .as_ref()
+            .map(|(_, statistics)| statistics.clone())
+    }
+
     fn supports_filters_pushdown(
         &self,
         filters: &[&Expr],
@@ -372,6 +410,19 @@ impl TableProvider for ObjStoreTableProvider {
     }
 }
 
+/// Pair objects with their already read statistics.
+fn files_with_statistics<'a>(
+    objects: &'a [ObjectMeta],
+    statistics: &'a [Statistics],
+) -> impl Stream<Item = DatafusionResult<(PartitionedFile, Statistics)>> + 'a {
+    futures::stream::iter(
+        objects
+            .iter()
+            .zip(statistics)
+            .map(|(object, stats)| Ok((object.clone().into(), stats.clone()))),
+    )
+}
+
 pub fn file_type_from_path(path: &ObjectStorePath) -> Result<FileType> {
     path.extension()
         .ok_or(ObjectStoreSourceError::NoFileExtension)?
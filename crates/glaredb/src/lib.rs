@@ -19,14 +19,16 @@ use std::task::{Context, Poll};
 use datafusion::arrow::array::{StringArray, UInt64Array};
 use datafusion::arrow::datatypes::{DataType, Field, Schema};
 use datafusion::arrow::error::ArrowError;
+use datafusion::datasource::{MemTable, TableProvider};
 use datafusion::error::DataFusionError;
-use datafusion::logical_expr::LogicalPlan;
+use datafusion::logical_expr::{LogicalPlan, ScalarUDF};
 use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
 use derive_builder::Builder;
 use futures::lock::Mutex;
 use futures::stream::{self, Stream, StreamExt};
 use futures::TryStreamExt;
 use metastore::errors::MetastoreError;
+use sqlbuiltins::functions::scalars::user_defined::UserDefinedScalarUDF;
 use sqlexec::engine::{Engine, EngineStorage, TrackedSession};
 use sqlexec::errors::ExecError;
 use sqlexec::remote::client::RemoteClientType;
@@ -37,11 +39,11 @@ use url::Url;
 // public re-export so downstream users of this package don't have to
 // directly depend on DF (and our version no-less) to use our interfaces.
 pub mod ext {
-    pub use datafusion;
-    pub use datafusion::arrow;
     pub use datafusion::arrow::record_batch::RecordBatch;
+    pub use datafusion::common::ParamValues;
     pub use datafusion::physical_plan::SendableRecordBatchStream;
     pub use datafusion::scalar::ScalarValue;
+    pub use datafusion::{self, arrow};
     pub use sqlexec::environment::EnvironmentReader;
 
     // public exports for some quasi-internal tools used by external and
@@ -52,7 +54,13 @@ pub mod ext {
     }
 }
 
-use crate::ext::{EnvironmentReader, RecordBatch, ScalarValue, SendableRecordBatchStream};
+use crate::ext::{
+    EnvironmentReader,
+    ParamValues,
+    RecordBatch,
+    ScalarValue,
+    SendableRecordBatchStream,
+};
 
 /// ConnectOptions are the set of options to configure a GlareDB
 /// instance, and are an analogue to the commandline arguments to
@@ -255,10 +263,13 @@ impl Connection {
         Operation {
             op: OperationType::Execute,
             query: query.into(),
+            params: None,
             conn: Arc::new(self.clone()),
             schema: None,
             plan: None,
             results: None,
+            rows_affected: None,
+            returns_rows: true,
         }
     }
 
@@ -269,10 +280,13 @@ impl Connection {
         Operation {
             op: OperationType::Sql,
             query: query.into(),
+            params: None,
             conn: Arc::new(self.clone()),
             schema: None,
             plan: None,
             results: None,
+            rows_affected: None,
+            returns_rows: true,
         }
     }
 
@@ -283,12 +297,107 @@ impl Connection {
         Operation {
             op: OperationType::Prql,
             query: query.into(),
+            params: None,
             conn: Arc::new(self.clone()),
             schema: None,
             plan: None,
             results: None,
+            rows_affected: None,
+            returns_rows: true,
         }
     }
+
+    /// Like `sql()`, but with values for the parameters in the
+    /// query. Parameters are either positional (`$1`, `$2`, ...),
+    /// with values passed as a list, or named (`:name`), with values
+    /// passed as a map or list of pairs keyed by name. Values are
+    /// cast to the types inferred for the parameters from the query.
+    pub fn sql_with_params(
+        &self,
+        query: impl Into<String>,
+        params: impl Into<ParamValues>,
+    ) -> Operation {
+        self.sql(query).with_params(params)
+    }
+
+    /// Like `execute()`, but with values for the parameters in the
+    /// query. See `sql_with_params()` for how parameters are passed.
+    pub fn execute_with_params(
+        &self,
+        query: impl Into<String>,
+        params: impl Into<ParamValues>,
+    ) -> Operation {
+        self.execute(query).with_params(params)
+    }
+
+    /// Register a scalar UDF that can be called from queries on this
+    /// connection. The function only lives as long as the connection
+    /// and isn't persisted in the catalog. Registering a function
+    /// with the name of an existing builtin function is an error,
+    /// but a function registered on this connection may be replaced.
+    pub async fn register_udf(&self, udf: ScalarUDF) -> Result<(), DatabaseError> {
+        let mut ses = self.session.lock().await;
+        ses.register_session_function(Arc::new(UserDefinedScalarUDF::new(udf)))?;
+        Ok(())
+    }
+
+    /// Register a table provider as a temporary table for this
+    /// connection, so that it can be queried (and joined with any
+    /// other tables) by name. Registering a table with the name of
+    /// an existing temporary table is an error.
+    pub async fn register_table(
+        &self,
+        name: impl Into<String>,
+        table: Arc<dyn TableProvider>,
+    ) -> Result<(), DatabaseError> {
+        let ses = self.session.lock().await;
+        ses.register_temp_table(name, table)?;
+        Ok(())
+    }
+
+    /// Register record batches as a temporary table for this
+    /// connection. The schema of the table is the schema of the
+    /// batches, so at least one batch is required.
+    pub async fn register_record_batches(
+        &self,
+        name: impl Into<String>,
+        batches: Vec<RecordBatch>,
+    ) -> Result<(), DatabaseError> {
+        let schema = match batches.first() {
+            Some(batch) => batch.schema(),
+            None => {
+                return Err(DatabaseError::new(
+                    "cannot register a table without any record batches",
+                ))
+            }
+        };
+        let table = MemTable::try_new(schema, vec![batches])?;
+        self.register_table(name, Arc::new(table)).await
+    }
+
+    /// Register the results of a stream as a temporary table for
+    /// this connection. The stream is read to completion before
+    /// the table is registered, so the table can be queried any
+    /// number of times.
+    pub async fn register_stream(
+        &self,
+        name: impl Into<String>,
+        stream: SendableRecordBatchStream,
+    ) -> Result<(), DatabaseError> {
+        let schema = stream.schema();
+        let batches = stream.try_collect::<Vec<_>>().await?;
+        let table = MemTable::try_new(schema, vec![batches])?;
+        self.register_table(name, Arc::new(table)).await
+    }
+
+    /// Remove a temporary table from this connection. This works
+    /// for registered tables as well as tables created with
+    /// `CREATE TEMP TABLE`.
+    pub async fn deregister(&self, name: &str) -> Result<(), DatabaseError> {
+        let ses = self.session.lock().await;
+        ses.deregister_temp_table(name)?;
+        Ok(())
+    }
 }
 
 /// RecordStream is like DataFusion's `SendableRecordBatchStream`,
@@ -477,10 +586,13 @@ enum OperationType {
 pub struct Operation {
     op: OperationType,
     query: String,
+    params: Option<ParamValues>,
     conn: Arc<Connection>,
     schema: Option<Arc<Schema>>,
     plan: Option<sqlexec::LogicalPlan>,
     results: Option<Vec<RecordBatch>>,
+    rows_affected: Option<u64>,
+    returns_rows: bool,
 }
 
 // TODO: implement Display instead.
@@ -498,6 +610,33 @@ impl Operation {
         self.schema.clone()
     }
 
+    /// The number of rows inserted, updated, or deleted by the
+    /// operation. This is `None` before `evaluate()` runs and for
+    /// operations that don't modify rows.
+    pub fn rows_affected(&self) -> Option<u64> {
+        self.rows_affected
+    }
+
+    /// Whether the results of the operation are rows of a query, as
+    /// opposed to the count or status of a statement that modified
+    /// the database. Only meaningful after `evaluate()` runs.
+    pub fn returns_rows(&self) -> bool {
+        self.returns_rows
+    }
+
+    /// Sets the values for the parameters in the query, replacing
+    /// any values previously set. Any plan or results from an earlier
+    /// evaluation are discarded.
+    pub fn with_params(mut self, params: impl Into<ParamValues>) -> Self {
+        self.params = Some(params.into());
+        self.plan = None;
+        self.schema = None;
+        self.results = None;
+        self.rows_affected = None;
+        self.returns_rows = true;
+        self
+    }
+
     /// Evaluate constructs a plan for the query that runs run when
     /// `.resolve()` is called. `execute()` (and `sql()` that modify
     /// the state of the database,) run during the `evaluate()` call,
@@ -510,12 +649,7 @@ impl Operation {
 
         let plan = {
             let mut ses = self.conn.session.lock().await;
-            match self.op {
-                OperationType::Sql | OperationType::Execute => {
-                    ses.create_logical_plan(&self.query).await?
-                }
-                OperationType::Prql => ses.prql_to_lp(&self.query).await?,
-            }
+            self.create_logical_plan(&mut ses).await?
         };
 
         self.plan = Some(plan.clone());
@@ -534,20 +668,21 @@ impl Operation {
                         | LogicalPlan::Extension(_)
                         | LogicalPlan::Prepare(_) => {
                             let mut ses = self.conn.session.lock().await;
+                            let (_, result) = ses
+                                .execute_logical_plan(
+                                    plan,
+                                    &OperationInfo::new().with_query_text(self.query.clone()),
+                                )
+                                .await?;
 
+                            self.rows_affected = Self::rows_affected_by(&result);
+                            self.returns_rows = matches!(result, ExecutionResult::Query { .. });
                             self.results = Some(
-                                Self::process_result(
-                                    ses.execute_logical_plan(
-                                        plan,
-                                        &OperationInfo::new().with_query_text(self.query.clone()),
-                                    )
-                                    .await?
-                                    .1,
-                                )
-                                .collect::<Vec<Result<_, _>>>()
-                                .await
-                                .into_iter()
-                                .collect::<Result<Vec<_>, _>>()?,
+                                Self::process_result(result)
+                                    .collect::<Vec<Result<_, _>>>()
+                                    .await
+                                    .into_iter()
+                                    .collect::<Result<Vec<_>, _>>()?,
                             )
                         }
                         _ => {}
@@ -561,20 +696,21 @@ impl Operation {
             OperationType::Prql => {}
             OperationType::Execute => {
                 let mut ses = self.conn.session.lock().await;
+                let (_, result) = ses
+                    .execute_logical_plan(
+                        plan,
+                        &OperationInfo::new().with_query_text(self.query.clone()),
+                    )
+                    .await?;
 
+                self.rows_affected = Self::rows_affected_by(&result);
+                self.returns_rows = matches!(result, ExecutionResult::Query { .. });
                 self.results = Some(
-                    Self::process_result(
-                        ses.execute_logical_plan(
-                            plan,
-                            &OperationInfo::new().with_query_text(self.query.clone()),
-                        )
-                        .await?
-                        .1,
-                    )
-                    .collect::<Vec<Result<_, _>>>()
-                    .await
-                    .into_iter()
-                    .collect::<Result<Vec<_>, _>>()?,
+                    Self::process_result(result)
+                        .collect::<Vec<Result<_, _>>>()
+                        .await
+                        .into_iter()
+                        .collect::<Result<Vec<_>, _>>()?,
                 );
             }
         }
@@ -665,7 +801,7 @@ impl Operation {
                 }
                 None => {
                     let mut ses = self.conn.session.lock().await;
-                    let plan = ses.create_logical_plan(&self.query).await?;
+                    let plan = self.create_logical_plan(&mut ses).await?;
                     let op = OperationInfo::new().with_query_text(self.query.clone());
 
                     Ok(Self::process_result(
@@ -692,6 +828,25 @@ impl Operation {
         ))
     }
 
+    /// Plans the query, binding the operation's parameters (if any).
+    async fn create_logical_plan(
+        &self,
+        ses: &mut TrackedSession,
+    ) -> Result<sqlexec::LogicalPlan, DatabaseError> {
+        let mut plan = match self.op {
+            OperationType::Sql | OperationType::Execute => {
+                ses.create_logical_plan(&self.query).await?
+            }
+            OperationType::Prql => ses.prql_to_lp(&self.query).await?,
+        };
+
+        if let Some(params) = &self.params {
+            plan.replace_param_values(params.clone())?;
+        }
+
+        Ok(plan)
+    }
+
     fn handle_error(err: impl Into<DataFusionError>) -> SendableRecordBatchStream {
         Self::process_result(ExecutionResult::Error(err.into()))
     }
@@ -716,6 +871,15 @@ impl Operation {
         }
     }
 
+    fn rows_affected_by(res: &ExecutionResult) -> Option<u64> {
+        match res {
+            ExecutionResult::InsertSuccess { rows_inserted } => Some(*rows_inserted as u64),
+            ExecutionResult::DeleteSuccess { deleted_rows } => Some(*deleted_rows as u64),
+            ExecutionResult::UpdateSuccess { updated_rows } => Some(*updated_rows as u64),
+            _ => None,
+        }
+    }
+
     fn numeric_result(field_name: impl Into<String>, num: u64) -> SendableRecordBatchStream {
         let schema = Arc::new(Schema::new(vec![Field::new(
             field_name,
@@ -946,4 +1110,204 @@ mod test {
             ScalarValue::Int32(Some(42))
         );
     }
+
+    #[tokio::test]
+    async fn query_with_params() {
+        let db = db().await.unwrap();
+        db.execute("INSERT INTO test_fixture VALUES ('a', 1), ('b', 42)")
+            .evaluate()
+            .await
+            .unwrap()
+            .call()
+            .check()
+            .await
+            .unwrap();
+
+        // Positional, the value is cast to the type of the column.
+        let results = db
+            .sql_with_params(
+                "SELECT title FROM test_fixture WHERE total = $1",
+                vec![ScalarValue::Int64(Some(42))],
+            )
+            .evaluate()
+            .await
+            .unwrap()
+            .call()
+            .to_rows()
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].get(&"title".to_string()).unwrap().to_owned(),
+            ScalarValue::new_utf8("b")
+        );
+
+        // Named.
+        let results = db
+            .sql_with_params(
+                "SELECT total FROM test_fixture WHERE title = :title",
+                vec![("title", ScalarValue::new_utf8("a"))],
+            )
+            .evaluate()
+            .await
+            .unwrap()
+            .call()
+            .to_rows()
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].get(&"total".to_string()).unwrap().to_owned(),
+            ScalarValue::Int32(Some(1))
+        );
+
+        // Wrong number of values.
+        db.sql_with_params(
+            "SELECT * FROM test_fixture WHERE total = $1",
+            Vec::<ScalarValue>::new(),
+        )
+        .evaluate()
+        .await
+        .unwrap_err();
+
+        // Value that can't be cast.
+        db.sql_with_params(
+            "SELECT * FROM test_fixture WHERE total = $1",
+            vec![ScalarValue::new_utf8("not a number")],
+        )
+        .evaluate()
+        .await
+        .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn register_udf() {
+        use datafusion::arrow::array::{ArrayRef, Int64Array};
+        use datafusion::arrow::compute::kernels::numeric::add;
+        use datafusion::logical_expr::{create_udf, ColumnarValue, Volatility};
+
+        let db = db().await.unwrap();
+
+        let add_one = create_udf(
+            "add_one",
+            vec![DataType::Int64],
+            Arc::new(DataType::Int64),
+            Volatility::Immutable,
+            Arc::new(|args: &[ColumnarValue]| {
+                let arr = match &args[0] {
+                    ColumnarValue::Array(arr) => arr.clone(),
+                    ColumnarValue::Scalar(scalar) => scalar.to_array()?,
+                };
+                let ones: ArrayRef = Arc::new(Int64Array::from(vec![1; arr.len()]));
+                Ok(ColumnarValue::Array(add(&arr, &ones)?))
+            }),
+        );
+        db.register_udf(add_one.clone()).await.unwrap();
+
+        let results = db
+            .sql("SELECT add_one(41) AS answer")
+            .evaluate()
+            .await
+            .unwrap()
+            .call()
+            .to_rows()
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].get(&"answer".to_string()).unwrap().to_owned(),
+            ScalarValue::Int64(Some(42))
+        );
+
+        // Replacing a function registered on the connection is fine,
+        // shadowing a builtin isn't.
+        db.register_udf(add_one).await.unwrap();
+
+        let abs = create_udf(
+            "abs",
+            vec![DataType::Int64],
+            Arc::new(DataType::Int64),
+            Volatility::Immutable,
+            Arc::new(|args: &[ColumnarValue]| Ok(args[0].clone())),
+        );
+        db.register_udf(abs).await.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn register_tables() {
+        let db = db().await.unwrap();
+        db.execute("INSERT INTO test_fixture VALUES ('a', 1), ('b', 42)")
+            .evaluate()
+            .await
+            .unwrap()
+            .call()
+            .check()
+            .await
+            .unwrap();
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("title", DataType::Utf8, false),
+            Field::new("label", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(vec!["a", "b"])),
+                Arc::new(StringArray::from(vec!["first", "second"])),
+            ],
+        )
+        .unwrap();
+
+        db.register_record_batches("labels", vec![batch.clone()])
+            .await
+            .unwrap();
+
+        let results = db
+            .sql("SELECT l.label FROM test_fixture t JOIN labels l ON t.title = l.title WHERE t.total = 42")
+            .evaluate()
+            .await
+            .unwrap()
+            .call()
+            .to_rows()
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].get(&"label".to_string()).unwrap().to_owned(),
+            ScalarValue::new_utf8("second")
+        );
+
+        // Names can't be reused until the table is deregistered.
+        db.register_record_batches("labels", vec![batch.clone()])
+            .await
+            .unwrap_err();
+        db.register_record_batches("empty", Vec::new())
+            .await
+            .unwrap_err();
+
+        db.deregister("labels").await.unwrap();
+        db.deregister("labels").await.unwrap_err();
+        db.sql("SELECT * FROM labels").evaluate().await.unwrap_err();
+
+        // Streams are read into memory.
+        let stream = RecordBatchStreamAdapter::new(batch.schema(), stream::iter(vec![Ok(batch)]));
+        db.register_stream("labels", Box::pin(stream))
+            .await
+            .unwrap();
+
+        let results = db
+            .sql("SELECT * FROM labels")
+            .evaluate()
+            .await
+            .unwrap()
+            .call()
+            .to_rows()
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+    }
 }
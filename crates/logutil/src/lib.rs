@@ -1,9 +1,13 @@
 //! Utilities for logging and tracing.
+pub mod otlp;
+
 use std::fs::File;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use tracing::{subscriber, trace, Level};
+use opentelemetry_sdk::trace::Tracer;
+use tracing::{subscriber, trace, Level, Subscriber};
+use tracing_opentelemetry::OpenTelemetryLayer;
 use tracing_subscriber::filter::EnvFilter;
 use tracing_subscriber::fmt::format::{
     Compact,
@@ -16,6 +20,8 @@ use tracing_subscriber::fmt::format::{
 };
 use tracing_subscriber::fmt::time::FormatTime;
 use tracing_subscriber::fmt::SubscriberBuilder;
+use tracing_subscriber::layer::{Layered, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::FmtSubscriber;
 
 #[derive(Debug)]
@@ -78,10 +84,26 @@ pub fn init_test() {
 
 /// Initialize a trace subsriber printing to the console using the given
 /// verbosity count.
-pub fn init(verbosity: impl Into<Verbosity>, mode: LoggingMode, log_file: Option<&PathBuf>) {
+///
+/// If an OTLP endpoint is provided, spans will also be exported to that
+/// endpoint. Call [`otlp::shutdown`] before exiting to flush pending spans.
+pub fn init(
+    verbosity: impl Into<Verbosity>,
+    mode: LoggingMode,
+    log_file: Option<&PathBuf>,
+    otlp_endpoint: Option<&str>,
+) {
     let verbosity: Verbosity = verbosity.into();
     let level: Level = verbosity.into();
 
+    let tracer = otlp_endpoint.and_then(|endpoint| match otlp::init_tracer(endpoint) {
+        Ok(tracer) => Some(tracer),
+        Err(e) => {
+            eprintln!("Failed to initialize OTLP exporter: {e}");
+            None
+        }
+    });
+
     // TODO: Currently with this enabled, we get a _ton_ of logs.
     // LogTracer::init().unwrap();
     let env_filter = env_filter(level);
@@ -94,13 +116,20 @@ pub fn init(verbosity: impl Into<Verbosity>, mode: LoggingMode, log_file: Option
                     Ok(file) => Arc::new(file),
                     Err(_) => {
                         eprintln!("Failed to create file: {:#?}", file);
-                        return subscriber::set_global_default(subscriber.finish()).unwrap();
+                        return subscriber::set_global_default(with_otlp(
+                            subscriber.finish(),
+                            tracer,
+                        ))
+                        .unwrap();
                     }
                 };
 
-                subscriber::set_global_default(subscriber.with_writer(debug_log).finish())
+                subscriber::set_global_default(with_otlp(
+                    subscriber.with_writer(debug_log).finish(),
+                    tracer,
+                ))
             } else {
-                subscriber::set_global_default(subscriber.finish())
+                subscriber::set_global_default(with_otlp(subscriber.finish(), tracer))
             }
         }
         LoggingMode::Full => {
@@ -111,13 +140,20 @@ pub fn init(verbosity: impl Into<Verbosity>, mode: LoggingMode, log_file: Option
                     Ok(file) => Arc::new(file),
                     Err(_) => {
                         eprintln!("Failed to create file: {:#?}", file);
-                        return subscriber::set_global_default(subscriber.finish()).unwrap();
+                        return subscriber::set_global_default(with_otlp(
+                            subscriber.finish(),
+                            tracer,
+                        ))
+                        .unwrap();
                     }
                 };
 
-                subscriber::set_global_default(subscriber.with_writer(debug_log).finish())
+                subscriber::set_global_default(with_otlp(
+                    subscriber.with_writer(debug_log).finish(),
+                    tracer,
+                ))
             } else {
-                subscriber::set_global_default(subscriber.finish())
+                subscriber::set_global_default(with_otlp(subscriber.finish(), tracer))
             }
         }
         LoggingMode::Compact => {
@@ -127,13 +163,20 @@ pub fn init(verbosity: impl Into<Verbosity>, mode: LoggingMode, log_file: Option
                     Ok(file) => Arc::new(file),
                     Err(_) => {
                         eprintln!("Failed to create file: {:#?}", file);
-                        return subscriber::set_global_default(subscriber.finish()).unwrap();
+                        return subscriber::set_global_default(with_otlp(
+                            subscriber.finish(),
+                            tracer,
+                        ))
+                        .unwrap();
                     }
                 };
 
-                subscriber::set_global_default(subscriber.with_writer(debug_log).finish())
+                subscriber::set_global_default(with_otlp(
+                    subscriber.with_writer(debug_log).finish(),
+                    tracer,
+                ))
             } else {
-                subscriber::set_global_default(subscriber.finish())
+                subscriber::set_global_default(with_otlp(subscriber.finish(), tracer))
             }
         }
     }
@@ -142,6 +185,17 @@ pub fn init(verbosity: impl Into<Verbosity>, mode: LoggingMode, log_file: Option
     trace!(set_level = %level, "log level set");
 }
 
+/// Add a layer for exporting spans if a tracer was configured.
+fn with_otlp<S>(
+    subscriber: S,
+    tracer: Option<Tracer>,
+) -> Layered<Option<OpenTelemetryLayer<S, Tracer>>, S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    subscriber.with(tracer.map(|tracer| tracing_opentelemetry::layer().with_tracer(tracer)))
+}
+
 struct PrettyTime;
 impl FormatTime for PrettyTime {
     fn format_time(&self, w: &mut Writer<'_>) -> std::fmt::Result {
@@ -0,0 +1,98 @@
+//! Opt-in export of tracing spans to an OpenTelemetry collector using OTLP.
+//!
+//! Trace context can be propagated across process boundaries (e.g. between a
+//! local client and a remote node) using [`current_trace_context`] and
+//! [`set_parent_from_trace_context`].
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+use opentelemetry::trace::TraceError;
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Config, Tracer};
+use opentelemetry_sdk::{runtime, Resource};
+use tokio::runtime::Runtime;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Name of the service reported to the collector.
+const SERVICE_NAME: &str = "glaredb";
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Runtime the batch exporter runs on.
+///
+/// Logging is initialized before the main runtime is built, and the exporter
+/// needs to outlive that runtime anyways so that spans can be flushed on
+/// shutdown.
+static EXPORT_RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+/// Returns if spans are being exported.
+///
+/// Useful for skipping work that only exists to produce spans.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Create a tracer exporting to the OTLP/gRPC endpoint. The pipeline sets its
+/// tracer provider as the global provider.
+pub(crate) fn init_tracer(endpoint: &str) -> Result<Tracer, TraceError> {
+    let rt = EXPORT_RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("otlp-export")
+            .enable_all()
+            .build()
+            .expect("failed to build otlp export runtime")
+    });
+    let _guard = rt.enter();
+
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(endpoint),
+        )
+        .with_trace_config(
+            Config::default()
+                .with_resource(Resource::new([KeyValue::new("service.name", SERVICE_NAME)])),
+        )
+        .install_batch(runtime::Tokio)?;
+
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    ENABLED.store(true, Ordering::Relaxed);
+
+    Ok(tracer)
+}
+
+/// Flush any pending spans and shut down the exporter.
+pub fn shutdown() {
+    if is_enabled() {
+        global::shutdown_tracer_provider();
+    }
+}
+
+/// Get the trace context for the current span as key/value pairs suitable for
+/// sending as request metadata.
+///
+/// Empty if spans aren't being exported.
+pub fn current_trace_context() -> HashMap<String, String> {
+    let mut carrier = HashMap::new();
+    if is_enabled() {
+        let cx = Span::current().context();
+        global::get_text_map_propagator(|propagator| propagator.inject_context(&cx, &mut carrier));
+    }
+    carrier
+}
+
+/// Set the parent of a span using trace context received from a remote
+/// caller.
+pub fn set_parent_from_trace_context(span: &Span, carrier: &HashMap<String, String>) {
+    if is_enabled() {
+        let cx = global::get_text_map_propagator(|propagator| propagator.extract(carrier));
+        span.set_parent(cx);
+    }
+}
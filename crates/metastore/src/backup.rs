@@ -0,0 +1,66 @@
+//! Catalog snapshots for database backups.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use object_store::ObjectStore;
+use pgrepr::oid::FIRST_AVAILABLE_ID;
+use protogen::metastore::types::catalog::CatalogState;
+use protogen::metastore::types::storage::{ExtraState, PersistedCatalog};
+use uuid::Uuid;
+
+use crate::database::builtin_comments;
+use crate::errors::Result;
+use crate::storage::persist::Storage;
+
+/// Write a snapshot of a database's catalog to a store.
+///
+/// The snapshot becomes the latest version of the catalog in that store, so
+/// the store can be used as the storage for a metastore afterwards. Builtin
+/// objects are left out of the snapshot, they're added back when the catalog
+/// is loaded.
+///
+/// Errors if the store already contains a catalog for the database.
+pub async fn write_catalog_snapshot(
+    store: Arc<dyn ObjectStore>,
+    db_id: Uuid,
+    state: &CatalogState,
+) -> Result<()> {
+    let entries: HashMap<_, _> = state
+        .entries
+        .iter()
+        .filter(|(_, ent)| {
+            let meta = ent.get_meta();
+            !meta.builtin && !meta.is_temp
+        })
+        .map(|(oid, ent)| (*oid, ent.clone()))
+        .collect();
+
+    // Dropped tables keep their oids so that they can be undropped, make sure
+    // those aren't handed out again either.
+    let max_oid = entries
+        .keys()
+        .copied()
+        .chain(state.dropped_tables.iter().map(|t| t.table.meta.id))
+        .max()
+        .unwrap_or(0);
+
+    let catalog = PersistedCatalog {
+        state: CatalogState {
+            version: state.version,
+            entries,
+            deployment: state.deployment.clone(),
+            catalog_version: state.catalog_version,
+            dropped_tables: state.dropped_tables.clone(),
+            builtin_comments: builtin_comments(&state.entries),
+        },
+        extra: ExtraState {
+            oid_counter: FIRST_AVAILABLE_ID.max(max_oid + 1),
+        },
+    };
+
+    let storage = Storage::new(Uuid::new_v4(), store);
+    storage.import_catalog(db_id, catalog).await?;
+
+    Ok(())
+}
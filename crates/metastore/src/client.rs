@@ -57,8 +57,14 @@ use std::time::Duration;
 use catalog::client::{ClientRequest, MetastoreClientConfig, MetastoreClientHandle};
 use catalog::errors::CatalogError;
 use protogen::gen::metastore::service::metastore_service_client::MetastoreServiceClient;
-use protogen::gen::metastore::service::{CommitRequest, FetchCatalogRequest, MutateRequest};
+use protogen::gen::metastore::service::{
+    CommitRequest,
+    FetchCatalogHistoryRequest,
+    FetchCatalogRequest,
+    MutateRequest,
+};
 use protogen::metastore::types::catalog::CatalogState;
+use protogen::metastore::types::storage::CatalogVersionInfo;
 use tokio::sync::{mpsc, RwLock};
 use tokio::task::JoinHandle;
 use tonic::transport::Channel;
@@ -241,6 +247,7 @@ impl StatefulWorker {
         let resp = client
             .fetch_catalog(tonic::Request::new(FetchCatalogRequest {
                 db_id: db_id.into_bytes().to_vec(),
+                version: None,
             }))
             .await
             .unwrap();
@@ -410,6 +417,47 @@ impl StatefulWorker {
                     error!("failed to respond to refresh cached catalog state request");
                 }
             }
+            ClientRequest::GetStateAtVersion { version, response } => {
+                let result = self
+                    .client
+                    .fetch_catalog(tonic::Request::new(FetchCatalogRequest {
+                        db_id: self.db_id.into_bytes().to_vec(),
+                        version: Some(version),
+                    }))
+                    .await
+                    .map_err(CatalogError::from)
+                    .and_then(|resp| match resp.into_inner().catalog {
+                        Some(catalog) => {
+                            let state: CatalogState = catalog.try_into()?;
+                            Ok(Arc::new(state))
+                        }
+                        None => Err(CatalogError::new("missing field: 'catalog'")),
+                    });
+
+                if response.send(result).is_err() {
+                    error!("failed to respond to get state at version request");
+                }
+            }
+            ClientRequest::GetCatalogHistory { response } => {
+                let result = self
+                    .client
+                    .fetch_catalog_history(tonic::Request::new(FetchCatalogHistoryRequest {
+                        db_id: self.db_id.into_bytes().to_vec(),
+                    }))
+                    .await
+                    .map_err(CatalogError::from)
+                    .and_then(|resp| {
+                        resp.into_inner()
+                            .versions
+                            .into_iter()
+                            .map(|info| Ok(CatalogVersionInfo::try_from(info)?))
+                            .collect::<Result<Vec<_>, CatalogError>>()
+                    });
+
+                if response.send(result).is_err() {
+                    error!("failed to respond to get catalog history request");
+                }
+            }
         }
     }
 
@@ -419,6 +467,7 @@ impl StatefulWorker {
             .client
             .fetch_catalog(tonic::Request::new(FetchCatalogRequest {
                 db_id: self.db_id.into_bytes().to_vec(),
+                version: None,
             }))
             .await
         {
@@ -488,6 +537,7 @@ mod tests {
                     sql: "select 1".to_string(),
                     or_replace: false,
                     columns: Vec::new(),
+                    owner: 0,
                 })],
             )
             .await
@@ -1,7 +1,8 @@
 //! Module for handling the catalog for a single database.
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use once_cell::sync::Lazy;
 use pgrepr::oid::FIRST_AVAILABLE_ID;
@@ -11,10 +12,14 @@ use protogen::metastore::types::catalog::{
     CredentialsEntry,
     DatabaseEntry,
     DeploymentMetadata,
+    DroppedTable,
     EntryMeta,
     EntryType,
     FunctionEntry,
+    PrivilegeGrant,
+    RoleEntry,
     SchemaEntry,
+    SequenceEntry,
     SourceAccessMode,
     TableEntry,
     TunnelEntry,
@@ -25,10 +30,16 @@ use protogen::metastore::types::options::{
     DatabaseOptions,
     DatabaseOptionsInternal,
     TableOptionsInternal,
+    TableOptionsV0,
     TunnelOptions,
 };
-use protogen::metastore::types::service::{AlterDatabaseOperation, AlterTableOperation, Mutation};
-use protogen::metastore::types::storage::{ExtraState, PersistedCatalog};
+use protogen::metastore::types::service::{
+    AlterDatabaseOperation,
+    AlterTableOperation,
+    CommentObject,
+    Mutation,
+};
+use protogen::metastore::types::storage::{CatalogVersionInfo, ExtraState, PersistedCatalog};
 use sqlbuiltins::builtins::{
     BuiltinDatabase,
     BuiltinSchema,
@@ -59,6 +70,9 @@ const DATABASE_PARENT_ID: u32 = 0;
 /// is not configurable when creating a session.
 const MAX_DATABASE_OBJECTS: usize = 2048;
 
+/// Upper bound on how long dropped tables can be retained for (100 years).
+const MAX_DROPPED_TABLE_RETENTION_HOURS: u64 = 100 * 365 * 24;
+
 /// A global builtin catalog. This is meant to be cloned for every database
 /// catalog.
 static BUILTIN_CATALOG: Lazy<BuiltinCatalog> = Lazy::new(|| BuiltinCatalog::new().unwrap());
@@ -120,11 +134,21 @@ impl DatabaseCatalog {
         state_to_commit: CatalogState,
     ) -> Result<CatalogState> {
         let state = self.cached.lock().await;
+        // Descriptions of the mutations are only available if the state being
+        // committed came from mutating the cached state.
+        let (mutations, sequences_only) = if state.version == state_to_commit.version {
+            (
+                state.pending_mutations.clone(),
+                !state.pending_mutations.is_empty() && state.pending_sequences_only,
+            )
+        } else {
+            (Vec::new(), false)
+        };
         let state = State::from_catalog_state_and_counter(state_to_commit, state.oid_counter)?;
         let persist = state.to_persisted();
 
         self.storage
-            .write_catalog(self.db_id, version, persist)
+            .write_catalog(self.db_id, version, persist, mutations, sequences_only)
             .await?;
         self.require_full_load.store(true, Ordering::Relaxed);
 
@@ -162,6 +186,20 @@ impl DatabaseCatalog {
         Ok(state)
     }
 
+    /// Get the state of the catalog at some older version.
+    ///
+    /// Errors if the version is no longer retained.
+    pub async fn get_state_at(&self, version: u64) -> Result<CatalogState> {
+        let persisted = self.storage.read_catalog_at(self.db_id, version).await?;
+        let state = State::from_persisted(persisted)?;
+        Ok(self.serializable_state(&state))
+    }
+
+    /// Get the retained versions of the catalog, oldest first.
+    pub async fn history(&self) -> Result<Vec<CatalogVersionInfo>> {
+        Ok(self.storage.catalog_history(&self.db_id).await?)
+    }
+
     /// Return the serializable state of the catalog at this version.
     fn serializable_state(&self, state: &State) -> CatalogState {
         CatalogState {
@@ -169,6 +207,9 @@ impl DatabaseCatalog {
             entries: state.entries.as_ref().clone(),
             deployment: state.deployment.clone(),
             catalog_version: CURRENT_CATALOG_VERSION,
+            dropped_tables: state.dropped_tables.clone(),
+            // Builtin entries are included, along with their comments.
+            builtin_comments: HashMap::new(),
         }
     }
 
@@ -239,6 +280,15 @@ impl DatabaseEntries {
         }
     }
 
+    /// Get a mutable reference to the catalog entry if it exists, including
+    /// builtin entries.
+    ///
+    /// Only comments may be changed on builtin entries, since those are
+    /// persisted separately.
+    fn get_mut_for_comment(&mut self, oid: &u32) -> Option<&mut CatalogEntry> {
+        self.0.get_mut(oid)
+    }
+
     /// Remove an entry. Errors if the entry is builtin.
     fn remove(&mut self, oid: &u32) -> Result<Option<CatalogEntry>> {
         if let Some(ent) = self.0.get(oid) {
@@ -276,6 +326,18 @@ impl DatabaseEntries {
     }
 }
 
+/// Get the comments set on builtin entries.
+///
+/// Builtin entries aren't persisted, so their comments are stored separately
+/// and applied again when the catalog is loaded.
+pub(crate) fn builtin_comments(entries: &HashMap<u32, CatalogEntry>) -> HashMap<u32, String> {
+    entries
+        .iter()
+        .filter(|(_, ent)| ent.get_meta().builtin)
+        .filter_map(|(oid, ent)| Some((*oid, ent.get_meta().comment.clone()?)))
+        .collect()
+}
+
 impl From<HashMap<u32, CatalogEntry>> for DatabaseEntries {
     fn from(value: HashMap<u32, CatalogEntry>) -> Self {
         DatabaseEntries(value)
@@ -335,10 +397,19 @@ struct State {
     tunnel_names: HashMap<String, u32>,
     /// Map credentials names to their ids.
     credentials_names: HashMap<String, u32>,
+    /// Map role names to their ids.
+    role_names: HashMap<String, u32>,
     /// Map schema names to their ids.
     schema_names: HashMap<String, u32>,
     /// Map schema IDs to objects in the schema.
     schema_objects: HashMap<u32, SchemaObjects>,
+    /// Dropped native tables that can still be undropped, oldest first.
+    dropped_tables: Vec<DroppedTable>,
+    /// Descriptions of mutations applied to this state since it was loaded.
+    /// Recorded in the catalog history on commit.
+    pending_mutations: Vec<String>,
+    /// Whether the pending mutations only changed sequence values.
+    pending_sequences_only: bool,
 }
 
 impl State {
@@ -349,6 +420,7 @@ impl State {
         if state.entries.iter().any(|(_, ent)| ent.get_meta().is_temp) {
             panic!("temp object found in catalog: {:?}", state.entries);
         }
+        state.builtin_comments = builtin_comments(&state.entries);
         state
             .entries
             .retain(|_, ent| !ent.get_meta().is_temp && !ent.get_meta().builtin);
@@ -373,6 +445,7 @@ impl State {
         let mut database_names = HashMap::new();
         let mut tunnel_names = HashMap::new();
         let mut credentials_names = HashMap::new();
+        let mut role_names = HashMap::new();
         let mut schema_names = HashMap::new();
         let mut schema_objects = HashMap::new();
 
@@ -392,6 +465,17 @@ impl State {
         schema_names.extend(builtin.schema_names);
         schema_objects.extend(builtin.schema_objects);
 
+        // Comments are the only part of builtin objects that can be changed.
+        for (oid, comment) in std::mem::take(&mut state.builtin_comments) {
+            if let Some(ent) = state
+                .entries
+                .get_mut(&oid)
+                .filter(|ent| ent.get_meta().builtin)
+            {
+                ent.get_meta_mut().comment = Some(comment);
+            }
+        }
+
         // Rebuild name maps for user objects.
         //
         // All non-database objects are checked to ensure they have non-zero
@@ -435,6 +519,17 @@ impl State {
 
                     credentials_names.insert(creds.meta.name.clone(), *oid);
                 }
+                CatalogEntry::Role(role) => {
+                    if role.meta.parent != DATABASE_PARENT_ID {
+                        return Err(MetastoreError::ObjectHasNonZeroParent {
+                            object: *oid,
+                            parent: role.meta.parent,
+                            object_type: "role",
+                        });
+                    }
+
+                    role_names.insert(role.meta.name.clone(), *oid);
+                }
                 CatalogEntry::Schema(schema) => {
                     if schema.meta.parent == DATABASE_PARENT_ID {
                         return Err(MetastoreError::ObjectHasInvalidParentId {
@@ -446,7 +541,9 @@ impl State {
 
                     schema_names.insert(schema.meta.name.clone(), *oid);
                 }
-                entry @ CatalogEntry::View(_) | entry @ CatalogEntry::Table(_) => {
+                entry @ CatalogEntry::View(_)
+                | entry @ CatalogEntry::Table(_)
+                | entry @ CatalogEntry::Sequence(_) => {
                     if entry.get_meta().parent == DATABASE_PARENT_ID {
                         return Err(MetastoreError::ObjectHasInvalidParentId {
                             object: *oid,
@@ -501,8 +598,12 @@ impl State {
             database_names,
             tunnel_names,
             credentials_names,
+            role_names,
             schema_names,
             schema_objects,
+            dropped_tables: state.dropped_tables,
+            pending_mutations: Vec::new(),
+            pending_sequences_only: true,
         };
 
         Ok(internal_state)
@@ -537,6 +638,8 @@ impl State {
                     .filter(|(_, ent)| !ent.get_meta().builtin)
                     .collect(),
                 catalog_version: CURRENT_CATALOG_VERSION,
+                dropped_tables: self.dropped_tables.clone(),
+                builtin_comments: builtin_comments(self.entries.as_ref()),
             },
             extra: ExtraState {
                 oid_counter: self.oid_counter,
@@ -569,6 +672,11 @@ impl State {
         (self.version, _) = self.version.overflowing_add(1);
 
         for mutation in mutations {
+            self.pending_mutations.push(mutation.to_string());
+            self.pending_sequences_only &= matches!(
+                mutation,
+                Mutation::AdvanceSequence(_) | Mutation::SetSequenceValue(_)
+            );
             self.mutate_one(mutation)?;
         }
 
@@ -609,6 +717,24 @@ impl State {
 
                 self.entries.remove(&credentials_id)?.unwrap();
             }
+            Mutation::DropRole(drop_role) => {
+                let if_exists = drop_role.if_exists;
+                let role_id = match self.role_names.remove(&drop_role.name) {
+                    None if if_exists => return Ok(()),
+                    None => return Err(MetastoreError::MissingRole(drop_role.name)),
+                    Some(id) => id,
+                };
+
+                self.entries.remove(&role_id)?.unwrap();
+
+                // Remove membership in the dropped role from all other roles.
+                let member_ids: Vec<_> = self.role_names.values().copied().collect();
+                for id in member_ids {
+                    if let Some(CatalogEntry::Role(role)) = self.entries.get_mut(&id)? {
+                        role.member_of.retain(|oid| *oid != role_id);
+                    }
+                }
+            }
             Mutation::DropSchema(drop_schema) => {
                 let if_exists = drop_schema.if_exists;
                 let schema_id = match self.schema_names.remove(&drop_schema.name) {
@@ -652,7 +778,7 @@ impl State {
                     Some(id) => *id,
                 };
 
-                let objs = match self.schema_objects.get_mut(&schema_id) {
+                let objs = match self.schema_objects.get(&schema_id) {
                     None if if_exists => return Ok(()),
                     None => {
                         return Err(MetastoreError::MissingNamedObject {
@@ -665,7 +791,7 @@ impl State {
 
                 // TODO: This will need to be tweaked if/when we support
                 // dropping functions.
-                let ent_id = match objs.tables.remove(&drop_object.name) {
+                let ent_id = match objs.tables.get(&drop_object.name) {
                     None if if_exists => return Ok(()),
                     None => {
                         return Err(MetastoreError::MissingNamedObject {
@@ -673,10 +799,178 @@ impl State {
                             name: drop_object.name,
                         })
                     }
-                    Some(id) => id,
+                    Some(id) => *id,
+                };
+
+                // Sequences backing identity columns can only be dropped
+                // along with their table.
+                if let Some(CatalogEntry::Sequence(seq)) = self.entries.get(&ent_id)? {
+                    if let Some(table) = seq.owned_by.and_then(|id| self.entries.as_ref().get(&id))
+                    {
+                        return Err(MetastoreError::SequenceOwnedByTable {
+                            name: drop_object.name,
+                            table: table.get_meta().name.clone(),
+                        });
+                    }
+                }
+
+                // Checked above.
+                self.schema_objects
+                    .get_mut(&schema_id)
+                    .unwrap()
+                    .tables
+                    .remove(&drop_object.name);
+                let ent = self.entries.remove(&ent_id)?.unwrap(); // Bug if doesn't exist.
+                let sequences = self.remove_owned_sequences(ent_id)?;
+
+                // Hold on to native tables so that the drop can be undone.
+                // The table's data is left in place until it's purged. The
+                // expiry is fixed now so later changes to the retention
+                // setting don't affect already dropped tables.
+                let retention_hours = self.deployment.dropped_table_retention_hours;
+                if let CatalogEntry::Table(table) = ent {
+                    if drop_object.retain && !table.meta.external && retention_hours > 0 {
+                        let dropped_at = now_millis();
+                        let expires_at = i64::try_from(retention_hours)
+                            .ok()
+                            .and_then(|hours| hours.checked_mul(60 * 60 * 1000))
+                            .and_then(|retention| dropped_at.checked_add(retention))
+                            .unwrap_or(i64::MAX);
+                        self.dropped_tables.push(DroppedTable {
+                            table,
+                            schema: drop_object.schema,
+                            dropped_at,
+                            sequences,
+                            expires_at,
+                        });
+                    }
+                }
+            }
+            Mutation::UndropTable(undrop) => {
+                // Most recently dropped table wins if the same name was
+                // dropped multiple times.
+                let idx = self
+                    .dropped_tables
+                    .iter()
+                    .rposition(|dropped| {
+                        dropped.schema == undrop.schema && dropped.table.meta.name == undrop.name
+                    })
+                    .ok_or_else(|| MetastoreError::MissingDroppedTable {
+                        schema: undrop.schema.clone(),
+                        name: undrop.name.clone(),
+                    })?;
+
+                // The schema may have been dropped and recreated since.
+                let schema_id = self.get_schema_id(&undrop.schema)?;
+
+                let mut table = self.dropped_tables[idx].table.clone();
+                table.meta.parent = schema_id;
+                let oid = table.meta.id;
+
+                self.try_insert_table_namespace(
+                    CatalogEntry::Table(table),
+                    schema_id,
+                    oid,
+                    CreatePolicy::Create,
+                )?;
+                for mut seq in self.dropped_tables[idx].sequences.clone() {
+                    seq.meta.parent = schema_id;
+                    let seq_oid = seq.meta.id;
+                    self.try_insert_table_namespace(
+                        CatalogEntry::Sequence(seq),
+                        schema_id,
+                        seq_oid,
+                        CreatePolicy::Create,
+                    )?;
+                }
+                self.dropped_tables.remove(idx);
+            }
+            Mutation::PurgeDroppedTables(purge) => {
+                // Never purge anything that hasn't expired yet, regardless of
+                // what the caller thinks the time is.
+                let expired_before = purge.expired_before.min(now_millis());
+                self.dropped_tables
+                    .retain(|dropped| dropped.expires_at > expired_before);
+            }
+            Mutation::CommentOn(comment_on) => {
+                let oid = match &comment_on.object {
+                    CommentObject::Database { name } => *self
+                        .database_names
+                        .get(name)
+                        .ok_or_else(|| MetastoreError::MissingDatabase(name.clone()))?,
+                    CommentObject::Schema { name } => self.get_schema_id(name)?,
+                    CommentObject::Table { schema, name }
+                    | CommentObject::View { schema, name }
+                    | CommentObject::Column {
+                        schema,
+                        table: name,
+                        ..
+                    } => {
+                        let schema_id = self.get_schema_id(schema)?;
+                        self.schema_objects
+                            .get(&schema_id)
+                            .and_then(|objs| objs.tables.get(name))
+                            .cloned()
+                            .ok_or_else(|| MetastoreError::MissingNamedObject {
+                                schema: schema.clone(),
+                                name: name.clone(),
+                            })?
+                    }
                 };
 
-                self.entries.remove(&ent_id)?.unwrap(); // Bug if doesn't exist.
+                let ent = match &comment_on.object {
+                    // Builtin databases and schemas can have comments, other
+                    // builtin objects are checked when planning.
+                    CommentObject::Database { .. } | CommentObject::Schema { .. } => {
+                        self.entries.get_mut_for_comment(&oid)
+                    }
+                    _ => self.entries.get_mut(&oid)?,
+                }
+                .unwrap();
+                match (comment_on.object, ent) {
+                    (
+                        CommentObject::Column {
+                            schema,
+                            table,
+                            column,
+                        },
+                        CatalogEntry::Table(ent),
+                    ) => {
+                        // Columns of external tables aren't stored, those get
+                        // checked when planning.
+                        if let Some(cols) = ent.get_internal_columns() {
+                            if !cols.iter().any(|col| col.name == column) {
+                                return Err(MetastoreError::MissingNamedColumn {
+                                    schema,
+                                    table,
+                                    column,
+                                });
+                            }
+                        }
+                        match comment_on.comment {
+                            Some(comment) => ent.column_comments.insert(column, comment),
+                            None => ent.column_comments.remove(&column),
+                        };
+                    }
+                    (CommentObject::Database { .. }, ent @ CatalogEntry::Database(_))
+                    | (CommentObject::Schema { .. }, ent @ CatalogEntry::Schema(_))
+                    | (CommentObject::Table { .. }, ent @ CatalogEntry::Table(_))
+                    | (CommentObject::View { .. }, ent @ CatalogEntry::View(_)) => {
+                        ent.get_meta_mut().comment = comment_on.comment;
+                    }
+                    (object, ent) => {
+                        let expected = match object {
+                            CommentObject::Database { .. } => "database",
+                            CommentObject::Schema { .. } => "schema",
+                            CommentObject::Table { .. } | CommentObject::Column { .. } => "table",
+                            CommentObject::View { .. } => "view",
+                        };
+                        return Err(MetastoreError::UnexpectedObjectType {
+                            name: ent.get_meta().name.clone(),
+                            expected,
+                        });
+                    }
+                }
             }
             Mutation::CreateExternalDatabase(create_database) => {
                 validate_object_name(&create_database.name)?;
@@ -710,6 +1004,8 @@ impl State {
                         builtin: false,
                         external: true,
                         is_temp: false,
+                        comment: None,
+                        owner: 0,
                     },
                     options: create_database.options,
                     tunnel_id,
@@ -740,6 +1036,8 @@ impl State {
                         builtin: false,
                         external: false,
                         is_temp: false,
+                        comment: None,
+                        owner: 0,
                     },
                     options: create_tunnel.options,
                 };
@@ -775,6 +1073,8 @@ impl State {
                         builtin: false,
                         external: false,
                         is_temp: false,
+                        comment: None,
+                        owner: 0,
                     },
                     options: create_credentials.options,
                     comment: create_credentials.comment,
@@ -784,6 +1084,65 @@ impl State {
                 // Add to creadentials map
                 self.credentials_names.insert(create_credentials.name, oid);
             }
+            Mutation::CreateRole(create_role) => {
+                validate_object_name(&create_role.name)?;
+                match self.role_names.get(&create_role.name) {
+                    Some(_) if create_role.if_not_exists => return Ok(()), // Already exists, nothing to do.
+                    Some(_) => return Err(MetastoreError::DuplicateName(create_role.name)),
+                    None => (),
+                }
+
+                let member_of = create_role
+                    .member_of
+                    .into_iter()
+                    .map(|name| match self.role_names.get(&name) {
+                        Some(oid) => Ok(*oid),
+                        None => Err(MetastoreError::MissingRole(name)),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                let oid = self.next_oid();
+                let ent = RoleEntry {
+                    meta: EntryMeta {
+                        entry_type: EntryType::Role,
+                        id: oid,
+                        // Roles are global to the database, no parent.
+                        parent: DATABASE_PARENT_ID,
+                        name: create_role.name.clone(),
+                        builtin: false,
+                        external: false,
+                        is_temp: false,
+                        comment: None,
+                        owner: 0,
+                    },
+                    login: create_role.login,
+                    superuser: create_role.superuser,
+                    password: create_role.password,
+                    member_of,
+                    grants: Vec::new(),
+                };
+                self.entries.insert(oid, CatalogEntry::Role(ent))?;
+
+                self.role_names.insert(create_role.name, oid);
+            }
+            Mutation::GrantPrivileges(grant) => {
+                self.check_grantable_object(grant.object_id)?;
+                let role = self.get_role_entry_mut(&grant.role)?;
+                for privilege in grant.privileges {
+                    if !role.has_grant(grant.object_id, privilege) {
+                        role.grants.push(PrivilegeGrant {
+                            object_id: grant.object_id,
+                            privilege,
+                        });
+                    }
+                }
+            }
+            Mutation::RevokePrivileges(revoke) => {
+                let role = self.get_role_entry_mut(&revoke.role)?;
+                role.grants.retain(|g| {
+                    g.object_id != revoke.object_id || !revoke.privileges.contains(&g.privilege)
+                });
+            }
             Mutation::CreateSchema(create_schema) => {
                 validate_object_name(&create_schema.name)?;
 
@@ -807,6 +1166,8 @@ impl State {
                         builtin: false,
                         external: false,
                         is_temp: false,
+                        comment: None,
+                        owner: 0,
                     },
                 };
                 self.entries.insert(oid, CatalogEntry::Schema(ent))?;
@@ -830,6 +1191,8 @@ impl State {
                         builtin: false,
                         external: false,
                         is_temp: false,
+                        comment: None,
+                        owner: create_view.owner,
                     },
                     sql: create_view.sql,
                     columns: create_view.columns,
@@ -847,9 +1210,61 @@ impl State {
                 validate_object_name(&create_table.name)?;
 
                 let schema_id = self.get_schema_id(&create_table.schema)?;
+                let policy =
+                    CreatePolicy::new(create_table.if_not_exists, create_table.or_replace)?;
+
+                let existing = self
+                    .schema_objects
+                    .get(&schema_id)
+                    .and_then(|objs| objs.tables.get(&create_table.name))
+                    .copied();
+                match (existing, policy) {
+                    (Some(_), CreatePolicy::CreateIfNotExists) => return Ok(()),
+                    (Some(_), CreatePolicy::Create) => {
+                        return Err(MetastoreError::DuplicateName(create_table.name))
+                    }
+                    (Some(existing), CreatePolicy::CreateOrReplace) => {
+                        self.remove_owned_sequences(existing)?;
+                    }
+                    (None, _) => (),
+                }
 
                 let oid = self.get_or_next_oid(schema_id, &create_table.name);
 
+                // Create the sequences backing identity columns.
+                let mut options = create_table.options;
+                let mut sequences = Vec::with_capacity(options.identity_columns.len());
+                for identity in options.identity_columns.iter_mut() {
+                    let name = self.unused_table_namespace_name(
+                        schema_id,
+                        format!("{}_{}_seq", create_table.name, identity.name),
+                    );
+                    identity.sequence_options.validate().map_err(|reason| {
+                        MetastoreError::InvalidSequenceOptions {
+                            name: name.clone(),
+                            reason,
+                        }
+                    })?;
+                    identity.sequence_id = self.next_oid();
+                    sequences.push(SequenceEntry {
+                        meta: EntryMeta {
+                            entry_type: EntryType::Sequence,
+                            id: identity.sequence_id,
+                            parent: schema_id,
+                            name,
+                            builtin: false,
+                            external: false,
+                            is_temp: false,
+                            comment: None,
+                            owner: create_table.owner,
+                        },
+                        options: identity.sequence_options,
+                        last_value: identity.sequence_options.start,
+                        is_called: false,
+                        owned_by: Some(oid),
+                    });
+                }
+
                 // Create new entry
                 let ent = TableEntry {
                     meta: EntryMeta {
@@ -860,17 +1275,81 @@ impl State {
                         builtin: false,
                         external: false,
                         is_temp: false,
+                        comment: None,
+                        owner: create_table.owner,
                     },
-                    options: create_table.options.into(),
+                    options: options.into(),
                     tunnel_id: None,
                     access_mode: SourceAccessMode::ReadWrite,
                     columns: None,
+                    statistics: None,
+                    column_comments: BTreeMap::new(),
                 };
 
-                let policy =
-                    CreatePolicy::new(create_table.if_not_exists, create_table.or_replace)?;
-
                 self.try_insert_table_namespace(CatalogEntry::Table(ent), schema_id, oid, policy)?;
+                for seq in sequences {
+                    let seq_oid = seq.meta.id;
+                    self.try_insert_table_namespace(
+                        CatalogEntry::Sequence(seq),
+                        schema_id,
+                        seq_oid,
+                        CreatePolicy::Create,
+                    )?;
+                }
+            }
+            Mutation::CreateSequence(create_sequence) => {
+                validate_object_name(&create_sequence.name)?;
+                create_sequence.options.validate().map_err(|reason| {
+                    MetastoreError::InvalidSequenceOptions {
+                        name: create_sequence.name.clone(),
+                        reason,
+                    }
+                })?;
+
+                let schema_id = self.get_schema_id(&create_sequence.schema)?;
+                let oid = self.next_oid();
+                let ent = SequenceEntry {
+                    meta: EntryMeta {
+                        entry_type: EntryType::Sequence,
+                        id: oid,
+                        parent: schema_id,
+                        name: create_sequence.name,
+                        builtin: false,
+                        external: false,
+                        is_temp: false,
+                        comment: None,
+                        owner: create_sequence.owner,
+                    },
+                    options: create_sequence.options,
+                    last_value: create_sequence.options.start,
+                    is_called: false,
+                    owned_by: None,
+                };
+
+                let policy = CreatePolicy::new(create_sequence.if_not_exists, false)?;
+                self.try_insert_table_namespace(
+                    CatalogEntry::Sequence(ent),
+                    schema_id,
+                    oid,
+                    policy,
+                )?;
+            }
+            Mutation::AdvanceSequence(advance) => {
+                let seq = self.get_sequence_entry_mut(advance.sequence_id)?;
+                if seq.advance(advance.count).is_none() {
+                    return Err(MetastoreError::SequenceExhausted {
+                        name: seq.meta.name.clone(),
+                    });
+                }
+            }
+            Mutation::SetSequenceValue(set_value) => {
+                let seq = self.get_sequence_entry_mut(set_value.sequence_id)?;
+                if !seq.set_value(set_value.value, set_value.is_called) {
+                    return Err(MetastoreError::SequenceValueOutOfBounds {
+                        name: seq.meta.name.clone(),
+                        value: set_value.value,
+                    });
+                }
             }
             Mutation::CreateExternalTable(create_ext) => {
                 validate_object_name(&create_ext.name)?;
@@ -901,11 +1380,15 @@ impl State {
                         builtin: false,
                         external: true,
                         is_temp: false,
+                        comment: None,
+                        owner: create_ext.owner,
                     },
                     options: create_ext.options.clone(),
                     tunnel_id,
                     access_mode: SourceAccessMode::ReadOnly,
                     columns: create_ext.columns,
+                    statistics: None,
+                    column_comments: BTreeMap::new(),
                 };
 
                 let policy = CreatePolicy::new(create_ext.if_not_exists, create_ext.or_replace)?;
@@ -975,6 +1458,51 @@ impl State {
                             other => unreachable!("unexpected entry type: {:?}", other),
                         };
                     }
+                    AlterTableOperation::SetMaintenance { policy } => {
+                        let oid = match objs.tables.get(&alter_table.name) {
+                            None => {
+                                return Err(MetastoreError::MissingNamedObject {
+                                    schema: alter_table.schema,
+                                    name: alter_table.name,
+                                })
+                            }
+                            Some(id) => id,
+                        };
+
+                        match self.entries.get_mut(oid)?.unwrap() {
+                            CatalogEntry::Table(TableEntry {
+                                options: TableOptionsV0::Internal(opts),
+                                ..
+                            }) => {
+                                opts.maintenance = policy;
+                            }
+                            CatalogEntry::Table(_) => {
+                                return Err(MetastoreError::NotNativeTable {
+                                    schema: alter_table.schema,
+                                    name: alter_table.name,
+                                })
+                            }
+                            other => unreachable!("unexpected entry type: {:?}", other),
+                        };
+                    }
+                    AlterTableOperation::SetStatistics { statistics } => {
+                        let oid = match objs.tables.get(&alter_table.name) {
+                            None => {
+                                return Err(MetastoreError::MissingNamedObject {
+                                    schema: alter_table.schema,
+                                    name: alter_table.name,
+                                })
+                            }
+                            Some(id) => id,
+                        };
+
+                        match self.entries.get_mut(oid)?.unwrap() {
+                            CatalogEntry::Table(ent) => {
+                                ent.statistics = Some(statistics);
+                            }
+                            other => unreachable!("unexpected entry type: {:?}", other),
+                        };
+                    }
                 };
             }
             Mutation::AlterDatabase(alter_database) => {
@@ -1013,6 +1541,20 @@ impl State {
                             other => unreachable!("unexpected entry type: {:?}", other),
                         };
                     }
+                    AlterDatabaseOperation::SetDroppedTableRetention { hours } => {
+                        if alter_database.name != DATABASE_DEFAULT.name {
+                            return Err(MetastoreError::DroppedTableRetentionNotDefault(
+                                alter_database.name,
+                            ));
+                        }
+                        if hours > MAX_DROPPED_TABLE_RETENTION_HOURS {
+                            return Err(MetastoreError::InvalidDroppedTableRetention {
+                                hours,
+                                max: MAX_DROPPED_TABLE_RETENTION_HOURS,
+                            });
+                        }
+                        self.deployment.dropped_table_retention_hours = hours;
+                    }
                 };
             }
             Mutation::AlterTunnelRotateKeys(alter_tunnel_rotate_keys) => {
@@ -1065,6 +1607,8 @@ impl State {
                         builtin: false,
                         external: true,
                         is_temp: false,
+                        comment: None,
+                        owner: 0,
                     },
                     func_type: f.function_type,
                     signature: Some(f.signature),
@@ -1125,6 +1669,59 @@ impl State {
         Ok(())
     }
 
+    /// Find a name in the "table" namespace of a schema, appending a number
+    /// to `name` if it's already taken.
+    fn unused_table_namespace_name(&self, schema_id: u32, name: String) -> String {
+        let taken = |name: &str| {
+            self.schema_objects
+                .get(&schema_id)
+                .map(|objs| objs.tables.contains_key(name))
+                .unwrap_or(false)
+        };
+        if !taken(&name) {
+            return name;
+        }
+        (1..)
+            .map(|n| format!("{name}{n}"))
+            .find(|candidate| !taken(candidate))
+            .unwrap()
+    }
+
+    /// Remove the sequences backing the identity columns of a table.
+    fn remove_owned_sequences(&mut self, table_id: u32) -> Result<Vec<SequenceEntry>> {
+        let owned: Vec<u32> = self
+            .entries
+            .as_ref()
+            .iter()
+            .filter_map(|(oid, ent)| match ent {
+                CatalogEntry::Sequence(seq) if seq.owned_by == Some(table_id) => Some(*oid),
+                _ => None,
+            })
+            .collect();
+
+        let mut removed = Vec::with_capacity(owned.len());
+        for oid in owned {
+            if let Some(CatalogEntry::Sequence(seq)) = self.entries.remove(&oid)? {
+                if let Some(objs) = self.schema_objects.get_mut(&seq.meta.parent) {
+                    objs.tables.remove(&seq.meta.name);
+                }
+                removed.push(seq);
+            }
+        }
+        Ok(removed)
+    }
+
+    fn get_sequence_entry_mut(&mut self, oid: u32) -> Result<&mut SequenceEntry> {
+        match self.entries.get_mut(&oid)? {
+            Some(CatalogEntry::Sequence(seq)) => Ok(seq),
+            Some(ent) => Err(MetastoreError::UnexpectedObjectType {
+                name: ent.get_meta().name.clone(),
+                expected: "sequence",
+            }),
+            None => Err(MetastoreError::MissingEntry(oid)),
+        }
+    }
+
     fn get_schema_id(&self, name: &str) -> Result<u32> {
         self.schema_names
             .get(name)
@@ -1132,6 +1729,38 @@ impl State {
             .ok_or_else(|| MetastoreError::MissingNamedSchema(name.to_string()))
     }
 
+    fn get_role_entry_mut(&mut self, name: &str) -> Result<&mut RoleEntry> {
+        let role_id = *self
+            .role_names
+            .get(name)
+            .ok_or_else(|| MetastoreError::MissingRole(name.to_string()))?;
+        match self.entries.get_mut(&role_id)?.expect("entry should exist") {
+            CatalogEntry::Role(role) => Ok(role),
+            ent => unreachable!("entry should be a role entry but found: {ent:?}"),
+        }
+    }
+
+    /// Check that privileges can be granted on an object.
+    ///
+    /// Builtin objects are allowed here since privileges on them (e.g. the
+    /// default schema) don't modify the object itself.
+    fn check_grantable_object(&self, object_id: u32) -> Result<()> {
+        match self.entries.as_ref().get(&object_id) {
+            Some(
+                CatalogEntry::Database(_)
+                | CatalogEntry::Schema(_)
+                | CatalogEntry::Table(_)
+                | CatalogEntry::View(_)
+                | CatalogEntry::Sequence(_),
+            ) => Ok(()),
+            Some(ent) => Err(MetastoreError::ObjectNotGrantable {
+                object: object_id,
+                object_type: ent.entry_type().as_str(),
+            }),
+            None => Err(MetastoreError::MissingEntry(object_id)),
+        }
+    }
+
     fn get_tunnel_entry(&self, tunnel_name: Option<&String>) -> Result<Option<&TunnelEntry>> {
         let tunnel_entry = if let Some(tunnel) = tunnel_name {
             let tunnel_id = *self
@@ -1230,6 +1859,8 @@ impl BuiltinCatalog {
                         builtin: true,
                         external: false,
                         is_temp: false,
+                        comment: None,
+                        owner: 0,
                     },
                     options: DatabaseOptions::Internal(DatabaseOptionsInternal {}),
                     tunnel_id: None,
@@ -1252,6 +1883,8 @@ impl BuiltinCatalog {
                         builtin: true,
                         external: false,
                         is_temp: false,
+                        comment: None,
+                        owner: 0,
                     },
                 }),
             )?;
@@ -1272,14 +1905,21 @@ impl BuiltinCatalog {
                         builtin: true,
                         external: false,
                         is_temp: false,
+                        comment: None,
+                        owner: 0,
                     },
                     options: TableOptionsInternal {
                         columns: table.columns.clone(),
+                        maintenance: None,
+                        partition_columns: Vec::new(),
+                        identity_columns: Vec::new(),
                     }
                     .into(),
                     tunnel_id: None,
                     access_mode: SourceAccessMode::ReadOnly,
                     columns: None,
+                    statistics: None,
+                    column_comments: BTreeMap::new(),
                 }),
             )?;
             schema_objects
@@ -1307,6 +1947,8 @@ impl BuiltinCatalog {
                         builtin: true,
                         external: false,
                         is_temp: false,
+                        comment: None,
+                        owner: 0,
                     },
                     sql: view.sql.to_string(),
                     columns: Vec::new(),
@@ -1407,6 +2049,8 @@ impl BuiltinCatalog {
                     builtin: true,
                     external: false,
                     is_temp: false,
+                    comment: None,
+                    owner: 0,
                 };
 
                 ents.push(FunctionEntry {
@@ -1435,27 +2079,49 @@ impl DatabaseCatalog {
     }
 }
 
+/// Current time in milliseconds since the epoch.
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as i64
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
     use datafusion::arrow::datatypes::DataType;
     use object_store::memory::InMemory;
+    use protogen::metastore::types::catalog::Privilege;
     use protogen::metastore::types::options::{
         DatabaseOptionsDebug,
+        IdentityColumn,
         InternalColumnDefinition,
+        SequenceOptions,
         TableOptionsDebug,
         TableOptionsInternal,
     };
     use protogen::metastore::types::service::{
+        AdvanceSequence,
         AlterDatabase,
+        CommentOn,
         CreateExternalDatabase,
         CreateExternalTable,
+        CreateRole,
         CreateSchema,
+        CreateSequence,
         CreateTable,
         CreateView,
         DropDatabase,
+        DropObject,
+        DropRole,
         DropSchema,
+        GrantPrivileges,
+        PurgeDroppedTables,
+        RevokePrivileges,
+        SetSequenceValue,
+        UndropTable,
     };
     use sqlbuiltins::builtins::{DEFAULT_CATALOG, INTERNAL_SCHEMA};
 
@@ -1475,6 +2141,13 @@ mod tests {
         db.get_state().await.unwrap().version
     }
 
+    fn set_dropped_table_retention(hours: u64) -> Mutation {
+        Mutation::AlterDatabase(AlterDatabase {
+            name: DEFAULT_CATALOG.to_string(),
+            operation: AlterDatabaseOperation::SetDroppedTableRetention { hours },
+        })
+    }
+
     #[test]
     fn builtin_catalog_builds() {
         BuiltinCatalog::new().unwrap();
@@ -1564,6 +2237,7 @@ mod tests {
                     sql: format!("select {i}"),
                     or_replace: false,
                     columns: Vec::new(),
+                    owner: 0,
                 })
             })
             .collect();
@@ -1660,6 +2334,7 @@ mod tests {
                     sql: "select 1".to_string(),
                     or_replace: false,
                     columns: Vec::new(),
+                    owner: 0,
                 })],
             )
             .await
@@ -1702,6 +2377,7 @@ mod tests {
                 sql: "select 1".to_string(),
                 or_replace: false,
                 columns: Vec::new(),
+                owner: 0,
             })],
         )
         .await
@@ -1716,6 +2392,7 @@ mod tests {
                 sql: "select 2".to_string(),
                 or_replace: false,
                 columns: Vec::new(),
+                owner: 0,
             })],
         )
         .await
@@ -1735,6 +2412,7 @@ mod tests {
                 sql: "select 1".to_string(),
                 or_replace: false,
                 columns: Vec::new(),
+                owner: 0,
             })],
         )
         .await
@@ -1749,6 +2427,7 @@ mod tests {
                 sql: "select 2".to_string(),
                 or_replace: false,
                 columns: Vec::new(),
+                owner: 0,
             })],
         )
         .await
@@ -1763,6 +2442,7 @@ mod tests {
                 sql: "select 3".to_string(),
                 or_replace: true,
                 columns: Vec::new(),
+                owner: 0,
             })],
         )
         .await
@@ -1793,6 +2473,7 @@ mod tests {
                     sql: "select 1".to_string(),
                     or_replace: false,
                     columns: Vec::new(),
+                    owner: 0,
                 })],
             )
             .await
@@ -1808,6 +2489,7 @@ mod tests {
                     sql: "select 1".to_string(),
                     or_replace: false,
                     columns: Vec::new(),
+                    owner: 0,
                 })],
             )
             .await
@@ -1823,6 +2505,7 @@ mod tests {
                     sql: "select 1".to_string(),
                     or_replace: false,
                     columns: Vec::new(),
+                    owner: 0,
                 })],
             )
             .await
@@ -1871,6 +2554,7 @@ mod tests {
             or_replace: false,
             tunnel: None,
             columns: None,
+            owner: 0,
         });
         let _ = db
             .try_mutate_and_commit(state.version, vec![mutation.clone(), mutation])
@@ -1983,6 +2667,57 @@ mod tests {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn catalog_history() {
+        let db = new_catalog().await;
+        let initial = version(&db).await;
+
+        let created = db
+            .try_mutate_and_commit(
+                initial,
+                vec![Mutation::CreateExternalDatabase(CreateExternalDatabase {
+                    name: "bq".to_string(),
+                    options: DatabaseOptions::Debug(DatabaseOptionsDebug {}),
+                    if_not_exists: false,
+                    tunnel: None,
+                })],
+            )
+            .await
+            .unwrap();
+        db.try_mutate_and_commit(
+            created.version,
+            vec![Mutation::DropDatabase(DropDatabase {
+                name: "bq".to_string(),
+                if_exists: false,
+            })],
+        )
+        .await
+        .unwrap();
+
+        let history = db.history().await.unwrap();
+        let mutations: Vec<_> = history.iter().map(|info| info.mutations.clone()).collect();
+        assert_eq!(
+            vec![
+                Vec::new(),
+                vec!["create external database bq (debug)".to_string()],
+                vec!["drop database bq".to_string()],
+            ],
+            mutations
+        );
+
+        // The dropped database can still be seen in the older version.
+        let old = db.get_state_at(created.version).await.unwrap();
+        assert!(old.entries.values().any(|ent| matches!(
+            ent,
+            CatalogEntry::Database(ent) if ent.meta.name == "bq"
+        )));
+        let latest = db.get_state().await.unwrap();
+        assert!(!latest.entries.values().any(|ent| matches!(
+            ent,
+            CatalogEntry::Database(ent) if ent.meta.name == "bq"
+        )));
+    }
+
     #[tokio::test]
     async fn try_modify_default_db() {
         let db = new_catalog().await;
@@ -2027,6 +2762,7 @@ mod tests {
                     or_replace: false,
                     tunnel: None,
                     columns: None,
+                    owner: 0,
                 })],
             )
             .await
@@ -2052,7 +2788,11 @@ mod tests {
                         nullable: true,
                         arrow_type: DataType::Utf8,
                     }],
+                    maintenance: None,
+                    partition_columns: Vec::new(),
+                    identity_columns: Vec::new(),
                 },
+                owner: 0,
             })],
         )
         .await
@@ -2078,10 +2818,618 @@ mod tests {
                         nullable: true,
                         arrow_type: DataType::Utf8,
                     }],
+                    maintenance: None,
+                    partition_columns: Vec::new(),
+                    identity_columns: Vec::new(),
                 },
+                owner: 0,
             })],
         )
         .await
         .unwrap();
     }
+
+    #[tokio::test]
+    async fn role_grants() {
+        let db = new_catalog().await;
+
+        let create_role = |name: &str, member_of: Vec<String>| {
+            Mutation::CreateRole(CreateRole {
+                name: name.to_string(),
+                if_not_exists: false,
+                login: true,
+                superuser: false,
+                password: None,
+                member_of,
+            })
+        };
+
+        let state = db
+            .try_mutate_and_commit(
+                version(&db).await,
+                vec![
+                    create_role("analysts", Vec::new()),
+                    create_role("alice", vec!["analysts".to_string()]),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let find_role = |state: &CatalogState, name: &str| {
+            state
+                .entries
+                .values()
+                .find_map(|ent| match ent {
+                    CatalogEntry::Role(role) if role.meta.name == name => Some(role.clone()),
+                    _ => None,
+                })
+                .unwrap()
+        };
+        let analysts = find_role(&state, "analysts");
+        assert_eq!(vec![analysts.meta.id], find_role(&state, "alice").member_of);
+
+        // Member of a missing role.
+        db.try_mutate_and_commit(
+            version(&db).await,
+            vec![create_role("bob", vec!["missing".to_string()])],
+        )
+        .await
+        .unwrap_err();
+
+        // Grant on the builtin default schema.
+        let state = db
+            .try_mutate_and_commit(
+                version(&db).await,
+                vec![Mutation::GrantPrivileges(GrantPrivileges {
+                    role: "analysts".to_string(),
+                    object_id: SCHEMA_DEFAULT.oid,
+                    privileges: vec![Privilege::Select, Privilege::Usage, Privilege::Select],
+                })],
+            )
+            .await
+            .unwrap();
+        let analysts = find_role(&state, "analysts");
+        assert_eq!(2, analysts.grants.len());
+        assert!(analysts.has_grant(SCHEMA_DEFAULT.oid, Privilege::Select));
+
+        // Roles aren't grantable objects.
+        db.try_mutate_and_commit(
+            version(&db).await,
+            vec![Mutation::GrantPrivileges(GrantPrivileges {
+                role: "alice".to_string(),
+                object_id: analysts.meta.id,
+                privileges: vec![Privilege::Select],
+            })],
+        )
+        .await
+        .unwrap_err();
+
+        let state = db
+            .try_mutate_and_commit(
+                version(&db).await,
+                vec![Mutation::RevokePrivileges(RevokePrivileges {
+                    role: "analysts".to_string(),
+                    object_id: SCHEMA_DEFAULT.oid,
+                    privileges: vec![Privilege::Select],
+                })],
+            )
+            .await
+            .unwrap();
+        let analysts = find_role(&state, "analysts");
+        assert!(!analysts.has_grant(SCHEMA_DEFAULT.oid, Privilege::Select));
+        assert!(analysts.has_grant(SCHEMA_DEFAULT.oid, Privilege::Usage));
+
+        // Dropping a role removes memberships.
+        let state = db
+            .try_mutate_and_commit(
+                version(&db).await,
+                vec![Mutation::DropRole(DropRole {
+                    name: "analysts".to_string(),
+                    if_exists: false,
+                })],
+            )
+            .await
+            .unwrap();
+        assert!(find_role(&state, "alice").member_of.is_empty());
+    }
+
+    #[tokio::test]
+    async fn undrop_table() {
+        let db = new_catalog().await;
+
+        let create_table = || {
+            Mutation::CreateTable(CreateTable {
+                schema: DEFAULT_SCHEMA.to_string(),
+                name: "peach".to_string(),
+                if_not_exists: false,
+                or_replace: false,
+                options: TableOptionsInternal {
+                    columns: Vec::new(),
+                    maintenance: None,
+                    partition_columns: Vec::new(),
+                    identity_columns: Vec::new(),
+                },
+                owner: 0,
+            })
+        };
+        let drop_table = |retain| {
+            Mutation::DropObject(DropObject {
+                schema: DEFAULT_SCHEMA.to_string(),
+                name: "peach".to_string(),
+                if_exists: false,
+                retain,
+            })
+        };
+        let undrop_table = || {
+            Mutation::UndropTable(UndropTable {
+                schema: DEFAULT_SCHEMA.to_string(),
+                name: "peach".to_string(),
+            })
+        };
+        let find_table = |state: &CatalogState| {
+            state.entries.values().find_map(|ent| match ent {
+                CatalogEntry::Table(table) if table.meta.name == "peach" => Some(table.clone()),
+                _ => None,
+            })
+        };
+
+        let state = db
+            .try_mutate_and_commit(version(&db).await, vec![create_table()])
+            .await
+            .unwrap();
+        let original = find_table(&state).unwrap();
+
+        // No retention configured, nothing to undrop.
+        let state = db
+            .try_mutate_and_commit(version(&db).await, vec![drop_table(true)])
+            .await
+            .unwrap();
+        assert!(state.dropped_tables.is_empty());
+
+        // Retention is bounded, and only settable on the default database.
+        db.try_mutate_and_commit(
+            version(&db).await,
+            vec![set_dropped_table_retention(u64::MAX)],
+        )
+        .await
+        .unwrap_err();
+        db.try_mutate_and_commit(
+            version(&db).await,
+            vec![Mutation::AlterDatabase(AlterDatabase {
+                name: "other".to_string(),
+                operation: AlterDatabaseOperation::SetDroppedTableRetention { hours: 1 },
+            })],
+        )
+        .await
+        .unwrap_err();
+        db.try_mutate_and_commit(version(&db).await, vec![set_dropped_table_retention(24)])
+            .await
+            .unwrap();
+
+        // Not retained, nothing to undrop.
+        db.try_mutate_and_commit(version(&db).await, vec![create_table()])
+            .await
+            .unwrap();
+        db.try_mutate_and_commit(version(&db).await, vec![drop_table(false)])
+            .await
+            .unwrap();
+        db.try_mutate_and_commit(version(&db).await, vec![undrop_table()])
+            .await
+            .unwrap_err();
+
+        db.try_mutate_and_commit(version(&db).await, vec![create_table()])
+            .await
+            .unwrap();
+        let state = db
+            .try_mutate_and_commit(version(&db).await, vec![drop_table(true)])
+            .await
+            .unwrap();
+        assert!(find_table(&state).is_none());
+        assert_eq!(1, state.dropped_tables.len());
+        let expires_in = state.dropped_tables[0].expires_at - state.dropped_tables[0].dropped_at;
+        assert_eq!(24 * 60 * 60 * 1000, expires_in);
+        let dropped = state.dropped_tables[0].table.clone();
+        assert_ne!(original.meta.id, dropped.meta.id);
+
+        // Can't undrop while a table with the same name exists.
+        db.try_mutate_and_commit(version(&db).await, vec![create_table()])
+            .await
+            .unwrap();
+        db.try_mutate_and_commit(version(&db).await, vec![undrop_table()])
+            .await
+            .unwrap_err();
+
+        let state = db
+            .try_mutate_and_commit(version(&db).await, vec![drop_table(false), undrop_table()])
+            .await
+            .unwrap();
+        assert_eq!(Some(dropped), find_table(&state));
+        assert!(state.dropped_tables.is_empty());
+
+        // Purging only forgets about tables that have expired, even if the
+        // caller asks for more.
+        db.try_mutate_and_commit(version(&db).await, vec![drop_table(true)])
+            .await
+            .unwrap();
+        let purge_all = || {
+            Mutation::PurgeDroppedTables(PurgeDroppedTables {
+                expired_before: i64::MAX,
+            })
+        };
+        let state = db
+            .try_mutate_and_commit(version(&db).await, vec![purge_all()])
+            .await
+            .unwrap();
+        assert_eq!(1, state.dropped_tables.len());
+
+        // Changing the retention doesn't affect tables already dropped.
+        db.try_mutate_and_commit(version(&db).await, vec![set_dropped_table_retention(0)])
+            .await
+            .unwrap();
+        let state = db
+            .try_mutate_and_commit(version(&db).await, vec![purge_all()])
+            .await
+            .unwrap();
+        assert_eq!(1, state.dropped_tables.len());
+
+        // Expired tables are purged.
+        db.try_mutate_and_commit(version(&db).await, vec![set_dropped_table_retention(24)])
+            .await
+            .unwrap();
+        db.cached.lock().await.dropped_tables[0].expires_at = 0;
+        let state = db
+            .try_mutate_and_commit(version(&db).await, vec![purge_all()])
+            .await
+            .unwrap();
+        assert!(state.dropped_tables.is_empty());
+        db.try_mutate_and_commit(version(&db).await, vec![undrop_table()])
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn sequences() {
+        let db = new_catalog().await;
+
+        let find_sequence = |state: &CatalogState, name: &str| {
+            state.entries.values().find_map(|ent| match ent {
+                CatalogEntry::Sequence(seq) if seq.meta.name == name => Some(seq.clone()),
+                _ => None,
+            })
+        };
+
+        let state = db
+            .try_mutate_and_commit(
+                version(&db).await,
+                vec![Mutation::CreateSequence(CreateSequence {
+                    schema: DEFAULT_SCHEMA.to_string(),
+                    name: "counter".to_string(),
+                    options: SequenceOptions::with_defaults(1, None, None, Some(3), false, 1, 10),
+                    if_not_exists: false,
+                    owner: 0,
+                })],
+            )
+            .await
+            .unwrap();
+        let counter = find_sequence(&state, "counter").unwrap();
+        assert_eq!((1, false), (counter.last_value, counter.is_called));
+
+        let advance = |count| {
+            Mutation::AdvanceSequence(AdvanceSequence {
+                sequence_id: counter.meta.id,
+                count,
+            })
+        };
+        let state = db
+            .try_mutate_and_commit(version(&db).await, vec![advance(2)])
+            .await
+            .unwrap();
+        assert_eq!(2, find_sequence(&state, "counter").unwrap().last_value);
+
+        // Past the max value.
+        db.try_mutate_and_commit(version(&db).await, vec![advance(2)])
+            .await
+            .unwrap_err();
+
+        let state = db
+            .try_mutate_and_commit(
+                version(&db).await,
+                vec![Mutation::SetSequenceValue(SetSequenceValue {
+                    sequence_id: counter.meta.id,
+                    value: 1,
+                    is_called: true,
+                })],
+            )
+            .await
+            .unwrap();
+        assert_eq!(1, find_sequence(&state, "counter").unwrap().last_value);
+
+        // Versions only changing sequence values are marked as such.
+        let history = db.history().await.unwrap();
+        let latest = history.last().unwrap();
+        assert_eq!(state.version, latest.version);
+        assert!(latest.sequences_only);
+
+        // Identity columns get a sequence owned by the table.
+        let state = db
+            .try_mutate_and_commit(
+                version(&db).await,
+                vec![Mutation::CreateTable(CreateTable {
+                    schema: DEFAULT_SCHEMA.to_string(),
+                    name: "plum".to_string(),
+                    if_not_exists: false,
+                    or_replace: false,
+                    options: TableOptionsInternal {
+                        columns: InternalColumnDefinition::from_tuples([(
+                            "id",
+                            DataType::Int64,
+                            false,
+                        )]),
+                        maintenance: None,
+                        partition_columns: Vec::new(),
+                        identity_columns: vec![IdentityColumn {
+                            name: "id".to_string(),
+                            always: false,
+                            sequence_id: 0,
+                            sequence_options: SequenceOptions::with_defaults(
+                                1,
+                                None,
+                                None,
+                                None,
+                                false,
+                                1,
+                                i64::MAX,
+                            ),
+                        }],
+                    },
+                    owner: 0,
+                })],
+            )
+            .await
+            .unwrap();
+        let table = state
+            .entries
+            .values()
+            .find_map(|ent| match ent {
+                CatalogEntry::Table(table) if table.meta.name == "plum" => Some(table.clone()),
+                _ => None,
+            })
+            .unwrap();
+        let seq = find_sequence(&state, "plum_id_seq").unwrap();
+        assert_eq!(Some(table.meta.id), seq.owned_by);
+        match &table.options {
+            TableOptionsV0::Internal(opts) => {
+                assert_eq!(seq.meta.id, opts.identity_columns[0].sequence_id)
+            }
+            other => panic!("unexpected options: {other:?}"),
+        }
+
+        db.try_mutate_and_commit(version(&db).await, vec![set_dropped_table_retention(1)])
+            .await
+            .unwrap();
+
+        // Owned sequences can't be dropped on their own...
+        let drop_object = |name: &str| {
+            Mutation::DropObject(DropObject {
+                schema: DEFAULT_SCHEMA.to_string(),
+                name: name.to_string(),
+                if_exists: false,
+                retain: true,
+            })
+        };
+        db.try_mutate_and_commit(version(&db).await, vec![drop_object("plum_id_seq")])
+            .await
+            .unwrap_err();
+
+        // ...but are dropped and undropped with the table.
+        let state = db
+            .try_mutate_and_commit(version(&db).await, vec![drop_object("plum")])
+            .await
+            .unwrap();
+        assert!(find_sequence(&state, "plum_id_seq").is_none());
+        assert_eq!(1, state.dropped_tables[0].sequences.len());
+
+        let state = db
+            .try_mutate_and_commit(
+                version(&db).await,
+                vec![Mutation::UndropTable(UndropTable {
+                    schema: DEFAULT_SCHEMA.to_string(),
+                    name: "plum".to_string(),
+                })],
+            )
+            .await
+            .unwrap();
+        assert_eq!(Some(seq), find_sequence(&state, "plum_id_seq"));
+    }
+
+    #[tokio::test]
+    async fn comment_on() {
+        let db = new_catalog().await;
+
+        db.try_mutate_and_commit(
+            version(&db).await,
+            vec![
+                Mutation::CreateSchema(CreateSchema {
+                    name: "notes".to_string(),
+                    if_not_exists: false,
+                }),
+                Mutation::CreateTable(CreateTable {
+                    schema: "notes".to_string(),
+                    name: "peach".to_string(),
+                    if_not_exists: false,
+                    or_replace: false,
+                    options: TableOptionsInternal {
+                        columns: vec![InternalColumnDefinition {
+                            name: "a".to_string(),
+                            nullable: true,
+                            arrow_type: DataType::Int32,
+                        }],
+                        maintenance: None,
+                        partition_columns: Vec::new(),
+                        identity_columns: Vec::new(),
+                    },
+                    owner: 0,
+                }),
+                Mutation::CreateView(CreateView {
+                    schema: "notes".to_string(),
+                    name: "pear".to_string(),
+                    sql: "select 1".to_string(),
+                    or_replace: false,
+                    columns: Vec::new(),
+                    owner: 0,
+                }),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let comment = |object, comment: Option<&str>| {
+            Mutation::CommentOn(CommentOn {
+                object,
+                comment: comment.map(String::from),
+            })
+        };
+        let table = || CommentObject::Table {
+            schema: "notes".to_string(),
+            name: "peach".to_string(),
+        };
+        let column = |column: &str| CommentObject::Column {
+            schema: "notes".to_string(),
+            table: "peach".to_string(),
+            column: column.to_string(),
+        };
+        let find = |state: &CatalogState, name: &str| {
+            state
+                .entries
+                .values()
+                .find(|ent| ent.get_meta().name == name)
+                .cloned()
+                .unwrap()
+        };
+
+        let state = db
+            .try_mutate_and_commit(
+                version(&db).await,
+                vec![
+                    comment(
+                        CommentObject::Schema {
+                            name: "notes".to_string(),
+                        },
+                        Some("all the notes"),
+                    ),
+                    comment(table(), Some("a table")),
+                    comment(column("a"), Some("a column")),
+                    comment(
+                        CommentObject::View {
+                            schema: "notes".to_string(),
+                            name: "pear".to_string(),
+                        },
+                        Some("a view"),
+                    ),
+                ],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some("all the notes"),
+            find(&state, "notes").get_meta().comment.as_deref()
+        );
+        assert_eq!(
+            Some("a view"),
+            find(&state, "pear").get_meta().comment.as_deref()
+        );
+        match find(&state, "peach") {
+            CatalogEntry::Table(ent) => {
+                assert_eq!(Some("a table"), ent.meta.comment.as_deref());
+                assert_eq!(
+                    Some("a column"),
+                    ent.column_comments.get("a").map(String::as_str)
+                );
+            }
+            other => panic!("unexpected entry: {other:?}"),
+        }
+
+        // Removing comments.
+        let state = db
+            .try_mutate_and_commit(
+                version(&db).await,
+                vec![comment(table(), None), comment(column("a"), None)],
+            )
+            .await
+            .unwrap();
+        match find(&state, "peach") {
+            CatalogEntry::Table(ent) => {
+                assert_eq!(None, ent.meta.comment);
+                assert!(ent.column_comments.is_empty());
+            }
+            other => panic!("unexpected entry: {other:?}"),
+        }
+
+        // Builtin databases and schemas, which are persisted separately.
+        db.try_mutate_and_commit(
+            version(&db).await,
+            vec![
+                comment(
+                    CommentObject::Database {
+                        name: DEFAULT_CATALOG.to_string(),
+                    },
+                    Some("the default"),
+                ),
+                comment(
+                    CommentObject::Schema {
+                        name: INTERNAL_SCHEMA.to_string(),
+                    },
+                    Some("internal"),
+                ),
+            ],
+        )
+        .await
+        .unwrap();
+
+        db.require_full_load.store(true, Ordering::Relaxed);
+        let state = db.get_state().await.unwrap();
+        assert_eq!(
+            Some("the default"),
+            find(&state, DEFAULT_CATALOG).get_meta().comment.as_deref()
+        );
+        assert_eq!(
+            Some("internal"),
+            find(&state, INTERNAL_SCHEMA).get_meta().comment.as_deref()
+        );
+
+        // Other builtin objects can't have comments.
+        db.try_mutate_and_commit(
+            version(&db).await,
+            vec![comment(
+                CommentObject::Table {
+                    schema: INTERNAL_SCHEMA.to_string(),
+                    name: "tables".to_string(),
+                },
+                Some("builtin"),
+            )],
+        )
+        .await
+        .unwrap_err();
+
+        // Missing columns and mismatched object types.
+        db.try_mutate_and_commit(
+            version(&db).await,
+            vec![comment(column("b"), Some("missing"))],
+        )
+        .await
+        .unwrap_err();
+        db.try_mutate_and_commit(
+            version(&db).await,
+            vec![comment(
+                CommentObject::View {
+                    schema: "notes".to_string(),
+                    name: "peach".to_string(),
+                },
+                Some("not a view"),
+            )],
+        )
+        .await
+        .unwrap_err();
+    }
 }
@@ -41,15 +41,61 @@ pub enum MetastoreError {
     #[error("Missing credentials: {0}")]
     MissingCredentials(String),
 
+    #[error("Missing role: {0}")]
+    MissingRole(String),
+
+    #[error("Cannot grant privileges on object {object} of type '{object_type}'")]
+    ObjectNotGrantable {
+        object: u32,
+        object_type: &'static str,
+    },
+
     #[error("Missing schema: {0}")]
     MissingNamedSchema(String),
 
     #[error("Missing database object; schema: {schema}, name: {name}")]
     MissingNamedObject { schema: String, name: String },
 
+    #[error("Missing column; schema: {schema}, table: {table}, column: {column}")]
+    MissingNamedColumn {
+        schema: String,
+        table: String,
+        column: String,
+    },
+
+    #[error("Object '{name}' is not a {expected}")]
+    UnexpectedObjectType {
+        name: String,
+        expected: &'static str,
+    },
+
+    #[error("No dropped table to restore; schema: {schema}, name: {name}")]
+    MissingDroppedTable { schema: String, name: String },
+
+    #[error("Not a native table; schema: {schema}, name: {name}")]
+    NotNativeTable { schema: String, name: String },
+
+    #[error("Dropped table retention of {hours} hours exceeds the maximum of {max} hours")]
+    InvalidDroppedTableRetention { hours: u64, max: u64 },
+
+    #[error("Dropped table retention can only be set on the default database, got: {0}")]
+    DroppedTableRetentionNotDefault(String),
+
     #[error("Missing entry: {0}")]
     MissingEntry(u32),
 
+    #[error("Sequence {name} has reached its limit")]
+    SequenceExhausted { name: String },
+
+    #[error("Value {value} is out of bounds for sequence {name}")]
+    SequenceValueOutOfBounds { name: String, value: i64 },
+
+    #[error("Invalid options for sequence {name}: {reason}")]
+    InvalidSequenceOptions { name: String, reason: String },
+
+    #[error("Cannot drop sequence {name} since it's used by an identity column of table {table}")]
+    SequenceOwnedByTable { name: String, table: String },
+
     #[error("Tunnel '{tunnel} not supported for {action}'")]
     TunnelNotSupportedForAction {
         tunnel: String,
@@ -1,4 +1,5 @@
 //! The metastore crate defines the service for managing database catalogs.
+pub mod backup;
 pub mod client;
 mod database;
 pub mod errors;
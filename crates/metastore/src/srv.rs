@@ -8,6 +8,8 @@ use protogen::gen::metastore::service::{
     self,
     CommitRequest,
     CommitResponse,
+    FetchCatalogHistoryRequest,
+    FetchCatalogHistoryResponse,
     FetchCatalogRequest,
     FetchCatalogResponse,
     MutateRequest,
@@ -85,13 +87,33 @@ impl MetastoreService for Service {
             .map_err(|_| MetastoreError::InvalidDatabaseId(req.db_id))?;
 
         let catalog = self.get_or_load_catalog(id).await?;
-        let state = catalog.get_state().await?;
+        let state = match req.version {
+            Some(version) => catalog.get_state_at(version).await?,
+            None => catalog.get_state().await?,
+        };
 
         Ok(Response::new(FetchCatalogResponse {
             catalog: Some(state.try_into().map_err(MetastoreError::from)?),
         }))
     }
 
+    async fn fetch_catalog_history(
+        &self,
+        request: Request<FetchCatalogHistoryRequest>,
+    ) -> Result<Response<FetchCatalogHistoryResponse>, Status> {
+        let req = request.into_inner();
+        debug!(?req, "fetch catalog history");
+        let id = Uuid::from_slice(&req.db_id)
+            .map_err(|_| MetastoreError::InvalidDatabaseId(req.db_id))?;
+
+        let catalog = self.get_or_load_catalog(id).await?;
+        let history = catalog.history().await?;
+
+        Ok(Response::new(FetchCatalogHistoryResponse {
+            versions: history.into_iter().map(|info| info.into()).collect(),
+        }))
+    }
+
     /// Mutate the catalog.
     /// Returns the new UNCOMMITTED catalog state.
     async fn mutate_catalog(
@@ -158,6 +180,7 @@ mod tests {
         let svc = new_service();
         svc.fetch_catalog(Request::new(FetchCatalogRequest {
             db_id: Uuid::new_v4().into_bytes().to_vec(),
+            version: None,
         }))
         .await
         .unwrap();
@@ -173,6 +196,7 @@ mod tests {
         let resp = svc
             .fetch_catalog(Request::new(FetchCatalogRequest {
                 db_id: id_bs.clone(),
+                version: None,
             }))
             .await
             .unwrap();
@@ -209,6 +233,7 @@ mod tests {
         let resp = svc
             .fetch_catalog(Request::new(FetchCatalogRequest {
                 db_id: id_bs.clone(),
+                version: None,
             }))
             .await
             .unwrap();
@@ -222,5 +247,34 @@ mod tests {
             .find(|ent| ent.get_meta().name == "test_schema")
             .unwrap();
         assert!(matches!(ent, CatalogEntry::Schema(_)));
+
+        // The previous version is still available.
+        let resp = svc
+            .fetch_catalog(Request::new(FetchCatalogRequest {
+                db_id: id_bs.clone(),
+                version: Some(version),
+            }))
+            .await
+            .unwrap();
+        let old: CatalogState = resp.into_inner().catalog.unwrap().try_into().unwrap();
+        assert_eq!(version, old.version);
+        assert!(!old
+            .entries
+            .values()
+            .any(|ent| ent.get_meta().name == "test_schema"));
+
+        let history = svc
+            .fetch_catalog_history(Request::new(FetchCatalogHistoryRequest {
+                db_id: id_bs.clone(),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .versions;
+        assert_eq!(2, history.len());
+        assert_eq!(
+            vec!["create schema test_schema".to_string()],
+            history[1].mutations
+        );
     }
 }
@@ -40,6 +40,12 @@ pub enum StorageError {
     #[error("Attempted to write to the catalog with an out of date version; expected: {expected}, have: {have}")]
     AttemptedOutOfDataCatalogWrite { expected: u64, have: u64 },
 
+    #[error("Catalog already exists for database: {db_id}")]
+    CatalogAlreadyExists { db_id: Uuid },
+
+    #[error("Catalog version {version} is no longer retained for database: {db_id}")]
+    CatalogVersionNotRetained { db_id: Uuid, version: u64 },
+
     #[error("Lease not valid for database: {db_id}")]
     LeaseNotValid { db_id: Uuid },
 
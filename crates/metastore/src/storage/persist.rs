@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use bytes::BytesMut;
 use object_store::{Error as ObjectStoreError, ObjectStore};
@@ -11,8 +12,13 @@ use protogen::metastore::types::catalog::{
     DeploymentMetadata,
     CURRENT_CATALOG_VERSION,
 };
-use protogen::metastore::types::storage::{CatalogMetadata, ExtraState, PersistedCatalog};
-use tracing::{debug, error};
+use protogen::metastore::types::storage::{
+    CatalogMetadata,
+    CatalogVersionInfo,
+    ExtraState,
+    PersistedCatalog,
+};
+use tracing::{debug, error, warn};
 use uuid::Uuid;
 
 use crate::storage::lease::{RemoteLease, RemoteLeaser};
@@ -29,6 +35,10 @@ const CATALOG_METADATA: SingletonStorageObject = SingletonStorageObject("metadat
 
 const PERSISTENT_CATALOG_OBJECT: VersionedStorageObject = VersionedStorageObject("catalog", 0);
 
+/// Number of catalog versions to retain in storage. Versions older than this
+/// are removed when a new version is written.
+pub const MAX_RETAINED_CATALOG_VERSIONS: usize = 100;
+
 /// Persistent storage for database catalogs.
 #[derive(Debug, Clone)]
 pub struct Storage {
@@ -78,8 +88,10 @@ impl Storage {
             state: CatalogState {
                 version: 0,
                 entries: HashMap::new(),
-                deployment: DeploymentMetadata { storage_size: 0 },
+                deployment: DeploymentMetadata::default(),
                 catalog_version: CURRENT_CATALOG_VERSION,
+                dropped_tables: Vec::new(),
+                builtin_comments: HashMap::new(),
             },
             extra: ExtraState {
                 oid_counter: FIRST_AVAILABLE_ID,
@@ -104,6 +116,7 @@ impl Storage {
         let first_metadata: storage::CatalogMetadata = CatalogMetadata {
             latest_version: 0,
             last_written_by: self.process_id,
+            history: vec![self.version_info(0, Vec::new(), false)],
         }
         .into();
         let mut bs = BytesMut::new();
@@ -118,27 +131,104 @@ impl Storage {
         Ok(())
     }
 
+    /// Write an existing catalog as the latest version of a database's
+    /// catalog.
+    ///
+    /// Used for writing out backups. Errors if this storage already contains a
+    /// catalog for the database.
+    pub async fn import_catalog(&self, db_id: Uuid, catalog: PersistedCatalog) -> Result<()> {
+        match self
+            .store
+            .head(&CATALOG_METADATA.visible_path(&db_id))
+            .await
+        {
+            Ok(_) => return Err(StorageError::CatalogAlreadyExists { db_id }),
+            Err(ObjectStoreError::NotFound { .. }) => (),
+            Err(e) => return Err(e.into()),
+        }
+
+        let version = catalog.state.version;
+        debug!(%db_id, %version, "importing catalog for database");
+
+        let proto: storage::PersistedCatalog = catalog.try_into()?;
+        let mut bs = BytesMut::new();
+        proto.encode(&mut bs)?;
+
+        self.store
+            .put(
+                &PERSISTENT_CATALOG_OBJECT
+                    .with_version(version)
+                    .visible_path(&db_id),
+                bs.freeze(),
+            )
+            .await?;
+
+        self.leaser.initialize(&db_id).await?;
+
+        // Metadata is written last, the catalog isn't readable until then.
+        let metadata: storage::CatalogMetadata = CatalogMetadata {
+            latest_version: version,
+            last_written_by: self.process_id,
+            history: vec![self.version_info(version, Vec::new(), false)],
+        }
+        .into();
+        let mut bs = BytesMut::new();
+        metadata.encode(&mut bs)?;
+
+        self.store
+            .put(&CATALOG_METADATA.visible_path(&db_id), bs.freeze())
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn latest_version(&self, db_id: &Uuid) -> Result<u64> {
         Ok(self.read_metadata(db_id).await?.latest_version)
     }
 
+    /// Get the retained versions of a catalog, oldest first.
+    ///
+    /// The catalog must already exist.
+    pub async fn catalog_history(&self, db_id: &Uuid) -> Result<Vec<CatalogVersionInfo>> {
+        Ok(self.read_metadata(db_id).await?.history)
+    }
+
     /// Read the state of some catalog.
     ///
     /// The catalog must already exist.
     pub async fn read_catalog(&self, db_id: Uuid) -> Result<PersistedCatalog> {
         // Note that we're not acquiring a lease. These reads are safe since
-        // we're not (currently) removing old catalog versions. And at most,
-        // we'll be reading one version out of date.
+        // old catalog versions are only removed once they've fallen out of the
+        // retained history. And at most, we'll be reading one version out of
+        // date.
+
+        let metadata = self.read_metadata(&db_id).await?;
+        self.read_catalog_version(db_id, metadata.latest_version)
+            .await
+    }
 
+    /// Read an older version of some catalog.
+    ///
+    /// Errors if the version is no longer retained.
+    pub async fn read_catalog_at(&self, db_id: Uuid, version: u64) -> Result<PersistedCatalog> {
         let metadata = self.read_metadata(&db_id).await?;
+        if metadata.latest_version != version
+            && !metadata.history.iter().any(|info| info.version == version)
+        {
+            return Err(StorageError::CatalogVersionNotRetained { db_id, version });
+        }
 
+        self.read_catalog_version(db_id, version).await
+    }
+
+    async fn read_catalog_version(&self, db_id: Uuid, version: u64) -> Result<PersistedCatalog> {
         let path = PERSISTENT_CATALOG_OBJECT
-            .with_version(metadata.latest_version)
+            .with_version(version)
             .visible_path(&db_id);
         let bs = self.store.get(&path).await?.bytes().await?;
 
         // Log we'll want to keep an eye on so we can monitor catalog size.
-        debug!(byte_len = %bs.len(), %db_id, "read catalog");
+        debug!(byte_len = %bs.len(), %db_id, %version, "read catalog");
 
         let proto = storage::PersistedCatalog::decode(bs)?;
 
@@ -147,12 +237,19 @@ impl Storage {
 
     /// Write a new version of the catalog.
     ///
+    /// `mutations` describes the changes made since `old_version`, and is
+    /// recorded in the catalog's history. `sequences_only` indicates the
+    /// mutations only changed sequence values, in which case the version is
+    /// dropped from the history once superseded.
+    ///
     /// The catalog must already exist.
     pub async fn write_catalog(
         &self,
         db_id: Uuid,
         old_version: u64,
         catalog: PersistedCatalog,
+        mutations: Vec<String>,
+        sequences_only: bool,
     ) -> Result<()> {
         // Unlike reads, writes need to acquire the lease for the catalog.
         //
@@ -164,6 +261,7 @@ impl Storage {
         // 4. Write new metadata to temp space.
         // 5. Rename temp catalog to make visible.
         // 6. Rename metadata to make visible.
+        // 7. Delete catalog versions that are no longer retained.
         //
         // Only after step 6 will the new version of the catalog be read.
         //
@@ -172,9 +270,16 @@ impl Storage {
 
         let lease = self.leaser.acquire(db_id).await?;
 
-        // Steps 2 through 6...
+        // Steps 2 through 7...
         if let Err(e) = self
-            .write_catalog_inner(db_id, old_version, catalog, &lease)
+            .write_catalog_inner(
+                db_id,
+                old_version,
+                catalog,
+                mutations,
+                sequences_only,
+                &lease,
+            )
             .await
         {
             if let Err(e) = lease.drop_lease().await {
@@ -195,6 +300,8 @@ impl Storage {
         db_id: Uuid,
         old_version: u64,
         catalog: PersistedCatalog,
+        mutations: Vec<String>,
+        sequences_only: bool,
         lease: &RemoteLease,
     ) -> Result<()> {
         let metadata = self.read_metadata(&db_id).await?;
@@ -206,10 +313,27 @@ impl Storage {
             });
         }
 
+        // Versions that only changed sequence values are frequent (every
+        // `nextval` that runs out of cached values), so they're removed once
+        // superseded instead of pushing other versions out of the history.
+        // The previous version is kept for readers that may still be on it.
+        let (mut history, mut pruned): (Vec<_>, Vec<_>) = metadata
+            .history
+            .into_iter()
+            .partition(|info| !info.sequences_only || info.version == old_version);
+        history.push(self.version_info(catalog.state.version, mutations, sequences_only));
+
+        while history.iter().filter(|info| !info.sequences_only).count()
+            > MAX_RETAINED_CATALOG_VERSIONS
+        {
+            pruned.push(history.remove(0));
+        }
+
         // New metadata to write.
         let metadata = CatalogMetadata {
             latest_version: catalog.state.version,
             last_written_by: self.process_id,
+            history,
         };
 
         let catalog_obj = PERSISTENT_CATALOG_OBJECT.with_version(catalog.state.version);
@@ -245,9 +369,39 @@ impl Storage {
             .rename(&tmp_metadata_path, &CATALOG_METADATA.visible_path(&db_id))
             .await?;
 
+        // The new version is visible, failing to clean up old versions just
+        // leaves them around in storage.
+        for info in pruned {
+            let path = PERSISTENT_CATALOG_OBJECT
+                .with_version(info.version)
+                .visible_path(&db_id);
+            if let Err(e) = self.store.delete(&path).await {
+                warn!(%e, %db_id, version = %info.version, "failed to delete old catalog version");
+            }
+        }
+
         Ok(())
     }
 
+    /// Describe a version of the catalog being written by this process.
+    fn version_info(
+        &self,
+        version: u64,
+        mutations: Vec<String>,
+        sequences_only: bool,
+    ) -> CatalogVersionInfo {
+        CatalogVersionInfo {
+            version,
+            committed_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as i64)
+                .unwrap_or_default(),
+            written_by: self.process_id,
+            mutations,
+            sequences_only,
+        }
+    }
+
     /// Read the metadata for a catalog.
     ///
     /// Note that this doesn't require a lease if the catalog is only being
@@ -303,7 +457,7 @@ mod tests {
         let old_version = catalog.state.version;
         catalog.state.version += 1;
         storage
-            .write_catalog(db_id, old_version, catalog.clone())
+            .write_catalog(db_id, old_version, catalog.clone(), Vec::new(), false)
             .await
             .unwrap();
 
@@ -311,7 +465,146 @@ mod tests {
         assert_eq!(1, updated.state.version);
 
         // Check that we can't write using out of date version.
-        storage.write_catalog(db_id, 0, catalog).await.unwrap_err();
+        storage
+            .write_catalog(db_id, 0, catalog, Vec::new(), false)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn catalog_history() {
+        let storage = new_storage();
+
+        let db_id = Uuid::new_v4();
+        storage.initialize(db_id).await.unwrap();
+
+        let mut catalog = storage.read_catalog(db_id).await.unwrap();
+        for version in 1..=(MAX_RETAINED_CATALOG_VERSIONS as u64 + 1) {
+            catalog.state.version = version;
+            storage
+                .write_catalog(
+                    db_id,
+                    version - 1,
+                    catalog.clone(),
+                    vec![format!("mutation {version}")],
+                    false,
+                )
+                .await
+                .unwrap();
+        }
+        let latest = MAX_RETAINED_CATALOG_VERSIONS as u64 + 1;
+
+        let history = storage.catalog_history(&db_id).await.unwrap();
+        assert_eq!(MAX_RETAINED_CATALOG_VERSIONS, history.len());
+        assert_eq!(2, history[0].version);
+        assert_eq!(vec!["mutation 2".to_string()], history[0].mutations);
+        assert_eq!(latest, history.last().unwrap().version);
+        assert_eq!(storage.process_id, history.last().unwrap().written_by);
+
+        // Retained versions can be read.
+        let old = storage.read_catalog_at(db_id, 2).await.unwrap();
+        assert_eq!(2, old.state.version);
+        let old = storage.read_catalog_at(db_id, latest).await.unwrap();
+        assert_eq!(latest, old.state.version);
+
+        // Versions that fell out of the history were removed.
+        storage.read_catalog_at(db_id, 1).await.unwrap_err();
+        storage
+            .store
+            .head(
+                &PERSISTENT_CATALOG_OBJECT
+                    .with_version(1)
+                    .visible_path(&db_id),
+            )
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn sequence_only_versions_not_retained() {
+        let storage = new_storage();
+
+        let db_id = Uuid::new_v4();
+        storage.initialize(db_id).await.unwrap();
+
+        let mut catalog = storage.read_catalog(db_id).await.unwrap();
+        let mut write = |version: u64, sequences_only: bool| {
+            catalog.state.version = version;
+            let catalog = catalog.clone();
+            let storage = storage.clone();
+            async move {
+                storage
+                    .write_catalog(db_id, version - 1, catalog, Vec::new(), sequences_only)
+                    .await
+                    .unwrap()
+            }
+        };
+
+        write(1, false).await;
+        write(2, true).await;
+        write(3, true).await;
+        write(4, true).await;
+
+        // Only the latest and previous sequence versions are kept.
+        let versions: Vec<_> = storage
+            .catalog_history(&db_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|info| info.version)
+            .collect();
+        assert_eq!(vec![0, 1, 3, 4], versions);
+        storage.read_catalog_at(db_id, 2).await.unwrap_err();
+
+        write(5, false).await;
+        let versions: Vec<_> = storage
+            .catalog_history(&db_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|info| info.version)
+            .collect();
+        assert_eq!(vec![0, 1, 4, 5], versions);
+
+        // Sequence versions don't push others out of the history.
+        for version in 6..(MAX_RETAINED_CATALOG_VERSIONS as u64 + 10) {
+            write(version, true).await;
+        }
+        let history = storage.catalog_history(&db_id).await.unwrap();
+        assert_eq!(0, history[0].version);
+        storage.read_catalog_at(db_id, 1).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn import_catalog() {
+        let storage = new_storage();
+
+        let db_id = Uuid::new_v4();
+        storage.initialize(db_id).await.unwrap();
+
+        let mut catalog = storage.read_catalog(db_id).await.unwrap();
+        catalog.state.version = 5;
+
+        // Import into a different store.
+        let imported = new_storage();
+        imported
+            .import_catalog(db_id, catalog.clone())
+            .await
+            .unwrap();
+
+        let read = imported.read_catalog(db_id).await.unwrap();
+        assert_eq!(5, read.state.version);
+
+        // Imported catalogs can be written to.
+        let mut next = read.clone();
+        next.state.version += 1;
+        imported
+            .write_catalog(db_id, 5, next, Vec::new(), false)
+            .await
+            .unwrap();
+
+        // Can't import over an existing catalog.
+        imported.import_catalog(db_id, catalog).await.unwrap_err();
     }
 
     #[tokio::test]
@@ -337,14 +630,14 @@ mod tests {
         catalog.state.version += 1;
         // Write should fail, can't acquire lease.
         storage
-            .write_catalog(db_id, old_version, catalog.clone())
+            .write_catalog(db_id, old_version, catalog.clone(), Vec::new(), false)
             .await
             .unwrap_err();
 
         // Write should work after dropping lease.
         lease.drop_lease().await.unwrap();
         storage
-            .write_catalog(db_id, old_version, catalog)
+            .write_catalog(db_id, old_version, catalog, Vec::new(), false)
             .await
             .unwrap();
     }
@@ -2,14 +2,15 @@ pub mod errors;
 pub mod options;
 use std::collections::{BTreeMap, VecDeque};
 use std::fmt;
+use std::ops::ControlFlow;
 
 use prql_compiler::sql::Dialect as PrqlDialect;
 use prql_compiler::{compile, Options, Target};
 pub use sqlparser;
-use sqlparser::ast::{self, ColumnDef, Ident, ObjectName};
+use sqlparser::ast::{self, ColumnDef, Ident, ObjectName, VisitMut, VisitorMut};
 use sqlparser::dialect::GenericDialect;
 use sqlparser::keywords::Keyword;
-use sqlparser::parser::{Parser, ParserError, ParserOptions};
+use sqlparser::parser::{IsOptional, Parser, ParserError, ParserOptions};
 use sqlparser::tokenizer::{Token, Tokenizer, Word};
 
 use self::options::{OptionValue, StatementOptions};
@@ -70,6 +71,10 @@ impl fmt::Display for CreateExternalTableStmt {
             write!(f, " {}", self.options)?;
         }
 
+        if let Some(columns) = &self.columns {
+            write!(f, " COLUMNS ({})", ast::display_comma_separated(columns))?;
+        }
+
         Ok(())
     }
 }
@@ -139,6 +144,7 @@ impl fmt::Display for DropDatabaseStmt {
 pub enum AlterDatabaseOperation {
     RenameDatabase { new_name: Ident },
     SetAccessMode { access_mode: Ident },
+    SetDroppedTableRetention { hours: u64 },
 }
 
 impl fmt::Display for AlterDatabaseOperation {
@@ -150,6 +156,9 @@ impl fmt::Display for AlterDatabaseOperation {
             Self::SetAccessMode { access_mode } => {
                 write!(f, "SET ACCESS_MODE TO {access_mode}")
             }
+            Self::SetDroppedTableRetention { hours } => {
+                write!(f, "SET DROPPED_TABLE_RETENTION_HOURS TO {hours}")
+            }
         }
     }
 }
@@ -169,6 +178,8 @@ impl fmt::Display for AlterDatabaseStmt {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AlterTableOperationExtension {
     SetAccessMode { access_mode: Ident },
+    SetMaintenance { options: StatementOptions },
+    ResetMaintenance,
 }
 
 impl fmt::Display for AlterTableOperationExtension {
@@ -177,6 +188,10 @@ impl fmt::Display for AlterTableOperationExtension {
             Self::SetAccessMode { access_mode } => {
                 write!(f, "SET ACCESS_MODE TO {access_mode}")
             }
+            Self::SetMaintenance { options } => {
+                write!(f, "SET MAINTENANCE {options}")
+            }
+            Self::ResetMaintenance => write!(f, "RESET MAINTENANCE"),
         }
     }
 }
@@ -193,6 +208,223 @@ impl fmt::Display for AlterTableStmtExtension {
     }
 }
 
+/// Compact the files of a native table.
+///
+/// `OPTIMIZE <table> [ZORDER BY (<col>, ...)]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptimizeStmt {
+    pub name: ObjectName,
+    /// Columns to cluster rows by. Files are only bin-packed if empty.
+    pub zorder_by: Vec<Ident>,
+}
+
+impl fmt::Display for OptimizeStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "OPTIMIZE {}", self.name)?;
+        if !self.zorder_by.is_empty() {
+            write!(
+                f,
+                " ZORDER BY ({})",
+                ast::display_comma_separated(&self.zorder_by)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Remove files no longer referenced by a native table.
+///
+/// `VACUUM <table> [RETAIN <n> HOURS] [DRY RUN]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VacuumStmt {
+    pub name: ObjectName,
+    /// Keep files removed within this many hours.
+    pub retain_hours: Option<u64>,
+    /// Only list the files that would be removed.
+    pub dry_run: bool,
+}
+
+impl fmt::Display for VacuumStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "VACUUM {}", self.name)?;
+        if let Some(hours) = self.retain_hours {
+            write!(f, " RETAIN {hours} HOURS")?;
+        }
+        if self.dry_run {
+            write!(f, " DRY RUN")?;
+        }
+        Ok(())
+    }
+}
+
+/// Bring back a dropped native table.
+///
+/// `UNDROP TABLE <table>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndropTableStmt {
+    pub name: ObjectName,
+}
+
+impl fmt::Display for UndropTableStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UNDROP TABLE {}", self.name)
+    }
+}
+
+/// Version of a table to restore to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RestoreTableTarget {
+    Version(u64),
+    Timestamp(String),
+}
+
+impl fmt::Display for RestoreTableTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Version(version) => write!(f, "VERSION {version}"),
+            Self::Timestamp(ts) => write!(f, "TIMESTAMP '{ts}'"),
+        }
+    }
+}
+
+/// Roll a native table back to a previous version.
+///
+/// `RESTORE TABLE <table> TO (VERSION <n> | TIMESTAMP '<timestamp>')`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestoreTableStmt {
+    pub name: ObjectName,
+    pub target: RestoreTableTarget,
+}
+
+impl fmt::Display for RestoreTableStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RESTORE TABLE {} TO {}", self.name, self.target)
+    }
+}
+
+/// Collect statistics for a table.
+///
+/// `ANALYZE [[TABLE] <table>]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalyzeStmt {
+    /// Table to analyze, all tables if not provided.
+    pub name: Option<ObjectName>,
+}
+
+impl fmt::Display for AnalyzeStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ANALYZE")?;
+        if let Some(name) = &self.name {
+            write!(f, " {}", name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Kind of object a comment is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentObjectType {
+    Database,
+    Schema,
+    Table,
+    View,
+    Column,
+}
+
+impl fmt::Display for CommentObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Database => "DATABASE",
+            Self::Schema => "SCHEMA",
+            Self::Table => "TABLE",
+            Self::View => "VIEW",
+            Self::Column => "COLUMN",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Set or remove the comment on an object.
+///
+/// `COMMENT ON <object_type> <name> IS ('<comment>' | NULL)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentOnStmt {
+    pub object_type: CommentObjectType,
+    /// Name of the object. For columns, the last part is the column name.
+    pub name: ObjectName,
+    /// The new comment, `None` removes the comment.
+    pub comment: Option<String>,
+}
+
+impl fmt::Display for CommentOnStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "COMMENT ON {} {} IS ", self.object_type, self.name)?;
+        match &self.comment {
+            Some(comment) => write!(f, "{}", ast::Value::SingleQuotedString(comment.clone())),
+            None => write!(f, "NULL"),
+        }
+    }
+}
+
+/// Kind of object to show the create statement for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShowCreateObjectType {
+    Database,
+    Table,
+    View,
+}
+
+impl fmt::Display for ShowCreateObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Database => "DATABASE",
+            Self::Table => "TABLE",
+            Self::View => "VIEW",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Show the statements that recreate an object.
+///
+/// `SHOW CREATE <object_type> <name>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShowCreateStmt {
+    pub object_type: ShowCreateObjectType,
+    pub name: ObjectName,
+}
+
+impl fmt::Display for ShowCreateStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SHOW CREATE {} {}", self.object_type, self.name)
+    }
+}
+
+/// Write a backup of the current database.
+///
+/// `BACKUP DATABASE TO '<location>' [OPTIONS (...)]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupDatabaseStmt {
+    /// Local path or object store URL to write the backup to.
+    pub location: String,
+    /// Options for accessing the location.
+    pub options: StatementOptions,
+}
+
+impl fmt::Display for BackupDatabaseStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "BACKUP DATABASE TO {}",
+            ast::Value::SingleQuotedString(self.location.clone())
+        )?;
+        if !self.options.is_empty() {
+            write!(f, " {}", self.options)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateTunnelStmt {
     /// Name of the tunnel as it exists in GlareDB.
@@ -306,7 +538,11 @@ impl fmt::Display for CreateCredentialsStmt {
             write!(f, " {}", self.options)?;
         }
         if !self.comment.is_empty() {
-            write!(f, " COMMENT '{}'", self.comment)?
+            write!(
+                f,
+                " COMMENT {}",
+                ast::Value::SingleQuotedString(self.comment.clone())
+            )?
         }
 
         Ok(())
@@ -438,6 +674,22 @@ pub enum StatementWithExtensions {
     DropCredentials(DropCredentialsStmt),
     /// Copy To extension.
     CopyTo(CopyToStmt),
+    /// Optimize native table extension.
+    Optimize(OptimizeStmt),
+    /// Vacuum native table extension.
+    Vacuum(VacuumStmt),
+    /// Undrop table extension.
+    UndropTable(UndropTableStmt),
+    /// Restore table extension.
+    RestoreTable(RestoreTableStmt),
+    /// Analyze table extension.
+    Analyze(AnalyzeStmt),
+    /// Comment on extension.
+    CommentOn(CommentOnStmt),
+    /// Show create extension.
+    ShowCreate(ShowCreateStmt),
+    /// Backup database extension.
+    BackupDatabase(BackupDatabaseStmt),
 }
 
 impl fmt::Display for StatementWithExtensions {
@@ -455,6 +707,14 @@ impl fmt::Display for StatementWithExtensions {
             StatementWithExtensions::CreateCredentials(stmt) => write!(f, "{}", stmt),
             StatementWithExtensions::DropCredentials(stmt) => write!(f, "{}", stmt),
             StatementWithExtensions::CopyTo(stmt) => write!(f, "{}", stmt),
+            StatementWithExtensions::Optimize(stmt) => write!(f, "{}", stmt),
+            StatementWithExtensions::Vacuum(stmt) => write!(f, "{}", stmt),
+            StatementWithExtensions::UndropTable(stmt) => write!(f, "{}", stmt),
+            StatementWithExtensions::RestoreTable(stmt) => write!(f, "{}", stmt),
+            StatementWithExtensions::Analyze(stmt) => write!(f, "{}", stmt),
+            StatementWithExtensions::CommentOn(stmt) => write!(f, "{}", stmt),
+            StatementWithExtensions::ShowCreate(stmt) => write!(f, "{}", stmt),
+            StatementWithExtensions::BackupDatabase(stmt) => write!(f, "{}", stmt),
         }
     }
 }
@@ -462,6 +722,138 @@ impl fmt::Display for StatementWithExtensions {
 /// Parser with our extensions.
 pub struct GlareDbParser<'a> {
     parser: Parser<'a>,
+    /// Versions extracted from `AS OF` clauses during tokenization, indexed by
+    /// the placeholder identifier that replaced them.
+    table_versions: Vec<Option<ast::TableVersion>>,
+}
+
+/// Prefix for the placeholder identifier we substitute for a table's `AS OF`
+/// clause. Contains a NUL so it can never collide with a user identifier.
+const TABLE_VERSION_MARKER: &str = "\0as_of:";
+
+/// Pull time travel clauses out of the token stream.
+///
+/// `sqlparser` only parses `FOR SYSTEM_TIME AS OF` for the BigQuery and MSSQL
+/// dialects, so we handle the following forms ourselves:
+///
+/// - `<table> AS OF VERSION <number>`
+/// - `<table> AS OF TIMESTAMP '<timestamp>'`
+/// - `<table> FOR SYSTEM_TIME AS OF <number | TIMESTAMP '<timestamp>'>`
+///
+/// The last form is what `ast::TableVersion` displays as, so statements
+/// round trip.
+///
+/// Each clause is replaced with an extra quoted part on the preceding object
+/// name. After parsing, `TableVersionResolver` strips that part and fills in
+/// the table factor's `version`.
+fn extract_table_versions(tokens: Vec<Token>) -> (Vec<Token>, Vec<Option<ast::TableVersion>>) {
+    fn is_word(tok: Option<&Token>, word: &str) -> bool {
+        matches!(tok, Some(Token::Word(w)) if w.value.eq_ignore_ascii_case(word))
+    }
+
+    let mut out: Vec<Token> = Vec::with_capacity(tokens.len());
+    let mut versions = Vec::new();
+
+    let mut idx = 0;
+    while idx < tokens.len() {
+        let curr = Some(&tokens[idx]);
+        let starts_clause = is_word(curr, "AS") || is_word(curr, "FOR");
+        let prev = out
+            .iter()
+            .rev()
+            .find(|t| !matches!(t, Token::Whitespace(_)));
+        let follows_name = matches!(prev, Some(Token::Word(_)));
+
+        if starts_clause && follows_name {
+            // Indices of the upcoming non-whitespace tokens.
+            let upcoming: Vec<usize> = (idx..tokens.len())
+                .filter(|i| !matches!(tokens[*i], Token::Whitespace(_)))
+                .take(6)
+                .collect();
+            let tok = |n: usize| upcoming.get(n).map(|i| &tokens[*i]);
+
+            let mut n = 0;
+            let system_time = is_word(tok(0), "FOR") && is_word(tok(1), "SYSTEM_TIME");
+            if system_time {
+                n = 2;
+            }
+
+            if is_word(tok(n), "AS") && is_word(tok(n + 1), "OF") {
+                n += 2;
+                let version = match (tok(n), tok(n + 1)) {
+                    (Some(Token::Word(w)), Some(Token::Number(num, _)))
+                        if w.value.eq_ignore_ascii_case("VERSION") =>
+                    {
+                        n += 2;
+                        Some(ast::Expr::Value(ast::Value::Number(num.clone(), false)))
+                    }
+                    (Some(Token::Word(w)), Some(Token::SingleQuotedString(ts)))
+                        if w.value.eq_ignore_ascii_case("TIMESTAMP") =>
+                    {
+                        n += 2;
+                        Some(ast::Expr::TypedString {
+                            data_type: ast::DataType::Timestamp(None, ast::TimezoneInfo::None),
+                            value: ts.clone(),
+                        })
+                    }
+                    (Some(Token::Number(num, _)), _) if system_time => {
+                        n += 1;
+                        Some(ast::Expr::Value(ast::Value::Number(num.clone(), false)))
+                    }
+                    _ => None,
+                };
+
+                if let Some(version) = version {
+                    while matches!(out.last(), Some(Token::Whitespace(_))) {
+                        out.pop();
+                    }
+                    out.push(Token::Period);
+                    out.push(Token::Word(Word {
+                        value: format!("{TABLE_VERSION_MARKER}{}", versions.len()),
+                        quote_style: Some('"'),
+                        keyword: Keyword::NoKeyword,
+                    }));
+                    versions.push(Some(ast::TableVersion::ForSystemTimeAsOf(version)));
+                    idx = upcoming[n - 1] + 1;
+                    continue;
+                }
+            }
+        }
+
+        out.push(tokens[idx].clone());
+        idx += 1;
+    }
+
+    (out, versions)
+}
+
+/// Moves versions extracted by `extract_table_versions` onto the table
+/// factors they were written against.
+struct TableVersionResolver<'a> {
+    versions: &'a mut [Option<ast::TableVersion>],
+}
+
+impl VisitorMut for TableVersionResolver<'_> {
+    type Break = ParserError;
+
+    fn pre_visit_table_factor(
+        &mut self,
+        table_factor: &mut ast::TableFactor,
+    ) -> ControlFlow<Self::Break> {
+        if let ast::TableFactor::Table { name, version, .. } = table_factor {
+            let idx = name
+                .0
+                .last()
+                .and_then(|ident| ident.value.strip_prefix(TABLE_VERSION_MARKER))
+                .and_then(|idx| idx.parse::<usize>().ok());
+
+            if let Some(idx) = idx {
+                name.0.pop();
+                *version = self.versions.get_mut(idx).and_then(Option::take);
+            }
+        }
+        ControlFlow::Continue(())
+    }
 }
 
 impl GlareDbParser<'_> {
@@ -476,6 +868,7 @@ impl GlareDbParser<'_> {
 
     pub fn new(mut sql: &str, dialect: Dialect) -> Result<GlareDbParser<'_>, ParserError> {
         let tokens = Tokenizer::new(Self::SQL_DIALECT, sql).tokenize()?;
+        let (tokens, mut table_versions) = extract_table_versions(tokens);
         let mut parser = Parser::new(Self::SQL_DIALECT)
             .with_options(ParserOptions {
                 trailing_commas: true,
@@ -493,10 +886,15 @@ impl GlareDbParser<'_> {
                     ParserError::ParserError(format!("Error compiling PRQL: {}", e))
                 })?;
                 let tokens = Tokenizer::new(Self::SQL_DIALECT, &s).tokenize()?;
+                let (tokens, versions) = extract_table_versions(tokens);
+                table_versions = versions;
                 parser = parser.with_tokens(tokens);
             }
         }
-        Ok(GlareDbParser { parser })
+        Ok(GlareDbParser {
+            parser,
+            table_versions,
+        })
     }
 }
 
@@ -530,7 +928,8 @@ impl<'a> GlareDbParser<'a> {
                 return parser.expected("end of statement", parser.parser.peek_token().token);
             }
 
-            let statement = parser.parse_statement()?;
+            let mut statement = parser.parse_statement()?;
+            parser.resolve_table_versions(&mut statement)?;
             stmts.push_back(statement);
             expecting_statement_delimiter = true;
         }
@@ -538,6 +937,66 @@ impl<'a> GlareDbParser<'a> {
         Ok(stmts)
     }
 
+    /// Attach any `AS OF` clauses in the statement to their table factors.
+    ///
+    /// Errors if the statement has `AS OF` clauses anywhere else, including on
+    /// the table being written to by `UPDATE` or `DELETE`.
+    fn resolve_table_versions(
+        &mut self,
+        statement: &mut StatementWithExtensions,
+    ) -> Result<(), ParserError> {
+        if self.table_versions.is_empty() {
+            return Ok(());
+        }
+
+        let mut resolver = TableVersionResolver {
+            versions: &mut self.table_versions,
+        };
+        let flow = match statement {
+            StatementWithExtensions::Statement(stmt) => stmt.visit(&mut resolver),
+            StatementWithExtensions::CopyTo(CopyToStmt {
+                source: CopyToSource::Query(query),
+                ..
+            }) => query.visit(&mut resolver),
+            _ => ControlFlow::Continue(()),
+        };
+
+        if let ControlFlow::Break(e) = flow {
+            return Err(e);
+        }
+
+        // Clauses that weren't moved onto a table factor are still in some
+        // object name or identifier.
+        let unresolved = statement.to_string().contains(TABLE_VERSION_MARKER);
+
+        let versioned = |table: &ast::TableWithJoins| {
+            matches!(
+                table.relation,
+                ast::TableFactor::Table {
+                    version: Some(_),
+                    ..
+                }
+            )
+        };
+        let writes_version = match statement {
+            StatementWithExtensions::Statement(ast::Statement::Update { table, .. }) => {
+                versioned(table)
+            }
+            StatementWithExtensions::Statement(ast::Statement::Delete {
+                from: ast::FromTable::WithFromKeyword(from) | ast::FromTable::WithoutKeyword(from),
+                ..
+            }) => from.iter().any(versioned),
+            _ => false,
+        };
+
+        if unresolved || writes_version {
+            return Err(ParserError::ParserError(
+                "AS OF is only supported on tables being read from".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     fn parse_statement(&mut self) -> Result<StatementWithExtensions, ParserError> {
         match self.parser.peek_token().token {
             Token::Word(w) => match w.keyword {
@@ -557,9 +1016,41 @@ impl<'a> GlareDbParser<'a> {
                     self.parser.next_token();
                     self.parse_copy()
                 }
-                _ => Ok(StatementWithExtensions::Statement(
-                    self.parser.parse_statement()?,
-                )),
+                Keyword::ANALYZE => {
+                    self.parser.next_token();
+                    self.parse_analyze()
+                }
+                Keyword::COMMENT => {
+                    self.parser.next_token();
+                    self.parse_comment_on()
+                }
+                Keyword::SHOW
+                    if matches!(
+                        self.parser.peek_nth_token(1).token,
+                        Token::Word(ref w) if w.keyword == Keyword::CREATE
+                    ) =>
+                {
+                    self.parser.next_token();
+                    self.parser.next_token();
+                    self.parse_show_create()
+                }
+                _ => {
+                    if self.consume_token(&Token::make_keyword("OPTIMIZE")) {
+                        self.parse_optimize()
+                    } else if self.consume_token(&Token::make_keyword("VACUUM")) {
+                        self.parse_vacuum()
+                    } else if self.consume_token(&Token::make_keyword("UNDROP")) {
+                        self.parse_undrop_table()
+                    } else if self.consume_token(&Token::make_keyword("RESTORE")) {
+                        self.parse_restore_table()
+                    } else if self.consume_token(&Token::make_keyword("BACKUP")) {
+                        self.parse_backup_database()
+                    } else {
+                        Ok(StatementWithExtensions::Statement(
+                            self.parser.parse_statement()?,
+                        ))
+                    }
+                }
             },
             _ => Ok(StatementWithExtensions::Statement(
                 self.parser.parse_statement()?,
@@ -593,6 +1084,9 @@ impl<'a> GlareDbParser<'a> {
         } else if self.parser.parse_keyword(Keyword::CREDENTIALS) {
             // CREATE CREDENTIALS ...
             self.parse_create_credentials(true, or_replace)
+        } else if !or_replace && self.parser.parse_keyword(Keyword::USER) {
+            // CREATE USER ...
+            self.parse_create_user()
         } else {
             // Fall back to underlying parser.
 
@@ -608,6 +1102,20 @@ impl<'a> GlareDbParser<'a> {
         }
     }
 
+    /// Parse `CREATE USER ...`.
+    ///
+    /// Postgres treats this as an alias for `CREATE ROLE ... LOGIN`, so we
+    /// reuse the role parser and default to allowing login.
+    fn parse_create_user(&mut self) -> Result<StatementWithExtensions, ParserError> {
+        let mut stmt = self.parser.parse_create_role()?;
+        if let ast::Statement::CreateRole { login, .. } = &mut stmt {
+            if login.is_none() {
+                *login = Some(true);
+            }
+        }
+        Ok(StatementWithExtensions::Statement(stmt))
+    }
+
     /// Parse a SQL ALTER statement
     fn parse_alter(&mut self) -> Result<StatementWithExtensions, ParserError> {
         if self.parser.parse_keyword(Keyword::DATABASE) {
@@ -1049,11 +1557,18 @@ impl<'a> GlareDbParser<'a> {
             validate_ident(&new_name)?;
             AlterDatabaseOperation::RenameDatabase { new_name }
         } else if self.parser.parse_keyword(Keyword::SET) {
-            self.expect_token(&Token::make_keyword("ACCESS_MODE"))?;
-            self.expect_token(&Token::make_keyword("TO"))?;
+            if self.consume_token(&Token::make_keyword("DROPPED_TABLE_RETENTION_HOURS")) {
+                self.expect_token(&Token::make_keyword("TO"))?;
+
+                let hours = self.parser.parse_literal_uint()?;
+                AlterDatabaseOperation::SetDroppedTableRetention { hours }
+            } else {
+                self.expect_token(&Token::make_keyword("ACCESS_MODE"))?;
+                self.expect_token(&Token::make_keyword("TO"))?;
 
-            let access_mode = self.parser.parse_identifier(false)?;
-            AlterDatabaseOperation::SetAccessMode { access_mode }
+                let access_mode = self.parser.parse_identifier(false)?;
+                AlterDatabaseOperation::SetAccessMode { access_mode }
+            }
         } else {
             return self.expected(
                 "an alter database operation",
@@ -1073,11 +1588,22 @@ impl<'a> GlareDbParser<'a> {
         let name = self.parser.parse_object_name(false)?;
 
         let operation = if self.parser.parse_keyword(Keyword::SET) {
-            self.expect_token(&Token::make_keyword("ACCESS_MODE"))?;
-            self.expect_token(&Token::make_keyword("TO"))?;
+            if self.consume_token(&Token::make_keyword("MAINTENANCE")) {
+                let options = self.parse_options()?;
+                if options.is_empty() {
+                    return self.expected("maintenance OPTIONS", self.parser.peek_token().token);
+                }
+                AlterTableOperationExtension::SetMaintenance { options }
+            } else {
+                self.expect_token(&Token::make_keyword("ACCESS_MODE"))?;
+                self.expect_token(&Token::make_keyword("TO"))?;
 
-            let access_mode = self.parser.parse_identifier(false)?;
-            AlterTableOperationExtension::SetAccessMode { access_mode }
+                let access_mode = self.parser.parse_identifier(false)?;
+                AlterTableOperationExtension::SetAccessMode { access_mode }
+            }
+        } else if self.consume_token(&Token::make_keyword("RESET")) {
+            self.expect_token(&Token::make_keyword("MAINTENANCE"))?;
+            AlterTableOperationExtension::ResetMaintenance
         } else {
             let operations = self
                 .parser
@@ -1098,6 +1624,198 @@ impl<'a> GlareDbParser<'a> {
         ))
     }
 
+    /// Parse `OPTIMIZE [TABLE] <table> [ZORDER BY (<col>, ...)]`.
+    fn parse_optimize(&mut self) -> Result<StatementWithExtensions, ParserError> {
+        let _ = self.parser.parse_keyword(Keyword::TABLE);
+        let name = self.parser.parse_object_name(false)?;
+        validate_object_name(&name)?;
+
+        let zorder_by = if self.consume_token(&Token::make_keyword("ZORDER")) {
+            self.parser.expect_keyword(Keyword::BY)?;
+            self.parser
+                .parse_parenthesized_column_list(IsOptional::Mandatory, false)?
+        } else {
+            Vec::new()
+        };
+
+        Ok(StatementWithExtensions::Optimize(OptimizeStmt {
+            name,
+            zorder_by,
+        }))
+    }
+
+    /// Parse `VACUUM [TABLE] <table> [RETAIN <n> HOURS] [DRY RUN]`.
+    fn parse_vacuum(&mut self) -> Result<StatementWithExtensions, ParserError> {
+        let _ = self.parser.parse_keyword(Keyword::TABLE);
+        let name = self.parser.parse_object_name(false)?;
+        validate_object_name(&name)?;
+
+        let retain_hours = if self.consume_token(&Token::make_keyword("RETAIN")) {
+            let hours = self.parser.parse_literal_uint()?;
+            self.expect_token(&Token::make_keyword("HOURS"))?;
+            Some(hours)
+        } else {
+            None
+        };
+
+        let dry_run = if self.consume_token(&Token::make_keyword("DRY")) {
+            self.expect_token(&Token::make_keyword("RUN"))?;
+            true
+        } else {
+            false
+        };
+
+        Ok(StatementWithExtensions::Vacuum(VacuumStmt {
+            name,
+            retain_hours,
+            dry_run,
+        }))
+    }
+
+    /// Parse `UNDROP TABLE <table>`.
+    fn parse_undrop_table(&mut self) -> Result<StatementWithExtensions, ParserError> {
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let name = self.parser.parse_object_name(false)?;
+        validate_object_name(&name)?;
+
+        Ok(StatementWithExtensions::UndropTable(UndropTableStmt {
+            name,
+        }))
+    }
+
+    /// Parse `RESTORE [TABLE] <table> TO (VERSION <n> | TIMESTAMP '<timestamp>')`.
+    fn parse_restore_table(&mut self) -> Result<StatementWithExtensions, ParserError> {
+        let _ = self.parser.parse_keyword(Keyword::TABLE);
+        let name = self.parser.parse_object_name(false)?;
+        validate_object_name(&name)?;
+
+        self.parser.expect_keyword(Keyword::TO)?;
+        let target = if self.consume_token(&Token::make_keyword("VERSION")) {
+            RestoreTableTarget::Version(self.parser.parse_literal_uint()?)
+        } else if self.parser.parse_keyword(Keyword::TIMESTAMP) {
+            RestoreTableTarget::Timestamp(self.parser.parse_literal_string()?)
+        } else {
+            return self.expected("VERSION or TIMESTAMP", self.parser.peek_token().token);
+        };
+
+        Ok(StatementWithExtensions::RestoreTable(RestoreTableStmt {
+            name,
+            target,
+        }))
+    }
+
+    /// Parse `ANALYZE [[TABLE] <table>]`.
+    fn parse_analyze(&mut self) -> Result<StatementWithExtensions, ParserError> {
+        if matches!(
+            self.parser.peek_token().token,
+            Token::EOF | Token::SemiColon
+        ) {
+            return Ok(StatementWithExtensions::Analyze(AnalyzeStmt { name: None }));
+        }
+
+        let _ = self.parser.parse_keyword(Keyword::TABLE);
+        let name = self.parser.parse_object_name(false)?;
+        validate_object_name(&name)?;
+
+        Ok(StatementWithExtensions::Analyze(AnalyzeStmt {
+            name: Some(name),
+        }))
+    }
+
+    /// Parse `COMMENT ON <object_type> <name> IS ('<comment>' | NULL)`.
+    fn parse_comment_on(&mut self) -> Result<StatementWithExtensions, ParserError> {
+        self.parser.expect_keyword(Keyword::ON)?;
+
+        let object_type = match self.parser.parse_one_of_keywords(&[
+            Keyword::DATABASE,
+            Keyword::SCHEMA,
+            Keyword::TABLE,
+            Keyword::VIEW,
+            Keyword::COLUMN,
+        ]) {
+            Some(Keyword::DATABASE) => CommentObjectType::Database,
+            Some(Keyword::SCHEMA) => CommentObjectType::Schema,
+            Some(Keyword::TABLE) => CommentObjectType::Table,
+            Some(Keyword::VIEW) => CommentObjectType::View,
+            Some(Keyword::COLUMN) => CommentObjectType::Column,
+            _ => {
+                return self.expected(
+                    "DATABASE, SCHEMA, TABLE, VIEW or COLUMN",
+                    self.parser.peek_token().token,
+                )
+            }
+        };
+
+        let name = self.parser.parse_object_name(false)?;
+        let max_parts = match object_type {
+            CommentObjectType::Database | CommentObjectType::Schema => 1,
+            CommentObjectType::Table | CommentObjectType::View => 3,
+            CommentObjectType::Column => 4,
+        };
+        if object_type == CommentObjectType::Column && name.0.len() < 2 {
+            return Err(ParserError::ParserError(format!(
+                "Expected a column name qualified by its table, found: {name}"
+            )));
+        }
+        if name.0.len() > max_parts {
+            return Err(ParserError::ParserError(format!(
+                "Too many parts in {object_type} name: {name}"
+            )));
+        }
+        validate_object_name(&name)?;
+
+        self.parser.expect_keyword(Keyword::IS)?;
+        let comment = if self.parser.parse_keyword(Keyword::NULL) {
+            None
+        } else {
+            Some(self.parser.parse_literal_string()?)
+        };
+
+        Ok(StatementWithExtensions::CommentOn(CommentOnStmt {
+            object_type,
+            name,
+            comment,
+        }))
+    }
+
+    /// Parse `BACKUP DATABASE TO '<location>' [OPTIONS (...)]`.
+    fn parse_backup_database(&mut self) -> Result<StatementWithExtensions, ParserError> {
+        self.parser.expect_keyword(Keyword::DATABASE)?;
+        self.parser.expect_keyword(Keyword::TO)?;
+        let location = self.parser.parse_literal_string()?;
+        let options = self.parse_options()?;
+
+        Ok(StatementWithExtensions::BackupDatabase(
+            BackupDatabaseStmt { location, options },
+        ))
+    }
+
+    fn parse_show_create(&mut self) -> Result<StatementWithExtensions, ParserError> {
+        let object_type = match self.parser.parse_one_of_keywords(&[
+            Keyword::DATABASE,
+            Keyword::TABLE,
+            Keyword::VIEW,
+        ]) {
+            Some(Keyword::DATABASE) => ShowCreateObjectType::Database,
+            Some(Keyword::TABLE) => ShowCreateObjectType::Table,
+            Some(Keyword::VIEW) => ShowCreateObjectType::View,
+            _ => return self.expected("DATABASE, TABLE or VIEW", self.parser.peek_token().token),
+        };
+
+        let name = self.parser.parse_object_name(false)?;
+        if object_type == ShowCreateObjectType::Database && name.0.len() > 1 {
+            return Err(ParserError::ParserError(format!(
+                "Too many parts in database name: {name}"
+            )));
+        }
+        validate_object_name(&name)?;
+
+        Ok(StatementWithExtensions::ShowCreate(ShowCreateStmt {
+            object_type,
+            name,
+        }))
+    }
+
     fn parse_alter_tunnel(&mut self) -> Result<StatementWithExtensions, ParserError> {
         let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
 
@@ -1249,6 +1967,7 @@ mod tests {
             "CREATE EXTERNAL TABLE test FROM postgres TUNNEL my_ssh CREDENTIALS my_pg OPTIONS (postgres_conn = 'host=localhost user=postgres', schema = 'public')",
             "CREATE OR REPLACE EXTERNAL TABLE test FROM postgres TUNNEL my_ssh CREDENTIALS my_pg OPTIONS (postgres_conn = 'host=localhost user=postgres', schema = 'public')",
             "CREATE EXTERNAL TABLE IF NOT EXISTS test FROM postgres TUNNEL my_ssh CREDENTIALS my_pg OPTIONS (postgres_conn = 'host=localhost user=postgres', schema = 'public')",
+            "CREATE EXTERNAL TABLE test FROM local OPTIONS (location = 'data.csv') COLUMNS (a INT, b TEXT)",
         ];
 
         for test_case in test_cases {
@@ -1332,6 +2051,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn create_user_is_login_role() {
+        let test_cases = [
+            ("CREATE USER analyst", "CREATE ROLE analyst LOGIN"),
+            (
+                "CREATE USER ingest PASSWORD 'secret'",
+                "CREATE ROLE ingest LOGIN PASSWORD 'secret'",
+            ),
+            ("CREATE USER nologin NOLOGIN", "CREATE ROLE nologin NOLOGIN"),
+        ];
+
+        for (test_case, expected) in test_cases {
+            let stmt = GlareDbParser::parse_sql(test_case)
+                .unwrap()
+                .pop_front()
+                .unwrap();
+            assert_eq!(expected, stmt.to_string().as_str());
+        }
+    }
+
     #[test]
     fn drop_tunnel_roundtrips() {
         let test_cases = ["DROP TUNNEL my_tunnel", "DROP TUNNEL IF EXISTS my_tunnel"];
@@ -1382,6 +2121,7 @@ mod tests {
         let test_cases = [
             "ALTER DATABASE my_db RENAME TO your_db",
             "ALTER DATABASE my_db SET ACCESS_MODE TO readwrite",
+            "ALTER DATABASE default SET DROPPED_TABLE_RETENTION_HOURS TO 24",
         ];
 
         for test_case in test_cases {
@@ -1395,7 +2135,11 @@ mod tests {
 
     #[test]
     fn alter_table_extension_roundtrips() {
-        let test_cases = ["ALTER TABLE my_db SET ACCESS_MODE TO readonly"];
+        let test_cases = [
+            "ALTER TABLE my_db SET ACCESS_MODE TO readonly",
+            "ALTER TABLE my_table SET MAINTENANCE OPTIONS (optimize_min_files = 100, vacuum_retain_hours = 24)",
+            "ALTER TABLE my_table RESET MAINTENANCE",
+        ];
 
         for test_case in test_cases {
             let stmt = GlareDbParser::parse_sql(test_case)
@@ -1406,6 +2150,161 @@ mod tests {
         }
     }
 
+    #[test]
+    fn optimize_and_vacuum_roundtrips() {
+        let test_cases = [
+            "OPTIMIZE my_table",
+            "OPTIMIZE my_schema.my_table ZORDER BY (a, b)",
+            "VACUUM my_table",
+            "VACUUM my_table RETAIN 0 HOURS",
+            "VACUUM my_table RETAIN 24 HOURS DRY RUN",
+            "VACUUM my_table DRY RUN",
+        ];
+
+        for test_case in test_cases {
+            let stmt = GlareDbParser::parse_sql(test_case)
+                .unwrap()
+                .pop_front()
+                .unwrap();
+            assert_eq!(test_case, stmt.to_string().as_str());
+        }
+
+        // Optional TABLE keyword.
+        let stmt = GlareDbParser::parse_sql("vacuum table my_table retain 1 hours")
+            .unwrap()
+            .pop_front()
+            .unwrap();
+        assert_eq!("VACUUM my_table RETAIN 1 HOURS", stmt.to_string());
+
+        GlareDbParser::parse_sql("OPTIMIZE my_table ZORDER BY ()").unwrap_err();
+        GlareDbParser::parse_sql("VACUUM my_table RETAIN 1").unwrap_err();
+    }
+
+    #[test]
+    fn undrop_and_restore_table_roundtrips() {
+        let test_cases = [
+            "UNDROP TABLE my_table",
+            "UNDROP TABLE my_schema.my_table",
+            "RESTORE TABLE my_table TO VERSION 3",
+            "RESTORE TABLE my_schema.my_table TO TIMESTAMP '2024-01-01 00:00:00'",
+        ];
+
+        for test_case in test_cases {
+            let stmt = GlareDbParser::parse_sql(test_case)
+                .unwrap()
+                .pop_front()
+                .unwrap();
+            assert_eq!(test_case, stmt.to_string().as_str());
+        }
+
+        // Optional TABLE keyword for restore.
+        let stmt = GlareDbParser::parse_sql("restore my_table to version 0")
+            .unwrap()
+            .pop_front()
+            .unwrap();
+        assert_eq!("RESTORE TABLE my_table TO VERSION 0", stmt.to_string());
+
+        GlareDbParser::parse_sql("UNDROP my_table").unwrap_err();
+        GlareDbParser::parse_sql("RESTORE TABLE my_table TO 3").unwrap_err();
+        GlareDbParser::parse_sql("RESTORE TABLE my_table VERSION 3").unwrap_err();
+    }
+
+    #[test]
+    fn analyze_roundtrips() {
+        let stmt = GlareDbParser::parse_sql("ANALYZE my_schema.my_table")
+            .unwrap()
+            .pop_front()
+            .unwrap();
+        assert_eq!("ANALYZE my_schema.my_table", stmt.to_string());
+
+        // Optional TABLE keyword.
+        let stmt = GlareDbParser::parse_sql("analyze table my_table")
+            .unwrap()
+            .pop_front()
+            .unwrap();
+        assert_eq!("ANALYZE my_table", stmt.to_string());
+
+        // All tables.
+        let stmts = GlareDbParser::parse_sql("ANALYZE; ANALYZE").unwrap();
+        assert_eq!(2, stmts.len());
+        for stmt in stmts {
+            assert_eq!("ANALYZE", stmt.to_string());
+        }
+
+        GlareDbParser::parse_sql("ANALYZE TABLE").unwrap_err();
+    }
+
+    #[test]
+    fn comment_on_roundtrips() {
+        let test_cases = [
+            "COMMENT ON DATABASE my_db IS 'a database'",
+            "COMMENT ON SCHEMA my_schema IS 'a schema'",
+            "COMMENT ON TABLE my_schema.my_table IS 'it''s a table'",
+            "COMMENT ON VIEW my_view IS NULL",
+            "COMMENT ON COLUMN my_schema.my_table.my_col IS 'a column'",
+        ];
+
+        for test_case in test_cases {
+            let stmt = GlareDbParser::parse_sql(test_case)
+                .unwrap()
+                .pop_front()
+                .unwrap();
+            assert_eq!(test_case, stmt.to_string());
+        }
+
+        // Columns need a table, databases and schemas can't be qualified.
+        GlareDbParser::parse_sql("COMMENT ON COLUMN my_col IS 'a column'").unwrap_err();
+        GlareDbParser::parse_sql("COMMENT ON SCHEMA my_db.my_schema IS 'a schema'").unwrap_err();
+        GlareDbParser::parse_sql("COMMENT ON FUNCTION my_func IS 'a function'").unwrap_err();
+    }
+
+    #[test]
+    fn show_create_roundtrips() {
+        let test_cases = [
+            "SHOW CREATE DATABASE my_db",
+            "SHOW CREATE TABLE my_schema.my_table",
+            "SHOW CREATE VIEW my_view",
+        ];
+
+        for test_case in test_cases {
+            let stmt = GlareDbParser::parse_sql(test_case)
+                .unwrap()
+                .pop_front()
+                .unwrap();
+            assert_eq!(test_case, stmt.to_string());
+        }
+
+        GlareDbParser::parse_sql("SHOW CREATE DATABASE my_db.my_schema").unwrap_err();
+        GlareDbParser::parse_sql("SHOW CREATE FUNCTION my_func").unwrap_err();
+
+        // Other SHOW statements are still parsed by sqlparser.
+        let stmt = GlareDbParser::parse_sql("SHOW search_path")
+            .unwrap()
+            .pop_front()
+            .unwrap();
+        assert!(matches!(stmt, StatementWithExtensions::Statement(_)));
+    }
+
+    #[test]
+    fn backup_database_roundtrips() {
+        let test_cases = [
+            "BACKUP DATABASE TO '/tmp/backup'",
+            "BACKUP DATABASE TO 's3://bucket/backup' OPTIONS (access_key_id = 'key', region = 'us-east-1')",
+            "BACKUP DATABASE TO '/tmp/it''s'",
+        ];
+
+        for test_case in test_cases {
+            let stmt = GlareDbParser::parse_sql(test_case)
+                .unwrap()
+                .pop_front()
+                .unwrap();
+            assert_eq!(test_case, stmt.to_string());
+        }
+
+        GlareDbParser::parse_sql("BACKUP TABLE t TO '/tmp/backup'").unwrap_err();
+        GlareDbParser::parse_sql("BACKUP DATABASE TO backup").unwrap_err();
+    }
+
     #[test]
     fn copy_to_roundtrips() {
         let test_cases = [
@@ -1429,6 +2328,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn table_version_parse() {
+        let test_cases = [
+            (
+                "SELECT * FROM t AS OF VERSION 12",
+                "SELECT * FROM t FOR SYSTEM_TIME AS OF 12",
+            ),
+            (
+                "SELECT * FROM s.t AS OF TIMESTAMP '2024-01-01 00:00:00' AS a",
+                "SELECT * FROM s.t FOR SYSTEM_TIME AS OF TIMESTAMP '2024-01-01 00:00:00' AS a",
+            ),
+            (
+                "SELECT * FROM t1 JOIN t2 as of version 3 ON t1.a = t2.a",
+                "SELECT * FROM t1 JOIN t2 FOR SYSTEM_TIME AS OF 3 ON t1.a = t2.a",
+            ),
+            (
+                "COPY (SELECT * FROM t AS OF VERSION 1) TO 's3://bucket'",
+                "COPY (SELECT * FROM t FOR SYSTEM_TIME AS OF 1) TO 's3://bucket'",
+            ),
+            (
+                "SELECT * FROM t FOR SYSTEM_TIME AS OF 12",
+                "SELECT * FROM t FOR SYSTEM_TIME AS OF 12",
+            ),
+        ];
+
+        for (sql, expected) in test_cases {
+            let stmt = GlareDbParser::parse_sql(sql).unwrap().pop_front().unwrap();
+            assert_eq!(expected, stmt.to_string().as_str());
+        }
+
+        // Not a table reference, or a table being written to.
+        let test_cases = [
+            "SELECT a AS OF VERSION 1 FROM t",
+            "INSERT INTO t AS OF VERSION 1 VALUES (1)",
+            "INSERT INTO t AS OF VERSION 1 SELECT * FROM t2",
+            "CREATE VIEW v AS OF VERSION 1 AS SELECT 1",
+            "CREATE TABLE t AS OF VERSION 1 (a INT)",
+            "CREATE EXTERNAL TABLE t AS OF VERSION 1 FROM local OPTIONS (location = 'f')",
+            "DROP TABLE t AS OF VERSION 1",
+            "UPDATE t AS OF VERSION 1 SET a = 1",
+            "DELETE FROM t AS OF VERSION 1",
+            "SELECT * FROM t; SELECT a AS OF VERSION 1 FROM t",
+        ];
+        for sql in test_cases {
+            let err = GlareDbParser::parse_sql(sql).unwrap_err();
+            assert!(
+                err.to_string().contains("AS OF is only supported"),
+                "sql: {sql}, error: {err}"
+            );
+        }
+
+        // Tables read by statements writing to other tables.
+        let stmt = GlareDbParser::parse_sql("INSERT INTO t SELECT * FROM t2 AS OF VERSION 1")
+            .unwrap()
+            .pop_front()
+            .unwrap();
+        assert_eq!(
+            "INSERT INTO t SELECT * FROM t2 FOR SYSTEM_TIME AS OF 1",
+            stmt.to_string()
+        );
+    }
+
     #[test]
     fn options_parse() {
         let mut options = BTreeMap::new();
@@ -1539,6 +2500,7 @@ mod tests {
             let t = Tokenizer::new(&d, sql).tokenize().unwrap();
             let mut p = GlareDbParser {
                 parser: Parser::new(&d).with_tokens(t),
+                table_versions: Vec::new(),
             };
             let opts = p.parse_options().unwrap();
             let expected_opts = StatementOptions::new(map);
@@ -1,4 +1,5 @@
 use crate::errors::{PgSrvError, Result};
+use crate::scram::{md5_stored_password, ScramSecret};
 
 #[derive(Debug, Clone, Copy)]
 pub enum PasswordMode {
@@ -7,6 +8,17 @@ pub enum PasswordMode {
     /// Should error if no password is provided.
     RequireCleartext,
 
+    /// An MD5 hashed password is required.
+    ///
+    /// Only usable for users with a cleartext or MD5 stored password.
+    RequireMd5,
+
+    /// SASL authentication using SCRAM-SHA-256 is required.
+    ///
+    /// SCRAM-SHA-256-PLUS (channel binding) is offered as well if the
+    /// connection is encrypted.
+    RequireScramSha256,
+
     /// No password is required.
     NoPassword {
         /// Drop any authentication messages as well.
@@ -19,10 +31,57 @@ pub enum PasswordMode {
     },
 }
 
+/// A password as stored by an authenticator.
+///
+/// Challenge-response password modes (MD5 and SCRAM) never receive the
+/// cleartext password from the client, and need this to verify the response.
+#[derive(Debug, Clone)]
+pub enum StoredPassword {
+    /// Password stored in cleartext. Usable with any password mode.
+    Cleartext(String),
+    /// Password stored as "md5" followed by md5(password + user).
+    Md5(String),
+    /// Password stored as a SCRAM-SHA-256 secret.
+    ScramSha256(ScramSecret),
+}
+
+impl StoredPassword {
+    /// Get the MD5 hash for this password.
+    ///
+    /// Returns `None` if the password is stored as a SCRAM secret since the
+    /// MD5 hash can't be derived from it.
+    pub fn md5(&self, user: &str) -> Option<String> {
+        match self {
+            Self::Cleartext(password) => Some(md5_stored_password(user, password)),
+            Self::Md5(hash) => Some(hash.clone()),
+            Self::ScramSha256(_) => None,
+        }
+    }
+
+    /// Get the SCRAM-SHA-256 secret for this password.
+    ///
+    /// Returns `None` if the password is stored as an MD5 hash.
+    pub fn scram_sha_256(&self) -> Option<ScramSecret> {
+        match self {
+            Self::Cleartext(password) => Some(ScramSecret::new(password)),
+            Self::Md5(_) => None,
+            Self::ScramSha256(secret) => Some(secret.clone()),
+        }
+    }
+}
+
 /// Authenticate connection on the glaredb node itself.
 pub trait LocalAuthenticator: Sync + Send {
     fn password_mode(&self) -> PasswordMode;
+
+    /// Authenticate using a cleartext password.
     fn authenticate(&self, user: &str, password: &str, db_name: &str) -> Result<()>;
+
+    /// Get the stored password for a user.
+    ///
+    /// Used for the MD5 and SCRAM-SHA-256 password modes. Should return
+    /// `InvalidUserOrPassword` if the user doesn't exist.
+    fn stored_password(&self, user: &str, db_name: &str) -> Result<StoredPassword>;
 }
 impl<B> LocalAuthenticator for Box<B>
 where
@@ -35,6 +94,10 @@ where
     fn authenticate(&self, user: &str, password: &str, db_name: &str) -> Result<()> {
         (**self).authenticate(user, password, db_name)
     }
+
+    fn stored_password(&self, user: &str, db_name: &str) -> Result<StoredPassword> {
+        (**self).stored_password(user, db_name)
+    }
 }
 
 /// A simple single user authenticator.
@@ -42,11 +105,13 @@ where
 pub struct SingleUserAuthenticator {
     pub user: String,
     pub password: String,
+    /// How the password should be sent by the client.
+    pub password_mode: PasswordMode,
 }
 
 impl LocalAuthenticator for SingleUserAuthenticator {
     fn password_mode(&self) -> PasswordMode {
-        PasswordMode::RequireCleartext
+        self.password_mode
     }
 
     fn authenticate(&self, user: &str, password: &str, _db_name: &str) -> Result<()> {
@@ -59,6 +124,13 @@ impl LocalAuthenticator for SingleUserAuthenticator {
         }
         Ok(())
     }
+
+    fn stored_password(&self, user: &str, _db_name: &str) -> Result<StoredPassword> {
+        if user != self.user {
+            return Err(PgSrvError::InvalidUserOrPassword);
+        }
+        Ok(StoredPassword::Cleartext(self.password.clone()))
+    }
 }
 
 /// Require no password provided.
@@ -77,4 +149,64 @@ impl LocalAuthenticator for PasswordlessAuthenticator {
     fn authenticate(&self, _user: &str, _password: &str, _db_name: &str) -> Result<()> {
         Ok(())
     }
+
+    fn stored_password(&self, _user: &str, _db_name: &str) -> Result<StoredPassword> {
+        Err(PgSrvError::Internal(
+            "passwordless authenticator does not store passwords".to_string(),
+        ))
+    }
+}
+
+/// Authenticator for a single role stored in the catalog.
+///
+/// Catalog passwords are stored as SCRAM-SHA-256 secrets, so MD5 can't be
+/// used. Roles without a password can only log in if the server doesn't
+/// require one.
+#[derive(Debug, Clone)]
+pub struct RoleAuthenticator {
+    pub user: String,
+    pub secret: Option<ScramSecret>,
+    pub password_mode: PasswordMode,
+}
+
+impl RoleAuthenticator {
+    /// Create an authenticator for a role, using the server's password mode
+    /// as a base.
+    pub fn try_new(
+        user: impl Into<String>,
+        password: Option<&str>,
+        server_mode: PasswordMode,
+    ) -> Result<Self> {
+        let secret = password.map(ScramSecret::parse).transpose()?;
+        let password_mode = match (&secret, server_mode) {
+            (None, mode @ PasswordMode::NoPassword { .. }) => mode,
+            (_, PasswordMode::RequireCleartext) => PasswordMode::RequireCleartext,
+            _ => PasswordMode::RequireScramSha256,
+        };
+        Ok(RoleAuthenticator {
+            user: user.into(),
+            secret,
+            password_mode,
+        })
+    }
+}
+
+impl LocalAuthenticator for RoleAuthenticator {
+    fn password_mode(&self) -> PasswordMode {
+        self.password_mode
+    }
+
+    fn authenticate(&self, user: &str, password: &str, _db_name: &str) -> Result<()> {
+        match &self.secret {
+            Some(secret) if user == self.user && secret.verify_password(password) => Ok(()),
+            _ => Err(PgSrvError::InvalidUserOrPassword),
+        }
+    }
+
+    fn stored_password(&self, user: &str, _db_name: &str) -> Result<StoredPassword> {
+        match &self.secret {
+            Some(secret) if user == self.user => Ok(StoredPassword::ScramSha256(secret.clone())),
+            _ => Err(PgSrvError::InvalidUserOrPassword),
+        }
+    }
 }
@@ -42,7 +42,6 @@ where
     }
 
     /// Sends a single frontend message to the underlying connection.
-    #[allow(dead_code)]
     pub async fn send(&mut self, msg: FrontendMessage) -> Result<()> {
         trace!(?msg, "sending message");
         self.conn.send(msg).await
@@ -59,12 +58,36 @@ where
 pub struct PgClientCodec;
 
 impl PgClientCodec {
-    fn decode_authentication(buf: &mut Cursor<'_>) -> Result<BackendMessage> {
+    fn decode_authentication(buf: &mut Cursor<'_>, msg_len: i32) -> Result<BackendMessage> {
+        if buf.remaining() < std::mem::size_of::<i32>() {
+            return Err(PgSrvError::InvalidMsgLength(msg_len));
+        }
         let auth_type = buf.get_i32();
 
         match auth_type {
             0 => Ok(BackendMessage::AuthenticationOk),
             3 => Ok(BackendMessage::AuthenticationCleartextPassword),
+            5 => {
+                let mut salt = [0; 4];
+                if buf.remaining() < salt.len() {
+                    return Err(PgSrvError::InvalidMsgLength(msg_len));
+                }
+                buf.copy_to_slice(&mut salt);
+                Ok(BackendMessage::AuthenticationMD5Password { salt })
+            }
+            10 => {
+                let mut mechanisms = Vec::new();
+                while buf.remaining() > 0 && !buf.peek_next_is_null() {
+                    mechanisms.push(buf.read_cstring()?.to_string());
+                }
+                Ok(BackendMessage::AuthenticationSASL { mechanisms })
+            }
+            11 => Ok(BackendMessage::AuthenticationSASLContinue {
+                data: buf.chunk().to_vec(),
+            }),
+            12 => Ok(BackendMessage::AuthenticationSASLFinal {
+                data: buf.chunk().to_vec(),
+            }),
             _ => unimplemented!("auth type {}", auth_type),
         }
     }
@@ -117,7 +140,9 @@ impl Encoder<FrontendMessage> for PgClientCodec {
 
     fn encode(&mut self, item: FrontendMessage, dst: &mut BytesMut) -> Result<(), Self::Error> {
         let byte = match &item {
-            FrontendMessage::PasswordMessage { .. } => b'p',
+            FrontendMessage::PasswordMessage { .. }
+            | FrontendMessage::SASLInitialResponse { .. }
+            | FrontendMessage::SASLResponse { .. } => b'p',
             other => unimplemented!("encode<FrontendMessage>::{:?}", other),
         };
         dst.put_u8(byte);
@@ -130,6 +155,19 @@ impl Encoder<FrontendMessage> for PgClientCodec {
             FrontendMessage::PasswordMessage { password } => {
                 dst.put_cstring(&password);
             }
+            FrontendMessage::SASLInitialResponse { mechanism, data } => {
+                dst.put_cstring(&mechanism);
+                match data {
+                    Some(data) => {
+                        dst.put_i32(data.len() as i32);
+                        dst.put_slice(&data);
+                    }
+                    None => dst.put_i32(-1),
+                }
+            }
+            FrontendMessage::SASLResponse { data } => {
+                dst.put_slice(&data);
+            }
             other => unimplemented!("encode<FrontendMessage>::{:?}", other),
         }
 
@@ -167,10 +205,48 @@ impl Decoder for PgClientCodec {
         buf.advance(5);
 
         let msg = match msg_type {
-            b'R' => Self::decode_authentication(&mut buf)?,
+            b'R' => Self::decode_authentication(&mut buf, msg_len as i32)?,
             other => return Err(PgSrvError::InvalidMsgType(other)),
         };
 
         Ok(Some(msg))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authentication(auth_type: i32, data: &[u8]) -> BytesMut {
+        let mut msg = BytesMut::new();
+        msg.put_u8(b'R');
+        msg.put_i32(data.len() as i32 + 8);
+        msg.put_i32(auth_type);
+        msg.put_slice(data);
+        msg
+    }
+
+    #[test]
+    fn decode_md5_password() {
+        let mut buf = authentication(5, &[1, 2, 3, 4]);
+        match PgClientCodec.decode(&mut buf).unwrap().unwrap() {
+            BackendMessage::AuthenticationMD5Password { salt } => assert_eq!([1, 2, 3, 4], salt),
+            other => panic!("unexpected message: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decode_truncated_authentication() {
+        // Salt is missing a byte.
+        let mut buf = authentication(5, &[1, 2, 3]);
+        let err = PgClientCodec.decode(&mut buf).unwrap_err();
+        assert!(matches!(err, PgSrvError::InvalidMsgLength(11)), "{err}");
+
+        // Auth type is missing.
+        let mut buf = BytesMut::new();
+        buf.put_u8(b'R');
+        buf.put_i32(4);
+        let err = PgClientCodec.decode(&mut buf).unwrap_err();
+        assert!(matches!(err, PgSrvError::InvalidMsgLength(4)), "{err}");
+    }
+}
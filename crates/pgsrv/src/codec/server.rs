@@ -16,6 +16,7 @@ use crate::errors::{PgSrvError, Result};
 use crate::messages::{
     BackendMessage,
     FrontendMessage,
+    PasswordMessageKind,
     StartupMessage,
     TransactionStatus,
     VERSION_CANCEL,
@@ -102,16 +103,31 @@ where
     pub fn set_encoding_state(&mut self, s: Vec<(PgType, Format)>) {
         self.conn.get_mut().codec_mut().encoding_state = s;
     }
+
+    /// Sets how the next password messages should be decoded.
+    ///
+    /// Any already peeked message will have been decoded with the previous
+    /// kind.
+    pub fn set_password_message_kind(&mut self, kind: PasswordMessageKind) {
+        self.conn.get_mut().codec_mut().password_message_kind = kind;
+    }
+
+    /// Check if the underlying connection is encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        matches!(self.conn.get_ref().get_ref(), Connection::Encrypted(_))
+    }
 }
 
 pub struct PgCodec {
     encoding_state: Vec<(PgType, Format)>,
+    password_message_kind: PasswordMessageKind,
 }
 
 impl PgCodec {
     fn new() -> Self {
         Self {
             encoding_state: Vec::new(),
+            password_message_kind: PasswordMessageKind::default(),
         }
     }
 
@@ -166,10 +182,36 @@ impl PgCodec {
         })
     }
 
-    fn decode_password(buf: &mut Cursor<'_>) -> Result<FrontendMessage> {
-        Ok(FrontendMessage::PasswordMessage {
-            password: buf.read_cstring()?.to_string(),
-        })
+    fn decode_password(&self, buf: &mut Cursor<'_>) -> Result<FrontendMessage> {
+        match self.password_message_kind {
+            PasswordMessageKind::Password => Ok(FrontendMessage::PasswordMessage {
+                password: buf.read_cstring()?.to_string(),
+            }),
+            PasswordMessageKind::SASLInitialResponse => {
+                let mechanism = buf.read_cstring()?.to_string();
+                if buf.remaining() < size_of::<i32>() {
+                    return Err(PgSrvError::InvalidMsgLength(buf.remaining() as i32));
+                }
+                let len = buf.get_i32();
+                let data = if len == -1 {
+                    None
+                } else {
+                    // The client controls this length, make sure it fits in
+                    // what's left of the message.
+                    let data_len = usize::try_from(len)
+                        .ok()
+                        .filter(|data_len| *data_len <= buf.remaining())
+                        .ok_or(PgSrvError::InvalidMsgLength(len))?;
+                    let mut data = vec![0; data_len];
+                    buf.copy_to_slice(&mut data);
+                    Some(data)
+                };
+                Ok(FrontendMessage::SASLInitialResponse { mechanism, data })
+            }
+            PasswordMessageKind::SASLResponse => Ok(FrontendMessage::SASLResponse {
+                data: buf.chunk().to_vec(),
+            }),
+        }
     }
 
     fn decode_parse(buf: &mut Cursor<'_>) -> Result<FrontendMessage> {
@@ -274,6 +316,10 @@ impl Encoder<BackendMessage> for PgCodec {
         let byte = match &item {
             BackendMessage::AuthenticationOk => b'R',
             BackendMessage::AuthenticationCleartextPassword => b'R',
+            BackendMessage::AuthenticationMD5Password { .. } => b'R',
+            BackendMessage::AuthenticationSASL { .. } => b'R',
+            BackendMessage::AuthenticationSASLContinue { .. } => b'R',
+            BackendMessage::AuthenticationSASLFinal { .. } => b'R',
             BackendMessage::EmptyQueryResponse => b'I',
             BackendMessage::ParameterStatus { .. } => b'S',
             BackendMessage::ReadyForQuery(_) => b'Z',
@@ -297,6 +343,26 @@ impl Encoder<BackendMessage> for PgCodec {
         match item {
             BackendMessage::AuthenticationOk => dst.put_i32(0),
             BackendMessage::AuthenticationCleartextPassword => dst.put_i32(3),
+            BackendMessage::AuthenticationMD5Password { salt } => {
+                dst.put_i32(5);
+                dst.put_slice(&salt);
+            }
+            BackendMessage::AuthenticationSASL { mechanisms } => {
+                dst.put_i32(10);
+                for mechanism in mechanisms {
+                    dst.put_cstring(&mechanism);
+                }
+                // Terminate list.
+                dst.put_u8(0);
+            }
+            BackendMessage::AuthenticationSASLContinue { data } => {
+                dst.put_i32(11);
+                dst.put_slice(&data);
+            }
+            BackendMessage::AuthenticationSASLFinal { data } => {
+                dst.put_i32(12);
+                dst.put_slice(&data);
+            }
             BackendMessage::EmptyQueryResponse => (),
             BackendMessage::ParseComplete => (),
             BackendMessage::BindComplete => (),
@@ -423,7 +489,7 @@ impl Decoder for PgCodec {
 
         let msg = match msg_type {
             b'Q' => Self::decode_query(&mut buf)?,
-            b'p' => Self::decode_password(&mut buf)?,
+            b'p' => self.decode_password(&mut buf)?,
             b'P' => Self::decode_parse(&mut buf)?,
             b'B' => Self::decode_bind(&mut buf)?,
             b'D' => Self::decode_describe(&mut buf)?,
@@ -438,3 +504,57 @@ impl Decoder for PgCodec {
         Ok(Some(msg))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sasl_initial_response(len: i32, data: &[u8]) -> BytesMut {
+        let mut body = BytesMut::new();
+        body.put_cstring("SCRAM-SHA-256");
+        body.put_i32(len);
+        body.put_slice(data);
+
+        let mut msg = BytesMut::new();
+        msg.put_u8(b'p');
+        msg.put_i32(body.len() as i32 + 4);
+        msg.put_slice(&body);
+        msg
+    }
+
+    #[test]
+    fn decode_sasl_initial_response() {
+        let mut codec = PgCodec::new();
+        codec.password_message_kind = PasswordMessageKind::SASLInitialResponse;
+
+        let mut buf = sasl_initial_response(3, b"abc");
+        match codec.decode(&mut buf).unwrap().unwrap() {
+            FrontendMessage::SASLInitialResponse { mechanism, data } => {
+                assert_eq!("SCRAM-SHA-256", mechanism);
+                assert_eq!(Some(b"abc".to_vec()), data);
+            }
+            other => panic!("unexpected message: {other:?}"),
+        }
+
+        let mut buf = sasl_initial_response(-1, &[]);
+        match codec.decode(&mut buf).unwrap().unwrap() {
+            FrontendMessage::SASLInitialResponse { data, .. } => assert_eq!(None, data),
+            other => panic!("unexpected message: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decode_sasl_initial_response_invalid_length() {
+        let mut codec = PgCodec::new();
+        codec.password_message_kind = PasswordMessageKind::SASLInitialResponse;
+
+        for len in [4, -2, i32::MAX] {
+            let mut buf = sasl_initial_response(len, b"abc");
+            let err = codec.decode(&mut buf).unwrap_err();
+            assert!(
+                matches!(err, PgSrvError::InvalidMsgLength(l) if l == len),
+                "len: {len}, err: {err}"
+            );
+        }
+    }
+}
@@ -42,6 +42,15 @@ pub enum PgSrvError {
     #[error("Invalid user or password")]
     InvalidUserOrPassword,
 
+    #[error("SCRAM authentication failed: {0}")]
+    ScramAuth(&'static str),
+
+    #[error("Invalid SCRAM secret")]
+    InvalidScramSecret,
+
+    #[error("Role '{0}' is not permitted to log in")]
+    RoleCannotLogin(String),
+
     /// A stringified error from cloud.
     #[error("cloud: {0}")]
     CloudResponse(String),
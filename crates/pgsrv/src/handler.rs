@@ -16,10 +16,10 @@ use sqlexec::engine::{Engine, SessionStorageConfig};
 use sqlexec::session::{ExecutionResult, Session};
 use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 use tokio_postgres::types::Type as PgType;
-use tracing::{debug, debug_span, warn, Instrument};
+use tracing::{debug, debug_span, info_span, warn, Instrument};
 use uuid::Uuid;
 
-use crate::auth::{LocalAuthenticator, PasswordMode};
+use crate::auth::{LocalAuthenticator, PasswordMode, RoleAuthenticator};
 use crate::codec::server::{FramedConn, PgCodec};
 use crate::errors::{PgSrvError, Result};
 use crate::messages::{
@@ -28,6 +28,7 @@ use crate::messages::{
     ErrorResponse,
     FieldDescriptionBuilder,
     FrontendMessage,
+    PasswordMessageKind,
     StartupMessage,
     TransactionStatus,
 };
@@ -41,6 +42,7 @@ use crate::proxy::{
     GLAREDB_MEMORY_LIMIT_BYTES_KEY,
     GLAREDB_USER_ID_KEY,
 };
+use crate::scram::{self, ScramServer};
 use crate::ssl::{Connection, SslConfig};
 
 pub struct ProtocolHandlerConfig {
@@ -186,71 +188,52 @@ impl ProtocolHandler {
             db_id
         };
 
-        // Handle password.
-        match self.conf.authenticator.password_mode() {
-            PasswordMode::RequireCleartext => {
+        // Users created in the catalog are authenticated against their
+        // stored password. Everyone else goes through the configured
+        // authenticator.
+        let role = match self.engine.resolve_role(db_id, &user_name).await {
+            Ok(role) => role,
+            Err(e) => {
                 framed
-                    .send(BackendMessage::AuthenticationCleartextPassword)
+                    .send(
+                        ErrorResponse::fatal_internal(format!("failed to look up role: {}", e))
+                            .into(),
+                    )
                     .await?;
-                let msg = framed.read().await?;
-                match msg {
-                    Some(FrontendMessage::PasswordMessage { password }) => {
-                        match self.conf.authenticator.authenticate(
-                            &user_name,
-                            &password,
-                            &database_name,
-                        ) {
-                            Ok(sess) => sess,
-                            Err(e) => {
-                                framed
-                                    .send(
-                                        ErrorResponse::fatal_internal(format!(
-                                            "Failed to authenticate: {}",
-                                            e
-                                        ))
-                                        .into(),
-                                    )
-                                    .await?;
-                                return Err(e);
-                            }
-                        }
-                        framed.send(BackendMessage::AuthenticationOk).await?;
-                    }
-                    Some(other) => {
-                        // TODO: Send error.
-                        return Err(PgSrvError::UnexpectedFrontendMessage(Box::new(other)));
-                    }
-                    None => return Ok(()),
-                }
+                return Err(e.into());
             }
-            PasswordMode::NoPassword { drop_auth_messages } => {
-                if drop_auth_messages {
-                    // Send the message to frontend to ask for an auth message.
-                    // We will drop this message later on.
-                    framed
-                        .send(BackendMessage::AuthenticationCleartextPassword)
-                        .await?;
-
-                    // Read the auth message from the frontend. This will be
-                    // ignored.
-                    let msg = framed.peek().await?;
-                    match msg {
-                        Some(msg) if msg.is_auth_message() => {
-                            let dropped = framed.read().await?; // Drop auth message.
-                            warn!(?dropped, "dropping authentication message");
-                        }
-                        Some(_msg) => (), // We peeked a message not related to auth.
-                        None => return Ok(()), // Connection closed
-                    }
-                }
-
-                // Nothin to do.
-                framed.send(BackendMessage::AuthenticationOk).await?;
+        };
+        let role_authenticator = match role {
+            Some(role) if !role.login => {
+                return Err(
+                    send_auth_error(&mut framed, PgSrvError::RoleCannotLogin(user_name)).await,
+                );
             }
+            Some(role) => Some(RoleAuthenticator::try_new(
+                role.meta.name,
+                role.password.as_deref(),
+                self.conf.authenticator.password_mode(),
+            )?),
+            None => None,
+        };
+        let authenticator: &dyn LocalAuthenticator = match &role_authenticator {
+            Some(auth) => auth,
+            None => &self.conf.authenticator,
+        };
+
+        // Handle password.
+        if !self
+            .authenticate(&mut framed, authenticator, &user_name, &database_name)
+            .await?
+        {
+            // Connection closed.
+            return Ok(());
         }
+
         let mut vars = SessionVars::default()
             .with_user_id(user_id, VarType::System)
             .with_user_name(user_name, VarType::System)
+            .with_is_catalog_role(role_authenticator.is_some(), VarType::System)
             .with_connection_id(conn_id, VarType::System)
             .with_database_id(db_id, VarType::System)
             .with_database_name(database_name, VarType::System)
@@ -311,6 +294,206 @@ impl ProtocolHandler {
         cs.run().await
     }
 
+    /// Run through the authentication flow according to the authenticator's
+    /// password mode, sending `AuthenticationOk` on success.
+    ///
+    /// Returns `false` if the client disconnected before completing
+    /// authentication.
+    async fn authenticate<C>(
+        &self,
+        framed: &mut FramedConn<C>,
+        authenticator: &dyn LocalAuthenticator,
+        user_name: &str,
+        database_name: &str,
+    ) -> Result<bool>
+    where
+        C: AsyncRead + AsyncWrite + Unpin,
+    {
+        let authenticated = match authenticator.password_mode() {
+            PasswordMode::RequireCleartext => {
+                framed
+                    .send(BackendMessage::AuthenticationCleartextPassword)
+                    .await?;
+                let msg = framed.read().await?;
+                match msg {
+                    Some(FrontendMessage::PasswordMessage { password }) => {
+                        if let Err(e) =
+                            authenticator.authenticate(user_name, &password, database_name)
+                        {
+                            return Err(send_auth_error(framed, e).await);
+                        }
+                        true
+                    }
+                    Some(other) => {
+                        // TODO: Send error.
+                        return Err(PgSrvError::UnexpectedFrontendMessage(Box::new(other)));
+                    }
+                    None => false,
+                }
+            }
+            PasswordMode::RequireMd5 => {
+                self.authenticate_md5(framed, authenticator, user_name, database_name)
+                    .await?
+            }
+            PasswordMode::RequireScramSha256 => {
+                self.authenticate_scram(framed, authenticator, user_name, database_name)
+                    .await?
+            }
+            PasswordMode::NoPassword { drop_auth_messages } => {
+                if drop_auth_messages {
+                    // Send the message to frontend to ask for an auth message.
+                    // We will drop this message later on.
+                    framed
+                        .send(BackendMessage::AuthenticationCleartextPassword)
+                        .await?;
+
+                    // Read the auth message from the frontend. This will be
+                    // ignored.
+                    let msg = framed.peek().await?;
+                    match msg {
+                        Some(msg) if msg.is_auth_message() => {
+                            let dropped = framed.read().await?; // Drop auth message.
+                            warn!(?dropped, "dropping authentication message");
+                        }
+                        Some(_msg) => (), // We peeked a message not related to auth.
+                        None => return Ok(false), // Connection closed
+                    }
+                }
+
+                // Nothin to do.
+                true
+            }
+        };
+
+        if authenticated {
+            framed.send(BackendMessage::AuthenticationOk).await?;
+        }
+
+        Ok(authenticated)
+    }
+
+    /// Authenticate using an MD5 hashed password.
+    async fn authenticate_md5<C>(
+        &self,
+        framed: &mut FramedConn<C>,
+        authenticator: &dyn LocalAuthenticator,
+        user_name: &str,
+        database_name: &str,
+    ) -> Result<bool>
+    where
+        C: AsyncRead + AsyncWrite + Unpin,
+    {
+        let salt = scram::md5_salt();
+        framed
+            .send(BackendMessage::AuthenticationMD5Password { salt })
+            .await?;
+
+        let password = match framed.read().await? {
+            Some(FrontendMessage::PasswordMessage { password }) => password,
+            Some(other) => return Err(PgSrvError::UnexpectedFrontendMessage(Box::new(other))),
+            None => return Ok(false),
+        };
+
+        // Unknown users and wrong passwords should look the same to the
+        // client.
+        let result = authenticator
+            .stored_password(user_name, database_name)
+            .and_then(|stored| {
+                let hash = stored.md5(user_name).ok_or_else(|| {
+                    // Reported as a generic failure so clients can't use
+                    // this to probe which users exist.
+                    warn!(%user_name, "stored password can't be used for md5 authentication");
+                    PgSrvError::InvalidUserOrPassword
+                })?;
+                if scram::md5_verify(&hash, &salt, &password) {
+                    Ok(())
+                } else {
+                    Err(PgSrvError::InvalidUserOrPassword)
+                }
+            });
+        if let Err(e) = result {
+            return Err(send_auth_error(framed, e).await);
+        }
+
+        Ok(true)
+    }
+
+    /// Authenticate using SCRAM-SHA-256, optionally with channel binding if
+    /// the connection is encrypted.
+    async fn authenticate_scram<C>(
+        &self,
+        framed: &mut FramedConn<C>,
+        authenticator: &dyn LocalAuthenticator,
+        user_name: &str,
+        database_name: &str,
+    ) -> Result<bool>
+    where
+        C: AsyncRead + AsyncWrite + Unpin,
+    {
+        let channel_binding = match (&self.conf.ssl_conf, framed.is_encrypted()) {
+            (Some(conf), true) => Some(conf.channel_binding.clone()),
+            _ => None,
+        };
+
+        // Continue with a mock exchange if the user doesn't exist so that we
+        // don't leak which users exist.
+        let stored = authenticator.stored_password(user_name, database_name);
+        let mut exchange = match stored.as_ref().ok().and_then(|s| s.scram_sha_256()) {
+            Some(secret) => ScramServer::new(secret, channel_binding),
+            None => {
+                if let Err(e) = &stored {
+                    debug!(%e, "unable to get stored password, using mock exchange");
+                }
+                ScramServer::new_mock(channel_binding)
+            }
+        };
+
+        framed
+            .send(BackendMessage::AuthenticationSASL {
+                mechanisms: exchange.mechanisms(),
+            })
+            .await?;
+
+        framed.set_password_message_kind(PasswordMessageKind::SASLInitialResponse);
+        let (mechanism, data) = match framed.read().await? {
+            Some(FrontendMessage::SASLInitialResponse { mechanism, data }) => {
+                (mechanism, data.unwrap_or_default())
+            }
+            Some(other) => return Err(PgSrvError::UnexpectedFrontendMessage(Box::new(other))),
+            None => return Ok(false),
+        };
+
+        let server_first = match exchange.handle_client_first(&mechanism, &data) {
+            Ok(msg) => msg,
+            Err(e) => return Err(send_auth_error(framed, e).await),
+        };
+        framed
+            .send(BackendMessage::AuthenticationSASLContinue {
+                data: server_first.into_bytes(),
+            })
+            .await?;
+
+        framed.set_password_message_kind(PasswordMessageKind::SASLResponse);
+        let data = match framed.read().await? {
+            Some(FrontendMessage::SASLResponse { data }) => data,
+            Some(other) => return Err(PgSrvError::UnexpectedFrontendMessage(Box::new(other))),
+            None => return Ok(false),
+        };
+        framed.set_password_message_kind(PasswordMessageKind::Password);
+
+        let server_final = match exchange.handle_client_final(&data) {
+            Ok(msg) => msg,
+            Err(e) => return Err(send_auth_error(framed, e).await),
+        };
+        framed
+            .send(BackendMessage::AuthenticationSASLFinal {
+                data: server_final.into_bytes(),
+            })
+            .await?;
+
+        Ok(true)
+    }
+
     /// Cancel a connection.
     ///
     /// Unimplemented. The protocol states that there's no guarantee that
@@ -324,6 +507,19 @@ impl ProtocolHandler {
     }
 }
 
+/// Send a fatal error to the client for a failed authentication attempt,
+/// returning the error that caused the failure.
+async fn send_auth_error<C>(framed: &mut FramedConn<C>, e: PgSrvError) -> PgSrvError
+where
+    C: AsyncRead + AsyncWrite + Unpin,
+{
+    let resp = ErrorResponse::fatal_internal(format!("Failed to authenticate: {}", e));
+    if let Err(send_err) = framed.send(resp.into()).await {
+        return send_err;
+    }
+    e
+}
+
 struct ClientSession<C, S> {
     conn: FramedConn<C>,
     session: S,
@@ -760,6 +956,10 @@ where
             ExecutionResult::DropCredentials => {
                 Self::command_complete(conn, "DROP CREDENTIALS").await?
             }
+            ExecutionResult::CreateRole => Self::command_complete(conn, "CREATE ROLE").await?,
+            ExecutionResult::DropRoles => Self::command_complete(conn, "DROP ROLE").await?,
+            ExecutionResult::Grant => Self::command_complete(conn, "GRANT").await?,
+            ExecutionResult::Revoke => Self::command_complete(conn, "REVOKE").await?,
         };
         Ok(())
     }
@@ -824,6 +1024,7 @@ fn parse_sql(
     session_vars: SessionVars,
     sql: &str,
 ) -> Result<VecDeque<StatementWithExtensions>, ErrorResponse> {
+    let _span = info_span!("parse").entered();
     match session_vars.dialect() {
         Dialect::Prql => parser::parse_prql(sql),
         Dialect::Sql => parser::parse_sql(sql),
@@ -38,6 +38,17 @@ pub enum FrontendMessage {
     Query { sql: String },
     /// An encrypted or unencrypted password.
     PasswordMessage { password: String },
+    /// The initial response in a SASL exchange, includes the selected
+    /// mechanism.
+    ///
+    /// Shares a message type with `PasswordMessage`, the codec needs to be told
+    /// which one to expect.
+    SASLInitialResponse {
+        mechanism: String,
+        data: Option<Vec<u8>>,
+    },
+    /// A subsequent response in a SASL exchange.
+    SASLResponse { data: Vec<u8> },
     /// An extended query parse message.
     Parse {
         /// The name of the prepared statement. An empty string denotes the
@@ -111,6 +122,8 @@ impl FrontendMessage {
         match self {
             FrontendMessage::Query { .. } => "query",
             FrontendMessage::PasswordMessage { .. } => "password",
+            FrontendMessage::SASLInitialResponse { .. } => "sasl_initial_response",
+            FrontendMessage::SASLResponse { .. } => "sasl_response",
             FrontendMessage::Parse { .. } => "parse",
             FrontendMessage::Bind { .. } => "bind",
             FrontendMessage::Describe { .. } => "describe",
@@ -123,10 +136,28 @@ impl FrontendMessage {
     }
 
     pub(crate) fn is_auth_message(&self) -> bool {
-        matches!(self, FrontendMessage::PasswordMessage { .. })
+        matches!(
+            self,
+            FrontendMessage::PasswordMessage { .. }
+                | FrontendMessage::SASLInitialResponse { .. }
+                | FrontendMessage::SASLResponse { .. }
+        )
     }
 }
 
+/// How the codec should decode a password message ('p').
+///
+/// The frontend uses the same message type for passwords and SASL responses,
+/// which one is being sent depends on the authentication request the backend
+/// sent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PasswordMessageKind {
+    #[default]
+    Password,
+    SASLInitialResponse,
+    SASLResponse,
+}
+
 #[derive(Debug)]
 pub enum TransactionStatus {
     Idle,
@@ -140,6 +171,10 @@ pub enum BackendMessage {
     NoticeResponse(pgrepr::notice::Notice),
     AuthenticationOk,
     AuthenticationCleartextPassword,
+    AuthenticationMD5Password { salt: [u8; 4] },
+    AuthenticationSASL { mechanisms: Vec<String> },
+    AuthenticationSASLContinue { data: Vec<u8> },
+    AuthenticationSASLFinal { data: Vec<u8> },
     ParameterStatus { key: String, val: String },
     EmptyQueryResponse,
     ReadyForQuery(TransactionStatus),
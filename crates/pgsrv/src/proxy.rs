@@ -11,6 +11,7 @@ use crate::codec::client::FramedClientConn;
 use crate::codec::server::{FramedConn, PgCodec};
 use crate::errors::{PgSrvError, Result};
 use crate::messages::{BackendMessage, ErrorResponse, FrontendMessage, StartupMessage, VERSION_V3};
+use crate::scram::{self, ScramClient};
 use crate::ssl::{Connection, SslConfig};
 
 /// Constant id for a database if running locally.
@@ -228,6 +229,12 @@ impl<A: ProxyAuthenticator> ProxyHandler<A> {
             None => return Ok(()), // Not an error, client disconnected.
         };
 
+        // Keep the password around in case the database requires it as well.
+        let password = match &msg {
+            FrontendMessage::PasswordMessage { password } => Some(password.clone()),
+            _ => None,
+        };
+
         // If we fail to auth, ensure an error response is sent to the
         // connection.
         let db_details = match self.authenticate_with_msg(msg, hostname, &params).await {
@@ -276,33 +283,40 @@ impl<A: ProxyAuthenticator> ProxyHandler<A> {
 
         // More params should be inserted here. See <https://github.com/GlareDB/glaredb/issues/600>
 
+        let user = params.get("user").cloned().unwrap_or_default();
         let startup = StartupMessage::StartupRequest {
             version: VERSION_V3,
             params,
         };
         db_framed.send_startup(startup).await?;
 
-        // The glaredb node should be configured to accept any user and
-        // password. Authentication already happened with Cloud, and we're just
-        // proxying now.
-        let auth_msg = db_framed.read().await?;
-        match auth_msg {
-            Some(BackendMessage::AuthenticationOk) => {
-                framed.send(BackendMessage::AuthenticationOk).await?;
-                // from here, we can just forward messages between the client to the database
-                let server_conn = db_framed.into_inner();
-                let client_conn = framed.into_inner();
-                tokio::io::copy_bidirectional(
-                    &mut client_conn.into_inner(),
-                    &mut server_conn.into_inner(),
-                )
-                .await?;
-
-                Ok(())
+        // The glaredb node is typically configured to accept any user and
+        // password since authentication already happened with Cloud. If it
+        // does require a password, answer using the password the client sent
+        // us.
+        let authenticated = match authenticate_with_db(&mut db_framed, &user, password).await {
+            Ok(authenticated) => authenticated,
+            Err(e) => {
+                framed
+                    .send(ErrorResponse::fatal_internal(format!("database auth: {}", e)).into())
+                    .await?;
+                return Err(e);
             }
-            Some(other) => Err(PgSrvError::UnexpectedBackendMessage(other)),
-            None => Ok(()),
+        };
+
+        if authenticated {
+            framed.send(BackendMessage::AuthenticationOk).await?;
+            // from here, we can just forward messages between the client to the database
+            let server_conn = db_framed.into_inner();
+            let client_conn = framed.into_inner();
+            tokio::io::copy_bidirectional(
+                &mut client_conn.into_inner(),
+                &mut server_conn.into_inner(),
+            )
+            .await?;
         }
+
+        Ok(())
     }
 
     /// Proxy a cancel request.
@@ -363,6 +377,87 @@ impl<A: ProxyAuthenticator> ProxyHandler<A> {
     }
 }
 
+/// Complete the authentication flow with a database, responding to whatever
+/// password request the database sends.
+///
+/// Returns `false` if the database closed the connection.
+async fn authenticate_with_db<C>(
+    db_framed: &mut FramedClientConn<C>,
+    user: &str,
+    password: Option<String>,
+) -> Result<bool>
+where
+    C: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut scram: Option<ScramClient> = None;
+
+    loop {
+        let msg = match db_framed.read().await? {
+            Some(msg) => msg,
+            None => return Ok(false),
+        };
+
+        let get_password = || {
+            password
+                .clone()
+                .ok_or(PgSrvError::MissingStartupParameter("password"))
+        };
+
+        match msg {
+            BackendMessage::AuthenticationOk => return Ok(true),
+            BackendMessage::AuthenticationCleartextPassword => {
+                db_framed
+                    .send(FrontendMessage::PasswordMessage {
+                        password: get_password()?,
+                    })
+                    .await?;
+            }
+            BackendMessage::AuthenticationMD5Password { salt } => {
+                let stored = scram::md5_stored_password(user, &get_password()?);
+                db_framed
+                    .send(FrontendMessage::PasswordMessage {
+                        password: scram::md5_salted_password(&stored, &salt),
+                    })
+                    .await?;
+            }
+            BackendMessage::AuthenticationSASL { mechanisms } => {
+                // Connection to the database is unencrypted, no channel
+                // binding.
+                if !mechanisms.iter().any(|m| m == scram::SCRAM_SHA_256) {
+                    return Err(PgSrvError::ScramAuth("no supported SASL mechanism"));
+                }
+                let mut client = ScramClient::new(get_password()?);
+                let client_first = client.client_first()?;
+                db_framed
+                    .send(FrontendMessage::SASLInitialResponse {
+                        mechanism: scram::SCRAM_SHA_256.to_string(),
+                        data: Some(client_first.into_bytes()),
+                    })
+                    .await?;
+                scram = Some(client);
+            }
+            BackendMessage::AuthenticationSASLContinue { data } => {
+                let client = scram
+                    .as_mut()
+                    .ok_or(PgSrvError::ScramAuth("unexpected SASL continue"))?;
+                let client_final = client.handle_server_first(&data)?;
+                db_framed
+                    .send(FrontendMessage::SASLResponse {
+                        data: client_final.into_bytes(),
+                    })
+                    .await?;
+            }
+            BackendMessage::AuthenticationSASLFinal { data } => {
+                let client = scram
+                    .as_mut()
+                    .ok_or(PgSrvError::ScramAuth("unexpected SASL final"))?;
+                client.handle_server_final(&data)?;
+            }
+            other => return Err(PgSrvError::UnexpectedBackendMessage(other)),
+        }
+    }
+}
+
 /// Get an org identifier (either id or name) and the db_name.
 ///
 /// 1. First try to get the org id from startup options parameter.
@@ -0,0 +1,762 @@
+//! SCRAM-SHA-256 and MD5 password authentication.
+//!
+//! See:
+//! - <https://www.postgresql.org/docs/current/sasl-authentication.html>
+//! - <https://datatracker.ietf.org/doc/html/rfc5802>
+//! - <https://datatracker.ietf.org/doc/html/rfc7677>
+//!
+//! Passwords are not normalized with SASLprep. This matches what Postgres does
+//! when a password isn't valid UTF-8, and makes no difference for ASCII
+//! passwords.
+use std::fmt;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use md5::Md5;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+use crate::errors::{PgSrvError, Result};
+
+/// SCRAM mechanism name without channel binding.
+pub const SCRAM_SHA_256: &str = "SCRAM-SHA-256";
+
+/// SCRAM mechanism name with channel binding.
+pub const SCRAM_SHA_256_PLUS: &str = "SCRAM-SHA-256-PLUS";
+
+/// The only channel binding type we support.
+const CHANNEL_BINDING_TYPE: &str = "tls-server-end-point";
+
+/// Iteration count used when generating new secrets. Matches the Postgres
+/// default.
+pub const DEFAULT_ITERATIONS: u32 = 4096;
+
+/// Length in bytes of generated salts.
+const SALT_LEN: usize = 16;
+
+/// Length in bytes of the random part of generated nonces (before encoding).
+const NONCE_LEN: usize = 18;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// A SCRAM-SHA-256 secret derived from a password.
+///
+/// Serializes to and from the same format Postgres uses in `pg_authid`:
+/// `SCRAM-SHA-256$<iterations>:<salt>$<StoredKey>:<ServerKey>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScramSecret {
+    pub iterations: u32,
+    pub salt: Vec<u8>,
+    pub stored_key: [u8; 32],
+    pub server_key: [u8; 32],
+}
+
+impl ScramSecret {
+    /// Create a new secret for a password using a random salt.
+    pub fn new(password: &str) -> Self {
+        let salt: [u8; SALT_LEN] = rand::thread_rng().gen();
+        Self::with_salt(password, &salt, DEFAULT_ITERATIONS)
+    }
+
+    /// Create a secret for a password using the provided salt and iteration
+    /// count.
+    pub fn with_salt(password: &str, salt: &[u8], iterations: u32) -> Self {
+        let salted = salted_password(password, salt, iterations);
+        let client_key = hmac(&salted, b"Client Key");
+        ScramSecret {
+            iterations,
+            salt: salt.to_vec(),
+            stored_key: Sha256::digest(client_key).into(),
+            server_key: hmac(&salted, b"Server Key"),
+        }
+    }
+
+    /// Create a secret that no password will match.
+    ///
+    /// Used to run through a full exchange for users that don't exist so that
+    /// the client can't tell the difference between an unknown user and a
+    /// wrong password.
+    pub fn mock() -> Self {
+        let mut rng = rand::thread_rng();
+        let salt: [u8; SALT_LEN] = rng.gen();
+        ScramSecret {
+            iterations: DEFAULT_ITERATIONS,
+            salt: salt.to_vec(),
+            stored_key: rng.gen(),
+            server_key: rng.gen(),
+        }
+    }
+
+    /// Parse a secret in the Postgres format.
+    pub fn parse(s: &str) -> Result<Self> {
+        fn parse_inner(s: &str) -> Option<ScramSecret> {
+            let s = s.strip_prefix(SCRAM_SHA_256)?.strip_prefix('$')?;
+            let (params, keys) = s.split_once('$')?;
+            let (iterations, salt) = params.split_once(':')?;
+            let (stored_key, server_key) = keys.split_once(':')?;
+
+            Some(ScramSecret {
+                iterations: iterations.parse().ok()?,
+                salt: BASE64.decode(salt).ok()?,
+                stored_key: BASE64.decode(stored_key).ok()?.try_into().ok()?,
+                server_key: BASE64.decode(server_key).ok()?.try_into().ok()?,
+            })
+        }
+
+        parse_inner(s).ok_or(PgSrvError::InvalidScramSecret)
+    }
+
+    /// Check if a cleartext password matches this secret.
+    pub fn verify_password(&self, password: &str) -> bool {
+        let other = Self::with_salt(password, &self.salt, self.iterations);
+        constant_time_eq(&self.stored_key, &other.stored_key)
+            && constant_time_eq(&self.server_key, &other.server_key)
+    }
+}
+
+impl fmt::Display for ScramSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{SCRAM_SHA_256}${}:{}${}:{}",
+            self.iterations,
+            BASE64.encode(&self.salt),
+            BASE64.encode(self.stored_key),
+            BASE64.encode(self.server_key),
+        )
+    }
+}
+
+#[derive(Debug)]
+enum ServerState {
+    /// Waiting on the client-first-message.
+    Initial,
+    /// Sent the server-first-message, waiting on the client-final-message.
+    SentServerFirst {
+        client_first_bare: String,
+        server_first: String,
+        /// The gs2 header the client sent. Needs to be echoed back (base64
+        /// encoded) along with any channel binding data in the client final
+        /// message.
+        gs2_header: String,
+        /// Combined client and server nonce.
+        nonce: String,
+        /// If the client selected the channel binding mechanism.
+        binding: bool,
+    },
+    Done,
+}
+
+/// Server side of a SCRAM-SHA-256 exchange.
+#[derive(Debug)]
+pub struct ScramServer {
+    secret: ScramSecret,
+    /// Whether the secret is a mock secret for an unknown user. The exchange
+    /// is always failed at the very end.
+    mock: bool,
+    /// The `tls-server-end-point` channel binding data for the connection.
+    /// `None` if the connection isn't encrypted.
+    channel_binding: Option<Vec<u8>>,
+    state: ServerState,
+}
+
+impl ScramServer {
+    pub fn new(secret: ScramSecret, channel_binding: Option<Vec<u8>>) -> Self {
+        ScramServer {
+            secret,
+            mock: false,
+            channel_binding,
+            state: ServerState::Initial,
+        }
+    }
+
+    /// Create an exchange for a user that doesn't exist (or doesn't have a
+    /// usable secret). The exchange will always fail.
+    pub fn new_mock(channel_binding: Option<Vec<u8>>) -> Self {
+        ScramServer {
+            mock: true,
+            ..Self::new(ScramSecret::mock(), channel_binding)
+        }
+    }
+
+    /// Mechanisms to advertise to the client, in order of preference.
+    pub fn mechanisms(&self) -> Vec<String> {
+        let mut mechs = Vec::with_capacity(2);
+        if self.channel_binding.is_some() {
+            mechs.push(SCRAM_SHA_256_PLUS.to_string());
+        }
+        mechs.push(SCRAM_SHA_256.to_string());
+        mechs
+    }
+
+    /// Handle the mechanism and client-first-message from the
+    /// SASLInitialResponse, returning the server-first-message.
+    pub fn handle_client_first(&mut self, mechanism: &str, msg: &[u8]) -> Result<String> {
+        self.handle_client_first_with_nonce(mechanism, msg, &generate_nonce())
+    }
+
+    fn handle_client_first_with_nonce(
+        &mut self,
+        mechanism: &str,
+        msg: &[u8],
+        server_nonce: &str,
+    ) -> Result<String> {
+        if !matches!(self.state, ServerState::Initial) {
+            return Err(PgSrvError::ScramAuth("unexpected client first message"));
+        }
+
+        let binding = match mechanism {
+            SCRAM_SHA_256 => false,
+            SCRAM_SHA_256_PLUS if self.channel_binding.is_some() => true,
+            _ => return Err(PgSrvError::ScramAuth("unsupported mechanism")),
+        };
+
+        let msg = std::str::from_utf8(msg)
+            .map_err(|_| PgSrvError::ScramAuth("client first message not valid utf8"))?;
+
+        // gs2-header = gs2-cbind-flag "," [ authzid ] ","
+        let (cbind_flag, rest) = msg
+            .split_once(',')
+            .ok_or(PgSrvError::ScramAuth("malformed client first message"))?;
+        let (authzid, client_first_bare) = rest
+            .split_once(',')
+            .ok_or(PgSrvError::ScramAuth("malformed client first message"))?;
+        if !authzid.is_empty() {
+            return Err(PgSrvError::ScramAuth(
+                "authorization identity not supported",
+            ));
+        }
+
+        match cbind_flag {
+            // Client doesn't support channel binding.
+            "n" if !binding => (),
+            // Client supports channel binding but thinks the server doesn't.
+            // If we advertised channel binding, this indicates the mechanism
+            // list was tampered with.
+            "y" if !binding && self.channel_binding.is_none() => (),
+            "y" if !binding => {
+                return Err(PgSrvError::ScramAuth(
+                    "client supports channel binding but it was not selected",
+                ))
+            }
+            flag if binding && flag == format!("p={CHANNEL_BINDING_TYPE}") => (),
+            _ => return Err(PgSrvError::ScramAuth("invalid channel binding flag")),
+        }
+
+        // client-first-message-bare = [reserved-mext ","] username "," nonce ["," extensions]
+        //
+        // The username is ignored, the user from the startup message is used
+        // instead (same as Postgres).
+        let mut attrs = client_first_bare.split(',');
+        let client_nonce = match (attrs.next(), attrs.next()) {
+            (Some(user), Some(nonce)) if user.starts_with("n=") => nonce
+                .strip_prefix("r=")
+                .ok_or(PgSrvError::ScramAuth("missing client nonce"))?,
+            _ => return Err(PgSrvError::ScramAuth("malformed client first message")),
+        };
+        if client_nonce.is_empty() || !client_nonce.chars().all(is_printable) {
+            return Err(PgSrvError::ScramAuth("invalid client nonce"));
+        }
+
+        let nonce = format!("{client_nonce}{server_nonce}");
+        let server_first = format!(
+            "r={nonce},s={},i={}",
+            BASE64.encode(&self.secret.salt),
+            self.secret.iterations
+        );
+
+        self.state = ServerState::SentServerFirst {
+            client_first_bare: client_first_bare.to_string(),
+            server_first: server_first.clone(),
+            gs2_header: format!("{cbind_flag},,"),
+            nonce,
+            binding,
+        };
+
+        Ok(server_first)
+    }
+
+    /// Handle the client-final-message from the SASLResponse, returning the
+    /// server-final-message.
+    ///
+    /// Errors if the client proof doesn't match.
+    pub fn handle_client_final(&mut self, msg: &[u8]) -> Result<String> {
+        let state = std::mem::replace(&mut self.state, ServerState::Done);
+        let (client_first_bare, server_first, gs2_header, nonce, binding) = match state {
+            ServerState::SentServerFirst {
+                client_first_bare,
+                server_first,
+                gs2_header,
+                nonce,
+                binding,
+            } => (client_first_bare, server_first, gs2_header, nonce, binding),
+            _ => return Err(PgSrvError::ScramAuth("unexpected client final message")),
+        };
+
+        let msg = std::str::from_utf8(msg)
+            .map_err(|_| PgSrvError::ScramAuth("client final message not valid utf8"))?;
+
+        // client-final-message = channel-binding "," nonce ["," extensions] "," proof
+        let (without_proof, proof) = msg
+            .rsplit_once(",p=")
+            .ok_or(PgSrvError::ScramAuth("missing client proof"))?;
+        let mut attrs = without_proof.split(',');
+        let (cbind, client_nonce) = match (attrs.next(), attrs.next()) {
+            (Some(cbind), Some(nonce)) => (
+                cbind
+                    .strip_prefix("c=")
+                    .ok_or(PgSrvError::ScramAuth("missing channel binding"))?,
+                nonce
+                    .strip_prefix("r=")
+                    .ok_or(PgSrvError::ScramAuth("missing nonce"))?,
+            ),
+            _ => return Err(PgSrvError::ScramAuth("malformed client final message")),
+        };
+
+        // Check channel binding.
+        let cbind = BASE64
+            .decode(cbind)
+            .map_err(|_| PgSrvError::ScramAuth("invalid channel binding encoding"))?;
+        let mut expected_cbind = gs2_header.into_bytes();
+        if binding {
+            // Checked when receiving the client first message.
+            let data = self.channel_binding.as_ref().expect("channel binding data");
+            expected_cbind.extend_from_slice(data);
+        }
+        if cbind != expected_cbind {
+            return Err(PgSrvError::ScramAuth("channel binding mismatch"));
+        }
+
+        if client_nonce != nonce {
+            return Err(PgSrvError::ScramAuth("nonce mismatch"));
+        }
+
+        let proof = BASE64
+            .decode(proof)
+            .map_err(|_| PgSrvError::ScramAuth("invalid proof encoding"))?;
+        if proof.len() != 32 {
+            return Err(PgSrvError::ScramAuth("invalid proof length"));
+        }
+
+        let auth_message = format!("{client_first_bare},{server_first},{without_proof}");
+
+        // ClientKey = ClientProof XOR HMAC(StoredKey, AuthMessage)
+        let client_signature = hmac(&self.secret.stored_key, auth_message.as_bytes());
+        let client_key: Vec<u8> = proof
+            .iter()
+            .zip(client_signature.iter())
+            .map(|(a, b)| a ^ b)
+            .collect();
+        let computed_stored_key = Sha256::digest(client_key);
+
+        if self.mock || !constant_time_eq(&computed_stored_key, &self.secret.stored_key) {
+            return Err(PgSrvError::InvalidUserOrPassword);
+        }
+
+        let server_signature = hmac(&self.secret.server_key, auth_message.as_bytes());
+        Ok(format!("v={}", BASE64.encode(server_signature)))
+    }
+}
+
+#[derive(Debug)]
+enum ClientState {
+    Initial,
+    SentClientFirst {
+        client_first_bare: String,
+        client_nonce: String,
+    },
+    SentClientFinal {
+        server_signature: [u8; 32],
+    },
+    Done,
+}
+
+/// Client side of a SCRAM-SHA-256 exchange.
+///
+/// Used when proxying to a GlareDB node configured to require SCRAM. Channel
+/// binding isn't supported as the connection between the proxy and the node is
+/// unencrypted.
+#[derive(Debug)]
+pub struct ScramClient {
+    password: String,
+    state: ClientState,
+}
+
+impl ScramClient {
+    pub fn new(password: impl Into<String>) -> Self {
+        ScramClient {
+            password: password.into(),
+            state: ClientState::Initial,
+        }
+    }
+
+    /// Get the client-first-message to send in the SASLInitialResponse.
+    pub fn client_first(&mut self) -> Result<String> {
+        self.client_first_with_nonce(&generate_nonce())
+    }
+
+    fn client_first_with_nonce(&mut self, nonce: &str) -> Result<String> {
+        if !matches!(self.state, ClientState::Initial) {
+            return Err(PgSrvError::ScramAuth("client first message already sent"));
+        }
+        // Username is left empty, the server uses the one from the startup
+        // message.
+        let client_first_bare = format!("n=,r={nonce}");
+        let msg = format!("n,,{client_first_bare}");
+        self.state = ClientState::SentClientFirst {
+            client_first_bare,
+            client_nonce: nonce.to_string(),
+        };
+        Ok(msg)
+    }
+
+    /// Handle the server-first-message from AuthenticationSASLContinue,
+    /// returning the client-final-message.
+    pub fn handle_server_first(&mut self, msg: &[u8]) -> Result<String> {
+        let state = std::mem::replace(&mut self.state, ClientState::Done);
+        let (client_first_bare, client_nonce) = match state {
+            ClientState::SentClientFirst {
+                client_first_bare,
+                client_nonce,
+            } => (client_first_bare, client_nonce),
+            _ => return Err(PgSrvError::ScramAuth("unexpected server first message")),
+        };
+
+        let server_first = std::str::from_utf8(msg)
+            .map_err(|_| PgSrvError::ScramAuth("server first message not valid utf8"))?;
+
+        let mut attrs = server_first.split(',');
+        let (nonce, salt, iterations) = match (attrs.next(), attrs.next(), attrs.next()) {
+            (Some(nonce), Some(salt), Some(iterations)) => (
+                nonce
+                    .strip_prefix("r=")
+                    .ok_or(PgSrvError::ScramAuth("missing server nonce"))?,
+                salt.strip_prefix("s=")
+                    .ok_or(PgSrvError::ScramAuth("missing salt"))?,
+                iterations
+                    .strip_prefix("i=")
+                    .ok_or(PgSrvError::ScramAuth("missing iteration count"))?,
+            ),
+            _ => return Err(PgSrvError::ScramAuth("malformed server first message")),
+        };
+
+        if !nonce.starts_with(&client_nonce) || nonce.len() == client_nonce.len() {
+            return Err(PgSrvError::ScramAuth("invalid server nonce"));
+        }
+        let salt = BASE64
+            .decode(salt)
+            .map_err(|_| PgSrvError::ScramAuth("invalid salt encoding"))?;
+        let iterations: u32 = iterations
+            .parse()
+            .map_err(|_| PgSrvError::ScramAuth("invalid iteration count"))?;
+
+        let salted = salted_password(&self.password, &salt, iterations);
+        let client_key = hmac(&salted, b"Client Key");
+        let stored_key = Sha256::digest(client_key);
+        let server_key = hmac(&salted, b"Server Key");
+
+        // "biws" is base64 for the "n,," gs2 header.
+        let without_proof = format!("c=biws,r={nonce}");
+        let auth_message = format!("{client_first_bare},{server_first},{without_proof}");
+
+        let client_signature = hmac(&stored_key, auth_message.as_bytes());
+        let proof: Vec<u8> = client_key
+            .iter()
+            .zip(client_signature.iter())
+            .map(|(a, b)| a ^ b)
+            .collect();
+
+        self.state = ClientState::SentClientFinal {
+            server_signature: hmac(&server_key, auth_message.as_bytes()),
+        };
+
+        Ok(format!("{without_proof},p={}", BASE64.encode(proof)))
+    }
+
+    /// Verify the server-final-message from AuthenticationSASLFinal.
+    pub fn handle_server_final(&mut self, msg: &[u8]) -> Result<()> {
+        let state = std::mem::replace(&mut self.state, ClientState::Done);
+        let server_signature = match state {
+            ClientState::SentClientFinal { server_signature } => server_signature,
+            _ => return Err(PgSrvError::ScramAuth("unexpected server final message")),
+        };
+
+        let msg = std::str::from_utf8(msg)
+            .map_err(|_| PgSrvError::ScramAuth("server final message not valid utf8"))?;
+        if msg.starts_with("e=") {
+            return Err(PgSrvError::InvalidUserOrPassword);
+        }
+        let verifier = msg
+            .strip_prefix("v=")
+            .ok_or(PgSrvError::ScramAuth("missing server signature"))?;
+        let verifier = BASE64
+            .decode(verifier)
+            .map_err(|_| PgSrvError::ScramAuth("invalid server signature encoding"))?;
+
+        if !constant_time_eq(&verifier, &server_signature) {
+            return Err(PgSrvError::ScramAuth("server signature mismatch"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Compute the hash of a password as stored by Postgres for MD5
+/// authentication: "md5" followed by the hex encoded md5 of the password
+/// concatenated with the user name.
+pub fn md5_stored_password(user: &str, password: &str) -> String {
+    format!("md5{}", md5_hex(&[password.as_bytes(), user.as_bytes()]))
+}
+
+/// Compute the response to an MD5 authentication request from a stored
+/// password hash (as returned by `md5_stored_password`) and the salt sent by
+/// the server.
+pub fn md5_salted_password(stored: &str, salt: &[u8; 4]) -> String {
+    let stored = stored.strip_prefix("md5").unwrap_or(stored);
+    format!("md5{}", md5_hex(&[stored.as_bytes(), salt]))
+}
+
+/// Check if the response from an MD5 authentication exchange matches.
+pub fn md5_verify(stored: &str, salt: &[u8; 4], response: &str) -> bool {
+    let expected = md5_salted_password(stored, salt);
+    constant_time_eq(expected.as_bytes(), response.as_bytes())
+}
+
+/// Generate a random salt for MD5 authentication.
+pub fn md5_salt() -> [u8; 4] {
+    rand::thread_rng().gen()
+}
+
+/// Compute the `tls-server-end-point` channel binding data for a DER encoded
+/// certificate.
+///
+/// RFC 5929 specifies using the hash function from the certificate's
+/// signature algorithm, with MD5 and SHA-1 upgraded to SHA-256. We always use
+/// SHA-256 which covers the common case of certificates signed with
+/// SHA-256.
+pub fn tls_server_end_point(cert: &[u8]) -> Vec<u8> {
+    Sha256::digest(cert).to_vec()
+}
+
+fn md5_hex(parts: &[&[u8]]) -> String {
+    let mut hasher = Md5::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+fn salted_password(password: &str, salt: &[u8], iterations: u32) -> [u8; 32] {
+    let mut out = [0; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), salt, iterations, &mut out);
+    out
+}
+
+fn hmac(key: &[u8], msg: &[u8]) -> [u8; 32] {
+    let mut mac = HmacSha256::new_from_slice(key).expect("hmac accepts keys of any length");
+    mac.update(msg);
+    mac.finalize().into_bytes().into()
+}
+
+fn generate_nonce() -> String {
+    let bytes: [u8; NONCE_LEN] = rand::thread_rng().gen();
+    BASE64.encode(bytes)
+}
+
+/// Printable characters excluding ',' (RFC 5802).
+fn is_printable(c: char) -> bool {
+    ('\x21'..='\x7e').contains(&c) && c != ','
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vector from RFC 7677.
+    const PASSWORD: &str = "pencil";
+    const CLIENT_NONCE: &str = "rOprNGfwEbeRWgbNEkqO";
+    const SERVER_NONCE: &str = "%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0";
+    const SALT: &str = "W22ZaJ0SNY7soEsUEjb6gQ==";
+    const CLIENT_FIRST: &str = "n,,n=user,r=rOprNGfwEbeRWgbNEkqO";
+    const SERVER_FIRST: &str =
+        "r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,s=W22ZaJ0SNY7soEsUEjb6gQ==,i=4096";
+    const CLIENT_FINAL: &str = "c=biws,r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,p=dHzbZapWIk4jUhN+Ute9ytag9zjfMHgsqmmiz7AndVQ=";
+    const SERVER_FINAL: &str = "v=6rriTRBi23WpRR/wtup+mMhUZUn/dB5nLTJRsjl95G4=";
+
+    fn rfc_secret() -> ScramSecret {
+        ScramSecret::with_salt(PASSWORD, &BASE64.decode(SALT).unwrap(), 4096)
+    }
+
+    #[test]
+    fn server_rfc7677_exchange() {
+        let mut server = ScramServer::new(rfc_secret(), None);
+
+        let server_first = server
+            .handle_client_first_with_nonce(SCRAM_SHA_256, CLIENT_FIRST.as_bytes(), SERVER_NONCE)
+            .unwrap();
+        assert_eq!(SERVER_FIRST, server_first);
+
+        let server_final = server.handle_client_final(CLIENT_FINAL.as_bytes()).unwrap();
+        assert_eq!(SERVER_FINAL, server_final);
+    }
+
+    #[test]
+    fn server_wrong_password() {
+        let secret = ScramSecret::with_salt("wrong", &BASE64.decode(SALT).unwrap(), 4096);
+        let mut server = ScramServer::new(secret, None);
+
+        server
+            .handle_client_first_with_nonce(SCRAM_SHA_256, CLIENT_FIRST.as_bytes(), SERVER_NONCE)
+            .unwrap();
+        let err = server
+            .handle_client_final(CLIENT_FINAL.as_bytes())
+            .unwrap_err();
+        assert!(matches!(err, PgSrvError::InvalidUserOrPassword), "{err}");
+    }
+
+    #[test]
+    fn server_mock_always_fails() {
+        let mut server = ScramServer::new_mock(None);
+        let mut client = ScramClient::new(PASSWORD);
+
+        let client_first = client.client_first().unwrap();
+        let server_first = server
+            .handle_client_first(SCRAM_SHA_256, client_first.as_bytes())
+            .unwrap();
+        let client_final = client.handle_server_first(server_first.as_bytes()).unwrap();
+        let err = server
+            .handle_client_final(client_final.as_bytes())
+            .unwrap_err();
+        assert!(matches!(err, PgSrvError::InvalidUserOrPassword), "{err}");
+    }
+
+    #[test]
+    fn client_rfc7677_exchange() {
+        let mut client = ScramClient::new(PASSWORD);
+
+        // We don't send the user name.
+        let client_first = client.client_first_with_nonce(CLIENT_NONCE).unwrap();
+        assert_eq!("n,,n=,r=rOprNGfwEbeRWgbNEkqO", client_first);
+
+        // Server responds with a nonce built off of our nonce. Note that the
+        // proof differs from the RFC since the client first message is
+        // different.
+        let client_final = client.handle_server_first(SERVER_FIRST.as_bytes()).unwrap();
+        assert!(client_final
+            .starts_with("c=biws,r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,p="));
+    }
+
+    #[test]
+    fn client_server_round_trip() {
+        let mut server = ScramServer::new(ScramSecret::new(PASSWORD), None);
+        let mut client = ScramClient::new(PASSWORD);
+
+        let client_first = client.client_first().unwrap();
+        let server_first = server
+            .handle_client_first(SCRAM_SHA_256, client_first.as_bytes())
+            .unwrap();
+        let client_final = client.handle_server_first(server_first.as_bytes()).unwrap();
+        let server_final = server.handle_client_final(client_final.as_bytes()).unwrap();
+        client.handle_server_final(server_final.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn channel_binding() {
+        let cbind_data = tls_server_end_point(b"certificate");
+        let mut server = ScramServer::new(rfc_secret(), Some(cbind_data.clone()));
+        assert_eq!(
+            vec![SCRAM_SHA_256_PLUS.to_string(), SCRAM_SHA_256.to_string()],
+            server.mechanisms()
+        );
+
+        let client_first = "p=tls-server-end-point,,n=,r=abcdef";
+        let server_first = server
+            .handle_client_first(SCRAM_SHA_256_PLUS, client_first.as_bytes())
+            .unwrap();
+
+        // Build the client final manually with the channel binding data.
+        let nonce = server_first
+            .split(',')
+            .next()
+            .unwrap()
+            .strip_prefix("r=")
+            .unwrap();
+        let mut cbind = b"p=tls-server-end-point,,".to_vec();
+        cbind.extend_from_slice(&cbind_data);
+        let without_proof = format!("c={},r={nonce}", BASE64.encode(&cbind));
+        let auth_message = format!("n=,r=abcdef,{server_first},{without_proof}");
+
+        let salted = salted_password(PASSWORD, &BASE64.decode(SALT).unwrap(), 4096);
+        let client_key = hmac(&salted, b"Client Key");
+        let client_signature = hmac(&Sha256::digest(client_key), auth_message.as_bytes());
+        let proof: Vec<u8> = client_key
+            .iter()
+            .zip(client_signature.iter())
+            .map(|(a, b)| a ^ b)
+            .collect();
+        let client_final = format!("{without_proof},p={}", BASE64.encode(proof));
+
+        server.handle_client_final(client_final.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn channel_binding_downgrade_rejected() {
+        let mut server = ScramServer::new(rfc_secret(), Some(vec![1, 2, 3]));
+        // Client claims it supports channel binding, but thinks the server
+        // doesn't.
+        server
+            .handle_client_first(SCRAM_SHA_256, b"y,,n=,r=abcdef")
+            .unwrap_err();
+    }
+
+    #[test]
+    fn channel_binding_unavailable() {
+        let mut server = ScramServer::new(rfc_secret(), None);
+        assert_eq!(vec![SCRAM_SHA_256.to_string()], server.mechanisms());
+        server
+            .handle_client_first(SCRAM_SHA_256_PLUS, b"p=tls-server-end-point,,n=,r=abcdef")
+            .unwrap_err();
+    }
+
+    #[test]
+    fn secret_round_trip() {
+        let secret = rfc_secret();
+        let s = secret.to_string();
+        assert!(s.starts_with("SCRAM-SHA-256$4096:W22ZaJ0SNY7soEsUEjb6gQ==$"));
+        assert_eq!(secret, ScramSecret::parse(&s).unwrap());
+
+        ScramSecret::parse("md5abc").unwrap_err();
+        ScramSecret::parse("SCRAM-SHA-256$4096:salt$bad").unwrap_err();
+    }
+
+    #[test]
+    fn secret_verify_password() {
+        let secret = rfc_secret();
+        assert!(secret.verify_password(PASSWORD));
+        assert!(!secret.verify_password("wrong"));
+    }
+
+    #[test]
+    fn md5_password() {
+        // Known value: md5("passwordpostgres")
+        let stored = md5_stored_password("postgres", "password");
+        assert_eq!("md532e12f215ba27cb750c9e093ce4b5127", stored);
+
+        let salt = [1, 2, 3, 4];
+        let response = md5_salted_password(&stored, &salt);
+        assert!(md5_verify(&stored, &salt, &response));
+        assert!(!md5_verify(&stored, &[4, 3, 2, 1], &response));
+    }
+}
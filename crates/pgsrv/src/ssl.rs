@@ -13,11 +13,15 @@ use tokio_rustls::TlsAcceptor;
 use tracing::debug;
 
 use crate::errors::{PgSrvError, Result};
+use crate::scram::tls_server_end_point;
 
 /// Configuration for creating encrypted connections using SSL/TLS.
 #[derive(Debug)]
 pub struct SslConfig {
     pub config: Arc<ServerConfig>,
+    /// Channel binding data (`tls-server-end-point`) for the server
+    /// certificate. Used for SCRAM-SHA-256-PLUS.
+    pub channel_binding: Vec<u8>,
 }
 
 impl SslConfig {
@@ -42,6 +46,11 @@ impl SslConfig {
             _ => return Err(PgSrvError::ReadCertsAndKeys("Expected exactly one key")),
         };
 
+        let channel_binding = match chain.first() {
+            Some(cert) => tls_server_end_point(cert),
+            None => return Err(PgSrvError::ReadCertsAndKeys("No certs found")),
+        };
+
         let resolver = CertResolver::new(chain, &key)?;
 
         static CRYPTO_PROVIDER: OnceCell<()> = OnceCell::new();
@@ -65,6 +74,7 @@ impl SslConfig {
 
         Ok(SslConfig {
             config: Arc::new(config),
+            channel_binding,
         })
     }
 }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::{self, Display};
 use std::str::FromStr;
 use std::sync::Arc;
@@ -10,6 +10,7 @@ use super::options::{
     CredentialsOptions,
     DatabaseOptions,
     InternalColumnDefinition,
+    SequenceOptions,
     TableOptionsV0,
     TunnelOptions,
 };
@@ -35,6 +36,11 @@ pub struct CatalogState {
     /// unlike the `version` field, this is only incremented when the physical representation of the catalog changes.
     /// it is associated with the "code" that represents the catalog.
     pub catalog_version: u32,
+    /// Native tables that were dropped but can still be undropped.
+    pub dropped_tables: Vec<DroppedTable>,
+    /// Comments on builtin objects, only set when the builtin objects
+    /// themselves aren't part of `entries`.
+    pub builtin_comments: HashMap<u32, String>,
 }
 
 impl TryFrom<catalog::CatalogState> for CatalogState {
@@ -60,6 +66,12 @@ impl TryFrom<catalog::CatalogState> for CatalogState {
             entries,
             deployment,
             catalog_version: value.catalog_version.unwrap_or(0),
+            dropped_tables: value
+                .dropped_tables
+                .into_iter()
+                .map(DroppedTable::try_from)
+                .collect::<Result<_, _>>()?,
+            builtin_comments: value.builtin_comments,
         })
     }
 }
@@ -79,13 +91,61 @@ impl TryFrom<CatalogState> for catalog::CatalogState {
                 .collect::<Result<_, _>>()?,
             deployment: Some(value.deployment.try_into()?),
             catalog_version: Some(value.catalog_version),
+            dropped_tables: value.dropped_tables.into_iter().map(Into::into).collect(),
+            builtin_comments: value.builtin_comments,
+        })
+    }
+}
+
+/// A native table that was dropped, but whose data is kept around for some
+/// time in case the drop needs to be undone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DroppedTable {
+    pub table: TableEntry,
+    /// Schema the table belonged to when it was dropped.
+    pub schema: String,
+    /// Milliseconds since the epoch.
+    pub dropped_at: i64,
+    /// Sequences owned by the table, restored along with it.
+    pub sequences: Vec<SequenceEntry>,
+    /// When the table may be purged (milliseconds since the epoch).
+    pub expires_at: i64,
+}
+
+impl TryFrom<catalog::DroppedTable> for DroppedTable {
+    type Error = ProtoConvError;
+    fn try_from(value: catalog::DroppedTable) -> Result<Self, Self::Error> {
+        Ok(DroppedTable {
+            table: value.table.required("table")?,
+            schema: value.schema,
+            dropped_at: value.dropped_at,
+            sequences: value
+                .sequences
+                .into_iter()
+                .map(SequenceEntry::try_from)
+                .collect::<Result<_, _>>()?,
+            expires_at: value.expires_at,
         })
     }
 }
 
+impl From<DroppedTable> for catalog::DroppedTable {
+    fn from(value: DroppedTable) -> Self {
+        catalog::DroppedTable {
+            table: Some(value.table.into()),
+            schema: value.schema,
+            dropped_at: value.dropped_at,
+            sequences: value.sequences.into_iter().map(Into::into).collect(),
+            expires_at: value.expires_at,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct DeploymentMetadata {
     pub storage_size: u64,
+    /// How many hours dropped native tables are kept around for.
+    pub dropped_table_retention_hours: u64,
 }
 
 impl TryFrom<catalog::DeploymentMetadata> for DeploymentMetadata {
@@ -93,6 +153,7 @@ impl TryFrom<catalog::DeploymentMetadata> for DeploymentMetadata {
     fn try_from(value: catalog::DeploymentMetadata) -> Result<Self, Self::Error> {
         Ok(Self {
             storage_size: value.storage_size,
+            dropped_table_retention_hours: value.dropped_table_retention_hours,
         })
     }
 }
@@ -103,6 +164,7 @@ impl TryFrom<DeploymentMetadata> for catalog::DeploymentMetadata {
     fn try_from(value: DeploymentMetadata) -> Result<Self, Self::Error> {
         Ok(Self {
             storage_size: value.storage_size,
+            dropped_table_retention_hours: value.dropped_table_retention_hours,
         })
     }
 }
@@ -118,6 +180,8 @@ pub enum CatalogEntry {
     Tunnel(TunnelEntry),
     Function(FunctionEntry),
     Credentials(CredentialsEntry),
+    Role(RoleEntry),
+    Sequence(SequenceEntry),
 }
 
 impl CatalogEntry {
@@ -130,6 +194,8 @@ impl CatalogEntry {
             CatalogEntry::Tunnel(_) => EntryType::Tunnel,
             CatalogEntry::Function(_) => EntryType::Function,
             CatalogEntry::Credentials(_) => EntryType::Credentials,
+            CatalogEntry::Role(_) => EntryType::Role,
+            CatalogEntry::Sequence(_) => EntryType::Sequence,
         }
     }
 
@@ -143,6 +209,8 @@ impl CatalogEntry {
             CatalogEntry::Tunnel(tunnel) => &tunnel.meta,
             CatalogEntry::Function(func) => &func.meta,
             CatalogEntry::Credentials(creds) => &creds.meta,
+            CatalogEntry::Role(role) => &role.meta,
+            CatalogEntry::Sequence(seq) => &seq.meta,
         }
     }
 
@@ -156,6 +224,8 @@ impl CatalogEntry {
             CatalogEntry::Tunnel(tunnel) => &mut tunnel.meta,
             CatalogEntry::Function(func) => &mut func.meta,
             CatalogEntry::Credentials(creds) => &mut creds.meta,
+            CatalogEntry::Role(role) => &mut role.meta,
+            CatalogEntry::Sequence(seq) => &mut seq.meta,
         }
     }
 }
@@ -173,6 +243,8 @@ impl TryFrom<catalog::catalog_entry::Entry> for CatalogEntry {
             catalog::catalog_entry::Entry::Credentials(v) => {
                 CatalogEntry::Credentials(v.try_into()?)
             }
+            catalog::catalog_entry::Entry::Role(v) => CatalogEntry::Role(v.try_into()?),
+            catalog::catalog_entry::Entry::Sequence(v) => CatalogEntry::Sequence(v.try_into()?),
         })
     }
 }
@@ -195,6 +267,8 @@ impl TryFrom<CatalogEntry> for catalog::CatalogEntry {
             CatalogEntry::Tunnel(v) => catalog::catalog_entry::Entry::Tunnel(v.into()),
             CatalogEntry::Function(v) => catalog::catalog_entry::Entry::Function(v.into()),
             CatalogEntry::Credentials(v) => catalog::catalog_entry::Entry::Credentials(v.into()),
+            CatalogEntry::Role(v) => catalog::catalog_entry::Entry::Role(v.into()),
+            CatalogEntry::Sequence(v) => catalog::catalog_entry::Entry::Sequence(v.into()),
         };
         Ok(catalog::CatalogEntry { entry: Some(ent) })
     }
@@ -209,6 +283,8 @@ pub enum EntryType {
     Tunnel,
     Function,
     Credentials,
+    Role,
+    Sequence,
 }
 
 impl EntryType {
@@ -221,6 +297,8 @@ impl EntryType {
             EntryType::Tunnel => "tunnel",
             EntryType::Function => "function",
             EntryType::Credentials => "credentials",
+            EntryType::Role => "role",
+            EntryType::Sequence => "sequence",
         }
     }
 }
@@ -248,6 +326,8 @@ impl TryFrom<catalog::entry_meta::EntryType> for EntryType {
             catalog::entry_meta::EntryType::Tunnel => EntryType::Tunnel,
             catalog::entry_meta::EntryType::Function => EntryType::Function,
             catalog::entry_meta::EntryType::Credentials => EntryType::Credentials,
+            catalog::entry_meta::EntryType::Role => EntryType::Role,
+            catalog::entry_meta::EntryType::Sequence => EntryType::Sequence,
         })
     }
 }
@@ -262,6 +342,8 @@ impl From<EntryType> for catalog::entry_meta::EntryType {
             EntryType::Tunnel => catalog::entry_meta::EntryType::Tunnel,
             EntryType::Function => catalog::entry_meta::EntryType::Function,
             EntryType::Credentials => catalog::entry_meta::EntryType::Credentials,
+            EntryType::Role => catalog::entry_meta::EntryType::Role,
+            EntryType::Sequence => catalog::entry_meta::EntryType::Sequence,
         }
     }
 }
@@ -286,6 +368,11 @@ pub struct EntryMeta {
     pub builtin: bool,
     pub external: bool,
     pub is_temp: bool,
+    /// Comment set with `COMMENT ON`.
+    pub comment: Option<String>,
+    /// OID of the role owning this entry. Zero if the entry isn't owned by a
+    /// role in the catalog.
+    pub owner: u32,
 }
 
 impl From<EntryMeta> for catalog::EntryMeta {
@@ -299,6 +386,8 @@ impl From<EntryMeta> for catalog::EntryMeta {
             builtin: value.builtin,
             external: value.external,
             is_temp: value.is_temp,
+            comment: value.comment,
+            owner: value.owner,
         }
     }
 }
@@ -314,6 +403,8 @@ impl TryFrom<catalog::EntryMeta> for EntryMeta {
             builtin: value.builtin,
             external: value.external,
             is_temp: value.is_temp,
+            comment: value.comment,
+            owner: value.owner,
         })
     }
 }
@@ -456,6 +547,10 @@ pub struct TableEntry {
     pub tunnel_id: Option<u32>,
     pub access_mode: SourceAccessMode,
     pub columns: Option<Vec<InternalColumnDefinition>>,
+    /// Statistics collected by `ANALYZE`, if the table has been analyzed.
+    pub statistics: Option<TableStatistics>,
+    /// Comments set with `COMMENT ON COLUMN`, keyed by column name.
+    pub column_comments: BTreeMap<String, String>,
 }
 
 impl TableEntry {
@@ -505,6 +600,8 @@ impl TryFrom<catalog::TableEntry> for TableEntry {
             tunnel_id: value.tunnel_id,
             access_mode: value.access_mode.try_into()?,
             columns,
+            statistics: value.statistics.map(Into::into),
+            column_comments: value.column_comments.into_iter().collect(),
         })
     }
 }
@@ -525,6 +622,74 @@ impl From<TableEntry> for catalog::TableEntry {
             access_mode: value.access_mode.into(),
             options: None,
             columns,
+            statistics: value.statistics.map(Into::into),
+            column_comments: value.column_comments.into_iter().collect(),
+        }
+    }
+}
+
+/// Table statistics collected by `ANALYZE`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableStatistics {
+    pub num_rows: u64,
+    /// Statistics for each column, in table column order.
+    pub columns: Vec<ColumnStatistics>,
+    /// When the statistics were collected, in milliseconds since the epoch.
+    pub analyzed_at: i64,
+}
+
+impl From<catalog::TableStatistics> for TableStatistics {
+    fn from(value: catalog::TableStatistics) -> Self {
+        TableStatistics {
+            num_rows: value.num_rows,
+            columns: value.columns.into_iter().map(Into::into).collect(),
+            analyzed_at: value.analyzed_at,
+        }
+    }
+}
+
+impl From<TableStatistics> for catalog::TableStatistics {
+    fn from(value: TableStatistics) -> Self {
+        catalog::TableStatistics {
+            num_rows: value.num_rows,
+            columns: value.columns.into_iter().map(Into::into).collect(),
+            analyzed_at: value.analyzed_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColumnStatistics {
+    pub name: String,
+    pub null_count: u64,
+    /// Min and max values, formatted as strings. These can be cast back to
+    /// the column's type.
+    pub min_value: Option<String>,
+    pub max_value: Option<String>,
+    /// Approximate number of distinct non-null values.
+    pub distinct_count: Option<u64>,
+}
+
+impl From<catalog::ColumnStatistics> for ColumnStatistics {
+    fn from(value: catalog::ColumnStatistics) -> Self {
+        ColumnStatistics {
+            name: value.name,
+            null_count: value.null_count,
+            min_value: value.min_value,
+            max_value: value.max_value,
+            distinct_count: value.distinct_count,
+        }
+    }
+}
+
+impl From<ColumnStatistics> for catalog::ColumnStatistics {
+    fn from(value: ColumnStatistics) -> Self {
+        catalog::ColumnStatistics {
+            name: value.name,
+            null_count: value.null_count,
+            min_value: value.min_value,
+            max_value: value.max_value,
+            distinct_count: value.distinct_count,
         }
     }
 }
@@ -889,6 +1054,275 @@ impl From<CredentialsEntry> for catalog::CredentialsEntry {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoleEntry {
+    pub meta: EntryMeta,
+    pub login: bool,
+    pub superuser: bool,
+    /// SCRAM-SHA-256 secret for the password, if one was set.
+    pub password: Option<String>,
+    /// OIDs of the roles this role is a member of.
+    pub member_of: Vec<u32>,
+    pub grants: Vec<PrivilegeGrant>,
+}
+
+impl RoleEntry {
+    /// Check if this role was directly granted a privilege on an object.
+    pub fn has_grant(&self, object_id: u32, privilege: Privilege) -> bool {
+        self.grants
+            .iter()
+            .any(|g| g.object_id == object_id && g.privilege == privilege)
+    }
+}
+
+impl TryFrom<catalog::RoleEntry> for RoleEntry {
+    type Error = ProtoConvError;
+    fn try_from(value: catalog::RoleEntry) -> Result<Self, Self::Error> {
+        let meta: EntryMeta = value.meta.required("meta")?;
+        Ok(RoleEntry {
+            meta,
+            login: value.login,
+            superuser: value.superuser,
+            password: value.password,
+            member_of: value.member_of,
+            grants: value
+                .grants
+                .into_iter()
+                .map(|g| g.try_into())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<RoleEntry> for catalog::RoleEntry {
+    fn from(value: RoleEntry) -> Self {
+        catalog::RoleEntry {
+            meta: Some(value.meta.into()),
+            login: value.login,
+            superuser: value.superuser,
+            password: value.password,
+            member_of: value.member_of,
+            grants: value.grants.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SequenceEntry {
+    pub meta: EntryMeta,
+    pub options: SequenceOptions,
+    /// Last value handed out, or the next value to hand out if `is_called`
+    /// is false.
+    pub last_value: i64,
+    pub is_called: bool,
+    /// Oid of the table whose identity column this sequence backs.
+    pub owned_by: Option<u32>,
+}
+
+impl SequenceEntry {
+    /// Hand out the next `count` values of the sequence.
+    ///
+    /// Returns `None` and leaves the sequence untouched if it would run past
+    /// its bounds without being allowed to cycle.
+    pub fn advance(&mut self, count: u64) -> Option<Vec<i64>> {
+        let SequenceOptions {
+            increment,
+            min_value,
+            max_value,
+            cycle,
+            ..
+        } = self.options;
+
+        let mut values = Vec::with_capacity(count as usize);
+        let mut last = self.last_value;
+        let mut is_called = self.is_called;
+        for _ in 0..count {
+            let next = if !is_called {
+                last
+            } else {
+                match last.checked_add(increment) {
+                    Some(v) if v >= min_value && v <= max_value => v,
+                    _ if cycle && increment > 0 => min_value,
+                    _ if cycle => max_value,
+                    _ => return None,
+                }
+            };
+            values.push(next);
+            last = next;
+            is_called = true;
+        }
+
+        self.last_value = last;
+        self.is_called = is_called;
+        Some(values)
+    }
+
+    /// Hand out at least `count` values of the sequence, and up to `block`
+    /// values if the sequence doesn't run past its bounds before then.
+    ///
+    /// Returns `None` and leaves the sequence untouched if `count` values
+    /// can't be handed out.
+    pub fn allocate(&mut self, count: u64, block: u64) -> Option<Vec<i64>> {
+        if block > count {
+            if let Some(values) = self.advance(block) {
+                return Some(values);
+            }
+        }
+        self.advance(count)
+    }
+
+    /// Set the current value of the sequence (`setval`).
+    ///
+    /// Returns false if the value is out of the sequence's bounds.
+    pub fn set_value(&mut self, value: i64, is_called: bool) -> bool {
+        if value < self.options.min_value || value > self.options.max_value {
+            return false;
+        }
+        self.last_value = value;
+        self.is_called = is_called;
+        true
+    }
+}
+
+impl TryFrom<catalog::SequenceEntry> for SequenceEntry {
+    type Error = ProtoConvError;
+    fn try_from(value: catalog::SequenceEntry) -> Result<Self, Self::Error> {
+        let meta: EntryMeta = value.meta.required("meta")?;
+        Ok(SequenceEntry {
+            meta,
+            options: value.options.required("options")?,
+            last_value: value.last_value,
+            is_called: value.is_called,
+            owned_by: value.owned_by,
+        })
+    }
+}
+
+impl From<SequenceEntry> for catalog::SequenceEntry {
+    fn from(value: SequenceEntry) -> Self {
+        catalog::SequenceEntry {
+            meta: Some(value.meta.into()),
+            options: Some(value.options.into()),
+            last_value: value.last_value,
+            is_called: value.is_called,
+            owned_by: value.owned_by,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrivilegeGrant {
+    pub object_id: u32,
+    pub privilege: Privilege,
+}
+
+impl TryFrom<catalog::PrivilegeGrant> for PrivilegeGrant {
+    type Error = ProtoConvError;
+    fn try_from(value: catalog::PrivilegeGrant) -> Result<Self, Self::Error> {
+        Ok(PrivilegeGrant {
+            object_id: value.object_id,
+            privilege: value.privilege.try_into()?,
+        })
+    }
+}
+
+impl From<PrivilegeGrant> for catalog::PrivilegeGrant {
+    fn from(value: PrivilegeGrant) -> Self {
+        catalog::PrivilegeGrant {
+            object_id: value.object_id,
+            privilege: value.privilege.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Privilege {
+    Select,
+    Insert,
+    Update,
+    Delete,
+    Create,
+    Usage,
+}
+
+impl Privilege {
+    /// All privileges that can be granted.
+    pub const ALL: [Privilege; 6] = [
+        Privilege::Select,
+        Privilege::Insert,
+        Privilege::Update,
+        Privilege::Delete,
+        Privilege::Create,
+        Privilege::Usage,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        catalog::Privilege::from(*self).as_str_name()
+    }
+}
+
+impl FromStr for Privilege {
+    type Err = ProtoConvError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_uppercase();
+        let privilege = catalog::Privilege::from_str_name(&s)
+            .ok_or_else(|| ProtoConvError::ParseError(format!("invalid privilege: {s}")))?;
+        privilege.try_into()
+    }
+}
+
+impl Display for Privilege {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<catalog::Privilege> for Privilege {
+    type Error = ProtoConvError;
+    fn try_from(value: catalog::Privilege) -> Result<Self, Self::Error> {
+        Ok(match value {
+            catalog::Privilege::Unknown => {
+                return Err(ProtoConvError::ZeroValueEnumVariant("Privilege"))
+            }
+            catalog::Privilege::Select => Self::Select,
+            catalog::Privilege::Insert => Self::Insert,
+            catalog::Privilege::Update => Self::Update,
+            catalog::Privilege::Delete => Self::Delete,
+            catalog::Privilege::Create => Self::Create,
+            catalog::Privilege::Usage => Self::Usage,
+        })
+    }
+}
+
+impl TryFrom<i32> for Privilege {
+    type Error = ProtoConvError;
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        catalog::Privilege::try_from(value)
+            .map_err(|_| ProtoConvError::UnknownEnumVariant("Privilege", value))
+            .and_then(|p| p.try_into())
+    }
+}
+
+impl From<Privilege> for catalog::Privilege {
+    fn from(value: Privilege) -> Self {
+        match value {
+            Privilege::Select => Self::Select,
+            Privilege::Insert => Self::Insert,
+            Privilege::Update => Self::Update,
+            Privilege::Delete => Self::Delete,
+            Privilege::Create => Self::Create,
+            Privilege::Usage => Self::Usage,
+        }
+    }
+}
+
+impl From<Privilege> for i32 {
+    fn from(value: Privilege) -> Self {
+        let value: catalog::Privilege = value.into();
+        value as i32
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -904,19 +1338,58 @@ mod tests {
             entries: HashMap::new(),
             deployment: None,
             catalog_version: None,
+            dropped_tables: Vec::new(),
+            builtin_comments: HashMap::new(),
         };
 
         let converted: CatalogState = state.try_into().unwrap();
         let expected = CatalogState {
             version: 4,
             entries: HashMap::new(),
-            deployment: DeploymentMetadata { storage_size: 0 },
+            deployment: DeploymentMetadata::default(),
             catalog_version: 0,
+            dropped_tables: Vec::new(),
+            builtin_comments: HashMap::new(),
         };
 
         assert_eq!(expected, converted);
     }
 
+    #[test]
+    fn sequence_advance() {
+        let mut seq = SequenceEntry {
+            meta: EntryMeta {
+                entry_type: EntryType::Sequence,
+                id: 20000,
+                parent: 2,
+                name: "seq".to_string(),
+                builtin: false,
+                external: false,
+                is_temp: false,
+                comment: None,
+                owner: 0,
+            },
+            options: SequenceOptions::with_defaults(2, None, None, Some(7), false, 1, i64::MAX),
+            last_value: 1,
+            is_called: false,
+            owned_by: None,
+        };
+
+        assert_eq!(Some(vec![1, 3]), seq.advance(2));
+        assert_eq!(Some(vec![5]), seq.advance(1));
+        // Would run past the max value.
+        assert_eq!(None, seq.advance(2));
+        assert_eq!(5, seq.last_value);
+        assert_eq!(Some(vec![7]), seq.advance(1));
+
+        seq.options.cycle = true;
+        assert_eq!(Some(vec![1, 3]), seq.advance(2));
+
+        assert!(!seq.set_value(100, true));
+        assert!(seq.set_value(4, false));
+        assert_eq!(Some(vec![4, 6]), seq.advance(2));
+    }
+
     #[test]
     fn source_access_mode_as_str() {
         let mode = SourceAccessMode::ReadOnly;
@@ -934,4 +1407,14 @@ mod tests {
 
         let _ = SourceAccessMode::from_str("DELETE").unwrap_err();
     }
+
+    #[test]
+    fn privilege_from_str() {
+        assert_eq!(Privilege::Select, Privilege::from_str("select").unwrap());
+        assert_eq!(Privilege::Usage, Privilege::from_str("USAGE").unwrap());
+        assert_eq!("INSERT", Privilege::Insert.as_str());
+
+        let _ = Privilege::from_str("PRIVILEGE_UNKNOWN").unwrap_err();
+        let _ = Privilege::from_str("DROP").unwrap_err();
+    }
 }
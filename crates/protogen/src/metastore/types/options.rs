@@ -732,7 +732,12 @@ impl TableOptionsV0 {
     pub const SQLITE: &'static str = "sqlite";
 
     pub const fn new_internal(columns: Vec<InternalColumnDefinition>) -> TableOptionsV0 {
-        TableOptionsV0::Internal(TableOptionsInternal { columns })
+        TableOptionsV0::Internal(TableOptionsInternal {
+            columns,
+            maintenance: None,
+            partition_columns: Vec::new(),
+            identity_columns: Vec::new(),
+        })
     }
 
     pub fn as_str(&self) -> &'static str {
@@ -1017,6 +1022,15 @@ impl From<TableOptionsDebug> for options::TableOptionsDebug {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TableOptionsInternal {
     pub columns: Vec<InternalColumnDefinition>,
+    /// Maintenance to run automatically after inserts.
+    #[serde(default)]
+    pub maintenance: Option<NativeMaintenancePolicy>,
+    /// Columns the table is partitioned by, in partition order.
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    /// Columns filled in from a sequence on insert.
+    #[serde(default)]
+    pub identity_columns: Vec<IdentityColumn>,
 }
 
 impl From<TableOptionsInternal> for TableOptionsV0 {
@@ -1041,6 +1055,9 @@ impl From<DFSchemaRef> for TableOptionsInternal {
                     arrow_type: col.data_type().clone(),
                 })
                 .collect::<Vec<_>>(),
+            maintenance: None,
+            partition_columns: Vec::new(),
+            identity_columns: Vec::new(),
         }
     }
 }
@@ -1057,6 +1074,9 @@ impl From<SchemaRef> for TableOptionsInternal {
                     arrow_type: col.data_type().clone(),
                 })
                 .collect::<Vec<_>>(),
+            maintenance: None,
+            partition_columns: Vec::new(),
+            identity_columns: Vec::new(),
         }
     }
 }
@@ -1070,6 +1090,13 @@ impl TryFrom<options::TableOptionsInternal> for TableOptionsInternal {
                 .into_iter()
                 .map(|col| col.try_into())
                 .collect::<Result<_, _>>()?,
+            maintenance: value.maintenance.map(Into::into),
+            partition_columns: value.partition_columns,
+            identity_columns: value
+                .identity_columns
+                .into_iter()
+                .map(|col| col.try_into())
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -1079,10 +1106,188 @@ impl TryFrom<TableOptionsInternal> for options::TableOptionsInternal {
     fn try_from(value: TableOptionsInternal) -> Result<Self, Self::Error> {
         Ok(options::TableOptionsInternal {
             columns: value.columns.into_iter().map(Into::into).collect(),
+            maintenance: value.maintenance.map(Into::into),
+            partition_columns: value.partition_columns,
+            identity_columns: value.identity_columns.into_iter().map(Into::into).collect(),
         })
     }
 }
 
+/// A column of a native table that's filled in from a sequence on insert
+/// (`SERIAL` or `GENERATED ... AS IDENTITY`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IdentityColumn {
+    pub name: String,
+    /// Reject explicit values for the column (`GENERATED ALWAYS`).
+    pub always: bool,
+    /// Oid of the sequence backing the column. Zero until metastore creates
+    /// the sequence along with the table.
+    pub sequence_id: u32,
+    /// Options used when creating the backing sequence.
+    pub sequence_options: SequenceOptions,
+}
+
+impl TryFrom<options::IdentityColumn> for IdentityColumn {
+    type Error = ProtoConvError;
+    fn try_from(value: options::IdentityColumn) -> Result<Self, Self::Error> {
+        Ok(IdentityColumn {
+            name: value.name,
+            always: value.always,
+            sequence_id: value.sequence_id,
+            sequence_options: value.sequence_options.required("sequence_options")?,
+        })
+    }
+}
+
+impl From<IdentityColumn> for options::IdentityColumn {
+    fn from(value: IdentityColumn) -> Self {
+        options::IdentityColumn {
+            name: value.name,
+            always: value.always,
+            sequence_id: value.sequence_id,
+            sequence_options: Some(value.sequence_options.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SequenceOptions {
+    pub start: i64,
+    pub increment: i64,
+    pub min_value: i64,
+    pub max_value: i64,
+    pub cycle: bool,
+    /// Number of values a session allocates from the catalog at a time,
+    /// handing out the rest from memory.
+    pub cache: i64,
+}
+
+/// Number of values sessions allocate for a sequence at a time if `CACHE`
+/// isn't given.
+///
+/// Unlike Postgres, which defaults to 1, a block is cached by default since
+/// every allocation commits a new version of the catalog.
+pub const DEFAULT_SEQUENCE_CACHE: i64 = 32;
+
+impl SequenceOptions {
+    /// Options for a sequence with Postgres' defaults for values in the range
+    /// `[type_min, type_max]`.
+    ///
+    /// Ascending sequences start at their minimum value, descending sequences
+    /// at their maximum.
+    pub fn with_defaults(
+        increment: i64,
+        start: Option<i64>,
+        min_value: Option<i64>,
+        max_value: Option<i64>,
+        cycle: bool,
+        type_min: i64,
+        type_max: i64,
+    ) -> Self {
+        let min_value = min_value.unwrap_or(if increment > 0 { 1 } else { type_min });
+        let max_value = max_value.unwrap_or(if increment > 0 { type_max } else { -1 });
+        let start = start.unwrap_or(if increment > 0 { min_value } else { max_value });
+        SequenceOptions {
+            start,
+            increment,
+            min_value,
+            max_value,
+            cycle,
+            cache: DEFAULT_SEQUENCE_CACHE,
+        }
+    }
+
+    /// Check that the options describe a usable sequence.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.increment == 0 {
+            return Err("INCREMENT must not be zero".to_string());
+        }
+        if self.min_value >= self.max_value {
+            return Err(format!(
+                "MINVALUE ({}) must be less than MAXVALUE ({})",
+                self.min_value, self.max_value
+            ));
+        }
+        if self.start < self.min_value || self.start > self.max_value {
+            return Err(format!(
+                "START value ({}) must be between MINVALUE ({}) and MAXVALUE ({})",
+                self.start, self.min_value, self.max_value
+            ));
+        }
+        if self.cache < 1 {
+            return Err(format!("CACHE ({}) must be greater than zero", self.cache));
+        }
+        Ok(())
+    }
+}
+
+impl From<options::SequenceOptions> for SequenceOptions {
+    fn from(value: options::SequenceOptions) -> Self {
+        SequenceOptions {
+            start: value.start,
+            increment: value.increment,
+            min_value: value.min_value,
+            max_value: value.max_value,
+            cycle: value.cycle,
+            cache: if value.cache == 0 {
+                DEFAULT_SEQUENCE_CACHE
+            } else {
+                value.cache
+            },
+        }
+    }
+}
+
+impl From<SequenceOptions> for options::SequenceOptions {
+    fn from(value: SequenceOptions) -> Self {
+        options::SequenceOptions {
+            start: value.start,
+            increment: value.increment,
+            min_value: value.min_value,
+            max_value: value.max_value,
+            cycle: value.cycle,
+            cache: value.cache,
+        }
+    }
+}
+
+/// Upper bound on how long vacuum may be asked to retain removed files
+/// (100 years).
+pub const MAX_VACUUM_RETAIN_HOURS: u64 = 100 * 365 * 24;
+
+/// Maintenance run in the background after inserting into a native table.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NativeMaintenancePolicy {
+    /// Compact the table once an insert leaves it with at least this many
+    /// data files.
+    pub optimize_min_files: u64,
+    /// Columns to Z-order by when compacting. Files are only bin-packed if
+    /// empty.
+    pub zorder_columns: Vec<String>,
+    /// Vacuum after compacting, retaining removed files for this many hours.
+    pub vacuum_retain_hours: Option<u64>,
+}
+
+impl From<options::NativeMaintenancePolicy> for NativeMaintenancePolicy {
+    fn from(value: options::NativeMaintenancePolicy) -> Self {
+        NativeMaintenancePolicy {
+            optimize_min_files: value.optimize_min_files,
+            zorder_columns: value.zorder_columns,
+            vacuum_retain_hours: value.vacuum_retain_hours,
+        }
+    }
+}
+
+impl From<NativeMaintenancePolicy> for options::NativeMaintenancePolicy {
+    fn from(value: NativeMaintenancePolicy) -> Self {
+        options::NativeMaintenancePolicy {
+            optimize_min_files: value.optimize_min_files,
+            zorder_columns: value.zorder_columns,
+            vacuum_retain_hours: value.vacuum_retain_hours,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TableOptionsPostgres {
     pub connection_string: String,
@@ -1,10 +1,14 @@
+use std::fmt;
+
 use datafusion::logical_expr::Signature;
 
-use super::catalog::{FunctionType, SourceAccessMode};
+use super::catalog::{FunctionType, Privilege, SourceAccessMode, TableStatistics};
 use super::options::{
     CredentialsOptions,
     DatabaseOptions,
     InternalColumnDefinition,
+    NativeMaintenancePolicy,
+    SequenceOptions,
     TableOptionsInternal,
     TableOptionsV0,
     TunnelOptions,
@@ -32,6 +36,16 @@ pub enum Mutation {
     // Deployment metadata updates
     UpdateDeploymentStorage(UpdateDeploymentStorage),
     CreateFunction(CreateFunction),
+    CreateRole(CreateRole),
+    DropRole(DropRole),
+    GrantPrivileges(GrantPrivileges),
+    RevokePrivileges(RevokePrivileges),
+    UndropTable(UndropTable),
+    PurgeDroppedTables(PurgeDroppedTables),
+    CommentOn(CommentOn),
+    CreateSequence(CreateSequence),
+    AdvanceSequence(AdvanceSequence),
+    SetSequenceValue(SetSequenceValue),
 }
 
 impl TryFrom<service::Mutation> for Mutation {
@@ -76,6 +90,28 @@ impl TryFrom<service::mutation::Mutation> for Mutation {
             service::mutation::Mutation::CreateFunction(v) => {
                 Mutation::CreateFunction(v.try_into()?)
             }
+            service::mutation::Mutation::CreateRole(v) => Mutation::CreateRole(v.try_into()?),
+            service::mutation::Mutation::DropRole(v) => Mutation::DropRole(v.try_into()?),
+            service::mutation::Mutation::GrantPrivileges(v) => {
+                Mutation::GrantPrivileges(v.try_into()?)
+            }
+            service::mutation::Mutation::RevokePrivileges(v) => {
+                Mutation::RevokePrivileges(v.try_into()?)
+            }
+            service::mutation::Mutation::UndropTable(v) => Mutation::UndropTable(v.try_into()?),
+            service::mutation::Mutation::PurgeDroppedTables(v) => {
+                Mutation::PurgeDroppedTables(v.try_into()?)
+            }
+            service::mutation::Mutation::CommentOn(v) => Mutation::CommentOn(v.try_into()?),
+            service::mutation::Mutation::CreateSequence(v) => {
+                Mutation::CreateSequence(v.try_into()?)
+            }
+            service::mutation::Mutation::AdvanceSequence(v) => {
+                Mutation::AdvanceSequence(v.try_into()?)
+            }
+            service::mutation::Mutation::SetSequenceValue(v) => {
+                Mutation::SetSequenceValue(v.try_into()?)
+            }
         })
     }
 }
@@ -112,10 +148,94 @@ impl TryFrom<Mutation> for service::mutation::Mutation {
             Mutation::CreateFunction(v) => {
                 service::mutation::Mutation::CreateFunction(v.try_into()?)
             }
+            Mutation::CreateRole(v) => service::mutation::Mutation::CreateRole(v.into()),
+            Mutation::DropRole(v) => service::mutation::Mutation::DropRole(v.into()),
+            Mutation::GrantPrivileges(v) => service::mutation::Mutation::GrantPrivileges(v.into()),
+            Mutation::RevokePrivileges(v) => {
+                service::mutation::Mutation::RevokePrivileges(v.into())
+            }
+            Mutation::UndropTable(v) => service::mutation::Mutation::UndropTable(v.into()),
+            Mutation::PurgeDroppedTables(v) => {
+                service::mutation::Mutation::PurgeDroppedTables(v.into())
+            }
+            Mutation::CommentOn(v) => service::mutation::Mutation::CommentOn(v.into()),
+            Mutation::CreateSequence(v) => service::mutation::Mutation::CreateSequence(v.into()),
+            Mutation::AdvanceSequence(v) => service::mutation::Mutation::AdvanceSequence(v.into()),
+            Mutation::SetSequenceValue(v) => {
+                service::mutation::Mutation::SetSequenceValue(v.into())
+            }
         })
     }
 }
 
+/// A short description of the mutation, recorded in the catalog history.
+///
+/// Only names the objects being modified, options (which may contain secrets)
+/// are never included.
+impl fmt::Display for Mutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mutation::DropDatabase(v) => write!(f, "drop database {}", v.name),
+            Mutation::DropSchema(v) => write!(f, "drop schema {}", v.name),
+            Mutation::DropObject(v) => write!(f, "drop {}.{}", v.schema, v.name),
+            Mutation::CreateSchema(v) => write!(f, "create schema {}", v.name),
+            Mutation::CreateView(v) => write!(f, "create view {}.{}", v.schema, v.name),
+            Mutation::CreateTable(v) => write!(f, "create table {}.{}", v.schema, v.name),
+            Mutation::CreateExternalTable(v) => write!(
+                f,
+                "create external table {}.{} ({})",
+                v.schema, v.name, v.options
+            ),
+            Mutation::CreateExternalDatabase(v) => {
+                write!(f, "create external database {} ({})", v.name, v.options)
+            }
+            Mutation::AlterTable(v) => write!(f, "alter table {}.{}", v.schema, v.name),
+            Mutation::AlterDatabase(v) => write!(f, "alter database {}", v.name),
+            Mutation::CreateTunnel(v) => write!(f, "create tunnel {} ({})", v.name, v.options),
+            Mutation::DropTunnel(v) => write!(f, "drop tunnel {}", v.name),
+            Mutation::AlterTunnelRotateKeys(v) => write!(f, "rotate keys for tunnel {}", v.name),
+            Mutation::CreateCredentials(v) => {
+                write!(f, "create credentials {} ({})", v.name, v.options)
+            }
+            Mutation::DropCredentials(v) => write!(f, "drop credentials {}", v.name),
+            Mutation::UpdateDeploymentStorage(_) => write!(f, "update deployment storage"),
+            Mutation::CreateFunction(v) => write!(f, "create function {}", v.name),
+            Mutation::CreateRole(v) => write!(f, "create role {}", v.name),
+            Mutation::DropRole(v) => write!(f, "drop role {}", v.name),
+            Mutation::GrantPrivileges(v) => {
+                write!(f, "grant privileges on {} to {}", v.object_id, v.role)
+            }
+            Mutation::RevokePrivileges(v) => {
+                write!(f, "revoke privileges on {} from {}", v.object_id, v.role)
+            }
+            Mutation::UndropTable(v) => write!(f, "undrop table {}.{}", v.schema, v.name),
+            Mutation::PurgeDroppedTables(_) => write!(f, "purge dropped tables"),
+            Mutation::CommentOn(v) => match &v.object {
+                CommentObject::Database { name } => write!(f, "comment on database {name}"),
+                CommentObject::Schema { name } => write!(f, "comment on schema {name}"),
+                CommentObject::Table { schema, name } => {
+                    write!(f, "comment on table {schema}.{name}")
+                }
+                CommentObject::View { schema, name } => {
+                    write!(f, "comment on view {schema}.{name}")
+                }
+                CommentObject::Column {
+                    schema,
+                    table,
+                    column,
+                } => write!(f, "comment on column {schema}.{table}.{column}"),
+            },
+            Mutation::CreateSequence(v) => write!(f, "create sequence {}.{}", v.schema, v.name),
+            Mutation::AdvanceSequence(v) => {
+                write!(f, "advance sequence {} by {}", v.sequence_id, v.count)
+            }
+            Mutation::SetSequenceValue(v) => {
+                write!(f, "set value of sequence {}", v.sequence_id)
+            }
+        }
+    }
+}
+
 impl TryFrom<Mutation> for service::Mutation {
     type Error = ProtoConvError;
     fn try_from(value: Mutation) -> Result<Self, Self::Error> {
@@ -185,6 +305,8 @@ pub struct DropObject {
     pub schema: String,
     pub name: String,
     pub if_exists: bool,
+    /// Keep dropped native tables around so they can be undropped.
+    pub retain: bool,
 }
 
 impl TryFrom<service::DropObject> for DropObject {
@@ -195,6 +317,7 @@ impl TryFrom<service::DropObject> for DropObject {
             schema: value.schema,
             name: value.name,
             if_exists: value.if_exists,
+            retain: value.retain,
         })
     }
 }
@@ -205,6 +328,150 @@ impl From<DropObject> for service::DropObject {
             schema: value.schema,
             name: value.name,
             if_exists: value.if_exists,
+            retain: value.retain,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndropTable {
+    pub schema: String,
+    pub name: String,
+}
+
+impl TryFrom<service::UndropTable> for UndropTable {
+    type Error = ProtoConvError;
+    fn try_from(value: service::UndropTable) -> Result<Self, Self::Error> {
+        Ok(UndropTable {
+            schema: value.schema,
+            name: value.name,
+        })
+    }
+}
+
+impl From<UndropTable> for service::UndropTable {
+    fn from(value: UndropTable) -> Self {
+        service::UndropTable {
+            schema: value.schema,
+            name: value.name,
+        }
+    }
+}
+
+/// The object a comment is attached to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommentObject {
+    Database {
+        name: String,
+    },
+    Schema {
+        name: String,
+    },
+    Table {
+        schema: String,
+        name: String,
+    },
+    View {
+        schema: String,
+        name: String,
+    },
+    Column {
+        schema: String,
+        table: String,
+        column: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommentOn {
+    pub object: CommentObject,
+    /// The new comment, `None` to remove it.
+    pub comment: Option<String>,
+}
+
+impl TryFrom<service::CommentOn> for CommentOn {
+    type Error = ProtoConvError;
+    fn try_from(value: service::CommentOn) -> Result<Self, Self::Error> {
+        use service::comment_on::ObjectType;
+
+        let object_type = ObjectType::try_from(value.object_type)
+            .map_err(|_| ProtoConvError::UnknownEnumVariant("ObjectType", value.object_type))?;
+        let object = match object_type {
+            ObjectType::Database => CommentObject::Database { name: value.name },
+            ObjectType::Schema => CommentObject::Schema { name: value.name },
+            ObjectType::Table => CommentObject::Table {
+                schema: value.schema,
+                name: value.name,
+            },
+            ObjectType::View => CommentObject::View {
+                schema: value.schema,
+                name: value.name,
+            },
+            ObjectType::Column => CommentObject::Column {
+                schema: value.schema,
+                table: value.name,
+                column: value.column,
+            },
+        };
+
+        Ok(CommentOn {
+            object,
+            comment: value.comment,
+        })
+    }
+}
+
+impl From<CommentOn> for service::CommentOn {
+    fn from(value: CommentOn) -> Self {
+        use service::comment_on::ObjectType;
+
+        let (object_type, schema, name, column) = match value.object {
+            CommentObject::Database { name } => {
+                (ObjectType::Database, String::new(), name, String::new())
+            }
+            CommentObject::Schema { name } => {
+                (ObjectType::Schema, String::new(), name, String::new())
+            }
+            CommentObject::Table { schema, name } => {
+                (ObjectType::Table, schema, name, String::new())
+            }
+            CommentObject::View { schema, name } => (ObjectType::View, schema, name, String::new()),
+            CommentObject::Column {
+                schema,
+                table,
+                column,
+            } => (ObjectType::Column, schema, table, column),
+        };
+
+        service::CommentOn {
+            object_type: object_type as i32,
+            schema,
+            name,
+            column,
+            comment: value.comment,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PurgeDroppedTables {
+    /// Milliseconds since the epoch.
+    pub expired_before: i64,
+}
+
+impl TryFrom<service::PurgeDroppedTables> for PurgeDroppedTables {
+    type Error = ProtoConvError;
+    fn try_from(value: service::PurgeDroppedTables) -> Result<Self, Self::Error> {
+        Ok(PurgeDroppedTables {
+            expired_before: value.expired_before,
+        })
+    }
+}
+
+impl From<PurgeDroppedTables> for service::PurgeDroppedTables {
+    fn from(value: PurgeDroppedTables) -> Self {
+        service::PurgeDroppedTables {
+            expired_before: value.expired_before,
         }
     }
 }
@@ -234,6 +501,97 @@ impl From<CreateSchema> for service::CreateSchema {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateSequence {
+    pub schema: String,
+    pub name: String,
+    pub options: SequenceOptions,
+    pub if_not_exists: bool,
+    /// OID of the role creating the sequence, zero if not a catalog role.
+    pub owner: u32,
+}
+
+impl TryFrom<service::CreateSequence> for CreateSequence {
+    type Error = ProtoConvError;
+    fn try_from(value: service::CreateSequence) -> Result<Self, Self::Error> {
+        Ok(CreateSequence {
+            schema: value.schema,
+            name: value.name,
+            options: value.options.required("options")?,
+            if_not_exists: value.if_not_exists,
+            owner: value.owner,
+        })
+    }
+}
+
+impl From<CreateSequence> for service::CreateSequence {
+    fn from(value: CreateSequence) -> Self {
+        service::CreateSequence {
+            schema: value.schema,
+            name: value.name,
+            options: Some(value.options.into()),
+            if_not_exists: value.if_not_exists,
+            owner: value.owner,
+        }
+    }
+}
+
+/// Hand out the next `count` values of a sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdvanceSequence {
+    pub sequence_id: u32,
+    pub count: u64,
+}
+
+impl TryFrom<service::AdvanceSequence> for AdvanceSequence {
+    type Error = ProtoConvError;
+    fn try_from(value: service::AdvanceSequence) -> Result<Self, Self::Error> {
+        Ok(AdvanceSequence {
+            sequence_id: value.sequence_id,
+            count: value.count,
+        })
+    }
+}
+
+impl From<AdvanceSequence> for service::AdvanceSequence {
+    fn from(value: AdvanceSequence) -> Self {
+        service::AdvanceSequence {
+            sequence_id: value.sequence_id,
+            count: value.count,
+        }
+    }
+}
+
+/// Reset the current value of a sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetSequenceValue {
+    pub sequence_id: u32,
+    pub value: i64,
+    /// If false, the next value handed out is `value` itself.
+    pub is_called: bool,
+}
+
+impl TryFrom<service::SetSequenceValue> for SetSequenceValue {
+    type Error = ProtoConvError;
+    fn try_from(value: service::SetSequenceValue) -> Result<Self, Self::Error> {
+        Ok(SetSequenceValue {
+            sequence_id: value.sequence_id,
+            value: value.value,
+            is_called: value.is_called,
+        })
+    }
+}
+
+impl From<SetSequenceValue> for service::SetSequenceValue {
+    fn from(value: SetSequenceValue) -> Self {
+        service::SetSequenceValue {
+            sequence_id: value.sequence_id,
+            value: value.value,
+            is_called: value.is_called,
+        }
+    }
+}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateView {
     pub schema: String,
@@ -241,6 +599,8 @@ pub struct CreateView {
     pub sql: String,
     pub or_replace: bool,
     pub columns: Vec<String>,
+    /// OID of the role creating the view, zero if not a catalog role.
+    pub owner: u32,
 }
 
 impl TryFrom<service::CreateView> for CreateView {
@@ -253,6 +613,7 @@ impl TryFrom<service::CreateView> for CreateView {
             sql: value.sql,
             or_replace: value.or_replace,
             columns: value.columns,
+            owner: value.owner,
         })
     }
 }
@@ -265,6 +626,7 @@ impl From<CreateView> for service::CreateView {
             sql: value.sql,
             or_replace: value.or_replace,
             columns: value.columns,
+            owner: value.owner,
         }
     }
 }
@@ -276,6 +638,8 @@ pub struct CreateTable {
     pub options: TableOptionsInternal,
     pub if_not_exists: bool,
     pub or_replace: bool,
+    /// OID of the role creating the table, zero if not a catalog role.
+    pub owner: u32,
 }
 
 impl TryFrom<service::CreateTable> for CreateTable {
@@ -288,6 +652,7 @@ impl TryFrom<service::CreateTable> for CreateTable {
             options,
             if_not_exists: value.if_not_exists,
             or_replace: value.or_replace,
+            owner: value.owner,
         })
     }
 }
@@ -301,6 +666,7 @@ impl TryFrom<CreateTable> for service::CreateTable {
             options: Some(value.options.try_into()?),
             if_not_exists: value.if_not_exists,
             or_replace: value.or_replace,
+            owner: value.owner,
         })
     }
 }
@@ -350,6 +716,8 @@ pub struct CreateExternalTable {
     pub if_not_exists: bool,
     pub tunnel: Option<String>,
     pub columns: Option<Vec<InternalColumnDefinition>>,
+    /// OID of the role creating the table, zero if not a catalog role.
+    pub owner: u32,
 }
 
 impl TryFrom<service::CreateExternalTable> for CreateExternalTable {
@@ -379,6 +747,7 @@ impl TryFrom<service::CreateExternalTable> for CreateExternalTable {
             if_not_exists: value.if_not_exists,
             tunnel: value.tunnel,
             columns,
+            owner: value.owner,
         })
     }
 }
@@ -399,6 +768,7 @@ impl TryFrom<CreateExternalTable> for service::CreateExternalTable {
             if_not_exists: value.if_not_exists,
             tunnel: value.tunnel,
             columns,
+            owner: value.owner,
         })
     }
 }
@@ -436,8 +806,18 @@ impl From<CreateExternalDatabase> for service::CreateExternalDatabase {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AlterTableOperation {
-    RenameTable { new_name: String },
-    SetAccessMode { access_mode: SourceAccessMode },
+    RenameTable {
+        new_name: String,
+    },
+    SetAccessMode {
+        access_mode: SourceAccessMode,
+    },
+    SetMaintenance {
+        policy: Option<NativeMaintenancePolicy>,
+    },
+    SetStatistics {
+        statistics: TableStatistics,
+    },
 }
 
 impl TryFrom<service::alter_table_operation::Operation> for AlterTableOperation {
@@ -452,6 +832,19 @@ impl TryFrom<service::alter_table_operation::Operation> for AlterTableOperation
             ) => Self::SetAccessMode {
                 access_mode: access_mode.try_into()?,
             },
+            service::alter_database_operation::Operation::AlterDatabaseOperationSetDroppedTableRetention(
+                service::AlterDatabaseOperationSetDroppedTableRetention { hours },
+            ) => Self::SetDroppedTableRetention { hours },
+            service::alter_table_operation::Operation::AlterTableOperationSetMaintenance(
+                service::AlterTableOperationSetMaintenance { policy },
+            ) => Self::SetMaintenance {
+                policy: policy.map(Into::into),
+            },
+            service::alter_table_operation::Operation::AlterTableOperationSetStatistics(
+                service::AlterTableOperationSetStatistics { statistics },
+            ) => Self::SetStatistics {
+                statistics: statistics.required("statistics")?,
+            },
         })
     }
 }
@@ -471,6 +864,20 @@ impl From<AlterTableOperation> for service::alter_table_operation::Operation {
                     },
                 )
             }
+            AlterTableOperation::SetMaintenance { policy } => {
+                service::alter_table_operation::Operation::AlterTableOperationSetMaintenance(
+                    service::AlterTableOperationSetMaintenance {
+                        policy: policy.map(Into::into),
+                    },
+                )
+            }
+            AlterTableOperation::SetStatistics { statistics } => {
+                service::alter_table_operation::Operation::AlterTableOperationSetStatistics(
+                    service::AlterTableOperationSetStatistics {
+                        statistics: Some(statistics.into()),
+                    },
+                )
+            }
         }
     }
 }
@@ -522,6 +929,7 @@ impl From<AlterTable> for service::AlterTable {
 pub enum AlterDatabaseOperation {
     RenameDatabase { new_name: String },
     SetAccessMode { access_mode: SourceAccessMode },
+    SetDroppedTableRetention { hours: u64 },
 }
 
 impl TryFrom<service::alter_database_operation::Operation> for AlterDatabaseOperation {
@@ -536,6 +944,9 @@ impl TryFrom<service::alter_database_operation::Operation> for AlterDatabaseOper
             ) => Self::SetAccessMode {
                 access_mode: access_mode.try_into()?,
             },
+            service::alter_database_operation::Operation::AlterDatabaseOperationSetDroppedTableRetention(
+                service::AlterDatabaseOperationSetDroppedTableRetention { hours },
+            ) => Self::SetDroppedTableRetention { hours },
         })
     }
 }
@@ -555,6 +966,11 @@ impl From<AlterDatabaseOperation> for service::alter_database_operation::Operati
                     },
                 )
             }
+            AlterDatabaseOperation::SetDroppedTableRetention { hours } => {
+                service::alter_database_operation::Operation::AlterDatabaseOperationSetDroppedTableRetention(
+                    service::AlterDatabaseOperationSetDroppedTableRetention { hours },
+                )
+            }
         }
     }
 }
@@ -736,6 +1152,134 @@ impl From<DropCredentials> for service::DropCredentials {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateRole {
+    pub name: String,
+    pub if_not_exists: bool,
+    pub login: bool,
+    pub superuser: bool,
+    /// SCRAM-SHA-256 secret for the password.
+    pub password: Option<String>,
+    /// Names of the roles the new role should be a member of.
+    pub member_of: Vec<String>,
+}
+
+impl TryFrom<service::CreateRole> for CreateRole {
+    type Error = ProtoConvError;
+    fn try_from(value: service::CreateRole) -> Result<Self, Self::Error> {
+        Ok(CreateRole {
+            name: value.name,
+            if_not_exists: value.if_not_exists,
+            login: value.login,
+            superuser: value.superuser,
+            password: value.password,
+            member_of: value.member_of,
+        })
+    }
+}
+
+impl From<CreateRole> for service::CreateRole {
+    fn from(value: CreateRole) -> Self {
+        service::CreateRole {
+            name: value.name,
+            if_not_exists: value.if_not_exists,
+            login: value.login,
+            superuser: value.superuser,
+            password: value.password,
+            member_of: value.member_of,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropRole {
+    pub name: String,
+    pub if_exists: bool,
+}
+
+impl TryFrom<service::DropRole> for DropRole {
+    type Error = ProtoConvError;
+    fn try_from(value: service::DropRole) -> Result<Self, Self::Error> {
+        Ok(DropRole {
+            name: value.name,
+            if_exists: value.if_exists,
+        })
+    }
+}
+
+impl From<DropRole> for service::DropRole {
+    fn from(value: DropRole) -> Self {
+        service::DropRole {
+            name: value.name,
+            if_exists: value.if_exists,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrantPrivileges {
+    pub role: String,
+    pub object_id: u32,
+    pub privileges: Vec<Privilege>,
+}
+
+impl TryFrom<service::GrantPrivileges> for GrantPrivileges {
+    type Error = ProtoConvError;
+    fn try_from(value: service::GrantPrivileges) -> Result<Self, Self::Error> {
+        Ok(GrantPrivileges {
+            role: value.role,
+            object_id: value.object_id,
+            privileges: value
+                .privileges
+                .into_iter()
+                .map(Privilege::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<GrantPrivileges> for service::GrantPrivileges {
+    fn from(value: GrantPrivileges) -> Self {
+        service::GrantPrivileges {
+            role: value.role,
+            object_id: value.object_id,
+            privileges: value.privileges.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevokePrivileges {
+    pub role: String,
+    pub object_id: u32,
+    pub privileges: Vec<Privilege>,
+}
+
+impl TryFrom<service::RevokePrivileges> for RevokePrivileges {
+    type Error = ProtoConvError;
+    fn try_from(value: service::RevokePrivileges) -> Result<Self, Self::Error> {
+        Ok(RevokePrivileges {
+            role: value.role,
+            object_id: value.object_id,
+            privileges: value
+                .privileges
+                .into_iter()
+                .map(Privilege::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<RevokePrivileges> for service::RevokePrivileges {
+    fn from(value: RevokePrivileges) -> Self {
+        service::RevokePrivileges {
+            role: value.role,
+            object_id: value.object_id,
+            privileges: value.privileges.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UpdateDeploymentStorage {
     pub new_storage_size: u64,
@@ -99,6 +99,8 @@ impl From<LeaseInformation> for storage::LeaseInformation {
 pub struct CatalogMetadata {
     pub latest_version: u64,
     pub last_written_by: Uuid,
+    /// Retained catalog versions, oldest first.
+    pub history: Vec<CatalogVersionInfo>,
 }
 
 impl TryFrom<storage::CatalogMetadata> for CatalogMetadata {
@@ -107,6 +109,11 @@ impl TryFrom<storage::CatalogMetadata> for CatalogMetadata {
         Ok(CatalogMetadata {
             latest_version: value.latest_version,
             last_written_by: Uuid::from_slice(&value.last_written_by)?,
+            history: value
+                .history
+                .into_iter()
+                .map(|v| v.try_into())
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -116,6 +123,45 @@ impl From<CatalogMetadata> for storage::CatalogMetadata {
         storage::CatalogMetadata {
             latest_version: value.latest_version,
             last_written_by: value.last_written_by.into_bytes().to_vec(),
+            history: value.history.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogVersionInfo {
+    pub version: u64,
+    /// Milliseconds since the epoch.
+    pub committed_at: i64,
+    /// Process that wrote this version.
+    pub written_by: Uuid,
+    /// Descriptions of the mutations that produced this version.
+    pub mutations: Vec<String>,
+    /// Whether the mutations only changed the values of sequences.
+    pub sequences_only: bool,
+}
+
+impl TryFrom<storage::CatalogVersionInfo> for CatalogVersionInfo {
+    type Error = ProtoConvError;
+    fn try_from(value: storage::CatalogVersionInfo) -> Result<Self, Self::Error> {
+        Ok(CatalogVersionInfo {
+            version: value.version,
+            committed_at: value.committed_at,
+            written_by: Uuid::from_slice(&value.written_by)?,
+            mutations: value.mutations,
+            sequences_only: value.sequences_only,
+        })
+    }
+}
+
+impl From<CatalogVersionInfo> for storage::CatalogVersionInfo {
+    fn from(value: CatalogVersionInfo) -> Self {
+        storage::CatalogVersionInfo {
+            version: value.version,
+            committed_at: value.committed_at,
+            written_by: value.written_by.into_bytes().to_vec(),
+            mutations: value.mutations,
+            sequences_only: value.sequences_only,
         }
     }
 }
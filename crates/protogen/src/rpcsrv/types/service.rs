@@ -250,6 +250,7 @@ pub struct PhysicalPlanExecuteRequest {
     pub physical_plan: Vec<u8>,
     pub user_id: Option<Uuid>,
     pub query_text: String,
+    pub user_name: String,
 }
 
 impl TryFrom<service::PhysicalPlanExecuteRequest> for PhysicalPlanExecuteRequest {
@@ -260,6 +261,7 @@ impl TryFrom<service::PhysicalPlanExecuteRequest> for PhysicalPlanExecuteRequest
             physical_plan: value.physical_plan,
             user_id: Uuid::from_slice(&value.user_id).ok(),
             query_text: value.query_text,
+            user_name: value.user_name,
         })
     }
 }
@@ -274,6 +276,7 @@ impl From<PhysicalPlanExecuteRequest> for service::PhysicalPlanExecuteRequest {
                 .map(|v| v.into_bytes().into())
                 .unwrap_or_default(),
             query_text: value.query_text,
+            user_name: value.user_name,
         }
     }
 }
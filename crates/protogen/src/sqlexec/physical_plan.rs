@@ -5,7 +5,7 @@ use prost::{Message, Oneof};
 
 use super::common::{FullObjectReference, FullSchemaReference};
 use super::copy_to::{CopyToDestinationOptions, CopyToFormatOptions};
-use crate::gen::metastore::catalog::TableEntry;
+use crate::gen::metastore::catalog::{Privilege, TableEntry};
 
 #[derive(Clone, PartialEq, Message)]
 pub struct ClientExchangeRecvExec {
@@ -41,6 +41,12 @@ pub struct CreateTableExec {
     pub or_replace: bool,
     #[prost(message, tag = "5")]
     pub arrow_schema: Option<Schema>,
+    #[prost(string, repeated, tag = "6")]
+    pub partition_columns: Vec<String>,
+    #[prost(message, repeated, tag = "7")]
+    pub identity_columns: Vec<crate::gen::metastore::options::IdentityColumn>,
+    #[prost(uint32, tag = "8")]
+    pub owner: u32,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -143,6 +149,58 @@ pub struct DropTunnelExec {
     pub if_exists: bool,
 }
 
+#[derive(Clone, PartialEq, Message)]
+pub struct CreateRoleExec {
+    #[prost(uint64, tag = "1")]
+    pub catalog_version: u64,
+    #[prost(string, tag = "2")]
+    pub name: String,
+    #[prost(bool, tag = "3")]
+    pub if_not_exists: bool,
+    #[prost(bool, tag = "4")]
+    pub login: bool,
+    #[prost(bool, tag = "5")]
+    pub superuser: bool,
+    #[prost(string, optional, tag = "6")]
+    pub password: Option<String>,
+    #[prost(string, repeated, tag = "7")]
+    pub member_of: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct DropRolesExec {
+    #[prost(uint64, tag = "1")]
+    pub catalog_version: u64,
+    #[prost(string, repeated, tag = "2")]
+    pub names: Vec<String>,
+    #[prost(bool, tag = "3")]
+    pub if_exists: bool,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct GrantPrivilegesExec {
+    #[prost(uint64, tag = "1")]
+    pub catalog_version: u64,
+    #[prost(string, repeated, tag = "2")]
+    pub grantees: Vec<String>,
+    #[prost(uint32, repeated, tag = "3")]
+    pub object_ids: Vec<u32>,
+    #[prost(enumeration = "Privilege", repeated, tag = "4")]
+    pub privileges: Vec<i32>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct RevokePrivilegesExec {
+    #[prost(uint64, tag = "1")]
+    pub catalog_version: u64,
+    #[prost(string, repeated, tag = "2")]
+    pub grantees: Vec<String>,
+    #[prost(uint32, repeated, tag = "3")]
+    pub object_ids: Vec<u32>,
+    #[prost(enumeration = "Privilege", repeated, tag = "4")]
+    pub privileges: Vec<i32>,
+}
+
 #[derive(Clone, PartialEq, Message)]
 pub struct DropViewsExec {
     #[prost(uint64, tag = "1")]
@@ -153,6 +211,30 @@ pub struct DropViewsExec {
     pub if_exists: bool,
 }
 
+#[derive(Clone, PartialEq, Message)]
+pub struct CreateSequenceExec {
+    #[prost(uint64, tag = "1")]
+    pub catalog_version: u64,
+    #[prost(message, tag = "2")]
+    pub sequence_reference: Option<FullObjectReference>,
+    #[prost(message, tag = "3")]
+    pub options: Option<crate::gen::metastore::options::SequenceOptions>,
+    #[prost(bool, tag = "4")]
+    pub if_not_exists: bool,
+    #[prost(uint32, tag = "5")]
+    pub owner: u32,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct DropSequencesExec {
+    #[prost(uint64, tag = "1")]
+    pub catalog_version: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub sequence_references: Vec<FullObjectReference>,
+    #[prost(bool, tag = "3")]
+    pub if_exists: bool,
+}
+
 #[derive(Clone, PartialEq, Message)]
 pub struct CreateSchema {
     #[prost(uint64, tag = "1")]
@@ -205,6 +287,8 @@ pub struct CreateExternalTableExec {
     pub or_replace: bool,
     #[prost(message, optional, tag = "7")]
     pub table_schema: Option<Schema>,
+    #[prost(uint32, tag = "8")]
+    pub owner: u32,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -231,6 +315,8 @@ pub struct CreateViewExec {
     pub columns: Vec<String>,
     #[prost(bool, tag = "5")]
     pub or_replace: bool,
+    #[prost(uint32, tag = "6")]
+    pub owner: u32,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -253,6 +339,55 @@ pub struct DropTablesExec {
     pub if_exists: bool,
     #[prost(message, repeated, tag = "4")]
     pub tbl_entries: Vec<TableEntry>,
+    #[prost(int64, tag = "5")]
+    pub purge_expired_before: i64,
+    #[prost(message, repeated, tag = "6")]
+    pub purge_entries: Vec<TableEntry>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct UndropTableExec {
+    #[prost(uint64, tag = "1")]
+    pub catalog_version: u64,
+    #[prost(string, tag = "2")]
+    pub schema: String,
+    #[prost(string, tag = "3")]
+    pub name: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct AnalyzeTableExec {
+    #[prost(uint64, tag = "1")]
+    pub catalog_version: u64,
+    /// One per input, in the same order.
+    #[prost(message, repeated, tag = "2")]
+    pub tables: Vec<AnalyzeTableTarget>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct AnalyzeTableTarget {
+    #[prost(string, tag = "1")]
+    pub schema: String,
+    #[prost(string, tag = "2")]
+    pub name: String,
+    #[prost(string, repeated, tag = "3")]
+    pub columns: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct CommentOnExec {
+    #[prost(uint64, tag = "1")]
+    pub catalog_version: u64,
+    #[prost(message, tag = "2")]
+    pub comment_on: Option<crate::gen::metastore::service::CommentOn>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct BackupDatabaseExec {
+    #[prost(string, tag = "1")]
+    pub location: String,
+    #[prost(message, tag = "2")]
+    pub storage_options: Option<crate::gen::metastore::options::StorageOptions>,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -295,6 +430,35 @@ pub struct DeleteExec {
     pub where_expr: Option<LogicalExprNode>,
 }
 
+#[derive(Clone, PartialEq, Message)]
+pub struct OptimizeExec {
+    #[prost(message, tag = "1")]
+    pub table: Option<TableEntry>,
+    #[prost(string, repeated, tag = "2")]
+    pub zorder_columns: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct VacuumExec {
+    #[prost(message, tag = "1")]
+    pub table: Option<TableEntry>,
+    #[prost(uint64, optional, tag = "2")]
+    pub retain_hours: Option<u64>,
+    #[prost(bool, tag = "3")]
+    pub dry_run: bool,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct RestoreTableExec {
+    #[prost(message, tag = "1")]
+    pub table: Option<TableEntry>,
+    #[prost(int64, optional, tag = "2")]
+    pub version: Option<i64>,
+    /// RFC 3339 timestamp.
+    #[prost(string, optional, tag = "3")]
+    pub timestamp: Option<String>,
+}
+
 #[derive(Clone, PartialEq, Message)]
 pub struct InsertExec {
     #[prost(bytes, tag = "1")]
@@ -343,7 +507,7 @@ pub struct AnalyzeExec {
 pub struct ExecutionPlanExtension {
     #[prost(
         oneof = "ExecutionPlanExtensionType",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44"
     )]
     pub inner: Option<ExecutionPlanExtensionType>,
 }
@@ -417,4 +581,33 @@ pub enum ExecutionPlanExtensionType {
     DataSourceMetricsExecAdapter(DataSourceMetricsExecAdapter),
     #[prost(message, tag = "31")]
     DescribeTable(DescribeTableExec),
+    // Roles
+    #[prost(message, tag = "32")]
+    CreateRoleExec(CreateRoleExec),
+    #[prost(message, tag = "33")]
+    DropRolesExec(DropRolesExec),
+    #[prost(message, tag = "34")]
+    GrantPrivilegesExec(GrantPrivilegesExec),
+    #[prost(message, tag = "35")]
+    RevokePrivilegesExec(RevokePrivilegesExec),
+    // Native table maintenance
+    #[prost(message, tag = "36")]
+    OptimizeExec(OptimizeExec),
+    #[prost(message, tag = "37")]
+    VacuumExec(VacuumExec),
+    #[prost(message, tag = "38")]
+    UndropTableExec(UndropTableExec),
+    #[prost(message, tag = "39")]
+    RestoreTableExec(RestoreTableExec),
+    #[prost(message, tag = "40")]
+    AnalyzeTableExec(AnalyzeTableExec),
+    #[prost(message, tag = "41")]
+    CommentOnExec(CommentOnExec),
+    #[prost(message, tag = "42")]
+    BackupDatabaseExec(BackupDatabaseExec),
+    // Sequences
+    #[prost(message, tag = "43")]
+    CreateSequenceExec(CreateSequenceExec),
+    #[prost(message, tag = "44")]
+    DropSequencesExec(DropSequencesExec),
 }
@@ -25,8 +25,9 @@ use protogen::rpcsrv::types::service::{
 };
 use sqlexec::engine::{Engine, SessionStorageConfig};
 use sqlexec::remote::batch_stream::ExecutionBatchStream;
+use tonic::metadata::{KeyAndValueRef, MetadataMap};
 use tonic::{Request, Response, Status, Streaming};
-use tracing::info;
+use tracing::{info, info_span, Instrument};
 use uuid::Uuid;
 
 use crate::errors::{Result, RpcsrvError};
@@ -173,9 +174,11 @@ impl RpcHandler {
 
         let session_metrics_handler = SessionMetricsHandler::new(
             req.user_id.unwrap_or_default(),
+            req.user_name,
             req.database_id,
             Uuid::nil(), // TODO: Connection ID?
             self.engine.get_tracker(),
+            self.engine.get_query_history(),
         );
 
         let query_metrics = QueryMetrics {
@@ -257,8 +260,12 @@ impl service::execution_service_server::ExecutionService for RpcHandler {
         &self,
         request: Request<service::PhysicalPlanExecuteRequest>,
     ) -> Result<Response<Self::PhysicalPlanExecuteStream>, Status> {
+        let span = info_span!("physical_plan_execute");
+        logutil::otlp::set_parent_from_trace_context(&span, &trace_context(request.metadata()));
+
         let resp = self
             .physical_plan_execute_inner(request.into_inner().try_into()?)
+            .instrument(span)
             .await?;
         Ok(Response::new(Box::pin(resp)))
     }
@@ -267,11 +274,30 @@ impl service::execution_service_server::ExecutionService for RpcHandler {
         &self,
         request: Request<Streaming<common::ExecutionResultBatch>>,
     ) -> Result<Response<service::BroadcastExchangeResponse>, Status> {
-        let resp = self.broadcast_exchange_inner(request.into_inner()).await?;
+        let span = info_span!("broadcast_exchange");
+        logutil::otlp::set_parent_from_trace_context(&span, &trace_context(request.metadata()));
+
+        let resp = self
+            .broadcast_exchange_inner(request.into_inner())
+            .instrument(span)
+            .await?;
         Ok(Response::new(resp))
     }
 }
 
+/// Get trace context propagated by the client through request metadata.
+fn trace_context(metadata: &MetadataMap) -> HashMap<String, String> {
+    metadata
+        .iter()
+        .filter_map(|kv| match kv {
+            KeyAndValueRef::Ascii(key, value) => {
+                Some((key.as_str().to_string(), value.to_str().ok()?.to_string()))
+            }
+            KeyAndValueRef::Binary(..) => None,
+        })
+        .collect()
+}
+
 /// Convert a record batch stream into a stream of execution responses
 /// containing ipc serialized batches.
 // TODO: StreamWriter
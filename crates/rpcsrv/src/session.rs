@@ -4,6 +4,7 @@ use std::sync::Arc;
 use datafusion::arrow::datatypes::Schema;
 use datafusion::physical_plan::{ExecutionPlan, SendableRecordBatchStream};
 use datafusion_ext::functions::FuncParamValue;
+use datafusion_ext::traced::TracedExec;
 use datafusion_proto::physical_plan::AsExecutionPlan;
 use datafusion_proto::protobuf::PhysicalPlanNode;
 use protogen::metastore::types::catalog::CatalogState;
@@ -63,6 +64,12 @@ impl RemoteSession {
             &codec,
         )?;
 
+        let plan = if logutil::otlp::is_enabled() {
+            TracedExec::wrap_plan(plan, &|_| true)?
+        } else {
+            plan
+        };
+
         let stream = self.session.execute_physical(plan.clone())?;
         Ok((plan, stream))
     }
@@ -5,6 +5,7 @@ use async_trait::async_trait;
 use tokio_postgres::Config;
 use tracing::warn;
 
+use crate::clients::postgres::PgTestClient;
 use crate::clients::TestClient;
 use crate::test::FnTest;
 
@@ -151,3 +152,401 @@ impl FnTest for PgBinaryEncoding {
         Ok(())
     }
 }
+
+/// Log in as a role without superuser and check that privileges are enforced.
+///
+/// Runs as its own test since sqllogictest files can't switch users.
+pub struct RolesTest;
+
+impl RolesTest {
+    async fn expect_denied(client: &PgTestClient, sql: &str) -> Result<()> {
+        let err = match client.batch_execute(sql).await {
+            Ok(_) => return Err(anyhow!("expected permission error for: {sql}")),
+            Err(e) => e,
+        };
+        let msg = match err.as_db_error() {
+            Some(e) => e.message().to_string(),
+            None => err.to_string(),
+        };
+        test_assert!(
+            msg.contains("Permission denied"),
+            anyhow!("expected permission error for: {sql}, got: {msg}")
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FnTest for RolesTest {
+    async fn run(
+        &self,
+        config: &Config,
+        client: TestClient,
+        _vars: &mut HashMap<String, String>,
+    ) -> Result<()> {
+        let admin = match client {
+            TestClient::Pg(client) => client,
+            TestClient::Rpc(_) | TestClient::FlightSql(_) => {
+                warn!("skipping roles test on rpc");
+                return Ok(());
+            }
+        };
+
+        admin
+            .batch_execute(
+                "
+CREATE SCHEMA roles_test;
+CREATE TABLE roles_test.readable (a int);
+CREATE TABLE roles_test.writable (a int);
+CREATE VIEW roles_test.view AS SELECT 1;
+CREATE SEQUENCE roles_test.seq;
+INSERT INTO roles_test.readable VALUES (1), (2);
+INSERT INTO roles_test.writable VALUES (1), (2);
+
+CREATE ROLE analysts;
+CREATE USER analyst PASSWORD 'secret' IN ROLE analysts;
+GRANT USAGE ON SCHEMA roles_test TO analysts;
+GRANT SELECT ON roles_test.readable TO analysts;
+GRANT INSERT, DELETE, UPDATE ON roles_test.writable TO analysts;
+CREATE SEQUENCE roles_test.granted_seq;
+GRANT SELECT, UPDATE ON SEQUENCE roles_test.granted_seq TO analysts;
+                ",
+            )
+            .await?;
+
+        let mut analyst_config = config.clone();
+        analyst_config.user("analyst").password("secret");
+        let analyst = PgTestClient::new(&analyst_config).await?;
+
+        // Granted privileges.
+        let row = analyst
+            .query_one("SELECT count(*) FROM roles_test.readable", &[])
+            .await?;
+        let count: i64 = row.get(0);
+        test_assert!(count == 2, anyhow!("expected 2 readable rows, got {count}"));
+        analyst
+            .batch_execute("INSERT INTO roles_test.writable VALUES (3)")
+            .await?;
+        analyst
+            .batch_execute("UPDATE roles_test.writable SET a = 0")
+            .await?;
+        analyst
+            .batch_execute("DELETE FROM roles_test.writable")
+            .await?;
+        analyst
+            .batch_execute("SELECT * FROM generate_series(1, 3)")
+            .await?;
+        analyst
+            .batch_execute(
+                "
+SELECT nextval('roles_test.granted_seq');
+SELECT currval('roles_test.granted_seq');
+SELECT setval('roles_test.granted_seq', 10);
+                ",
+            )
+            .await?;
+
+        // Missing table privileges.
+        for sql in [
+            "SELECT * FROM roles_test.writable",
+            "INSERT INTO roles_test.readable VALUES (3)",
+            "DELETE FROM roles_test.readable",
+            "UPDATE roles_test.readable SET a = 0",
+            // Reading rows of the target requires SELECT.
+            "DELETE FROM roles_test.writable WHERE a = 1",
+            "UPDATE roles_test.writable SET a = a + 1",
+            "INSERT INTO roles_test.writable SELECT * FROM roles_test.writable",
+            // Sequence functions require privileges on the sequence.
+            "SELECT nextval('roles_test.seq')",
+            "SELECT currval('roles_test.seq')",
+            "SELECT setval('roles_test.seq', 10)",
+        ] {
+            Self::expect_denied(&analyst, sql).await?;
+        }
+
+        // Table functions reading external data.
+        for sql in [
+            "SELECT * FROM read_csv('./testdata/csv/userdata1.csv')",
+            "SELECT * FROM read_parquet('./testdata/parquet/userdata1.parquet')",
+            "SELECT * FROM delta_scan('./testdata/delta/table1')",
+        ] {
+            Self::expect_denied(&analyst, sql).await?;
+        }
+
+        // Schema and catalog modifications.
+        for sql in [
+            "CREATE TABLE roles_test.created (a int)",
+            "DROP TABLE roles_test.readable",
+            "CREATE ROLE other",
+            "GRANT SELECT ON roles_test.writable TO analysts",
+        ] {
+            Self::expect_denied(&analyst, sql).await?;
+        }
+
+        // Tables can be dropped by their owner.
+        admin
+            .batch_execute("GRANT CREATE ON SCHEMA roles_test TO analysts")
+            .await?;
+        analyst
+            .batch_execute("CREATE TABLE roles_test.created (a int)")
+            .await?;
+        analyst
+            .batch_execute("DROP TABLE roles_test.created")
+            .await?;
+
+        // Objects owned by other roles can't be replaced, altered, maintained
+        // or dropped, even with CREATE on the schema.
+        for sql in [
+            "DROP TABLE roles_test.readable",
+            "DROP VIEW roles_test.view",
+            "DROP SEQUENCE roles_test.seq",
+            "CREATE OR REPLACE TABLE roles_test.readable (a int)",
+            "CREATE OR REPLACE EXTERNAL TABLE roles_test.readable FROM local OPTIONS (location = './testdata/csv/userdata1.csv')",
+            "CREATE OR REPLACE VIEW roles_test.view AS SELECT 2",
+            "ALTER TABLE roles_test.readable RENAME TO renamed",
+            "ALTER TABLE roles_test.readable SET ACCESS_MODE TO READ_ONLY",
+            "OPTIMIZE roles_test.readable",
+            "VACUUM roles_test.readable",
+            "RESTORE TABLE roles_test.readable TO VERSION 0",
+        ] {
+            Self::expect_denied(&analyst, sql).await?;
+        }
+
+        // But the owner can.
+        analyst
+            .batch_execute(
+                "
+CREATE TABLE roles_test.created (a int);
+CREATE OR REPLACE TABLE roles_test.created (b int);
+ALTER TABLE roles_test.created RENAME TO renamed;
+OPTIMIZE roles_test.renamed;
+VACUUM roles_test.renamed;
+DROP TABLE roles_test.renamed;
+CREATE VIEW roles_test.created_view AS SELECT 1;
+CREATE OR REPLACE VIEW roles_test.created_view AS SELECT 2;
+DROP VIEW roles_test.created_view;
+CREATE SEQUENCE roles_test.created_seq;
+DROP SEQUENCE roles_test.created_seq;
+                ",
+            )
+            .await?;
+
+        // Privileges are gone once revoked.
+        let row = admin
+            .query_one(
+                "SELECT max(version)::bigint FROM glare_catalog.catalog_history",
+                &[],
+            )
+            .await?;
+        let granted_version: i64 = row.get(0);
+        admin
+            .batch_execute("REVOKE SELECT ON roles_test.readable FROM analysts")
+            .await?;
+        Self::expect_denied(&analyst, "SELECT * FROM roles_test.readable").await?;
+
+        // Pinning to a version from before the revoke doesn't bring them back.
+        analyst
+            .batch_execute(&format!("SET catalog_as_of_version = {granted_version}"))
+            .await?;
+        Self::expect_denied(&analyst, "SELECT * FROM roles_test.readable").await?;
+        Self::expect_denied(&analyst, "SELECT * FROM roles_test.readable").await?;
+
+        admin
+            .batch_execute(
+                "
+DROP ROLE analyst, analysts;
+DROP SCHEMA roles_test CASCADE;
+                ",
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Pin a session to an older version of the catalog and check that it sees
+/// the catalog as it was.
+///
+/// Runs as its own test since sqllogictest files can't use the version
+/// numbers returned by queries.
+pub struct CatalogAsOfVersionTest;
+
+#[async_trait]
+impl FnTest for CatalogAsOfVersionTest {
+    async fn run(
+        &self,
+        config: &Config,
+        client: TestClient,
+        _vars: &mut HashMap<String, String>,
+    ) -> Result<()> {
+        let client = match client {
+            TestClient::Pg(client) => client,
+            TestClient::Rpc(_) | TestClient::FlightSql(_) => {
+                warn!("skipping catalog as of version test on rpc");
+                return Ok(());
+            }
+        };
+
+        client
+            .batch_execute(
+                "
+CREATE SCHEMA catalog_as_of;
+CREATE TABLE catalog_as_of.before (a int);
+                ",
+            )
+            .await?;
+        let row = client
+            .query_one(
+                "SELECT max(version)::bigint FROM glare_catalog.catalog_history",
+                &[],
+            )
+            .await?;
+        let version: i64 = row.get(0);
+        client
+            .batch_execute("CREATE TABLE catalog_as_of.after (a int)")
+            .await?;
+
+        // Pin a separate session so this one can clean up afterwards.
+        let pinned = PgTestClient::new(config).await?;
+        pinned
+            .batch_execute(&format!("SET catalog_as_of_version = {version}"))
+            .await?;
+
+        let rows = pinned
+            .query(
+                "SELECT table_name FROM information_schema.tables
+                    WHERE table_schema = 'catalog_as_of' ORDER BY table_name",
+                &[],
+            )
+            .await?;
+        let tables: Vec<String> = rows.iter().map(|row| row.get(0)).collect();
+        test_assert!(
+            tables == ["before"],
+            anyhow!("expected only the table created before pinning, got: {tables:?}")
+        );
+
+        // Pinned sessions are read-only.
+        match pinned
+            .batch_execute("CREATE TABLE catalog_as_of.pinned (a int)")
+            .await
+        {
+            Ok(_) => return Err(anyhow!("expected pinned session to be read-only")),
+            Err(e) => {
+                let msg = match e.as_db_error() {
+                    Some(e) => e.message().to_string(),
+                    None => e.to_string(),
+                };
+                test_assert!(
+                    msg.contains("read-only"),
+                    anyhow!("expected read-only error, got: {msg}")
+                );
+            }
+        }
+
+        client
+            .batch_execute("DROP SCHEMA catalog_as_of CASCADE")
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Check that statistics from `ANALYZE` change which side of a join is used
+/// as the build side.
+///
+/// Runs as its own test since sqllogictest files can only match the whole
+/// `EXPLAIN` output.
+pub struct AnalyzeJoinOrderTest;
+
+impl AnalyzeJoinOrderTest {
+    const QUERY: &'static str = "
+EXPLAIN SELECT *
+    FROM analyze_join.big
+    JOIN analyze_join.small ON big.big_id = small.small_id
+    ";
+
+    /// Get the join keys of the hash join in the physical plan, build side
+    /// first.
+    async fn hash_join_on(client: &PgTestClient) -> Result<String> {
+        let rows = client.query(Self::QUERY, &[]).await?;
+        let plan: String = rows
+            .iter()
+            .find(|row| row.get::<_, String>(0) == "physical_plan")
+            .ok_or_else(|| anyhow!("missing physical plan"))?
+            .get(1);
+
+        plan.lines()
+            .find_map(|line| {
+                let (_, on) = line
+                    .trim()
+                    .strip_prefix("HashJoinExec:")?
+                    .split_once("on=[")?;
+                Some(on.to_string())
+            })
+            .ok_or_else(|| anyhow!("missing hash join in plan:\n{plan}"))
+    }
+}
+
+#[async_trait]
+impl FnTest for AnalyzeJoinOrderTest {
+    async fn run(
+        &self,
+        _config: &Config,
+        client: TestClient,
+        vars: &mut HashMap<String, String>,
+    ) -> Result<()> {
+        let client = match client {
+            TestClient::Pg(client) => client,
+            TestClient::Rpc(_) | TestClient::FlightSql(_) => {
+                warn!("skipping analyze join order test on rpc");
+                return Ok(());
+            }
+        };
+        let tmp = vars
+            .get("TMP")
+            .ok_or_else(|| anyhow!("missing TMP directory"))?;
+
+        // CSV files don't have statistics of their own.
+        client
+            .batch_execute(&format!(
+                "
+CREATE SCHEMA analyze_join;
+COPY (SELECT generate_series AS big_id FROM generate_series(1, 1000))
+    TO '{tmp}/analyze_big.csv';
+COPY (SELECT generate_series AS small_id FROM generate_series(1, 10))
+    TO '{tmp}/analyze_small.csv';
+CREATE EXTERNAL TABLE analyze_join.big FROM local OPTIONS (
+    location = '{tmp}/analyze_big.csv'
+);
+CREATE EXTERNAL TABLE analyze_join.small FROM local OPTIONS (
+    location = '{tmp}/analyze_small.csv'
+);
+                "
+            ))
+            .await?;
+
+        // Without statistics, the join is planned as written.
+        let on = Self::hash_join_on(&client).await?;
+        test_assert!(
+            on.starts_with("(big_id@"),
+            anyhow!("expected big table as build side before analyze, got: {on}")
+        );
+
+        client
+            .batch_execute("ANALYZE analyze_join.big; ANALYZE analyze_join.small;")
+            .await?;
+
+        // The smaller table is now used to build the hash table.
+        let on = Self::hash_join_on(&client).await?;
+        test_assert!(
+            on.starts_with("(small_id@"),
+            anyhow!("expected small table as build side after analyze, got: {on}")
+        );
+
+        client
+            .batch_execute("DROP SCHEMA analyze_join CASCADE")
+            .await?;
+
+        Ok(())
+    }
+}
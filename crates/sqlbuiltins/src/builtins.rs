@@ -16,7 +16,12 @@
 
 use std::sync::Arc;
 
-use datafusion::arrow::datatypes::{DataType, Field as ArrowField, Schema as ArrowSchema};
+use datafusion::arrow::datatypes::{
+    DataType,
+    Field as ArrowField,
+    Schema as ArrowSchema,
+    TimeUnit,
+};
 use once_cell::sync::Lazy;
 use pgrepr::oid::FIRST_GLAREDB_BUILTIN_ID;
 use protogen::metastore::types::options::InternalColumnDefinition;
@@ -101,6 +106,7 @@ pub static GLARE_DATABASES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         ("external", DataType::Boolean, false),
         ("datasource", DataType::Utf8, false),
         ("access_mode", DataType::Utf8, false), // `SourceAccessMode::as_str()`
+        ("comment", DataType::Utf8, true),
     ]),
     oid: 16401,
 });
@@ -139,6 +145,7 @@ pub static GLARE_SCHEMAS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         ("database_name", DataType::Utf8, false),
         ("schema_name", DataType::Utf8, false),
         ("builtin", DataType::Boolean, false),
+        ("comment", DataType::Utf8, true),
     ]),
     oid: 16404,
 });
@@ -156,6 +163,7 @@ pub static GLARE_TABLES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         ("external", DataType::Boolean, false),
         ("datasource", DataType::Utf8, false),
         ("access_mode", DataType::Utf8, false), // `SourceAccessMode::as_str()`
+        ("comment", DataType::Utf8, true),
     ]),
     oid: 16405,
 });
@@ -171,6 +179,7 @@ pub static GLARE_VIEWS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         ("view_name", DataType::Utf8, false),
         ("builtin", DataType::Boolean, false),
         ("sql", DataType::Utf8, false),
+        ("comment", DataType::Utf8, true),
     ]),
     oid: 16406,
 });
@@ -186,6 +195,7 @@ pub static GLARE_COLUMNS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         ("column_ordinal", DataType::UInt32, false),
         ("data_type", DataType::Utf8, false),
         ("is_nullable", DataType::Boolean, false),
+        ("comment", DataType::Utf8, true),
     ]),
     oid: 16407,
 });
@@ -254,6 +264,115 @@ pub static GLARE_CACHED_EXTERNAL_DATABASE_TABLES: Lazy<BuiltinTable> = Lazy::new
     oid: 16411,
 });
 
+pub static GLARE_ROLES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    schema: INTERNAL_SCHEMA,
+    name: "roles",
+    columns: InternalColumnDefinition::from_tuples([
+        ("oid", DataType::UInt32, false),
+        ("role_name", DataType::Utf8, false),
+        ("builtin", DataType::Boolean, false),
+        ("superuser", DataType::Boolean, false),
+        ("login", DataType::Boolean, false),
+        ("has_password", DataType::Boolean, false),
+        (
+            "member_of",
+            DataType::List(Arc::new(ArrowField::new("item", DataType::Utf8, true))),
+            false,
+        ),
+    ]),
+    oid: 16412,
+});
+
+/// Privileges granted directly to roles.
+pub static GLARE_PRIVILEGES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    schema: INTERNAL_SCHEMA,
+    name: "privileges",
+    columns: InternalColumnDefinition::from_tuples([
+        ("role_oid", DataType::UInt32, false),
+        ("role_name", DataType::Utf8, false),
+        ("object_oid", DataType::UInt32, false),
+        ("object_type", DataType::Utf8, true),
+        ("schema_name", DataType::Utf8, true),
+        ("object_name", DataType::Utf8, true),
+        ("privilege", DataType::Utf8, false), // `Privilege::as_str()`
+    ]),
+    oid: 16413,
+});
+
+/// Recently executed queries, kept in memory by the engine.
+pub static GLARE_QUERY_HISTORY: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    schema: INTERNAL_SCHEMA,
+    name: "query_history",
+    columns: InternalColumnDefinition::from_tuples([
+        ("session_id", DataType::Utf8, false),
+        ("database_id", DataType::Utf8, false),
+        ("user_name", DataType::Utf8, false),
+        ("query_text", DataType::Utf8, false),
+        ("result_type", DataType::Utf8, false),
+        ("execution_status", DataType::Utf8, false), // `ExecutionStatus::as_str()`
+        ("error_message", DataType::Utf8, true),
+        (
+            "start_time",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            false,
+        ),
+        (
+            "end_time",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            false,
+        ),
+        ("elapsed_compute_ns", DataType::UInt64, true),
+        ("output_rows", DataType::UInt64, true),
+        ("bytes_read", DataType::UInt64, true),
+        ("bytes_written", DataType::UInt64, true),
+    ]),
+    oid: 16414,
+});
+
+/// Column statistics collected by `ANALYZE`.
+pub static GLARE_TABLE_STATISTICS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    schema: INTERNAL_SCHEMA,
+    name: "table_statistics",
+    columns: InternalColumnDefinition::from_tuples([
+        ("table_oid", DataType::UInt32, false),
+        ("schema_name", DataType::Utf8, false),
+        ("table_name", DataType::Utf8, false),
+        ("num_rows", DataType::UInt64, false),
+        ("column_name", DataType::Utf8, false),
+        ("null_count", DataType::UInt64, false),
+        ("min_value", DataType::Utf8, true),
+        ("max_value", DataType::Utf8, true),
+        ("distinct_count", DataType::UInt64, true),
+        (
+            "analyzed_at",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            false,
+        ),
+    ]),
+    oid: 16415,
+});
+
+/// Retained versions of the database catalog.
+pub static GLARE_CATALOG_HISTORY: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    schema: INTERNAL_SCHEMA,
+    name: "catalog_history",
+    columns: InternalColumnDefinition::from_tuples([
+        ("version", DataType::UInt64, false),
+        (
+            "committed_at",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            false,
+        ),
+        (
+            "mutations",
+            DataType::List(Arc::new(ArrowField::new("item", DataType::Utf8, true))),
+            false,
+        ),
+        ("process_id", DataType::Utf8, false),
+    ]),
+    oid: 16416,
+});
+
 impl BuiltinTable {
     /// Check if this table matches the provided schema and name.
     pub fn matches(&self, schema: &str, name: &str) -> bool {
@@ -284,6 +403,11 @@ impl BuiltinTable {
             &GLARE_SSH_KEYS,
             &GLARE_DEPLOYMENT_METADATA,
             &GLARE_CACHED_EXTERNAL_DATABASE_TABLES,
+            &GLARE_ROLES,
+            &GLARE_PRIVILEGES,
+            &GLARE_QUERY_HISTORY,
+            &GLARE_TABLE_STATISTICS,
+            &GLARE_CATALOG_HISTORY,
         ]
     }
 }
@@ -401,7 +525,8 @@ FROM (
         null AS user_defined_type_name,
         'NO' AS is_insertable_into,
         'NO' AS is_typed,
-        null AS commit_action
+        null AS commit_action,
+        t.comment AS table_comment
     FROM glare_catalog.tables t INNER JOIN glare_catalog.databases d ON t.database_oid = d.oid
     UNION ALL
     SELECT
@@ -416,7 +541,8 @@ FROM (
         null AS user_defined_type_name,
         'NO' AS is_insertable_into,
         'NO' AS is_typed,
-        null AS commit_action
+        null AS commit_action,
+        v.comment AS table_comment
     FROM glare_catalog.views v INNER JOIN glare_catalog.databases d ON v.database_oid = d.oid
 )",
 });
@@ -468,7 +594,8 @@ SELECT
     null AS identity_cycle,
     null AS is_generated,
     null AS generation_expression,
-    'NO' AS is_updatable
+    'NO' AS is_updatable,
+    c.comment AS column_comment
 FROM glare_catalog.columns c
 INNER JOIN glare_catalog.schemas s ON c.schema_oid = s.oid
 INNER JOIN glare_catalog.databases d ON s.database_oid = d.oid
@@ -581,12 +708,20 @@ pub static PG_DESCRIPTION: Lazy<BuiltinView> = Lazy::new(|| BuiltinView {
     schema: POSTGRES_SCHEMA,
     name: "pg_description",
     sql: "
-SELECT
-    1 AS objoid,
-    2 AS classoid,
-    3 AS objsubid,
-    4 AS description
-FROM (VALUES (NULL, NULL, NULL, NULL)) WHERE false", // Create empty table for now.
+SELECT oid AS objoid, 1262 AS classoid, 0::int AS objsubid, comment AS description
+FROM glare_catalog.databases WHERE comment IS NOT NULL
+UNION ALL
+SELECT oid, 2615, 0::int, comment
+FROM glare_catalog.schemas WHERE comment IS NOT NULL
+UNION ALL
+SELECT oid, 1259, 0::int, comment
+FROM glare_catalog.tables WHERE comment IS NOT NULL
+UNION ALL
+SELECT oid, 1259, 0::int, comment
+FROM glare_catalog.views WHERE comment IS NOT NULL
+UNION ALL
+SELECT table_oid, 1259, (column_ordinal + 1)::int, comment
+FROM glare_catalog.columns WHERE comment IS NOT NULL",
 });
 
 pub static PG_DATABASE: Lazy<BuiltinView> = Lazy::new(|| BuiltinView {
@@ -614,6 +749,28 @@ FROM glare_catalog.databases;
 ",
 });
 
+pub static PG_ROLES: Lazy<BuiltinView> = Lazy::new(|| BuiltinView {
+    schema: POSTGRES_SCHEMA,
+    name: "pg_roles",
+    sql: "
+SELECT
+    role_name as rolname,
+    superuser as rolsuper,
+    true as rolinherit,
+    superuser as rolcreaterole,
+    superuser as rolcreatedb,
+    login as rolcanlogin,
+    false as rolreplication,
+    -1 as rolconnlimit,
+    '********' as rolpassword,
+    null as rolvaliduntil,
+    superuser as rolbypassrls,
+    null as rolconfig,
+    oid as oid
+FROM glare_catalog.roles;
+",
+});
+
 pub static PG_TABLES: Lazy<BuiltinView> = Lazy::new(|| BuiltinView {
     schema: POSTGRES_SCHEMA,
     name: "pg_tables",
@@ -747,6 +904,7 @@ impl BuiltinView {
             &PG_NAMESPACE,
             &PG_DESCRIPTION,
             &PG_DATABASE,
+            &PG_ROLES,
             &PG_TABLES,
             &PG_VIEWS,
             &PG_TYPE,
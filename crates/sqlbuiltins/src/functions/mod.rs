@@ -22,6 +22,7 @@ use scalars::hashing::{FnvHash, PartitionResults, SipHash};
 use scalars::jaq::{JAQMatches, JAQSelect};
 use scalars::kdl::{KDLMatches, KDLSelect};
 use scalars::postgres::{
+    ColDescription,
     CurrentCatalog,
     CurrentDatabase,
     CurrentRole,
@@ -32,6 +33,7 @@ use scalars::postgres::{
     HasDatabasePrivilege,
     HasSchemaPrivilege,
     HasTablePrivilege,
+    ObjDescription,
     PgArrayToString,
     PgEncodingToChar,
     PgGetUserById,
@@ -241,6 +243,8 @@ impl FunctionRegistry {
             Arc::new(PgArrayToString),
             Arc::new(PgVersion),
             Arc::new(FormatType),
+            Arc::new(ObjDescription),
+            Arc::new(ColDescription),
             // System functions
             Arc::new(ConnectionId),
             Arc::new(Version),
@@ -6,6 +6,7 @@ pub mod kdl;
 pub mod openai;
 pub mod postgres;
 pub mod similarity;
+pub mod user_defined;
 use std::sync::Arc;
 
 use datafusion::arrow::array::Array;
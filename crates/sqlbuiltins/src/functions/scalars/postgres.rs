@@ -1,3 +1,4 @@
+use std::str::FromStr;
 use std::sync::Arc;
 
 use catalog::session_catalog::SessionCatalog;
@@ -16,10 +17,11 @@ use datafusion::physical_plan::ColumnarValue;
 use datafusion::prelude::Expr;
 use datafusion::scalar::ScalarValue;
 use pgrepr::compatible::server_version_with_build_info;
-use protogen::metastore::types::catalog::FunctionType;
+use protogen::metastore::types::catalog::{CatalogEntry, FunctionType, Privilege};
 
 use super::df_scalars::array_to_string;
 use super::{get_nth_scalar_value, session_var};
+use crate::builtins::{DEFAULT_CATALOG, DEFAULT_SCHEMA};
 use crate::errors::BuiltinError;
 use crate::functions::{BuiltinScalarUDF, ConstBuiltinFunction, FunctionNamespace};
 
@@ -183,10 +185,19 @@ impl ConstBuiltinFunction for HasSchemaPrivilege {
 }
 
 impl BuiltinScalarUDF for HasSchemaPrivilege {
-    fn try_as_expr(&self, _: &SessionCatalog, args: Vec<Expr>) -> DataFusionResult<Expr> {
+    fn try_as_expr(&self, catalog: &SessionCatalog, args: Vec<Expr>) -> DataFusionResult<Expr> {
         let return_type_fn: ReturnTypeFunction = Arc::new(|_| Ok(Arc::new(DataType::Boolean)));
-        let scalar_fn_impl: ScalarFunctionImplementation =
-            Arc::new(move |_input| Ok(ColumnarValue::Scalar(ScalarValue::Boolean(Some(true)))));
+        let catalog = catalog.clone();
+        let scalar_fn_impl: ScalarFunctionImplementation = Arc::new(move |input| {
+            Ok(eval_privilege_check(input, &|user, schema, privileges| {
+                let ent = catalog.resolve_schema(schema).ok_or_else(|| {
+                    BuiltinError::InvalidValue(format!("schema \"{schema}\" does not exist"))
+                })?;
+                Ok(parse_privileges(privileges)?.into_iter().any(|p| {
+                    catalog.role_has_privilege(privilege_role(&catalog, user), &ent.meta, p)
+                }))
+            })?)
+        });
         let udf = ScalarUDF::new(
             Self::NAME,
             &ConstBuiltinFunction::signature(self).unwrap(),
@@ -195,7 +206,7 @@ impl BuiltinScalarUDF for HasSchemaPrivilege {
         );
         Ok(Expr::ScalarFunction(ScalarFunction::new_udf(
             Arc::new(udf),
-            args,
+            with_current_user(args),
         )))
     }
 
@@ -264,10 +275,31 @@ impl ConstBuiltinFunction for HasTablePrivilege {
 }
 
 impl BuiltinScalarUDF for HasTablePrivilege {
-    fn try_as_expr(&self, _: &SessionCatalog, args: Vec<Expr>) -> DataFusionResult<Expr> {
+    fn try_as_expr(&self, catalog: &SessionCatalog, args: Vec<Expr>) -> DataFusionResult<Expr> {
         let return_type_fn: ReturnTypeFunction = Arc::new(|_| Ok(Arc::new(DataType::Boolean)));
-        let scalar_fn_impl: ScalarFunctionImplementation =
-            Arc::new(move |_| Ok(ColumnarValue::Scalar(ScalarValue::Boolean(Some(true)))));
+        let catalog = catalog.clone();
+        let scalar_fn_impl: ScalarFunctionImplementation = Arc::new(move |input| {
+            Ok(eval_privilege_check(input, &|user, table, privileges| {
+                let parts = parse_qualified_name(table);
+                let (schema, name) = match parts.as_slice() {
+                    [name] => (DEFAULT_SCHEMA, name.as_str()),
+                    [schema, name] => (schema.as_str(), name.as_str()),
+                    _ => {
+                        return Err(BuiltinError::InvalidValue(format!(
+                            "invalid relation name: {table}"
+                        )))
+                    }
+                };
+                let ent = catalog
+                    .resolve_entry(DEFAULT_CATALOG, schema, name)
+                    .ok_or_else(|| {
+                        BuiltinError::InvalidValue(format!("relation \"{table}\" does not exist"))
+                    })?;
+                Ok(parse_privileges(privileges)?.into_iter().any(|p| {
+                    catalog.role_has_privilege(privilege_role(&catalog, user), ent.get_meta(), p)
+                }))
+            })?)
+        });
         let udf = ScalarUDF::new(
             Self::NAME,
             &ConstBuiltinFunction::signature(self).unwrap(),
@@ -276,7 +308,7 @@ impl BuiltinScalarUDF for HasTablePrivilege {
         );
         Ok(Expr::ScalarFunction(ScalarFunction::new_udf(
             Arc::new(udf),
-            args,
+            with_current_user(args),
         )))
     }
 
@@ -561,3 +593,293 @@ impl BuiltinScalarUDF for FormatType {
         Ok(Expr::Literal(ScalarValue::Null))
     }
 }
+
+#[derive(Clone, Copy, Debug)]
+pub struct ObjDescription;
+
+impl ConstBuiltinFunction for ObjDescription {
+    const NAME: &'static str = "obj_description";
+    const DESCRIPTION: &'static str =
+        "Returns the comment for a database object, optionally checking the catalog it belongs to";
+    const EXAMPLE: &'static str = "obj_description(16385, 'pg_class')";
+    const FUNCTION_TYPE: FunctionType = FunctionType::Scalar;
+    fn signature(&self) -> Option<Signature> {
+        Some(Signature::new(
+            TypeSignature::OneOf(vec![
+                TypeSignature::Exact(vec![DataType::Int64]),
+                TypeSignature::Exact(vec![DataType::Int64, DataType::Utf8]),
+            ]),
+            Volatility::Stable,
+        ))
+    }
+}
+
+impl BuiltinScalarUDF for ObjDescription {
+    fn try_as_expr(&self, catalog: &SessionCatalog, args: Vec<Expr>) -> DataFusionResult<Expr> {
+        let return_type_fn: ReturnTypeFunction = Arc::new(|_| Ok(Arc::new(DataType::Utf8)));
+        let catalog = catalog.clone();
+        let scalar_fn_impl: ScalarFunctionImplementation = Arc::new(move |input| {
+            Ok(eval_description(input, &|args| {
+                let ent = match args.first().and_then(scalar_to_oid) {
+                    Some(oid) => catalog.get_by_oid(oid),
+                    None => None,
+                };
+                let ent = match ent {
+                    Some(ent) => ent,
+                    None => return Ok(None),
+                };
+
+                // Mirror postgres in only returning the comment if the object
+                // lives in the requested catalog.
+                let in_catalog = match args.get(1) {
+                    Some(ScalarValue::Utf8(Some(catalog_name))) => match catalog_name.as_str() {
+                        "pg_class" => {
+                            matches!(ent, CatalogEntry::Table(_) | CatalogEntry::View(_))
+                        }
+                        "pg_namespace" => matches!(ent, CatalogEntry::Schema(_)),
+                        "pg_database" => matches!(ent, CatalogEntry::Database(_)),
+                        _ => false,
+                    },
+                    Some(ScalarValue::Utf8(None)) => false,
+                    _ => true,
+                };
+
+                Ok(in_catalog.then(|| ent.get_meta().comment.clone()).flatten())
+            })?)
+        });
+        let udf = ScalarUDF::new(
+            Self::NAME,
+            &ConstBuiltinFunction::signature(self).unwrap(),
+            &return_type_fn,
+            &scalar_fn_impl,
+        );
+        Ok(Expr::ScalarFunction(ScalarFunction::new_udf(
+            Arc::new(udf),
+            args,
+        )))
+    }
+
+    fn namespace(&self) -> FunctionNamespace {
+        PG_CATALOG_NAMESPACE
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ColDescription;
+
+impl ConstBuiltinFunction for ColDescription {
+    const NAME: &'static str = "col_description";
+    const DESCRIPTION: &'static str =
+        "Returns the comment for a table column, identified by the table oid and column number";
+    const EXAMPLE: &'static str = "col_description(16385, 1)";
+    const FUNCTION_TYPE: FunctionType = FunctionType::Scalar;
+    fn signature(&self) -> Option<Signature> {
+        Some(Signature::exact(
+            vec![DataType::Int64, DataType::Int64],
+            Volatility::Stable,
+        ))
+    }
+}
+
+impl BuiltinScalarUDF for ColDescription {
+    fn try_as_expr(&self, catalog: &SessionCatalog, args: Vec<Expr>) -> DataFusionResult<Expr> {
+        let return_type_fn: ReturnTypeFunction = Arc::new(|_| Ok(Arc::new(DataType::Utf8)));
+        let catalog = catalog.clone();
+        let scalar_fn_impl: ScalarFunctionImplementation = Arc::new(move |input| {
+            Ok(eval_description(input, &|args| {
+                let table = match args.first().and_then(scalar_to_oid) {
+                    Some(oid) => catalog.get_by_oid(oid),
+                    None => None,
+                };
+                let table = match table {
+                    Some(CatalogEntry::Table(table)) => table,
+                    _ => return Ok(None),
+                };
+
+                // Column numbers start at 1.
+                let column = match args.get(1).and_then(scalar_to_oid) {
+                    Some(num) if num > 0 => table
+                        .get_internal_columns()
+                        .and_then(|cols| cols.into_iter().nth(num as usize - 1)),
+                    _ => None,
+                };
+
+                Ok(column.and_then(|col| table.column_comments.get(&col.name).cloned()))
+            })?)
+        });
+        let udf = ScalarUDF::new(
+            Self::NAME,
+            &ConstBuiltinFunction::signature(self).unwrap(),
+            &return_type_fn,
+            &scalar_fn_impl,
+        );
+        Ok(Expr::ScalarFunction(ScalarFunction::new_udf(
+            Arc::new(udf),
+            args,
+        )))
+    }
+
+    fn namespace(&self) -> FunctionNamespace {
+        PG_CATALOG_NAMESPACE
+    }
+}
+
+/// Get an oid (or other small non-negative integer) from a scalar argument.
+fn scalar_to_oid(value: &ScalarValue) -> Option<u32> {
+    match value {
+        ScalarValue::Int64(Some(v)) => u32::try_from(*v).ok(),
+        ScalarValue::Int32(Some(v)) => u32::try_from(*v).ok(),
+        ScalarValue::UInt64(Some(v)) => u32::try_from(*v).ok(),
+        ScalarValue::UInt32(Some(v)) => Some(*v),
+        _ => None,
+    }
+}
+
+/// Evaluate a description lookup for every row of the inputs.
+fn eval_description(
+    input: &[ColumnarValue],
+    describe: &dyn Fn(&[ScalarValue]) -> Result<Option<String>, BuiltinError>,
+) -> Result<ColumnarValue, BuiltinError> {
+    let eval_row = |row: usize| -> Result<ScalarValue, BuiltinError> {
+        let args = input
+            .iter()
+            .map(|value| match value {
+                ColumnarValue::Scalar(scalar) => Ok(scalar.clone()),
+                ColumnarValue::Array(arr) => ScalarValue::try_from_array(arr, row),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ScalarValue::Utf8(describe(&args)?))
+    };
+
+    let num_rows = input.iter().find_map(|value| match value {
+        ColumnarValue::Array(arr) => Some(arr.len()),
+        ColumnarValue::Scalar(_) => None,
+    });
+
+    match num_rows {
+        Some(num_rows) => {
+            let values = (0..num_rows).map(eval_row).collect::<Result<Vec<_>, _>>()?;
+            Ok(ColumnarValue::Array(ScalarValue::iter_to_array(values)?))
+        }
+        None => Ok(ColumnarValue::Scalar(eval_row(0)?)),
+    }
+}
+
+/// Prepend the current user to the arguments of a privilege function if the
+/// user was omitted.
+fn with_current_user(mut args: Vec<Expr>) -> Vec<Expr> {
+    if args.len() == 2 {
+        args.insert(0, session_var("current_user"));
+    }
+    args
+}
+
+/// Get the role to check privileges against for a user.
+///
+/// Users without a role in the catalog can only connect through the server's
+/// configured authenticator, and aren't subject to privilege checks.
+fn privilege_role<'a>(catalog: &SessionCatalog, user: &'a str) -> Option<&'a str> {
+    catalog.resolve_role(user).map(|_| user)
+}
+
+/// Split a possibly qualified and quoted relation name into its parts.
+///
+/// Unquoted parts are lowercased, quoted parts are kept as is (with doubled
+/// quotes unescaped), e.g. `"MySchema".Table` becomes `["MySchema", "table"]`.
+pub(crate) fn parse_qualified_name(s: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut chars = s.trim().chars().peekable();
+    let mut quoted = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                current.push('"');
+            }
+            '"' => quoted = !quoted,
+            '.' if !quoted => parts.push(std::mem::take(&mut current)),
+            c if quoted => current.push(c),
+            c => current.extend(c.to_lowercase()),
+        }
+    }
+    parts.push(current);
+    parts
+}
+
+/// Parse a Postgres privilege string, e.g. "SELECT, INSERT WITH GRANT OPTION".
+fn parse_privileges(s: &str) -> Result<Vec<Privilege>, BuiltinError> {
+    s.split(',')
+        .map(|p| {
+            let upper = p.trim().to_uppercase();
+            let p = upper
+                .strip_suffix("WITH GRANT OPTION")
+                .unwrap_or(&upper)
+                .trim();
+            Privilege::from_str(p).map_err(|_| {
+                BuiltinError::InvalidValue(format!("unrecognized privilege type: {p}"))
+            })
+        })
+        .collect()
+}
+
+/// Evaluate a privilege check for every row of (user, object, privileges)
+/// string inputs.
+///
+/// Rows containing a null produce a null.
+fn eval_privilege_check(
+    input: &[ColumnarValue],
+    check: &dyn Fn(&str, &str, &str) -> Result<bool, BuiltinError>,
+) -> Result<ColumnarValue, BuiltinError> {
+    let get = |idx: usize, row: usize| -> Result<Option<String>, BuiltinError> {
+        let value = match input.get(idx) {
+            Some(ColumnarValue::Scalar(scalar)) => scalar.clone(),
+            Some(ColumnarValue::Array(arr)) => ScalarValue::try_from_array(arr, row)?,
+            None => return Err(BuiltinError::MissingValueAtIndex(idx)),
+        };
+        match value {
+            ScalarValue::Utf8(v) | ScalarValue::LargeUtf8(v) => Ok(v),
+            other => Err(BuiltinError::IncorrectType(
+                other.data_type(),
+                DataType::Utf8,
+            )),
+        }
+    };
+
+    let eval_row = |row: usize| -> Result<ScalarValue, BuiltinError> {
+        Ok(match (get(0, row)?, get(1, row)?, get(2, row)?) {
+            (Some(user), Some(object), Some(privileges)) => {
+                ScalarValue::Boolean(Some(check(&user, &object, &privileges)?))
+            }
+            _ => ScalarValue::Boolean(None),
+        })
+    };
+
+    let num_rows = input.iter().find_map(|value| match value {
+        ColumnarValue::Array(arr) => Some(arr.len()),
+        ColumnarValue::Scalar(_) => None,
+    });
+
+    match num_rows {
+        Some(num_rows) => {
+            let values = (0..num_rows).map(eval_row).collect::<Result<Vec<_>, _>>()?;
+            Ok(ColumnarValue::Array(ScalarValue::iter_to_array(values)?))
+        }
+        None => Ok(ColumnarValue::Scalar(eval_row(0)?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qualified_name_parts() {
+        assert_eq!(vec!["t1"], parse_qualified_name("T1"));
+        assert_eq!(vec!["public", "t1"], parse_qualified_name("public.t1"));
+        assert_eq!(
+            vec!["My.Schema", "ta\"ble"],
+            parse_qualified_name("\"My.Schema\".\"ta\"\"ble\"")
+        );
+    }
+}
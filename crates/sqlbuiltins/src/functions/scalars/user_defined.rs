@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use datafusion::logical_expr::expr::ScalarFunction;
+use datafusion::logical_expr::{Expr, ScalarUDF, Signature};
+use protogen::metastore::types::catalog::FunctionType;
+
+use crate::functions::{BuiltinFunction, BuiltinScalarUDF};
+
+/// A scalar function defined by a user of the database, e.g. through one of
+/// the language bindings.
+///
+/// Wraps a fully formed DataFusion UDF, the function is planned as-is.
+pub struct UserDefinedScalarUDF {
+    udf: Arc<ScalarUDF>,
+}
+
+impl UserDefinedScalarUDF {
+    pub fn new(udf: ScalarUDF) -> Self {
+        UserDefinedScalarUDF { udf: Arc::new(udf) }
+    }
+}
+
+impl BuiltinFunction for UserDefinedScalarUDF {
+    fn name(&self) -> &str {
+        self.udf.name()
+    }
+
+    fn signature(&self) -> Option<Signature> {
+        Some(self.udf.signature().clone())
+    }
+
+    fn function_type(&self) -> FunctionType {
+        FunctionType::Scalar
+    }
+}
+
+impl BuiltinScalarUDF for UserDefinedScalarUDF {
+    fn try_as_expr(
+        &self,
+        _: &catalog::session_catalog::SessionCatalog,
+        args: Vec<Expr>,
+    ) -> datafusion::error::Result<Expr> {
+        Ok(Expr::ScalarFunction(ScalarFunction::new_udf(
+            self.udf.clone(),
+            args,
+        )))
+    }
+}
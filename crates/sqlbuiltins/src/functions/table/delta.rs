@@ -4,8 +4,8 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use datafusion::datasource::TableProvider;
 use datafusion_ext::errors::{ExtensionError, Result};
-use datafusion_ext::functions::{FuncParamValue, TableFuncContextProvider};
-use datasources::lake::delta::access::load_table_direct;
+use datafusion_ext::functions::{FuncParamValue, TableFuncContextProvider, TableVersion};
+use datasources::lake::delta::access::{load_table_direct, load_table_direct_at_version};
 use protogen::metastore::types::catalog::{FunctionType, RuntimePreference};
 
 use super::{table_location_and_opts, TableFunc};
@@ -18,6 +18,9 @@ use crate::functions::ConstBuiltinFunction;
 /// responsible for providing credentials, then it's responsible for creating
 /// the store.
 ///
+/// A previous version of the table can be read by providing either `version`
+/// or `as_of` as a named argument.
+///
 /// See <https://github.com/delta-io/delta-rs/issues/1521>
 #[derive(Debug, Clone, Copy)]
 pub struct DeltaScan;
@@ -47,11 +50,17 @@ impl TableFunc for DeltaScan {
         args: Vec<FuncParamValue>,
         mut opts: HashMap<String, FuncParamValue>,
     ) -> Result<Arc<dyn TableProvider>> {
+        let version = TableVersion::take_from_opts(&mut opts)?;
         let (source_url, storage_options) = table_location_and_opts(ctx, args, &mut opts)?;
 
-        let table = load_table_direct(&source_url.to_string(), storage_options)
-            .await
-            .map_err(|e| ExtensionError::Access(Box::new(e)))?;
+        let table = match version {
+            Some(version) => {
+                load_table_direct_at_version(&source_url.to_string(), storage_options, version)
+                    .await
+            }
+            None => load_table_direct(&source_url.to_string(), storage_options).await,
+        }
+        .map_err(|e| ExtensionError::Access(Box::new(e)))?;
 
         Ok(Arc::new(table))
     }
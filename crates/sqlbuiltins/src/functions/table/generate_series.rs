@@ -58,6 +58,10 @@ impl TableFunc for GenerateSeries {
         })
     }
 
+    fn reads_external_data(&self) -> bool {
+        false
+    }
+
     async fn create_provider(
         &self,
         _: &dyn TableFuncContextProvider,
@@ -21,6 +21,7 @@ mod snowflake;
 mod sqlite;
 mod sqlserver;
 pub mod system;
+mod table_history;
 mod virtual_listing;
 
 use std::collections::HashMap;
@@ -60,6 +61,7 @@ use self::snowflake::ReadSnowflake;
 use self::sqlite::ReadSqlite;
 use self::sqlserver::ReadSqlServer;
 use self::system::cache_external_tables::CacheExternalDatabaseTables;
+use self::table_history::TableHistory;
 use self::virtual_listing::{ListColumns, ListSchemas, ListTables};
 use crate::functions::alias_map::AliasMap;
 use crate::functions::BuiltinFunction;
@@ -84,6 +86,13 @@ pub trait TableFunc: BuiltinFunction {
         args: Vec<FuncParamValue>,
         opts: HashMap<String, FuncParamValue>,
     ) -> Result<Arc<dyn TableProvider>>;
+
+    /// Whether the function reads data from outside the catalog (files,
+    /// object stores, external databases). Only superusers may call such
+    /// functions since they bypass table privileges.
+    fn reads_external_data(&self) -> bool {
+        true
+    }
 }
 
 /// All builtin table functions.
@@ -130,6 +139,7 @@ impl BuiltinTableFuncs {
             Arc::new(CacheExternalDatabaseTables),
             // Metadata functions
             Arc::new(ParquetMetadataFunc),
+            Arc::new(TableHistory),
         ];
 
         let funcs: AliasMap<String, Arc<dyn TableFunc>> = funcs
@@ -764,11 +764,15 @@ async fn object_store_table_from_file_format(
         .infer_schema(&session_state, &store.inner, &objects)
         .await?;
 
-    Ok(Arc::new(ObjStoreTableProvider::new(
+    let provider = ObjStoreTableProvider::new(
         store.inner.clone(),
         arrow_schema,
         base_url,
         objects,
         file_format,
-    )))
+    )
+    .with_file_statistics(&session_state)
+    .await?;
+
+    Ok(Arc::new(provider))
 }
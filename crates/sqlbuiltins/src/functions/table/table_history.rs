@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::arrow::array::{
+    Int64Builder,
+    StringBuilder,
+    TimestampMillisecondBuilder,
+    UInt64Builder,
+};
+use datafusion::arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::{MemTable, TableProvider};
+use datafusion_ext::errors::{ExtensionError, Result};
+use datafusion_ext::functions::{FuncParamValue, TableFuncContextProvider};
+use datasources::native::access::NativeTableStorage;
+use protogen::metastore::types::catalog::{FunctionType, Privilege, RuntimePreference, TableEntry};
+
+use crate::builtins::DEFAULT_CATALOG;
+use crate::functions::scalars::postgres::parse_qualified_name;
+use crate::functions::table::TableFunc;
+use crate::functions::ConstBuiltinFunction;
+
+/// List the commits made to a native table, newest first.
+///
+/// Any version listed can be read with `SELECT ... FROM t AS OF VERSION n`.
+#[derive(Debug, Clone, Copy)]
+pub struct TableHistory;
+
+impl ConstBuiltinFunction for TableHistory {
+    const NAME: &'static str = "table_history";
+    const DESCRIPTION: &'static str = "Lists the commits made to a native table, newest first";
+    const EXAMPLE: &'static str = "SELECT * FROM table_history('public.my_table')";
+    const FUNCTION_TYPE: FunctionType = FunctionType::TableReturning;
+}
+
+#[async_trait]
+impl TableFunc for TableHistory {
+    fn detect_runtime(
+        &self,
+        _args: &[FuncParamValue],
+        _parent: RuntimePreference,
+    ) -> Result<RuntimePreference> {
+        // Native tables are stored wherever the catalog lives.
+        Ok(RuntimePreference::Remote)
+    }
+
+    fn reads_external_data(&self) -> bool {
+        // Privileges on the table are checked when resolving it.
+        false
+    }
+
+    async fn create_provider(
+        &self,
+        ctx: &dyn TableFuncContextProvider,
+        args: Vec<FuncParamValue>,
+        mut opts: HashMap<String, FuncParamValue>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        if args.len() != 1 {
+            return Err(ExtensionError::InvalidNumArgs);
+        }
+        let name: String = args.into_iter().next().unwrap().try_into()?;
+        let limit: Option<usize> = opts.remove("limit").map(TryInto::try_into).transpose()?;
+
+        let table = resolve_table(ctx, &name)?;
+
+        let storage = ctx
+            .get_session_state()
+            .config()
+            .get_extension::<NativeTableStorage>()
+            .ok_or_else(|| {
+                ExtensionError::String("Native table storage not available".to_string())
+            })?;
+        let commits = storage
+            .table_history(&table, limit)
+            .await
+            .map_err(ExtensionError::access)?;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("version", DataType::Int64, false),
+            Field::new(
+                "timestamp",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+                true,
+            ),
+            Field::new("operation", DataType::Utf8, true),
+            Field::new("operation_parameters", DataType::Utf8, true),
+            Field::new("files_added", DataType::UInt64, false),
+            Field::new("files_removed", DataType::UInt64, false),
+            Field::new("rows_added", DataType::UInt64, true),
+        ]));
+
+        let mut version = Int64Builder::new();
+        let mut timestamp = TimestampMillisecondBuilder::new().with_timezone("UTC");
+        let mut operation = StringBuilder::new();
+        let mut operation_parameters = StringBuilder::new();
+        let mut files_added = UInt64Builder::new();
+        let mut files_removed = UInt64Builder::new();
+        let mut rows_added = UInt64Builder::new();
+
+        for commit in commits {
+            version.append_value(commit.version);
+            timestamp.append_option(commit.timestamp);
+            operation.append_option(commit.operation);
+            operation_parameters.append_option(commit.operation_parameters);
+            files_added.append_value(commit.files_added);
+            files_removed.append_value(commit.files_removed);
+            rows_added.append_option(commit.rows_added);
+        }
+
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(version.finish()),
+                Arc::new(timestamp.finish()),
+                Arc::new(operation.finish()),
+                Arc::new(operation_parameters.finish()),
+                Arc::new(files_added.finish()),
+                Arc::new(files_removed.finish()),
+                Arc::new(rows_added.finish()),
+            ],
+        )?;
+
+        Ok(Arc::new(MemTable::try_new(schema, vec![vec![batch]])?))
+    }
+}
+
+/// Resolve a possibly qualified table name using the session's search path.
+///
+/// The session's role must be able to select from the table.
+fn resolve_table(ctx: &dyn TableFuncContextProvider, name: &str) -> Result<TableEntry> {
+    let catalog = ctx.get_session_catalog();
+    let vars = ctx.get_session_vars();
+
+    let table = match parse_qualified_name(name).as_slice() {
+        [table] => vars
+            .implicit_search_path_iter()
+            .find_map(|schema| catalog.resolve_table(DEFAULT_CATALOG, &schema, table)),
+        [schema, table] => catalog.resolve_table(DEFAULT_CATALOG, schema, table),
+        _ => {
+            return Err(ExtensionError::InvalidParamValue {
+                param: name.to_string(),
+                expected: "table name",
+            })
+        }
+    }
+    .ok_or_else(|| ExtensionError::MissingObject {
+        obj_typ: "table",
+        name: name.to_string(),
+    })?;
+
+    let role = vars.catalog_role();
+    if !catalog.role_has_privilege(role.as_deref(), &table.meta, Privilege::Select) {
+        return Err(ExtensionError::String(format!(
+            "Permission denied for role '{}': SELECT on '{}'",
+            vars.user_name(),
+            table.meta.name
+        )));
+    }
+
+    Ok(table.clone())
+}
@@ -0,0 +1,322 @@
+//! Backups of a database's catalog and native tables.
+//!
+//! A backup is laid out the same way as the storage of a local persistent
+//! instance, with a manifest at the root describing what it contains. The
+//! catalog is written at a single version, and each native table is copied at
+//! the version it had when the backup was taken.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use datasources::native::access::NativeTableStorage;
+use futures::StreamExt;
+use object_store::path::Path as ObjectPath;
+use object_store::ObjectStore;
+use object_store_util::shared::SharedObjectStore;
+use protogen::metastore::types::catalog::{CatalogEntry, CatalogState, TableEntry};
+use protogen::metastore::types::options::TableOptionsV0;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::engine::EngineStorageConfig;
+use crate::errors::{ExecError, Result};
+
+/// Path of the manifest relative to the root of a backup.
+///
+/// The manifest is written last, a backup without one is incomplete.
+pub const BACKUP_MANIFEST_PATH: &str = "backup.json";
+
+/// Describes the contents of a backup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// Database the backup was taken from.
+    pub db_id: String,
+    /// Version of the catalog in the backup.
+    pub catalog_version: u64,
+    /// Version of each native table in the backup, keyed by table oid.
+    pub tables: BTreeMap<u32, i64>,
+    /// Milliseconds since the epoch.
+    pub created_at: i64,
+}
+
+/// Open the object store for a backup location (a local path or object store
+/// URL).
+pub fn open_backup_location(
+    location: &str,
+    options: HashMap<String, String>,
+) -> Result<Arc<dyn ObjectStore>> {
+    let conf = EngineStorageConfig::try_from_options(location, options)?;
+    // Wrap up the store so that initializing the catalog lease works on
+    // stores without an atomic copy-if-not-exists.
+    Ok(Arc::new(SharedObjectStore::new(conf.new_object_store()?)))
+}
+
+/// Write a backup of a database to an empty location.
+///
+/// The catalog is written as of `state`. Native tables in the catalog
+/// (including dropped tables that can still be undropped) are copied at their
+/// latest version.
+pub async fn backup_database(
+    state: &CatalogState,
+    native: &NativeTableStorage,
+    dest: Arc<dyn ObjectStore>,
+) -> Result<BackupManifest> {
+    if dest.list(None).next().await.is_some() {
+        return Err(ExecError::String(
+            "Backup location must be empty".to_string(),
+        ));
+    }
+
+    let db_id = native.db_id();
+
+    let mut tables = BTreeMap::new();
+    for ent in state.entries.values() {
+        if let CatalogEntry::Table(table) = ent {
+            if !table.meta.builtin && is_native(table) {
+                let version = native.backup_table(table, dest.as_ref()).await?;
+                tables.insert(table.meta.id, version);
+            }
+        }
+    }
+    for dropped in &state.dropped_tables {
+        // Data for dropped tables may have already been purged.
+        if is_native(&dropped.table) && native.table_exists(&dropped.table).await? {
+            let version = native.backup_table(&dropped.table, dest.as_ref()).await?;
+            tables.insert(dropped.table.meta.id, version);
+        }
+    }
+
+    metastore::backup::write_catalog_snapshot(dest.clone(), db_id, state).await?;
+
+    let manifest = BackupManifest {
+        db_id: db_id.to_string(),
+        catalog_version: state.version,
+        tables,
+        created_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as i64)
+            .unwrap_or_default(),
+    };
+    let bytes = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| ExecError::Internal(format!("failed to serialize backup manifest: {e}")))?;
+    dest.put(&ObjectPath::from(BACKUP_MANIFEST_PATH), bytes.into())
+        .await?;
+
+    debug!(%db_id, catalog_version = %manifest.catalog_version, num_tables = %manifest.tables.len(), "wrote backup");
+
+    Ok(manifest)
+}
+
+/// Read the manifest of a backup.
+pub async fn read_backup_manifest(src: &dyn ObjectStore) -> Result<BackupManifest> {
+    let bytes = match src.get(&ObjectPath::from(BACKUP_MANIFEST_PATH)).await {
+        Ok(res) => res.bytes().await?,
+        Err(object_store::Error::NotFound { .. }) => {
+            return Err(ExecError::String(
+                "No complete backup found at location".to_string(),
+            ))
+        }
+        Err(e) => return Err(e.into()),
+    };
+    serde_json::from_slice(&bytes)
+        .map_err(|e| ExecError::String(format!("Invalid backup manifest: {e}")))
+}
+
+/// Restore a backup as the database `db_id` in `dest`.
+///
+/// The database must not exist in `dest` yet. Nothing else may be using the
+/// destination while restoring.
+pub async fn restore_database(
+    src: Arc<dyn ObjectStore>,
+    dest: Arc<dyn ObjectStore>,
+    db_id: Uuid,
+) -> Result<BackupManifest> {
+    let manifest = read_backup_manifest(src.as_ref()).await?;
+
+    let dest_prefix = format!("databases/{db_id}/");
+    if dest
+        .list(Some(&ObjectPath::from(dest_prefix.as_str())))
+        .next()
+        .await
+        .is_some()
+    {
+        return Err(ExecError::String(format!(
+            "Database '{db_id}' already exists at restore location"
+        )));
+    }
+
+    let src_prefix = format!("databases/{}/", manifest.db_id);
+    let mut paths = Vec::new();
+    let mut objects = src.list(Some(&ObjectPath::from(src_prefix.as_str())));
+    while let Some(meta) = objects.next().await {
+        paths.push(meta?.location);
+    }
+
+    // The catalog becomes visible once its metadata exists, copy that last so
+    // that a failed restore doesn't leave a catalog pointing at missing
+    // tables.
+    let metadata = format!("{src_prefix}visible/metadata");
+    paths.sort_by_key(|path| path.as_ref() == metadata);
+
+    for path in paths {
+        let relative = path
+            .as_ref()
+            .strip_prefix(&src_prefix)
+            .ok_or_else(|| ExecError::Internal(format!("unexpected path in backup: {path}")))?;
+        let bytes = src.get(&path).await?.bytes().await?;
+        dest.put(&ObjectPath::from(format!("{dest_prefix}{relative}")), bytes)
+            .await?;
+    }
+
+    debug!(%db_id, catalog_version = %manifest.catalog_version, "restored backup");
+
+    Ok(manifest)
+}
+
+fn is_native(table: &TableEntry) -> bool {
+    matches!(table.options, TableOptionsV0::Internal(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use datafusion::arrow::datatypes::DataType;
+    use datasources::native::access::SaveMode;
+    use metastore::local::start_inprocess;
+    use object_store::local::LocalFileSystem;
+    use protogen::gen::metastore::service::FetchCatalogRequest;
+    use protogen::metastore::types::catalog::{
+        DeploymentMetadata,
+        EntryMeta,
+        EntryType,
+        SourceAccessMode,
+        CURRENT_CATALOG_VERSION,
+    };
+    use protogen::metastore::types::options::{InternalColumnDefinition, TableOptionsInternal};
+    use tempfile::tempdir;
+    use url::Url;
+
+    use super::*;
+
+    fn local_store(path: &Path) -> Arc<dyn ObjectStore> {
+        Arc::new(LocalFileSystem::new_with_prefix(path).unwrap())
+    }
+
+    fn native_storage(db_id: Uuid, path: &Path) -> NativeTableStorage {
+        NativeTableStorage::new(
+            db_id,
+            Url::from_directory_path(path).unwrap(),
+            local_store(path),
+        )
+    }
+
+    fn test_table() -> TableEntry {
+        TableEntry {
+            meta: EntryMeta {
+                entry_type: EntryType::Table,
+                id: 20001,
+                parent: 20000,
+                name: "t1".to_string(),
+                builtin: false,
+                external: false,
+                is_temp: false,
+                comment: None,
+                owner: 0,
+            },
+            options: TableOptionsInternal {
+                columns: vec![InternalColumnDefinition {
+                    name: "a".to_string(),
+                    nullable: true,
+                    arrow_type: DataType::Int32,
+                }],
+                maintenance: None,
+                partition_columns: Vec::new(),
+                identity_columns: Vec::new(),
+            }
+            .into(),
+            tunnel_id: None,
+            access_mode: SourceAccessMode::ReadOnly,
+            columns: None,
+            statistics: None,
+            column_comments: BTreeMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn backup_and_restore() {
+        let data_dir = tempdir().unwrap();
+        let native = native_storage(Uuid::new_v4(), data_dir.path());
+
+        let table = test_table();
+        native
+            .create_table(&table, SaveMode::ErrorIfExists)
+            .await
+            .unwrap();
+
+        let state = CatalogState {
+            version: 3,
+            entries: HashMap::from([(table.meta.id, CatalogEntry::Table(table.clone()))]),
+            deployment: DeploymentMetadata::default(),
+            catalog_version: CURRENT_CATALOG_VERSION,
+            dropped_tables: Vec::new(),
+            builtin_comments: HashMap::new(),
+        };
+
+        let backup_dir = tempdir().unwrap();
+        let manifest = backup_database(&state, &native, local_store(backup_dir.path()))
+            .await
+            .unwrap();
+        assert_eq!(3, manifest.catalog_version);
+        assert_eq!(BTreeMap::from([(table.meta.id, 0)]), manifest.tables);
+
+        // Backups aren't written over existing data.
+        backup_database(&state, &native, local_store(backup_dir.path()))
+            .await
+            .unwrap_err();
+
+        // Restore as a different database.
+        let restore_dir = tempdir().unwrap();
+        let db_id = Uuid::new_v4();
+        let restored = restore_database(
+            local_store(backup_dir.path()),
+            local_store(restore_dir.path()),
+            db_id,
+        )
+        .await
+        .unwrap();
+        assert_eq!(manifest, restored);
+
+        let native = native_storage(db_id, restore_dir.path());
+        assert_eq!(0, native.load_table(&table).await.unwrap().version());
+
+        let mut client = start_inprocess(local_store(restore_dir.path()))
+            .await
+            .unwrap();
+        let catalog: CatalogState = client
+            .fetch_catalog(FetchCatalogRequest {
+                db_id: db_id.into_bytes().to_vec(),
+                version: None,
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .catalog
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(3, catalog.version);
+        assert!(catalog.entries.contains_key(&table.meta.id));
+
+        // Can't restore over an existing database.
+        restore_database(
+            local_store(backup_dir.path()),
+            local_store(restore_dir.path()),
+            db_id,
+        )
+        .await
+        .unwrap_err();
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::slice;
 use std::sync::Arc;
@@ -39,6 +39,7 @@ use super::{new_datafusion_runtime_env, new_datafusion_session_config_opts};
 use crate::environment::EnvironmentReader;
 use crate::errors::{internal, ExecError, Result};
 use crate::optimizer::DdlInputOptimizationRule;
+use crate::planner::errors::PlanError;
 use crate::planner::logical_plan::{
     FullObjectReference,
     FullSchemaReference,
@@ -46,6 +47,7 @@ use crate::planner::logical_plan::{
     OwnedFullObjectReference,
     OwnedFullSchemaReference,
 };
+use crate::planner::sequences::SessionSequenceValues;
 use crate::planner::session_planner::SessionPlanner;
 use crate::remote::client::{RemoteClient, RemoteSessionClient};
 
@@ -80,6 +82,10 @@ pub struct LocalSessionContext {
     notices: Vec<Notice>,
     /// Functions that are available to the session.
     functions: FunctionRegistry,
+    /// Names of functions registered only for this session.
+    session_functions: HashSet<String>,
+    /// Values handed out by `nextval` in this session.
+    sequence_values: SessionSequenceValues,
 }
 
 impl LocalSessionContext {
@@ -128,6 +134,8 @@ impl LocalSessionContext {
             task_scheduler,
             notices: Vec::new(),
             functions,
+            session_functions: HashSet::new(),
+            sequence_values: SessionSequenceValues::default(),
         })
     }
 
@@ -219,6 +227,31 @@ impl LocalSessionContext {
         Ok(())
     }
 
+    /// Register a UDF that's only available to this session.
+    ///
+    /// Unlike `register_function`, the function isn't persisted in the
+    /// catalog. Registering a function with the same name as a previously
+    /// registered session function replaces it, but builtin and catalog
+    /// functions can't be shadowed.
+    pub fn register_session_function(&mut self, udf: Arc<dyn BuiltinScalarUDF>) -> Result<()> {
+        let names = udf
+            .aliases()
+            .iter()
+            .map(|a| a.to_string())
+            .chain(std::iter::once(udf.name().to_string()))
+            .collect::<Vec<_>>();
+
+        for name in &names {
+            if self.functions.contains(name) && !self.session_functions.contains(name) {
+                return Err(ExecError::DuplicateObjectName(name.clone()));
+            }
+        }
+
+        self.session_functions.extend(names);
+        self.functions.register_udf(udf);
+        Ok(())
+    }
+
     pub fn register_env_reader(&mut self, reader: Option<Arc<dyn EnvironmentReader>>) {
         self.env_reader = reader;
     }
@@ -231,6 +264,11 @@ impl LocalSessionContext {
         self.metrics_handler.clone()
     }
 
+    /// Return a reference to the metrics handler without cloning it.
+    pub fn metrics_handler(&self) -> &SessionMetricsHandler {
+        &self.metrics_handler
+    }
+
     pub fn get_database_id(&self) -> Uuid {
         self.database_id
     }
@@ -253,7 +291,7 @@ impl LocalSessionContext {
         self.exec_client.clone()
     }
 
-    fn catalog_mutator(&self) -> Arc<CatalogMutator> {
+    pub fn catalog_mutator(&self) -> Arc<CatalogMutator> {
         self.df_ctx
             .state()
             .config()
@@ -261,6 +299,11 @@ impl LocalSessionContext {
             .expect("catalog mutator should be present")
     }
 
+    /// Get the values `nextval` handed out in this session.
+    pub fn sequence_values(&self) -> &SessionSequenceValues {
+        &self.sequence_values
+    }
+
     /// Get a reference to the session variables.
     pub fn get_session_vars(&self) -> SessionVars {
         let cfg = self.df_ctx.copied_config();
@@ -283,8 +326,54 @@ impl LocalSessionContext {
     pub async fn maybe_refresh_state(&mut self) -> Result<()> {
         let mutator = self.catalog_mutator();
         let client = mutator.get_metastore_client();
+        let vars = self.get_session_vars();
+
+        // Sessions pinned to an older version of the catalog never pick up
+        // newer versions.
+        if let Some(version) = vars.catalog_as_of_version() {
+            let client = client.ok_or_else(|| {
+                ExecError::String(
+                    "Catalog versions can only be pinned for sessions backed by a metastore"
+                        .to_string(),
+                )
+            })?;
+
+            // Older versions have older roles and grants, so only superusers
+            // of the latest version may pin, or revoked privileges would be
+            // usable again.
+            if vars.force_catalog_refresh() {
+                client.refresh_cached_state().await?;
+            }
+            let latest = SessionCatalog::new(
+                client.get_cached_state().await?,
+                self.catalog.resolve_config(),
+            );
+            if !latest.role_is_superuser(vars.catalog_role().as_deref()) {
+                vars.with_catalog_as_of_version(None, VarType::System);
+                return Err(PlanError::PermissionDenied {
+                    role: vars.user_name(),
+                    action: "SET catalog_as_of_version".to_string(),
+                }
+                .into());
+            }
+
+            if self.catalog.version() != version {
+                let state = match client.get_state_at_version(version).await {
+                    Ok(state) => state,
+                    Err(e) => {
+                        // Unpin so that the session isn't stuck erroring on
+                        // every query.
+                        vars.with_catalog_as_of_version(None, VarType::System);
+                        return Err(e.into());
+                    }
+                };
+                self.catalog.swap_state(state);
+            }
+            return Ok(());
+        }
+
         self.catalog
-            .maybe_refresh_state(client, self.get_session_vars().force_catalog_refresh())
+            .maybe_refresh_state(client, vars.force_catalog_refresh())
             .await
             .map_err(ExecError::from)
     }
@@ -11,7 +11,7 @@ use datafusion::execution::context::{SessionConfig, SessionContext as DfSessionC
 use datafusion::execution::FunctionRegistry as DFRegistry;
 use datafusion::physical_plan::{execute_stream, ExecutionPlan, SendableRecordBatchStream};
 use datafusion::variable::VarType;
-use datafusion_ext::functions::FuncParamValue;
+use datafusion_ext::functions::{FuncParamValue, TableVersion};
 use datafusion_ext::vars::SessionVars;
 use datasources::native::access::NativeTableStorage;
 use distexec::scheduler::Scheduler;
@@ -173,10 +173,26 @@ impl RemoteSessionContext {
         let prov: Arc<dyn TableProvider> = match table_ref {
             ResolvedTableReference::Internal { table_oid } => match catalog.get_by_oid(table_oid) {
                 Some(CatalogEntry::Table(tbl)) => {
-                    if tbl.meta.external {
-                        dispatcher.dispatch_external_table(tbl).await?
-                    } else {
-                        self.tables.load_table(tbl).await?.into_table_provider()
+                    // Time travel on native tables is requested through the
+                    // options.
+                    let version = match opts {
+                        Some(mut opts) => TableVersion::take_from_opts(&mut opts)?,
+                        None => None,
+                    };
+
+                    match version {
+                        Some(_) if tbl.meta.external => {
+                            return Err(ExecError::UnsupportedFeature("AS OF on external tables"))
+                        }
+                        Some(version) => self
+                            .tables
+                            .load_table_at_version(tbl, version)
+                            .await?
+                            .into_table_provider(),
+                        None if tbl.meta.external => {
+                            dispatcher.dispatch_external_table(tbl).await?
+                        }
+                        None => self.tables.load_table(tbl).await?.into_table_provider(),
                     }
                 }
                 Some(CatalogEntry::Function(f)) => {
@@ -6,11 +6,14 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use catalog::mutator::CatalogMutator;
 use catalog::session_catalog::SessionCatalog;
 use datafusion::datasource::{TableProvider, ViewTable};
 use datafusion::logical_expr::{LogicalPlan, LogicalPlanBuilder};
 use datafusion::prelude::{Column, Expr, SessionContext as DfSessionContext};
-use datafusion_ext::functions::{DefaultTableContextProvider, FuncParamValue};
+use datafusion_ext::functions::{DefaultTableContextProvider, FuncParamValue, TableVersion};
+use datafusion_ext::session_metrics::SessionMetricsHandler;
+use datasources::common::statistics::AnalyzedTableProvider;
 use datasources::native::access::NativeTableStorage;
 use parser::GlareDbParser;
 use protogen::metastore::types::catalog::{DatabaseEntry, FunctionEntry, TableEntry, ViewEntry};
@@ -104,6 +107,8 @@ pub enum DispatchError {
     #[error(transparent)]
     Builtin(#[from] sqlbuiltins::errors::BuiltinError),
     #[error(transparent)]
+    Catalog(#[from] catalog::errors::CatalogError),
+    #[error(transparent)]
     Datasource(#[from] Box<dyn std::error::Error + Send + Sync>),
 }
 
@@ -162,6 +167,10 @@ pub struct Dispatcher<'a> {
     /// Whether or not local file system access should be disabled.
     disable_local_fs_access: bool,
     function_registry: &'a FunctionRegistry,
+    /// Used for reading the query history.
+    metrics_handler: &'a SessionMetricsHandler,
+    /// Catalog role of the session, if any.
+    catalog_role: Option<String>,
 }
 
 impl<'a> Dispatcher<'a> {
@@ -172,6 +181,8 @@ impl<'a> Dispatcher<'a> {
         df_ctx: &'a DfSessionContext,
         disable_local_fs_access: bool,
         function_registry: &'a FunctionRegistry,
+        metrics_handler: &'a SessionMetricsHandler,
+        catalog_role: Option<String>,
     ) -> Self {
         Dispatcher {
             catalog,
@@ -180,6 +191,8 @@ impl<'a> Dispatcher<'a> {
             df_ctx,
             disable_local_fs_access,
             function_registry,
+            metrics_handler,
+            catalog_role,
         }
     }
 
@@ -198,25 +211,61 @@ impl<'a> Dispatcher<'a> {
 
         // Builtin tables
         if tbl.meta.builtin {
-            return SystemTableDispatcher::new(self.catalog, self.tables, self.function_registry)
-                .dispatch(tbl)
-                .await;
+            let mutator = self
+                .df_ctx
+                .state()
+                .config()
+                .get_extension::<CatalogMutator>();
+            return SystemTableDispatcher::new(
+                self.catalog,
+                self.tables,
+                self.function_registry,
+                self.metrics_handler,
+                self.catalog_role.as_deref(),
+                mutator.as_ref().and_then(|m| m.get_metastore_client()),
+            )
+            .dispatch(tbl)
+            .await;
         }
 
-        // External tables
-        if tbl.meta.external {
-            return ExternalDispatcher::new(
+        let provider = if tbl.meta.external {
+            // External tables
+            ExternalDispatcher::new(
                 self.catalog,
                 self.df_ctx,
                 self.function_registry,
                 self.disable_local_fs_access,
             )
             .dispatch_external_table(tbl)
-            .await;
+            .await?
+        } else {
+            // Native (user) tables
+            let table = self.tables.load_table(tbl).await?;
+            table.into_table_provider()
+        };
+
+        // Report statistics from the last ANALYZE, if any.
+        Ok(match &tbl.statistics {
+            Some(statistics) => Arc::new(AnalyzedTableProvider::new(provider, statistics.clone())),
+            None => provider,
+        })
+    }
+
+    /// Dispatch a previous version of a table.
+    ///
+    /// Only native tables keep their history.
+    pub async fn dispatch_table_at_version(
+        &self,
+        tbl: &TableEntry,
+        version: TableVersion,
+    ) -> Result<Arc<dyn TableProvider>> {
+        if tbl.meta.is_temp || tbl.meta.builtin || tbl.meta.external {
+            return Err(DispatchError::InvalidDispatch(
+                "AS OF is only supported for native tables",
+            ));
         }
 
-        // Native (user) tables
-        let table = self.tables.load_table(tbl).await?;
+        let table = self.tables.load_table_at_version(tbl, version).await?;
         Ok(table.into_table_provider())
     }
 
@@ -1,10 +1,20 @@
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use catalog::client::MetastoreClientHandle;
 use catalog::session_catalog::SessionCatalog;
-use datafusion::arrow::array::{BooleanBuilder, ListBuilder, StringBuilder, UInt32Builder};
+use datafusion::arrow::array::{
+    BooleanBuilder,
+    ListBuilder,
+    StringBuilder,
+    TimestampMicrosecondBuilder,
+    UInt32Builder,
+    UInt64Builder,
+};
 use datafusion::arrow::record_batch::RecordBatch;
 use datafusion::datasource::{MemTable, TableProvider};
 use datafusion::logical_expr::TypeSignature;
+use datafusion_ext::session_metrics::SessionMetricsHandler;
 use datasources::common::ssh::key::SshKey;
 use datasources::common::ssh::SshConnectionParameters;
 use datasources::native::access::NativeTableStorage;
@@ -14,14 +24,19 @@ use sqlbuiltins::builtins::{
     BuiltinTable,
     DATABASE_DEFAULT,
     GLARE_CACHED_EXTERNAL_DATABASE_TABLES,
+    GLARE_CATALOG_HISTORY,
     GLARE_COLUMNS,
     GLARE_CREDENTIALS,
     GLARE_DATABASES,
     GLARE_DEPLOYMENT_METADATA,
     GLARE_FUNCTIONS,
+    GLARE_PRIVILEGES,
+    GLARE_QUERY_HISTORY,
+    GLARE_ROLES,
     GLARE_SCHEMAS,
     GLARE_SSH_KEYS,
     GLARE_TABLES,
+    GLARE_TABLE_STATISTICS,
     GLARE_TUNNELS,
     GLARE_VIEWS,
     SCHEMA_CURRENT_SESSION,
@@ -35,6 +50,11 @@ pub struct SystemTableDispatcher<'a> {
     catalog: &'a SessionCatalog,
     tables: &'a NativeTableStorage,
     function_registry: &'a FunctionRegistry,
+    metrics_handler: &'a SessionMetricsHandler,
+    /// Catalog role of the session, if any.
+    catalog_role: Option<&'a str>,
+    /// Used for reading the catalog history.
+    metastore: Option<&'a MetastoreClientHandle>,
 }
 
 impl<'a> SystemTableDispatcher<'a> {
@@ -42,11 +62,17 @@ impl<'a> SystemTableDispatcher<'a> {
         catalog: &'a SessionCatalog,
         tables: &'a NativeTableStorage,
         function_registry: &'a FunctionRegistry,
+        metrics_handler: &'a SessionMetricsHandler,
+        catalog_role: Option<&'a str>,
+        metastore: Option<&'a MetastoreClientHandle>,
     ) -> Self {
         SystemTableDispatcher {
             catalog,
             tables,
             function_registry,
+            metrics_handler,
+            catalog_role,
+            metastore,
         }
     }
 
@@ -73,6 +99,16 @@ impl<'a> SystemTableDispatcher<'a> {
             Arc::new(self.build_glare_schemas())
         } else if GLARE_FUNCTIONS.matches(schema, name) {
             Arc::new(self.build_glare_functions())
+        } else if GLARE_ROLES.matches(schema, name) {
+            Arc::new(self.build_glare_roles())
+        } else if GLARE_PRIVILEGES.matches(schema, name) {
+            Arc::new(self.build_glare_privileges())
+        } else if GLARE_QUERY_HISTORY.matches(schema, name) {
+            Arc::new(self.build_glare_query_history())
+        } else if GLARE_TABLE_STATISTICS.matches(schema, name) {
+            Arc::new(self.build_glare_table_statistics())
+        } else if GLARE_CATALOG_HISTORY.matches(schema, name) {
+            Arc::new(self.build_glare_catalog_history().await?)
         } else if GLARE_SSH_KEYS.matches(schema, name) {
             Arc::new(self.build_ssh_keys()?)
         } else if GLARE_DEPLOYMENT_METADATA.matches(schema, name) {
@@ -125,6 +161,7 @@ impl<'a> SystemTableDispatcher<'a> {
         let mut external = BooleanBuilder::new();
         let mut datasource = StringBuilder::new();
         let mut access_mode = StringBuilder::new();
+        let mut comment = StringBuilder::new();
 
         for db in self
             .catalog
@@ -135,6 +172,7 @@ impl<'a> SystemTableDispatcher<'a> {
             database_name.append_value(&db.entry.get_meta().name);
             builtin.append_value(db.builtin);
             external.append_value(db.entry.get_meta().external);
+            comment.append_option(db.entry.get_meta().comment.as_deref());
 
             let db = match db.entry {
                 CatalogEntry::Database(db) => db,
@@ -154,6 +192,7 @@ impl<'a> SystemTableDispatcher<'a> {
                 Arc::new(external.finish()),
                 Arc::new(datasource.finish()),
                 Arc::new(access_mode.finish()),
+                Arc::new(comment.finish()),
             ],
         )
         .unwrap();
@@ -239,6 +278,297 @@ impl<'a> SystemTableDispatcher<'a> {
         MemTable::try_new(arrow_schema, vec![vec![batch]]).unwrap()
     }
 
+    fn build_glare_roles(&self) -> MemTable {
+        let arrow_schema = Arc::new(GLARE_ROLES.arrow_schema());
+
+        let mut oid = UInt32Builder::new();
+        let mut role_name = StringBuilder::new();
+        let mut builtin = BooleanBuilder::new();
+        let mut superuser = BooleanBuilder::new();
+        let mut login = BooleanBuilder::new();
+        let mut has_password = BooleanBuilder::new();
+        let mut member_of = ListBuilder::new(StringBuilder::new());
+
+        for role in self
+            .catalog
+            .iter_entries()
+            .filter(|ent| ent.entry_type() == EntryType::Role)
+        {
+            oid.append_value(role.oid);
+            role_name.append_value(&role.entry.get_meta().name);
+            builtin.append_value(role.builtin);
+
+            let role = match role.entry {
+                CatalogEntry::Role(role) => role,
+                other => unreachable!("unexpected entry type: {other:?}"),
+            };
+
+            superuser.append_value(role.superuser);
+            login.append_value(role.login);
+            has_password.append_value(role.password.is_some());
+            member_of.append_value(role.member_of.iter().map(|oid| {
+                self.catalog
+                    .get_by_oid(*oid)
+                    .map(|ent| ent.get_meta().name.clone())
+            }));
+        }
+
+        let batch = RecordBatch::try_new(
+            arrow_schema.clone(),
+            vec![
+                Arc::new(oid.finish()),
+                Arc::new(role_name.finish()),
+                Arc::new(builtin.finish()),
+                Arc::new(superuser.finish()),
+                Arc::new(login.finish()),
+                Arc::new(has_password.finish()),
+                Arc::new(member_of.finish()),
+            ],
+        )
+        .unwrap();
+        MemTable::try_new(arrow_schema, vec![vec![batch]]).unwrap()
+    }
+
+    fn build_glare_privileges(&self) -> MemTable {
+        let arrow_schema = Arc::new(GLARE_PRIVILEGES.arrow_schema());
+
+        let mut role_oid = UInt32Builder::new();
+        let mut role_name = StringBuilder::new();
+        let mut object_oid = UInt32Builder::new();
+        let mut object_type = StringBuilder::new();
+        let mut schema_name = StringBuilder::new();
+        let mut object_name = StringBuilder::new();
+        let mut privilege = StringBuilder::new();
+
+        for role in self
+            .catalog
+            .iter_entries()
+            .filter(|ent| ent.entry_type() == EntryType::Role)
+        {
+            let role = match role.entry {
+                CatalogEntry::Role(role) => role,
+                other => unreachable!("unexpected entry type: {other:?}"),
+            };
+
+            for grant in &role.grants {
+                role_oid.append_value(role.meta.id);
+                role_name.append_value(&role.meta.name);
+                object_oid.append_value(grant.object_id);
+                privilege.append_value(grant.privilege.as_str());
+
+                // Objects may have been dropped since the grant.
+                match self.catalog.get_namespaced_by_oid(grant.object_id) {
+                    Some(ent) => {
+                        object_type.append_value(ent.entry_type().as_str());
+                        schema_name.append_option(match ent.parent_entry {
+                            Some(CatalogEntry::Schema(schema)) => Some(&schema.meta.name),
+                            _ => None,
+                        });
+                        object_name.append_value(&ent.entry.get_meta().name);
+                    }
+                    None => {
+                        object_type.append_null();
+                        schema_name.append_null();
+                        object_name.append_null();
+                    }
+                }
+            }
+        }
+
+        let batch = RecordBatch::try_new(
+            arrow_schema.clone(),
+            vec![
+                Arc::new(role_oid.finish()),
+                Arc::new(role_name.finish()),
+                Arc::new(object_oid.finish()),
+                Arc::new(object_type.finish()),
+                Arc::new(schema_name.finish()),
+                Arc::new(object_name.finish()),
+                Arc::new(privilege.finish()),
+            ],
+        )
+        .unwrap();
+        MemTable::try_new(arrow_schema, vec![vec![batch]]).unwrap()
+    }
+
+    /// Build the query history for the current database.
+    ///
+    /// Users that aren't superusers are only able to see their own queries.
+    fn build_glare_query_history(&self) -> MemTable {
+        let arrow_schema = Arc::new(GLARE_QUERY_HISTORY.arrow_schema());
+
+        let user = self.metrics_handler.user_name();
+        let records = if self.catalog.role_is_superuser(self.catalog_role) {
+            self.metrics_handler.query_history(None)
+        } else {
+            self.metrics_handler.query_history(Some(user))
+        };
+
+        fn to_micros(time: SystemTime) -> i64 {
+            time.duration_since(UNIX_EPOCH)
+                .map(|d| d.as_micros() as i64)
+                .unwrap_or_default()
+        }
+
+        let mut session_id = StringBuilder::new();
+        let mut database_id = StringBuilder::new();
+        let mut user_name = StringBuilder::new();
+        let mut query_text = StringBuilder::new();
+        let mut result_type = StringBuilder::new();
+        let mut execution_status = StringBuilder::new();
+        let mut error_message = StringBuilder::new();
+        let mut start_time = TimestampMicrosecondBuilder::new().with_timezone("UTC");
+        let mut end_time = TimestampMicrosecondBuilder::new().with_timezone("UTC");
+        let mut elapsed_compute_ns = UInt64Builder::new();
+        let mut output_rows = UInt64Builder::new();
+        let mut bytes_read = UInt64Builder::new();
+        let mut bytes_written = UInt64Builder::new();
+
+        for record in records {
+            let metrics = record.metrics;
+            session_id.append_value(record.session_id.to_string());
+            database_id.append_value(record.database_id.to_string());
+            user_name.append_value(record.user_name);
+            query_text.append_value(metrics.query_text);
+            result_type.append_value(metrics.result_type);
+            execution_status.append_value(metrics.execution_status.as_str());
+            error_message.append_option(metrics.error_message);
+            start_time.append_value(to_micros(metrics.start_time));
+            end_time.append_value(to_micros(record.end_time));
+            elapsed_compute_ns.append_option(metrics.elapsed_compute_ns);
+            output_rows.append_option(metrics.output_rows);
+            bytes_read.append_option(metrics.bytes_read);
+            bytes_written.append_option(metrics.bytes_written);
+        }
+
+        let batch = RecordBatch::try_new(
+            arrow_schema.clone(),
+            vec![
+                Arc::new(session_id.finish()),
+                Arc::new(database_id.finish()),
+                Arc::new(user_name.finish()),
+                Arc::new(query_text.finish()),
+                Arc::new(result_type.finish()),
+                Arc::new(execution_status.finish()),
+                Arc::new(error_message.finish()),
+                Arc::new(start_time.finish()),
+                Arc::new(end_time.finish()),
+                Arc::new(elapsed_compute_ns.finish()),
+                Arc::new(output_rows.finish()),
+                Arc::new(bytes_read.finish()),
+                Arc::new(bytes_written.finish()),
+            ],
+        )
+        .unwrap();
+        MemTable::try_new(arrow_schema, vec![vec![batch]]).unwrap()
+    }
+
+    fn build_glare_table_statistics(&self) -> MemTable {
+        let arrow_schema = Arc::new(GLARE_TABLE_STATISTICS.arrow_schema());
+
+        let mut table_oid = UInt32Builder::new();
+        let mut schema_name = StringBuilder::new();
+        let mut table_name = StringBuilder::new();
+        let mut num_rows = UInt64Builder::new();
+        let mut column_name = StringBuilder::new();
+        let mut null_count = UInt64Builder::new();
+        let mut min_value = StringBuilder::new();
+        let mut max_value = StringBuilder::new();
+        let mut distinct_count = UInt64Builder::new();
+        let mut analyzed_at = TimestampMicrosecondBuilder::new().with_timezone("UTC");
+
+        for table in self
+            .catalog
+            .iter_entries()
+            .filter(|ent| ent.entry_type() == EntryType::Table)
+        {
+            let ent = match table.entry {
+                CatalogEntry::Table(ent) => ent,
+                other => panic!("unexpected entry type: {:?}", other), // Bug
+            };
+
+            let stats = match &ent.statistics {
+                Some(stats) => stats,
+                None => continue,
+            };
+
+            for col in &stats.columns {
+                table_oid.append_value(table.oid);
+                schema_name.append_value(
+                    table
+                        .parent_entry
+                        .map(|schema| schema.get_meta().name.as_str())
+                        .unwrap_or("<invalid>"),
+                );
+                table_name.append_value(&ent.meta.name);
+                num_rows.append_value(stats.num_rows);
+                column_name.append_value(&col.name);
+                null_count.append_value(col.null_count);
+                min_value.append_option(col.min_value.as_deref());
+                max_value.append_option(col.max_value.as_deref());
+                distinct_count.append_option(col.distinct_count);
+                analyzed_at.append_value(stats.analyzed_at * 1000);
+            }
+        }
+
+        let batch = RecordBatch::try_new(
+            arrow_schema.clone(),
+            vec![
+                Arc::new(table_oid.finish()),
+                Arc::new(schema_name.finish()),
+                Arc::new(table_name.finish()),
+                Arc::new(num_rows.finish()),
+                Arc::new(column_name.finish()),
+                Arc::new(null_count.finish()),
+                Arc::new(min_value.finish()),
+                Arc::new(max_value.finish()),
+                Arc::new(distinct_count.finish()),
+                Arc::new(analyzed_at.finish()),
+            ],
+        )
+        .unwrap();
+        MemTable::try_new(arrow_schema, vec![vec![batch]]).unwrap()
+    }
+
+    /// Build the retained history of the catalog.
+    ///
+    /// Empty if the session isn't backed by a metastore.
+    async fn build_glare_catalog_history(&self) -> Result<MemTable> {
+        let arrow_schema = Arc::new(GLARE_CATALOG_HISTORY.arrow_schema());
+
+        let history = match self.metastore {
+            Some(client) => client.get_catalog_history().await?,
+            None => Vec::new(),
+        };
+
+        let mut version = UInt64Builder::new();
+        let mut committed_at = TimestampMicrosecondBuilder::new().with_timezone("UTC");
+        let mut mutations = ListBuilder::new(StringBuilder::new());
+        let mut process_id = StringBuilder::new();
+
+        for info in history {
+            version.append_value(info.version);
+            committed_at.append_value(info.committed_at * 1000);
+            for mutation in info.mutations {
+                mutations.values().append_value(mutation);
+            }
+            mutations.append(true);
+            process_id.append_value(info.written_by.to_string());
+        }
+
+        let batch = RecordBatch::try_new(
+            arrow_schema.clone(),
+            vec![
+                Arc::new(version.finish()),
+                Arc::new(committed_at.finish()),
+                Arc::new(mutations.finish()),
+                Arc::new(process_id.finish()),
+            ],
+        )
+        .unwrap();
+        Ok(MemTable::try_new(arrow_schema, vec![vec![batch]]).unwrap())
+    }
+
     fn build_glare_schemas(&self) -> MemTable {
         let arrow_schema = Arc::new(GLARE_SCHEMAS.arrow_schema());
 
@@ -247,6 +577,7 @@ impl<'a> SystemTableDispatcher<'a> {
         let mut database_name = StringBuilder::new();
         let mut schema_name = StringBuilder::new();
         let mut builtin = BooleanBuilder::new();
+        let mut comment = StringBuilder::new();
 
         for schema in self
             .catalog
@@ -263,6 +594,7 @@ impl<'a> SystemTableDispatcher<'a> {
             );
             schema_name.append_value(&schema.entry.get_meta().name);
             builtin.append_value(schema.builtin);
+            comment.append_option(schema.entry.get_meta().comment.as_deref());
         }
         let batch = RecordBatch::try_new(
             arrow_schema.clone(),
@@ -272,6 +604,7 @@ impl<'a> SystemTableDispatcher<'a> {
                 Arc::new(database_name.finish()),
                 Arc::new(schema_name.finish()),
                 Arc::new(builtin.finish()),
+                Arc::new(comment.finish()),
             ],
         )
         .unwrap();
@@ -290,6 +623,7 @@ impl<'a> SystemTableDispatcher<'a> {
         let mut external = BooleanBuilder::new();
         let mut datasource = StringBuilder::new();
         let mut access_mode = StringBuilder::new();
+        let mut comment = StringBuilder::new();
 
         for table in self
             .catalog
@@ -313,6 +647,7 @@ impl<'a> SystemTableDispatcher<'a> {
             table_name.append_value(&table.entry.get_meta().name);
             builtin.append_value(table.builtin);
             external.append_value(table.entry.get_meta().external);
+            comment.append_option(table.entry.get_meta().comment.as_deref());
 
             let table = match table.entry {
                 CatalogEntry::Table(table) => table,
@@ -335,6 +670,7 @@ impl<'a> SystemTableDispatcher<'a> {
             external.append_value(table.meta.external);
             datasource.append_value(table.options.as_str());
             access_mode.append_value(SourceAccessMode::ReadWrite.as_str());
+            comment.append_option(table.meta.comment.as_deref());
         }
 
         let batch = RecordBatch::try_new(
@@ -349,6 +685,7 @@ impl<'a> SystemTableDispatcher<'a> {
                 Arc::new(external.finish()),
                 Arc::new(datasource.finish()),
                 Arc::new(access_mode.finish()),
+                Arc::new(comment.finish()),
             ],
         )
         .unwrap();
@@ -366,6 +703,7 @@ impl<'a> SystemTableDispatcher<'a> {
         let mut column_ordinal = UInt32Builder::new();
         let mut data_type = StringBuilder::new();
         let mut is_nullable = BooleanBuilder::new();
+        let mut comment = StringBuilder::new();
 
         for table in self
             .catalog
@@ -395,6 +733,7 @@ impl<'a> SystemTableDispatcher<'a> {
                 column_ordinal.append_value(i as u32);
                 data_type.append_value(col.arrow_type.to_string());
                 is_nullable.append_value(col.nullable);
+                comment.append_option(ent.column_comments.get(&col.name));
             }
         }
 
@@ -408,6 +747,7 @@ impl<'a> SystemTableDispatcher<'a> {
                 Arc::new(column_ordinal.finish()),
                 Arc::new(data_type.finish()),
                 Arc::new(is_nullable.finish()),
+                Arc::new(comment.finish()),
             ],
         )
         .unwrap();
@@ -425,6 +765,7 @@ impl<'a> SystemTableDispatcher<'a> {
         let mut view_name = StringBuilder::new();
         let mut builtin = BooleanBuilder::new();
         let mut sql = StringBuilder::new();
+        let mut comment = StringBuilder::new();
 
         for view in self
             .catalog
@@ -451,6 +792,7 @@ impl<'a> SystemTableDispatcher<'a> {
             view_name.append_value(&view.entry.get_meta().name);
             builtin.append_value(view.builtin);
             sql.append_value(&ent.sql);
+            comment.append_option(ent.meta.comment.as_deref());
         }
 
         let batch = RecordBatch::try_new(
@@ -463,6 +805,7 @@ impl<'a> SystemTableDispatcher<'a> {
                 Arc::new(view_name.finish()),
                 Arc::new(builtin.finish()),
                 Arc::new(sql.finish()),
+                Arc::new(comment.finish()),
             ],
         )
         .unwrap();
@@ -5,8 +5,11 @@ use std::ops::{Deref, DerefMut};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use catalog::client::MetastoreClientHandle;
 use catalog::session_catalog::{ResolveConfig, SessionCatalog};
+use datafusion_ext::session_metrics::QueryHistory;
 use datafusion_ext::vars::SessionVars;
 use datasources::common::errors::DatasourceCommonError;
 use datasources::common::url::{DatasourceUrl, DatasourceUrlType};
@@ -26,12 +29,14 @@ use object_store_util::conf::StorageConfig;
 use object_store_util::shared::SharedObjectStore;
 use once_cell::sync::OnceCell;
 use protogen::gen::metastore::service::metastore_service_client::MetastoreServiceClient;
+use protogen::metastore::types::catalog::RoleEntry;
+use protogen::metastore::types::service::{Mutation, PurgeDroppedTables};
 use protogen::rpcsrv::types::common;
 use sqlbuiltins::builtins::{SCHEMA_CURRENT_SESSION, SCHEMA_DEFAULT};
 use telemetry::Tracker;
 use tempfile;
 use tonic::transport::Channel;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use url::Url;
 use uuid::Uuid;
 
@@ -314,6 +319,8 @@ pub struct Engine {
     supervisor: MetastoreClientSupervisor,
     /// Telemetry.
     tracker: Arc<Tracker>,
+    /// Recently executed queries across all sessions.
+    query_history: Arc<QueryHistory>,
     /// Storage configuration.
     storage: EngineStorageConfig,
     /// Path to spill temp files.
@@ -381,6 +388,7 @@ impl Engine {
         Ok(Engine {
             supervisor: MetastoreClientSupervisor::new(metastore, DEFAULT_METASTORE_CLIENT_CONFIG),
             tracker,
+            query_history: Arc::new(QueryHistory::default()),
             storage,
             spill_path,
             session_counter: Arc::new(AtomicU64::new(0)),
@@ -395,6 +403,11 @@ impl Engine {
         self.tracker.clone()
     }
 
+    /// Returns the query history shared by all sessions on this engine.
+    pub fn get_query_history(&self) -> Arc<QueryHistory> {
+        self.query_history.clone()
+    }
+
     pub async fn from_storage(opts: EngineStorage) -> Result<Engine> {
         match opts {
             EngineStorage::Memory => Self::from_data_dir(None).await,
@@ -511,6 +524,7 @@ impl Engine {
         let native = self
             .storage
             .new_native_tables_storage(database_id, &storage)?;
+        purge_expired_dropped_tables_on_load(&metastore, &native).await;
         let state = metastore.get_cached_state().await?;
         let catalog = SessionCatalog::new_with_alias(
             state,
@@ -527,11 +541,31 @@ impl Engine {
             metastore.into(),
             native,
             self.tracker.clone(),
+            self.query_history.clone(),
             self.spill_path.clone(),
             self.task_scheduler.clone(),
         )
     }
 
+    /// Look up a role by name in a database's catalog.
+    ///
+    /// Used when authenticating connections for users created with `CREATE
+    /// USER` or `CREATE ROLE`. The catalog is refreshed first so that newly
+    /// created roles are able to log in immediately.
+    pub async fn resolve_role(&self, database_id: Uuid, name: &str) -> Result<Option<RoleEntry>> {
+        let metastore = self.supervisor.init_client(database_id).await?;
+        metastore.refresh_cached_state().await?;
+        let state = metastore.get_cached_state().await?;
+        let catalog = SessionCatalog::new(
+            state,
+            ResolveConfig {
+                default_schema_oid: SCHEMA_DEFAULT.oid,
+                session_schema_oid: SCHEMA_CURRENT_SESSION.oid,
+            },
+        );
+        Ok(catalog.resolve_role(name).cloned())
+    }
+
     /// Create a new remote session for plan execution.
     ///
     /// Note that this isn't wrapped in a tracked session yet (to avoid hanging
@@ -545,6 +579,7 @@ impl Engine {
         let native = self
             .storage
             .new_native_tables_storage(database_id, &storage)?;
+        purge_expired_dropped_tables_on_load(&metastore, &native).await;
 
         let state = metastore.get_cached_state().await?;
         let catalog = SessionCatalog::new(
@@ -598,6 +633,70 @@ impl Drop for TrackedSession {
     }
 }
 
+/// Purge dropped tables whose retention has expired when a session loads the
+/// catalog.
+///
+/// `DROP TABLE` purges expired tables as well, but databases where no more
+/// tables are dropped would otherwise keep their data forever. Failing to
+/// purge doesn't prevent the session from starting.
+async fn purge_expired_dropped_tables_on_load(
+    metastore: &MetastoreClientHandle,
+    native: &NativeTableStorage,
+) {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as i64;
+    if let Err(e) = purge_expired_dropped_tables(metastore, native, now).await {
+        warn!(%e, "failed to purge expired dropped tables");
+    }
+}
+
+/// Remove tables that expired before `now` (milliseconds since the epoch)
+/// from the catalog's dropped tables, then delete their data.
+async fn purge_expired_dropped_tables(
+    metastore: &MetastoreClientHandle,
+    native: &NativeTableStorage,
+    now: i64,
+) -> Result<()> {
+    let state = metastore.get_cached_state().await?;
+    let expired: Vec<_> = state
+        .dropped_tables
+        .iter()
+        .filter(|dropped| dropped.expires_at <= now)
+        .map(|dropped| dropped.table.clone())
+        .collect();
+    if expired.is_empty() {
+        return Ok(());
+    }
+
+    // Make sure the catalog no longer references the tables before deleting
+    // their data.
+    let state = metastore
+        .try_mutate_and_commit(
+            state.version,
+            vec![Mutation::PurgeDroppedTables(PurgeDroppedTables {
+                expired_before: now,
+            })],
+        )
+        .await?;
+
+    for table in expired {
+        // Our view of the dropped tables may have been out of date, e.g. if
+        // the table was undropped in the meantime.
+        let known = state.entries.contains_key(&table.meta.id)
+            || state
+                .dropped_tables
+                .iter()
+                .any(|dropped| dropped.table.meta.id == table.meta.id);
+        if !known {
+            native.delete_table(&table).await?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Ensure that the spill path exists and that it's writable if provided.
 pub fn ensure_spill_path<P: AsRef<Path>>(path: Option<P>) -> Result<()> {
     if let Some(p) = path {
@@ -617,11 +716,121 @@ pub fn ensure_spill_path<P: AsRef<Path>>(path: Option<P>) -> Result<()> {
 mod tests {
     use std::collections::HashMap;
 
+    use datafusion::arrow::datatypes::DataType;
+    use datasources::native::access::SaveMode;
+    use metastore::local::start_inprocess_inmemory;
+    use object_store::local::LocalFileSystem;
     use object_store_util::conf::StorageConfig;
-
+    use protogen::metastore::types::catalog::CatalogEntry;
+    use protogen::metastore::types::options::{InternalColumnDefinition, TableOptionsInternal};
+    use protogen::metastore::types::service::{
+        AlterDatabase,
+        AlterDatabaseOperation,
+        CreateTable,
+        DropObject,
+    };
+    use sqlbuiltins::builtins::{DEFAULT_CATALOG, DEFAULT_SCHEMA};
+    use tempfile::tempdir;
+
+    use super::*;
     use crate::engine::{EngineStorageConfig, SessionStorageConfig};
     use crate::errors::Result;
 
+    #[tokio::test]
+    async fn purge_expired_dropped_tables_deletes_data() {
+        let data_dir = tempdir().unwrap();
+        let db_id = Uuid::new_v4();
+        let native = NativeTableStorage::new(
+            db_id,
+            Url::from_directory_path(data_dir.path()).unwrap(),
+            Arc::new(LocalFileSystem::new_with_prefix(data_dir.path()).unwrap()),
+        );
+        let supervisor = MetastoreClientSupervisor::new(
+            start_inprocess_inmemory().await.unwrap(),
+            DEFAULT_METASTORE_CLIENT_CONFIG,
+        );
+        let metastore = supervisor.init_client(db_id).await.unwrap();
+
+        let state = metastore.get_cached_state().await.unwrap();
+        let state = metastore
+            .try_mutate_and_commit(
+                state.version,
+                vec![
+                    Mutation::AlterDatabase(AlterDatabase {
+                        name: DEFAULT_CATALOG.to_string(),
+                        operation: AlterDatabaseOperation::SetDroppedTableRetention { hours: 1 },
+                    }),
+                    Mutation::CreateTable(CreateTable {
+                        schema: DEFAULT_SCHEMA.to_string(),
+                        name: "dropped".to_string(),
+                        options: TableOptionsInternal {
+                            columns: vec![InternalColumnDefinition {
+                                name: "a".to_string(),
+                                nullable: true,
+                                arrow_type: DataType::Int32,
+                            }],
+                            maintenance: None,
+                            partition_columns: Vec::new(),
+                            identity_columns: Vec::new(),
+                        },
+                        if_not_exists: false,
+                        or_replace: false,
+                        owner: 0,
+                    }),
+                ],
+            )
+            .await
+            .unwrap();
+        let table = state
+            .entries
+            .values()
+            .find_map(|ent| match ent {
+                CatalogEntry::Table(table) if table.meta.name == "dropped" => Some(table.clone()),
+                _ => None,
+            })
+            .unwrap();
+        native
+            .create_table(&table, SaveMode::ErrorIfExists)
+            .await
+            .unwrap();
+
+        let state = metastore
+            .try_mutate_and_commit(
+                state.version,
+                vec![Mutation::DropObject(DropObject {
+                    schema: DEFAULT_SCHEMA.to_string(),
+                    name: "dropped".to_string(),
+                    if_exists: false,
+                    retain: true,
+                })],
+            )
+            .await
+            .unwrap();
+        assert_eq!(1, state.dropped_tables.len());
+
+        // Not expired yet.
+        purge_expired_dropped_tables_on_load(&metastore, &native).await;
+        assert!(native.table_exists(&table).await.unwrap());
+        let state = metastore.get_cached_state().await.unwrap();
+        assert_eq!(1, state.dropped_tables.len());
+
+        // Expire the table, the next session to load the catalog purges it.
+        let mut expired = state.as_ref().clone();
+        expired.version += 1;
+        expired.dropped_tables[0].expires_at = 0;
+        metastore
+            .commit_state(state.version, expired)
+            .await
+            .unwrap();
+        metastore.refresh_cached_state().await.unwrap();
+
+        purge_expired_dropped_tables_on_load(&metastore, &native).await;
+        assert!(!native.table_exists(&table).await.unwrap());
+        metastore.refresh_cached_state().await.unwrap();
+        let state = metastore.get_cached_state().await.unwrap();
+        assert!(state.dropped_tables.is_empty());
+    }
+
     #[test]
     fn merged_conf_session_bucket() -> Result<()> {
         let access_key_id = "my_key".to_string();
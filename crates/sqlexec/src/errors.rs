@@ -27,6 +27,9 @@ pub enum ExecError {
     #[error("Unknown portal with name: {0}")]
     UnknownPortal(String),
 
+    #[error("Invalid query parameters: {0}")]
+    InvalidParameters(String),
+
     #[error("Empty search path, unable to resolve schema")]
     EmptySearchPath,
 
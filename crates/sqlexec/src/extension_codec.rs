@@ -6,6 +6,7 @@ use datafusion::arrow::datatypes::Schema;
 use datafusion::arrow::error::ArrowError;
 use datafusion::arrow::ipc::reader::FileReader as IpcFileReader;
 use datafusion::arrow::ipc::writer::FileWriter as IpcFileWriter;
+use datafusion::common::ScalarValue;
 use datafusion::error::{DataFusionError, Result};
 use datafusion::execution::{FunctionRegistry, TaskContext};
 use datafusion::physical_plan::analyze::AnalyzeExec;
@@ -13,6 +14,7 @@ use datafusion::physical_plan::union::InterleaveExec;
 use datafusion::physical_plan::values::ValuesExec;
 use datafusion::physical_plan::{displayable, ExecutionPlan};
 use datafusion::prelude::Expr;
+use datafusion_ext::functions::{FuncParamValue, TableVersion};
 use datafusion_ext::metrics::{
     ReadOnlyDataSourceMetricsExecAdapter,
     WriteOnlyDataSourceMetricsExecAdapter,
@@ -21,18 +23,25 @@ use datafusion_ext::runtime::runtime_group::RuntimeGroupExec;
 use datafusion_proto::logical_plan::from_proto::parse_expr;
 use datafusion_proto::physical_plan::PhysicalExtensionCodec;
 use prost::Message;
-use protogen::metastore::types::catalog::RuntimePreference;
+use protogen::metastore::types::catalog::{Privilege, RuntimePreference};
+use protogen::metastore::types::options::IdentityColumn;
+use protogen::metastore::types::service;
 use uuid::Uuid;
 
 use crate::planner::physical_plan::alter_database::AlterDatabaseExec;
 use crate::planner::physical_plan::alter_table::AlterTableExec;
 use crate::planner::physical_plan::alter_tunnel_rotate_keys::AlterTunnelRotateKeysExec;
+use crate::planner::physical_plan::analyze_table::{AnalyzeTableExec, AnalyzeTableTarget};
+use crate::planner::physical_plan::backup_database::BackupDatabaseExec;
 use crate::planner::physical_plan::client_recv::ClientExchangeRecvExec;
+use crate::planner::physical_plan::comment_on::CommentOnExec;
 use crate::planner::physical_plan::copy_to::CopyToExec;
 use crate::planner::physical_plan::create_credentials::CreateCredentialsExec;
 use crate::planner::physical_plan::create_external_database::CreateExternalDatabaseExec;
 use crate::planner::physical_plan::create_external_table::CreateExternalTableExec;
+use crate::planner::physical_plan::create_role::CreateRoleExec;
 use crate::planner::physical_plan::create_schema::CreateSchemaExec;
+use crate::planner::physical_plan::create_sequence::CreateSequenceExec;
 use crate::planner::physical_plan::create_table::CreateTableExec;
 use crate::planner::physical_plan::create_temp_table::CreateTempTableExec;
 use crate::planner::physical_plan::create_tunnel::CreateTunnelExec;
@@ -41,15 +50,23 @@ use crate::planner::physical_plan::delete::DeleteExec;
 use crate::planner::physical_plan::describe_table::DescribeTableExec;
 use crate::planner::physical_plan::drop_credentials::DropCredentialsExec;
 use crate::planner::physical_plan::drop_database::DropDatabaseExec;
+use crate::planner::physical_plan::drop_roles::DropRolesExec;
 use crate::planner::physical_plan::drop_schemas::DropSchemasExec;
+use crate::planner::physical_plan::drop_sequences::DropSequencesExec;
 use crate::planner::physical_plan::drop_tables::DropTablesExec;
 use crate::planner::physical_plan::drop_tunnel::DropTunnelExec;
 use crate::planner::physical_plan::drop_views::DropViewsExec;
+use crate::planner::physical_plan::grant_privileges::GrantPrivilegesExec;
 use crate::planner::physical_plan::insert::InsertExec;
+use crate::planner::physical_plan::optimize::OptimizeExec;
 use crate::planner::physical_plan::remote_scan::{ProviderReference, RemoteScanExec};
+use crate::planner::physical_plan::restore_table::RestoreTableExec;
+use crate::planner::physical_plan::revoke_privileges::RevokePrivilegesExec;
 use crate::planner::physical_plan::set_var::SetVarExec;
 use crate::planner::physical_plan::show_var::ShowVarExec;
+use crate::planner::physical_plan::undrop_table::UndropTableExec;
 use crate::planner::physical_plan::update::UpdateExec;
+use crate::planner::physical_plan::vacuum::VacuumExec;
 use crate::planner::physical_plan::values::ExtValuesExec;
 use crate::remote::provider_cache::ProviderCache;
 
@@ -236,6 +253,13 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                     or_replace: ext.or_replace,
                     arrow_schema: Arc::new(schema),
                     source: inputs.first().cloned(),
+                    partition_columns: ext.partition_columns,
+                    identity_columns: ext
+                        .identity_columns
+                        .into_iter()
+                        .map(IdentityColumn::try_from)
+                        .collect::<Result<_, _>>()?,
+                    owner: ext.owner,
                 })
             }
             proto::ExecutionPlanExtensionType::CreateTempTableExec(ext) => {
@@ -272,11 +296,77 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                 names: ext.names,
                 if_exists: ext.if_exists,
             }),
+            proto::ExecutionPlanExtensionType::CreateRoleExec(ext) => Arc::new(CreateRoleExec {
+                catalog_version: ext.catalog_version,
+                name: ext.name,
+                if_not_exists: ext.if_not_exists,
+                login: ext.login,
+                superuser: ext.superuser,
+                password: ext.password,
+                member_of: ext.member_of,
+            }),
+            proto::ExecutionPlanExtensionType::DropRolesExec(ext) => Arc::new(DropRolesExec {
+                catalog_version: ext.catalog_version,
+                names: ext.names,
+                if_exists: ext.if_exists,
+            }),
+            proto::ExecutionPlanExtensionType::GrantPrivilegesExec(ext) => {
+                Arc::new(GrantPrivilegesExec {
+                    catalog_version: ext.catalog_version,
+                    grantees: ext.grantees,
+                    object_ids: ext.object_ids,
+                    privileges: ext
+                        .privileges
+                        .into_iter()
+                        .map(Privilege::try_from)
+                        .collect::<Result<_, _>>()?,
+                })
+            }
+            proto::ExecutionPlanExtensionType::RevokePrivilegesExec(ext) => {
+                Arc::new(RevokePrivilegesExec {
+                    catalog_version: ext.catalog_version,
+                    grantees: ext.grantees,
+                    object_ids: ext.object_ids,
+                    privileges: ext
+                        .privileges
+                        .into_iter()
+                        .map(Privilege::try_from)
+                        .collect::<Result<_, _>>()?,
+                })
+            }
             proto::ExecutionPlanExtensionType::DropViewsExec(ext) => Arc::new(DropViewsExec {
                 catalog_version: ext.catalog_version,
                 view_references: ext.view_references.into_iter().map(|r| r.into()).collect(),
                 if_exists: ext.if_exists,
             }),
+            proto::ExecutionPlanExtensionType::CreateSequenceExec(ext) => {
+                let options = ext.options.ok_or(protogen::ProtoConvError::RequiredField(
+                    "options".to_string(),
+                ))?;
+                Arc::new(CreateSequenceExec {
+                    catalog_version: ext.catalog_version,
+                    sequence_reference: ext
+                        .sequence_reference
+                        .ok_or_else(|| {
+                            DataFusionError::Internal("missing sequence reference".to_string())
+                        })?
+                        .into(),
+                    options: options.into(),
+                    if_not_exists: ext.if_not_exists,
+                    owner: ext.owner,
+                })
+            }
+            proto::ExecutionPlanExtensionType::DropSequencesExec(ext) => {
+                Arc::new(DropSequencesExec {
+                    catalog_version: ext.catalog_version,
+                    sequence_references: ext
+                        .sequence_references
+                        .into_iter()
+                        .map(|r| r.into())
+                        .collect(),
+                    if_exists: ext.if_exists,
+                })
+            }
             proto::ExecutionPlanExtensionType::CreateExternalDatabaseExec(ext) => {
                 let options = ext.options.ok_or(protogen::ProtoConvError::RequiredField(
                     "options".to_string(),
@@ -311,6 +401,7 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                         .table_schema
                         .map(|ref schema| schema.try_into())
                         .transpose()?,
+                    owner: ext.owner,
                 })
             }
             proto::ExecutionPlanExtensionType::CreateTunnelExec(ext) => {
@@ -333,6 +424,7 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                 sql: ext.sql,
                 columns: ext.columns,
                 or_replace: ext.or_replace,
+                owner: ext.owner,
             }),
             proto::ExecutionPlanExtensionType::DropCredentialsExec(ext) => {
                 Arc::new(DropCredentialsExec {
@@ -351,6 +443,18 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                     .collect::<Result<_, _>>()
                     .expect("failed to decode table entries"),
                 if_exists: ext.if_exists,
+                purge_expired_before: ext.purge_expired_before,
+                purge_entries: ext
+                    .purge_entries
+                    .into_iter()
+                    .map(|r| r.try_into())
+                    .collect::<Result<_, _>>()
+                    .expect("failed to decode table entries"),
+            }),
+            proto::ExecutionPlanExtensionType::UndropTableExec(ext) => Arc::new(UndropTableExec {
+                catalog_version: ext.catalog_version,
+                schema: ext.schema,
+                name: ext.name,
             }),
             proto::ExecutionPlanExtensionType::SetVarExec(ext) => Arc::new(SetVarExec {
                 variable: ext.variable,
@@ -421,6 +525,82 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                     where_expr,
                 })
             }
+            proto::ExecutionPlanExtensionType::OptimizeExec(ext) => Arc::new(OptimizeExec {
+                table: ext
+                    .table
+                    .ok_or_else(|| DataFusionError::Internal("missing table".to_string()))?
+                    .try_into()?,
+                zorder_columns: ext.zorder_columns,
+            }),
+            proto::ExecutionPlanExtensionType::VacuumExec(ext) => Arc::new(VacuumExec {
+                table: ext
+                    .table
+                    .ok_or_else(|| DataFusionError::Internal("missing table".to_string()))?
+                    .try_into()?,
+                retain_hours: ext.retain_hours,
+                dry_run: ext.dry_run,
+            }),
+            proto::ExecutionPlanExtensionType::RestoreTableExec(ext) => {
+                let version = match (ext.version, ext.timestamp) {
+                    (Some(version), None) => TableVersion::Version(version),
+                    (None, Some(ts)) => TableVersion::try_from_timestamp(FuncParamValue::Scalar(
+                        ScalarValue::Utf8(Some(ts)),
+                    ))
+                    .map_err(|e| DataFusionError::Internal(e.to_string()))?,
+                    _ => {
+                        return Err(DataFusionError::Internal(
+                            "expected either a version or timestamp to restore".to_string(),
+                        ))
+                    }
+                };
+                Arc::new(RestoreTableExec {
+                    table: ext
+                        .table
+                        .ok_or_else(|| DataFusionError::Internal("missing table".to_string()))?
+                        .try_into()?,
+                    version,
+                })
+            }
+            proto::ExecutionPlanExtensionType::AnalyzeTableExec(ext) => {
+                if ext.tables.len() != inputs.len() {
+                    return Err(DataFusionError::Internal(format!(
+                        "expected {} input sources, got {}",
+                        ext.tables.len(),
+                        inputs.len()
+                    )));
+                }
+                Arc::new(AnalyzeTableExec {
+                    catalog_version: ext.catalog_version,
+                    tables: ext
+                        .tables
+                        .into_iter()
+                        .zip(inputs)
+                        .map(|(table, source)| AnalyzeTableTarget {
+                            schema: table.schema,
+                            name: table.name,
+                            columns: table.columns,
+                            source: source.clone(),
+                        })
+                        .collect(),
+                })
+            }
+            proto::ExecutionPlanExtensionType::CommentOnExec(ext) => {
+                let comment_on: service::CommentOn = ext
+                    .comment_on
+                    .ok_or_else(|| DataFusionError::Internal("missing comment".to_string()))?
+                    .try_into()?;
+                Arc::new(CommentOnExec {
+                    catalog_version: ext.catalog_version,
+                    object: comment_on.object,
+                    comment: comment_on.comment,
+                })
+            }
+            proto::ExecutionPlanExtensionType::BackupDatabaseExec(ext) => {
+                Arc::new(BackupDatabaseExec {
+                    location: ext.location,
+                    storage_options: ext.storage_options.unwrap_or_default().into(),
+                })
+            }
             proto::ExecutionPlanExtensionType::CopyToExec(ext) => Arc::new(CopyToExec {
                 format: ext
                     .format
@@ -553,6 +733,14 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                 if_not_exists: exec.if_not_exists,
                 or_replace: exec.or_replace,
                 arrow_schema: Some(exec.arrow_schema.clone().try_into()?),
+                partition_columns: exec.partition_columns.clone(),
+                identity_columns: exec
+                    .identity_columns
+                    .iter()
+                    .cloned()
+                    .map(Into::into)
+                    .collect(),
+                owner: exec.owner,
             })
         } else if let Some(exec) = node.as_any().downcast_ref::<CreateTempTableExec>() {
             proto::ExecutionPlanExtensionType::CreateTempTableExec(proto::CreateTempTableExec {
@@ -607,6 +795,55 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                 names: exec.names.clone(),
                 if_exists: exec.if_exists,
             })
+        } else if let Some(exec) = node.as_any().downcast_ref::<CreateRoleExec>() {
+            proto::ExecutionPlanExtensionType::CreateRoleExec(proto::CreateRoleExec {
+                catalog_version: exec.catalog_version,
+                name: exec.name.clone(),
+                if_not_exists: exec.if_not_exists,
+                login: exec.login,
+                superuser: exec.superuser,
+                password: exec.password.clone(),
+                member_of: exec.member_of.clone(),
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<DropRolesExec>() {
+            proto::ExecutionPlanExtensionType::DropRolesExec(proto::DropRolesExec {
+                catalog_version: exec.catalog_version,
+                names: exec.names.clone(),
+                if_exists: exec.if_exists,
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<GrantPrivilegesExec>() {
+            proto::ExecutionPlanExtensionType::GrantPrivilegesExec(proto::GrantPrivilegesExec {
+                catalog_version: exec.catalog_version,
+                grantees: exec.grantees.clone(),
+                object_ids: exec.object_ids.clone(),
+                privileges: exec.privileges.iter().map(|p| (*p).into()).collect(),
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<RevokePrivilegesExec>() {
+            proto::ExecutionPlanExtensionType::RevokePrivilegesExec(proto::RevokePrivilegesExec {
+                catalog_version: exec.catalog_version,
+                grantees: exec.grantees.clone(),
+                object_ids: exec.object_ids.clone(),
+                privileges: exec.privileges.iter().map(|p| (*p).into()).collect(),
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<CreateSequenceExec>() {
+            proto::ExecutionPlanExtensionType::CreateSequenceExec(proto::CreateSequenceExec {
+                catalog_version: exec.catalog_version,
+                sequence_reference: Some(exec.sequence_reference.clone().into()),
+                options: Some(exec.options.into()),
+                if_not_exists: exec.if_not_exists,
+                owner: exec.owner,
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<DropSequencesExec>() {
+            proto::ExecutionPlanExtensionType::DropSequencesExec(proto::DropSequencesExec {
+                catalog_version: exec.catalog_version,
+                sequence_references: exec
+                    .sequence_references
+                    .clone()
+                    .into_iter()
+                    .map(|r| r.into())
+                    .collect(),
+                if_exists: exec.if_exists,
+            })
         } else if let Some(exec) = node.as_any().downcast_ref::<DropViewsExec>() {
             proto::ExecutionPlanExtensionType::DropViewsExec(proto::DropViewsExec {
                 catalog_version: exec.catalog_version,
@@ -642,6 +879,7 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                         .as_ref()
                         .map(|schema| schema.try_into())
                         .transpose()?,
+                    owner: exec.owner,
                 },
             )
         } else if let Some(exec) = node.as_any().downcast_ref::<CreateTunnelExec>() {
@@ -658,6 +896,7 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                 sql: exec.sql.clone(),
                 columns: exec.columns.clone(),
                 or_replace: exec.or_replace,
+                owner: exec.owner,
             })
         } else if let Some(exec) = node.as_any().downcast_ref::<DescribeTableExec>() {
             proto::ExecutionPlanExtensionType::DescribeTable(proto::DescribeTableExec {
@@ -685,6 +924,19 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                     .map(|r| r.into())
                     .collect(),
                 if_exists: exec.if_exists,
+                purge_expired_before: exec.purge_expired_before,
+                purge_entries: exec
+                    .purge_entries
+                    .clone()
+                    .into_iter()
+                    .map(|r| r.into())
+                    .collect(),
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<UndropTableExec>() {
+            proto::ExecutionPlanExtensionType::UndropTableExec(proto::UndropTableExec {
+                catalog_version: exec.catalog_version,
+                schema: exec.schema.clone(),
+                name: exec.name.clone(),
             })
         } else if let Some(exec) = node.as_any().downcast_ref::<SetVarExec>() {
             proto::ExecutionPlanExtensionType::SetVarExec(proto::SetVarExec {
@@ -735,6 +987,56 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                     .map(|expr| expr.try_into())
                     .transpose()?,
             })
+        } else if let Some(exec) = node.as_any().downcast_ref::<OptimizeExec>() {
+            proto::ExecutionPlanExtensionType::OptimizeExec(proto::OptimizeExec {
+                table: Some(exec.table.clone().into()),
+                zorder_columns: exec.zorder_columns.clone(),
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<VacuumExec>() {
+            proto::ExecutionPlanExtensionType::VacuumExec(proto::VacuumExec {
+                table: Some(exec.table.clone().into()),
+                retain_hours: exec.retain_hours,
+                dry_run: exec.dry_run,
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<RestoreTableExec>() {
+            let (version, timestamp) = match exec.version {
+                TableVersion::Version(version) => (Some(version), None),
+                TableVersion::Timestamp(ts) => (None, Some(ts.to_rfc3339())),
+            };
+            proto::ExecutionPlanExtensionType::RestoreTableExec(proto::RestoreTableExec {
+                table: Some(exec.table.clone().into()),
+                version,
+                timestamp,
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<AnalyzeTableExec>() {
+            proto::ExecutionPlanExtensionType::AnalyzeTableExec(proto::AnalyzeTableExec {
+                catalog_version: exec.catalog_version,
+                tables: exec
+                    .tables
+                    .iter()
+                    .map(|table| proto::AnalyzeTableTarget {
+                        schema: table.schema.clone(),
+                        name: table.name.clone(),
+                        columns: table.columns.clone(),
+                    })
+                    .collect(),
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<CommentOnExec>() {
+            proto::ExecutionPlanExtensionType::CommentOnExec(proto::CommentOnExec {
+                catalog_version: exec.catalog_version,
+                comment_on: Some(
+                    service::CommentOn {
+                        object: exec.object.clone(),
+                        comment: exec.comment.clone(),
+                    }
+                    .into(),
+                ),
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<BackupDatabaseExec>() {
+            proto::ExecutionPlanExtensionType::BackupDatabaseExec(proto::BackupDatabaseExec {
+                location: exec.location.clone(),
+                storage_options: Some(exec.storage_options.clone().into()),
+            })
         } else if let Some(exec) = node.as_any().downcast_ref::<CopyToExec>() {
             proto::ExecutionPlanExtensionType::CopyToExec(proto::CopyToExec {
                 format: Some(exec.format.clone().try_into()?),
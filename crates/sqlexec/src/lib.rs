@@ -1,5 +1,7 @@
 //! SQL execution.
+pub mod backup;
 pub mod context;
+pub mod ddl;
 pub mod engine;
 pub mod environment;
 pub mod errors;
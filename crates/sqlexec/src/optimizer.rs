@@ -3,7 +3,14 @@ use datafusion::optimizer::optimizer::Optimizer;
 use datafusion::optimizer::OptimizerRule;
 
 use crate::planner::extension::{ExtensionNode, ExtensionType};
-use crate::planner::logical_plan::{CopyTo, CreateTable, CreateTempTable, Insert};
+use crate::planner::logical_plan::{
+    AnalyzeTable,
+    AnalyzeTarget,
+    CopyTo,
+    CreateTable,
+    CreateTempTable,
+    Insert,
+};
 
 fn require_downcast_lp<P: 'static>(plan: &dyn UserDefinedLogicalNode) -> &P {
     match plan.as_any().downcast_ref::<P>() {
@@ -77,6 +84,24 @@ impl OptimizerRule for DdlInputOptimizationRule {
                         let lp = Insert { source, ..lp };
                         Ok(Some(DFLogicalPlan::Extension(lp.into_extension())))
                     }
+                    ExtensionType::AnalyzeTable => {
+                        let lp = require_downcast_lp::<AnalyzeTable>(node).clone();
+                        let tables = lp
+                            .tables
+                            .into_iter()
+                            .map(|table| {
+                                let source = self.default_optimizer.optimize(
+                                    &table.source,
+                                    config,
+                                    |_, _| {},
+                                )?;
+                                Ok(AnalyzeTarget { source, ..table })
+                            })
+                            .collect::<datafusion::error::Result<Vec<_>>>()?;
+
+                        let lp = AnalyzeTable { tables };
+                        Ok(Some(DFLogicalPlan::Extension(lp.into_extension())))
+                    }
                     _ => Ok(None),
                 }
             }
@@ -174,6 +199,9 @@ mod test {
                 or_replace: false,
                 schema: schema.clone(),
                 source: Some(plan),
+                partition_columns: Vec::new(),
+                identity_columns: Vec::new(),
+                owner: 0,
             }
             .into_extension(),
         );
@@ -250,6 +278,8 @@ mod test {
                 or_replace: false,
                 schema: schema.clone(),
                 source: Some(plan),
+                partition_columns: Vec::new(),
+                identity_columns: Vec::new(),
             }
             .into_extension(),
         );
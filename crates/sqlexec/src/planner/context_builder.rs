@@ -5,18 +5,24 @@ use async_trait::async_trait;
 use datafusion::arrow::datatypes::DataType;
 use datafusion::common::OwnedTableReference;
 use datafusion::config::ConfigOptions;
-use datafusion::datasource::DefaultTableSource;
+use datafusion::datasource::{DefaultTableSource, TableProvider};
 use datafusion::error::{DataFusionError, Result as DataFusionResult};
 use datafusion::execution::context::SessionState;
 use datafusion::logical_expr::{AggregateUDF, TableSource, WindowUDF};
 use datafusion::prelude::Expr;
 use datafusion::sql::TableReference;
 use datafusion::variable::VarProvider;
-use datafusion_ext::functions::FuncParamValue;
+use datafusion_ext::functions::{FuncParamValue, TableVersion};
 use datafusion_ext::planner::AsyncContextProvider;
 use datafusion_ext::runtime::table_provider::RuntimeAwareTableProvider;
 use datafusion_ext::vars::CredentialsVarProvider;
-use protogen::metastore::types::catalog::{CatalogEntry, RuntimePreference};
+use protogen::metastore::types::catalog::{
+    CatalogEntry,
+    EntryMeta,
+    Privilege,
+    RuntimePreference,
+    TableEntry,
+};
 use protogen::metastore::types::options::TableOptionsV0;
 use protogen::rpcsrv::types::service::ResolvedTableReference;
 
@@ -24,6 +30,7 @@ use crate::context::local::LocalSessionContext;
 use crate::dispatch::{DispatchError, Dispatcher};
 use crate::errors::ExecError;
 use crate::planner::errors::PlanError;
+use crate::planner::sequences::plan_sequence_function;
 use crate::resolve::{EntryResolver, ResolvedEntry};
 
 /// Partial context provider with table providers required to fulfill a single
@@ -43,6 +50,11 @@ pub struct PartialContextProvider<'a> {
     /// Entry resolver to use to resolve tables and other objects.
     resolver: EntryResolver<'a>,
     runtime_preference: RuntimePreference,
+    /// Privilege the session's role needs on tables and views being resolved.
+    privilege: Privilege,
+    /// Provider for the target of a write, handed out once to the next lookup
+    /// of the same reference.
+    write_target: Option<(OwnedTableReference, RuntimeAwareTableProvider)>,
 }
 
 impl<'a> PartialContextProvider<'a> {
@@ -54,9 +66,66 @@ impl<'a> PartialContextProvider<'a> {
             ctx,
             resolver,
             runtime_preference: RuntimePreference::Unspecified,
+            privilege: Privilege::Select,
+            write_target: None,
         })
     }
 
+    /// Get the table provider for a table that will be written to.
+    ///
+    /// The session's role is checked for `privilege` instead of `SELECT` on
+    /// the table. Only the next lookup of the same reference (the planner
+    /// fetching the target's schema) reuses this provider, any other reads of
+    /// the table during planning still require `SELECT`.
+    pub async fn table_provider_for_write(
+        &mut self,
+        name: OwnedTableReference,
+        privilege: Privilege,
+    ) -> Result<RuntimeAwareTableProvider, PlanError> {
+        let prev = std::mem::replace(&mut self.privilege, privilege);
+        let result = self
+            .resolve_reference(TableReference::from(&name), None, None, None)
+            .await;
+        self.privilege = prev;
+        let provider = result?;
+        self.write_target = Some((name, provider.clone()));
+        Ok(provider)
+    }
+
+    /// Check that the session's role has the required privilege on an entry.
+    fn check_privilege(&self, meta: &EntryMeta) -> Result<(), PlanError> {
+        let vars = self.ctx.get_session_vars();
+        if self.ctx.get_session_catalog().role_has_privilege(
+            vars.catalog_role().as_deref(),
+            meta,
+            self.privilege,
+        ) {
+            Ok(())
+        } else {
+            Err(PlanError::PermissionDenied {
+                role: vars.user_name(),
+                action: format!("{} on '{}'", self.privilege, meta.name),
+            })
+        }
+    }
+
+    /// Check that the session's role is a superuser.
+    fn check_superuser(&self, action: &str) -> Result<(), PlanError> {
+        let vars = self.ctx.get_session_vars();
+        if self
+            .ctx
+            .get_session_catalog()
+            .role_is_superuser(vars.catalog_role().as_deref())
+        {
+            Ok(())
+        } else {
+            Err(PlanError::PermissionDenied {
+                role: vars.user_name(),
+                action: action.to_string(),
+            })
+        }
+    }
+
     fn new_dispatcher(&self) -> Dispatcher {
         Dispatcher::new(
             self.ctx.get_session_catalog(),
@@ -65,6 +134,8 @@ impl<'a> PartialContextProvider<'a> {
             self.ctx.df_ctx(),
             self.ctx.get_session_vars().is_cloud_instance(), // TODO: This locks, remove the locks
             self.ctx.function_registry(),
+            self.ctx.metrics_handler(),
+            self.ctx.get_session_vars().catalog_role(),
         )
     }
 
@@ -73,11 +144,18 @@ impl<'a> PartialContextProvider<'a> {
         &mut self,
         name: OwnedTableReference,
     ) -> Result<RuntimeAwareTableProvider, PlanError> {
+        if let Some((target, provider)) = self.write_target.take() {
+            if target == name {
+                return Ok(provider);
+            }
+            self.write_target = Some((target, provider));
+        }
+
         let provider = match self.providers.get(&name) {
             Some(provider) => provider.clone(),
             None => {
                 let provider = self
-                    .resolve_reference(TableReference::from(&name), None, None)
+                    .resolve_reference(TableReference::from(&name), None, None, None)
                     .await?;
                 self.providers.insert(name, provider.clone());
                 provider
@@ -96,11 +174,15 @@ impl<'a> PartialContextProvider<'a> {
     /// When the session is configured for hybrid exec, the returned table
     /// providers will have their runtime preferences set to where the table
     /// should be scanned (remote or local).
+    ///
+    /// If a version is provided, the reference must resolve to a native
+    /// table.
     async fn resolve_reference(
         &mut self,
         reference: TableReference<'_>,
         args: Option<Vec<FuncParamValue>>,
         opts: Option<HashMap<String, FuncParamValue>>,
+        version: Option<TableVersion>,
     ) -> Result<RuntimeAwareTableProvider, PlanError> {
         // Try to read from the environment first.
         //
@@ -112,6 +194,12 @@ impl<'a> PartialContextProvider<'a> {
                     .resolve_table(table)
                     .map_err(ExecError::EnvironmentTableRead)?
                 {
+                    if version.is_some() {
+                        return Err(PlanError::String(
+                            "AS OF is only supported for native tables".to_string(),
+                        ));
+                    }
+
                     // Hint that the table being scanned from the environment
                     // should be scanned client-side.
                     return Ok(RuntimeAwareTableProvider::new(
@@ -124,6 +212,23 @@ impl<'a> PartialContextProvider<'a> {
 
         let ent = self.resolver.resolve_entry_from_reference(reference)?;
 
+        if version.is_some() && !matches!(ent, ResolvedEntry::Entry(CatalogEntry::Table(_))) {
+            return Err(PlanError::String(
+                "AS OF is only supported for native tables".to_string(),
+            ));
+        }
+
+        match &ent {
+            ResolvedEntry::Entry(CatalogEntry::Table(table)) => {
+                self.check_privilege(&table.meta)?
+            }
+            ResolvedEntry::Entry(CatalogEntry::View(view)) => self.check_privilege(&view.meta)?,
+            ResolvedEntry::NeedsExternalResolution { db_ent, .. } => {
+                self.check_privilege(&db_ent.meta)?
+            }
+            _ => (),
+        }
+
         let client = self.ctx.exec_client();
         let provider = match ent {
             // Views
@@ -147,6 +252,9 @@ impl<'a> PartialContextProvider<'a> {
                         )))
                     }
                 };
+                if table_func.reads_external_data() {
+                    self.check_superuser(&format!("EXECUTE on '{}'", func.meta.name))?;
+                }
 
                 match client {
                     Some(mut client) => {
@@ -203,9 +311,19 @@ impl<'a> PartialContextProvider<'a> {
                     if run_local {
                         RuntimeAwareTableProvider::new(
                             RuntimePreference::Local,
-                            self.new_dispatcher().dispatch_table(&table).await?,
+                            self.dispatch_table(&table, version).await?,
                         )
                     } else {
+                        // The remote node reads the version from the options.
+                        let opts = match version {
+                            Some(version) => {
+                                let mut opts = opts.unwrap_or_default();
+                                opts.extend(version.into_opts());
+                                Some(opts)
+                            }
+                            None => opts,
+                        };
+
                         RuntimeAwareTableProvider::new(
                             RuntimePreference::Remote,
                             client
@@ -222,7 +340,7 @@ impl<'a> PartialContextProvider<'a> {
                 }
                 None => RuntimeAwareTableProvider::new(
                     RuntimePreference::Local,
-                    self.new_dispatcher().dispatch_table(&table).await?,
+                    self.dispatch_table(&table, version).await?,
                 ),
             },
 
@@ -265,6 +383,20 @@ impl<'a> PartialContextProvider<'a> {
 
         Ok(provider)
     }
+
+    /// Dispatch a table, optionally at a previous version.
+    async fn dispatch_table(
+        &self,
+        table: &TableEntry,
+        version: Option<TableVersion>,
+    ) -> Result<Arc<dyn TableProvider>, PlanError> {
+        let dispatcher = self.new_dispatcher();
+        let provider = match version {
+            Some(version) => dispatcher.dispatch_table_at_version(table, version).await?,
+            None => dispatcher.dispatch_table(table).await?,
+        };
+        Ok(provider)
+    }
 }
 
 #[async_trait]
@@ -282,13 +414,31 @@ impl<'a> AsyncContextProvider for PartialContextProvider<'a> {
         Ok(Arc::new(DefaultTableSource::new(Arc::new(provider))))
     }
 
+    async fn get_table_source_at_version(
+        &mut self,
+        name: TableReference<'_>,
+        version: TableVersion,
+    ) -> DataFusionResult<Arc<dyn TableSource>> {
+        // Versioned providers aren't cached since the same table may be read
+        // at multiple versions in a single query.
+        let provider = self
+            .resolve_reference(name.clone(), None, None, Some(version))
+            .await
+            .map_err(|e| {
+                DataFusionError::Plan(format!(
+                    "Unable to fetch table provider for '{name}' at {version}: {e}"
+                ))
+            })?;
+        Ok(Arc::new(DefaultTableSource::new(Arc::new(provider))))
+    }
+
     async fn get_table_function_source(
         &mut self,
         name: TableReference<'_>,
         args: Vec<FuncParamValue>,
         opts: HashMap<String, FuncParamValue>,
     ) -> DataFusionResult<Arc<dyn TableSource>> {
-        self.resolve_reference(name.to_owned_reference(), Some(args), Some(opts))
+        self.resolve_reference(name.to_owned_reference(), Some(args), Some(opts), None)
             .await
             .map(|p| Arc::new(DefaultTableSource::new(Arc::new(p))) as _)
             .map_err(|e| DataFusionError::External(Box::new(e)))
@@ -299,6 +449,12 @@ impl<'a> AsyncContextProvider for PartialContextProvider<'a> {
         name: &str,
         args: &[Expr],
     ) -> DataFusionResult<Option<Expr>> {
+        if let Some(expr) = plan_sequence_function(self.ctx, &self.resolver, name, args)
+            .map_err(|e| DataFusionError::External(Box::new(e)))?
+        {
+            return Ok(Some(expr));
+        }
+
         self.ctx
             .function_registry()
             .get_scalar_udf(name)
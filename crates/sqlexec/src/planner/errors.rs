@@ -71,6 +71,18 @@ pub enum PlanError {
     #[error("Not allowed to write into the object: {0}")]
     ObjectNotAllowedToWriteInto(OwnedTableReference),
 
+    #[error("Permission denied for role '{role}': {action}")]
+    PermissionDenied { role: String, action: String },
+
+    #[error("{operation} is only supported for native tables, '{table}' is not one")]
+    NotNativeTable {
+        operation: &'static str,
+        table: String,
+    },
+
+    #[error("Invalid role statement: {msg}")]
+    InvalidRoleStatement { msg: &'static str },
+
     #[error("Exec error: {0}")]
     Exec(Box<crate::errors::ExecError>), // TODO: Try to remove.
 
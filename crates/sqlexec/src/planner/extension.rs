@@ -7,11 +7,16 @@ use super::logical_plan::{
     AlterDatabase,
     AlterTable,
     AlterTunnelRotateKeys,
+    AnalyzeTable,
+    BackupDatabase,
+    CommentOn,
     CopyTo,
     CreateCredentials,
     CreateExternalDatabase,
     CreateExternalTable,
+    CreateRole,
     CreateSchema,
+    CreateSequence,
     CreateTable,
     CreateTempTable,
     CreateTunnel,
@@ -20,14 +25,22 @@ use super::logical_plan::{
     DescribeTable,
     DropCredentials,
     DropDatabase,
+    DropRoles,
     DropSchemas,
+    DropSequences,
     DropTables,
     DropTunnel,
     DropViews,
+    GrantPrivileges,
     Insert,
+    Optimize,
+    RestoreTable,
+    RevokePrivileges,
     SetVariable,
     ShowVariable,
+    UndropTable,
     Update,
+    Vacuum,
 };
 use crate::errors::{internal, ExecError, Result};
 use crate::LogicalPlan;
@@ -43,7 +56,9 @@ pub enum ExtensionType {
     CreateCredentials,
     CreateExternalDatabase,
     CreateExternalTable,
+    CreateRole,
     CreateSchema,
+    CreateSequence,
     CreateTable,
     CreateTempTable,
     CreateTunnel,
@@ -52,15 +67,26 @@ pub enum ExtensionType {
     DropTables,
     DropCredentials,
     DropDatabase,
+    DropRoles,
     DropSchemas,
+    DropSequences,
     DropTunnel,
     DropViews,
+    GrantPrivileges,
+    RevokePrivileges,
     SetVariable,
     ShowVariable,
     CopyTo,
     Update,
     Insert,
     Delete,
+    Optimize,
+    Vacuum,
+    UndropTable,
+    RestoreTable,
+    AnalyzeTable,
+    CommentOn,
+    BackupDatabase,
 }
 
 impl FromStr for ExtensionType {
@@ -73,7 +99,9 @@ impl FromStr for ExtensionType {
             CreateCredentials::EXTENSION_NAME => Self::CreateCredentials,
             CreateExternalDatabase::EXTENSION_NAME => Self::CreateExternalDatabase,
             CreateExternalTable::EXTENSION_NAME => Self::CreateExternalTable,
+            CreateRole::EXTENSION_NAME => Self::CreateRole,
             CreateSchema::EXTENSION_NAME => Self::CreateSchema,
+            CreateSequence::EXTENSION_NAME => Self::CreateSequence,
             CreateTable::EXTENSION_NAME => Self::CreateTable,
             CreateTempTable::EXTENSION_NAME => Self::CreateTempTable,
             CreateTunnel::EXTENSION_NAME => Self::CreateTunnel,
@@ -82,15 +110,26 @@ impl FromStr for ExtensionType {
             DropTables::EXTENSION_NAME => Self::DropTables,
             DropCredentials::EXTENSION_NAME => Self::DropCredentials,
             DropDatabase::EXTENSION_NAME => Self::DropDatabase,
+            DropRoles::EXTENSION_NAME => Self::DropRoles,
             DropSchemas::EXTENSION_NAME => Self::DropSchemas,
+            DropSequences::EXTENSION_NAME => Self::DropSequences,
             DropTunnel::EXTENSION_NAME => Self::DropTunnel,
             DropViews::EXTENSION_NAME => Self::DropViews,
+            GrantPrivileges::EXTENSION_NAME => Self::GrantPrivileges,
+            RevokePrivileges::EXTENSION_NAME => Self::RevokePrivileges,
             SetVariable::EXTENSION_NAME => Self::SetVariable,
             ShowVariable::EXTENSION_NAME => Self::ShowVariable,
             CopyTo::EXTENSION_NAME => Self::CopyTo,
             Update::EXTENSION_NAME => Self::Update,
             Insert::EXTENSION_NAME => Self::Insert,
             Delete::EXTENSION_NAME => Self::Delete,
+            Optimize::EXTENSION_NAME => Self::Optimize,
+            Vacuum::EXTENSION_NAME => Self::Vacuum,
+            UndropTable::EXTENSION_NAME => Self::UndropTable,
+            RestoreTable::EXTENSION_NAME => Self::RestoreTable,
+            AnalyzeTable::EXTENSION_NAME => Self::AnalyzeTable,
+            CommentOn::EXTENSION_NAME => Self::CommentOn,
+            BackupDatabase::EXTENSION_NAME => Self::BackupDatabase,
             _ => return Err(internal!("unknown extension type: {}", s)),
         })
     }
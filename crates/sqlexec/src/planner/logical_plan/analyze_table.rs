@@ -0,0 +1,76 @@
+use super::{
+    DfLogicalPlan,
+    ExtensionNode,
+    UserDefinedLogicalNodeCore,
+    GENERIC_OPERATION_LOGICAL_SCHEMA,
+};
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct AnalyzeTable {
+    /// Tables to analyze, statistics for all of them are committed together.
+    pub tables: Vec<AnalyzeTarget>,
+}
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct AnalyzeTarget {
+    pub schema: String,
+    pub name: String,
+    /// Columns of the table in the order they're aggregated in `source`.
+    pub columns: Vec<String>,
+    /// Single row aggregate over the table. See
+    /// `SessionPlanner::plan_analyze_target` for the layout.
+    pub source: DfLogicalPlan,
+}
+
+impl std::fmt::Debug for AnalyzeTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnalyzeTable")
+            .field("tables", &self.tables)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for AnalyzeTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnalyzeTarget")
+            .field("schema", &self.schema)
+            .field("name", &self.name)
+            .field("columns", &self.columns)
+            .field("source", &self.source.schema())
+            .finish()
+    }
+}
+
+impl UserDefinedLogicalNodeCore for AnalyzeTable {
+    fn name(&self) -> &str {
+        Self::EXTENSION_NAME
+    }
+
+    fn inputs(&self) -> Vec<&DfLogicalPlan> {
+        self.tables.iter().map(|table| &table.source).collect()
+    }
+
+    fn schema(&self) -> &datafusion::common::DFSchemaRef {
+        &GENERIC_OPERATION_LOGICAL_SCHEMA
+    }
+
+    fn expressions(&self) -> Vec<datafusion::prelude::Expr> {
+        vec![]
+    }
+
+    fn fmt_for_explain(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", Self::EXTENSION_NAME)
+    }
+
+    fn from_template(
+        &self,
+        _exprs: &[datafusion::prelude::Expr],
+        _inputs: &[DfLogicalPlan],
+    ) -> Self {
+        self.clone()
+    }
+}
+
+impl ExtensionNode for AnalyzeTable {
+    const EXTENSION_NAME: &'static str = "AnalyzeTable";
+}
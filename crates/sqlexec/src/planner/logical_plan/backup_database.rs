@@ -0,0 +1,63 @@
+use datafusion::arrow::datatypes::{Field, Schema, SchemaRef};
+use datafusion::common::ToDFSchema;
+use protogen::metastore::types::options::StorageOptions;
+
+use super::{
+    Arc,
+    DFSchemaRef,
+    DataType,
+    DfLogicalPlan,
+    ExtensionNode,
+    Lazy,
+    UserDefinedLogicalNodeCore,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BackupDatabase {
+    pub location: String,
+    pub storage_options: StorageOptions,
+}
+
+pub static BACKUP_DATABASE_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("catalog_version", DataType::UInt64, false),
+        Field::new("tables", DataType::UInt64, false),
+    ]))
+});
+
+pub static BACKUP_DATABASE_LOGICAL_SCHEMA: Lazy<DFSchemaRef> =
+    Lazy::new(|| BACKUP_DATABASE_SCHEMA.clone().to_dfschema_ref().unwrap());
+
+impl UserDefinedLogicalNodeCore for BackupDatabase {
+    fn name(&self) -> &str {
+        Self::EXTENSION_NAME
+    }
+
+    fn inputs(&self) -> Vec<&DfLogicalPlan> {
+        Vec::new()
+    }
+
+    fn schema(&self) -> &datafusion::common::DFSchemaRef {
+        &BACKUP_DATABASE_LOGICAL_SCHEMA
+    }
+
+    fn expressions(&self) -> Vec<datafusion::prelude::Expr> {
+        Vec::new()
+    }
+
+    fn fmt_for_explain(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", Self::EXTENSION_NAME)
+    }
+
+    fn from_template(
+        &self,
+        _exprs: &[datafusion::prelude::Expr],
+        _inputs: &[DfLogicalPlan],
+    ) -> Self {
+        self.clone()
+    }
+}
+
+impl ExtensionNode for BackupDatabase {
+    const EXTENSION_NAME: &'static str = "BackupDatabase";
+}
@@ -17,6 +17,8 @@ pub struct CreateExternalTable {
     pub table_options: TableOptionsV0,
     pub tunnel: Option<String>,
     pub schema: Option<Schema>,
+    /// OID of the role creating the table, zero if not a catalog role.
+    pub owner: u32,
 }
 
 impl UserDefinedLogicalNodeCore for CreateExternalTable {
@@ -0,0 +1,52 @@
+use protogen::metastore::types::options::SequenceOptions;
+
+use super::{
+    DfLogicalPlan,
+    ExtensionNode,
+    OwnedFullObjectReference,
+    UserDefinedLogicalNodeCore,
+    GENERIC_OPERATION_LOGICAL_SCHEMA,
+};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct CreateSequence {
+    pub sequence_reference: OwnedFullObjectReference,
+    pub options: SequenceOptions,
+    pub if_not_exists: bool,
+    /// OID of the role creating the sequence, zero if not a catalog role.
+    pub owner: u32,
+}
+
+impl UserDefinedLogicalNodeCore for CreateSequence {
+    fn name(&self) -> &str {
+        Self::EXTENSION_NAME
+    }
+
+    fn inputs(&self) -> Vec<&DfLogicalPlan> {
+        vec![]
+    }
+
+    fn schema(&self) -> &datafusion::common::DFSchemaRef {
+        &GENERIC_OPERATION_LOGICAL_SCHEMA
+    }
+
+    fn expressions(&self) -> Vec<datafusion::prelude::Expr> {
+        vec![]
+    }
+
+    fn fmt_for_explain(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "CreateSequence")
+    }
+
+    fn from_template(
+        &self,
+        _exprs: &[datafusion::prelude::Expr],
+        _inputs: &[DfLogicalPlan],
+    ) -> Self {
+        self.clone()
+    }
+}
+
+impl ExtensionNode for CreateSequence {
+    const EXTENSION_NAME: &'static str = "CreateSequence";
+}
@@ -1,3 +1,5 @@
+use protogen::metastore::types::options::IdentityColumn;
+
 use super::{
     DFSchemaRef,
     DfLogicalPlan,
@@ -14,6 +16,12 @@ pub struct CreateTable {
     pub or_replace: bool,
     pub schema: DFSchemaRef,
     pub source: Option<DfLogicalPlan>,
+    /// Columns to partition the table by.
+    pub partition_columns: Vec<String>,
+    /// Columns filled in from sequences on insert.
+    pub identity_columns: Vec<IdentityColumn>,
+    /// OID of the role creating the table, zero if not a catalog role.
+    pub owner: u32,
 }
 
 impl UserDefinedLogicalNodeCore for CreateTable {
@@ -11,6 +11,8 @@ pub struct CreateView {
     pub sql: String,
     pub columns: Vec<String>,
     pub or_replace: bool,
+    /// OID of the role creating the view, zero if not a catalog role.
+    pub owner: u32,
 }
 
 impl UserDefinedLogicalNodeCore for CreateView {
@@ -22,6 +22,7 @@ pub static DESCRIBE_TABLE_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
         Field::new("column_name", DataType::Utf8, false),
         Field::new("data_type", DataType::Utf8, false),
         Field::new("is_nullable", DataType::Boolean, false),
+        Field::new("comment", DataType::Utf8, true),
     ]))
 });
 
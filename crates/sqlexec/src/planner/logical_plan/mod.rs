@@ -1,11 +1,16 @@
 mod alter_database;
 mod alter_table;
 mod alter_tunnel_rotate_keys;
+mod analyze_table;
+mod backup_database;
+mod comment_on;
 mod copy_to;
 mod create_credentials;
 mod create_external_database;
 mod create_external_table;
+mod create_role;
 mod create_schema;
+mod create_sequence;
 mod create_table;
 mod create_temp_table;
 mod create_tunnel;
@@ -14,14 +19,22 @@ mod delete;
 mod describe_table;
 mod drop_credentials;
 mod drop_database;
+mod drop_roles;
 mod drop_schemas;
+mod drop_sequences;
 mod drop_tables;
 mod drop_tunnel;
 mod drop_views;
+mod grant_privileges;
 mod insert;
+mod optimize;
+mod restore_table;
+mod revoke_privileges;
 mod set_variable;
 mod show_variable;
+mod undrop_table;
 mod update;
+mod vacuum;
 
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -31,11 +44,16 @@ use std::sync::Arc;
 pub use alter_database::*;
 pub use alter_table::*;
 pub use alter_tunnel_rotate_keys::*;
+pub use analyze_table::*;
+pub use backup_database::*;
+pub use comment_on::*;
 pub use copy_to::*;
 pub use create_credentials::*;
 pub use create_external_database::*;
 pub use create_external_table::*;
+pub use create_role::*;
 pub use create_schema::*;
+pub use create_sequence::*;
 pub use create_table::*;
 pub use create_temp_table::*;
 pub use create_tunnel::*;
@@ -54,12 +72,16 @@ pub use delete::*;
 pub use describe_table::*;
 pub use drop_credentials::*;
 pub use drop_database::*;
+pub use drop_roles::*;
 pub use drop_schemas::*;
+pub use drop_sequences::*;
 pub use drop_tables::*;
 pub use drop_tunnel::*;
 pub use drop_views::*;
+pub use grant_privileges::*;
 pub use insert::*;
 use once_cell::sync::Lazy;
+pub use optimize::*;
 use parser::sqlparser::ast;
 use protogen::metastore::types::options::{
     CopyToDestinationOptions,
@@ -68,15 +90,19 @@ use protogen::metastore::types::options::{
     DatabaseOptions,
     TunnelOptions,
 };
+pub use restore_table::*;
+pub use revoke_privileges::*;
 pub use set_variable::*;
 pub use show_variable::*;
+pub use undrop_table::*;
 pub use update::*;
+pub use vacuum::*;
 
 use super::physical_plan::{
     GENERIC_OPERATION_AND_COUNT_PHYSICAL_SCHEMA,
     GENERIC_OPERATION_PHYSICAL_SCHEMA,
 };
-use crate::errors::{internal, Result};
+use crate::errors::{internal, ExecError, Result};
 use crate::planner::extension::ExtensionNode;
 
 pub static GENERIC_OPERATION_LOGICAL_SCHEMA: Lazy<DFSchemaRef> = Lazy::new(|| {
@@ -166,7 +192,16 @@ impl LogicalPlan {
     ///
     /// Note this currently only replaces placeholders for datafusion plans.
     pub fn replace_placeholders(&mut self, scalars: Vec<ScalarValue>) -> Result<()> {
-        let param_values = ParamValues::List(scalars);
+        self.replace_param_values(ParamValues::List(scalars))
+    }
+
+    /// Replace placeholders in this plan with the provided parameter values.
+    ///
+    /// Positional placeholders (`$1`, `$2`, ...) take their values from a
+    /// list, named placeholders (`:name`, `$name`) from a map keyed by name.
+    /// Values are cast to the type inferred for their placeholder.
+    pub fn replace_param_values(&mut self, params: ParamValues) -> Result<()> {
+        let param_values = coerce_param_values(self.get_parameter_types()?, params)?;
 
         if let LogicalPlan::Datafusion(plan) = self {
             // Replace placeholders in the inner plan if the wrapped in an
@@ -195,6 +230,72 @@ impl LogicalPlan {
     }
 }
 
+/// Check that the provided parameter values cover exactly the placeholders of
+/// a plan, casting each value to the type of its placeholder if known.
+fn coerce_param_values(
+    types: HashMap<String, Option<DataType>>,
+    params: ParamValues,
+) -> Result<ParamValues> {
+    let mut types: Vec<_> = types.into_iter().collect();
+    types.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let coerce = |id: &str, value: &ScalarValue, typ: &Option<DataType>| match typ {
+        Some(typ) if &value.data_type() != typ => value.cast_to(typ).map_err(|_| {
+            ExecError::InvalidParameters(format!(
+                "cannot use value {value} of type {} for {id}, expected {typ}",
+                value.data_type()
+            ))
+        }),
+        _ => Ok(value.clone()),
+    };
+
+    match params {
+        ParamValues::List(mut values) => {
+            let mut expected = 0;
+            for (id, typ) in &types {
+                let idx = match id[1..].parse::<usize>() {
+                    Ok(idx) if idx > 0 => idx - 1,
+                    _ => {
+                        return Err(ExecError::InvalidParameters(format!(
+                            "query uses named parameter {id}, values must be provided by name"
+                        )))
+                    }
+                };
+                expected = expected.max(idx + 1);
+                if idx < values.len() {
+                    values[idx] = coerce(id, &values[idx], typ)?;
+                }
+            }
+            if values.len() != expected {
+                return Err(ExecError::InvalidParameters(format!(
+                    "expected {expected} values, got {}",
+                    values.len()
+                )));
+            }
+            Ok(ParamValues::List(values))
+        }
+        ParamValues::Map(mut values) => {
+            for (id, typ) in &types {
+                let name = &id[1..];
+                let value = values.get(name).ok_or_else(|| {
+                    ExecError::InvalidParameters(format!("missing value for {id}"))
+                })?;
+                let value = coerce(id, value, typ)?;
+                values.insert(name.to_string(), value);
+            }
+            if let Some(name) = values
+                .keys()
+                .find(|name| !types.iter().any(|(id, _)| &id[1..] == name.as_str()))
+            {
+                return Err(ExecError::InvalidParameters(format!(
+                    "query has no parameter named {name}"
+                )));
+            }
+            Ok(ParamValues::Map(values))
+        }
+    }
+}
+
 impl From<DfLogicalPlan> for LogicalPlan {
     fn from(plan: DfLogicalPlan) -> Self {
         LogicalPlan::Datafusion(plan)
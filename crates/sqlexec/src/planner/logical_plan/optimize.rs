@@ -0,0 +1,66 @@
+use datafusion::arrow::datatypes::{Field, Schema, SchemaRef};
+use datafusion::common::ToDFSchema;
+use protogen::metastore::types::catalog::TableEntry;
+
+use super::{
+    Arc,
+    DFSchemaRef,
+    DataType,
+    DfLogicalPlan,
+    ExtensionNode,
+    Lazy,
+    UserDefinedLogicalNodeCore,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Optimize {
+    pub table: TableEntry,
+    pub zorder_columns: Vec<String>,
+}
+
+pub static OPTIMIZE_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("files_added", DataType::UInt64, false),
+        Field::new("files_removed", DataType::UInt64, false),
+        Field::new("partitions_optimized", DataType::UInt64, false),
+        Field::new("files_considered", DataType::UInt64, false),
+        Field::new("files_skipped", DataType::UInt64, false),
+    ]))
+});
+
+pub static OPTIMIZE_LOGICAL_SCHEMA: Lazy<DFSchemaRef> =
+    Lazy::new(|| OPTIMIZE_SCHEMA.clone().to_dfschema_ref().unwrap());
+
+impl UserDefinedLogicalNodeCore for Optimize {
+    fn name(&self) -> &str {
+        Self::EXTENSION_NAME
+    }
+
+    fn inputs(&self) -> Vec<&DfLogicalPlan> {
+        Vec::new()
+    }
+
+    fn schema(&self) -> &datafusion::common::DFSchemaRef {
+        &OPTIMIZE_LOGICAL_SCHEMA
+    }
+
+    fn expressions(&self) -> Vec<datafusion::prelude::Expr> {
+        Vec::new()
+    }
+
+    fn fmt_for_explain(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", Self::EXTENSION_NAME)
+    }
+
+    fn from_template(
+        &self,
+        _exprs: &[datafusion::prelude::Expr],
+        _inputs: &[DfLogicalPlan],
+    ) -> Self {
+        self.clone()
+    }
+}
+
+impl ExtensionNode for Optimize {
+    const EXTENSION_NAME: &'static str = "Optimize";
+}
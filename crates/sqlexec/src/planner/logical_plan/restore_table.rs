@@ -0,0 +1,65 @@
+use datafusion::arrow::datatypes::{Field, Schema, SchemaRef};
+use datafusion::common::ToDFSchema;
+use datafusion_ext::functions::TableVersion;
+use protogen::metastore::types::catalog::TableEntry;
+
+use super::{
+    Arc,
+    DFSchemaRef,
+    DataType,
+    DfLogicalPlan,
+    ExtensionNode,
+    Lazy,
+    UserDefinedLogicalNodeCore,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RestoreTable {
+    pub table: TableEntry,
+    pub version: TableVersion,
+}
+
+pub static RESTORE_TABLE_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("version", DataType::Int64, false),
+        Field::new("files_removed", DataType::UInt64, false),
+        Field::new("files_restored", DataType::UInt64, false),
+    ]))
+});
+
+pub static RESTORE_TABLE_LOGICAL_SCHEMA: Lazy<DFSchemaRef> =
+    Lazy::new(|| RESTORE_TABLE_SCHEMA.clone().to_dfschema_ref().unwrap());
+
+impl UserDefinedLogicalNodeCore for RestoreTable {
+    fn name(&self) -> &str {
+        Self::EXTENSION_NAME
+    }
+
+    fn inputs(&self) -> Vec<&DfLogicalPlan> {
+        Vec::new()
+    }
+
+    fn schema(&self) -> &datafusion::common::DFSchemaRef {
+        &RESTORE_TABLE_LOGICAL_SCHEMA
+    }
+
+    fn expressions(&self) -> Vec<datafusion::prelude::Expr> {
+        Vec::new()
+    }
+
+    fn fmt_for_explain(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", Self::EXTENSION_NAME)
+    }
+
+    fn from_template(
+        &self,
+        _exprs: &[datafusion::prelude::Expr],
+        _inputs: &[DfLogicalPlan],
+    ) -> Self {
+        self.clone()
+    }
+}
+
+impl ExtensionNode for RestoreTable {
+    const EXTENSION_NAME: &'static str = "RestoreTable";
+}
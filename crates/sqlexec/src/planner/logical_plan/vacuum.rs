@@ -0,0 +1,65 @@
+use datafusion::arrow::datatypes::{Field, Schema, SchemaRef};
+use datafusion::common::ToDFSchema;
+use protogen::metastore::types::catalog::TableEntry;
+
+use super::{
+    Arc,
+    DFSchemaRef,
+    DataType,
+    DfLogicalPlan,
+    ExtensionNode,
+    Lazy,
+    UserDefinedLogicalNodeCore,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Vacuum {
+    pub table: TableEntry,
+    pub retain_hours: Option<u64>,
+    pub dry_run: bool,
+}
+
+pub static VACUUM_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![Field::new(
+        "files_deleted",
+        DataType::UInt64,
+        false,
+    )]))
+});
+
+pub static VACUUM_LOGICAL_SCHEMA: Lazy<DFSchemaRef> =
+    Lazy::new(|| VACUUM_SCHEMA.clone().to_dfschema_ref().unwrap());
+
+impl UserDefinedLogicalNodeCore for Vacuum {
+    fn name(&self) -> &str {
+        Self::EXTENSION_NAME
+    }
+
+    fn inputs(&self) -> Vec<&DfLogicalPlan> {
+        Vec::new()
+    }
+
+    fn schema(&self) -> &datafusion::common::DFSchemaRef {
+        &VACUUM_LOGICAL_SCHEMA
+    }
+
+    fn expressions(&self) -> Vec<datafusion::prelude::Expr> {
+        Vec::new()
+    }
+
+    fn fmt_for_explain(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", Self::EXTENSION_NAME)
+    }
+
+    fn from_template(
+        &self,
+        _exprs: &[datafusion::prelude::Expr],
+        _inputs: &[DfLogicalPlan],
+    ) -> Self {
+        self.clone()
+    }
+}
+
+impl ExtensionNode for Vacuum {
+    const EXTENSION_NAME: &'static str = "Vacuum";
+}
@@ -0,0 +1,227 @@
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use catalog::mutator::CatalogMutator;
+use datafusion::arrow::array::{Array, AsArray};
+use datafusion::arrow::datatypes::{Schema, UInt64Type};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::error::{DataFusionError, Result as DataFusionResult};
+use datafusion::execution::TaskContext;
+use datafusion::physical_expr::PhysicalSortExpr;
+use datafusion::physical_plan::coalesce_partitions::CoalescePartitionsExec;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::{
+    common,
+    DisplayAs,
+    DisplayFormatType,
+    ExecutionPlan,
+    Partitioning,
+    SendableRecordBatchStream,
+    Statistics,
+};
+use futures::stream;
+use protogen::metastore::types::catalog::{ColumnStatistics, TableStatistics};
+use protogen::metastore::types::service::{self, AlterTableOperation, Mutation};
+
+use super::{new_operation_batch, GENERIC_OPERATION_PHYSICAL_SCHEMA};
+
+/// Computes statistics for tables and stores them in the catalog.
+///
+/// Statistics for all tables are committed in a single catalog mutation.
+#[derive(Debug, Clone)]
+pub struct AnalyzeTableExec {
+    pub catalog_version: u64,
+    pub tables: Vec<AnalyzeTableTarget>,
+}
+
+/// A table to analyze.
+///
+/// The source is expected to produce a single row containing the row count
+/// followed by the non-null count, min, max and approximate distinct count
+/// for each column in `columns`.
+#[derive(Debug, Clone)]
+pub struct AnalyzeTableTarget {
+    pub schema: String,
+    pub name: String,
+    pub columns: Vec<String>,
+    pub source: Arc<dyn ExecutionPlan>,
+}
+
+impl ExecutionPlan for AnalyzeTableExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        GENERIC_OPERATION_PHYSICAL_SCHEMA.clone()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(1)
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        None
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        self.tables
+            .iter()
+            .map(|table| table.source.clone())
+            .collect()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
+        if children.len() != self.tables.len() {
+            return Err(DataFusionError::Plan(
+                "AnalyzeTableExec expects one child per table".to_string(),
+            ));
+        }
+        Ok(Arc::new(AnalyzeTableExec {
+            catalog_version: self.catalog_version,
+            tables: self
+                .tables
+                .iter()
+                .zip(children)
+                .map(|(table, source)| AnalyzeTableTarget {
+                    source,
+                    ..table.clone()
+                })
+                .collect(),
+        }))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> DataFusionResult<SendableRecordBatchStream> {
+        if partition != 0 {
+            return Err(DataFusionError::Execution(
+                "AnalyzeTableExec only supports 1 partition".to_string(),
+            ));
+        }
+
+        let mutator = context
+            .session_config()
+            .get_extension::<CatalogMutator>()
+            .expect("context should have catalog mutator");
+
+        let stream = stream::once(analyze_tables(mutator, self.clone(), context));
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema(),
+            stream,
+        )))
+    }
+
+    fn statistics(&self) -> DataFusionResult<Statistics> {
+        Ok(Statistics::new_unknown(self.schema().as_ref()))
+    }
+}
+
+impl DisplayAs for AnalyzeTableExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AnalyzeTableExec")
+    }
+}
+
+async fn analyze_tables(
+    mutator: Arc<CatalogMutator>,
+    plan: AnalyzeTableExec,
+    context: Arc<TaskContext>,
+) -> DataFusionResult<RecordBatch> {
+    let mut mutations = Vec::with_capacity(plan.tables.len());
+    for table in plan.tables {
+        let schema = table.schema.clone();
+        let name = table.name.clone();
+        let statistics = analyze_table(table, context.clone()).await?;
+        mutations.push(Mutation::AlterTable(service::AlterTable {
+            schema,
+            name,
+            operation: AlterTableOperation::SetStatistics { statistics },
+        }));
+    }
+
+    if !mutations.is_empty() {
+        mutator
+            .mutate_and_commit(plan.catalog_version, mutations)
+            .await
+            .map_err(|e| DataFusionError::Execution(format!("failed to analyze table: {e}")))?;
+    }
+
+    Ok(new_operation_batch("analyze"))
+}
+
+async fn analyze_table(
+    table: AnalyzeTableTarget,
+    context: Arc<TaskContext>,
+) -> DataFusionResult<TableStatistics> {
+    let source = if table.source.output_partitioning().partition_count() != 1 {
+        Arc::new(CoalescePartitionsExec::new(table.source))
+    } else {
+        table.source
+    };
+
+    let batches = common::collect(source.execute(0, context)?).await?;
+    let batch = batches
+        .iter()
+        .find(|batch| batch.num_rows() > 0)
+        .ok_or_else(|| DataFusionError::Execution("Missing table statistics".to_string()))?;
+
+    if batch.num_columns() != 1 + table.columns.len() * 4 {
+        return Err(DataFusionError::Execution(format!(
+            "Unexpected number of statistics columns: {}",
+            batch.num_columns()
+        )));
+    }
+
+    let count = |idx: usize| -> DataFusionResult<Option<u64>> {
+        let arr = batch
+            .column(idx)
+            .as_primitive_opt::<UInt64Type>()
+            .ok_or_else(|| DataFusionError::Execution("Expected count column".to_string()))?;
+        Ok(arr.is_valid(0).then(|| arr.value(0)))
+    };
+    let value = |idx: usize| -> DataFusionResult<Option<String>> {
+        let arr = batch
+            .column(idx)
+            .as_string_opt::<i32>()
+            .ok_or_else(|| DataFusionError::Execution("Expected value column".to_string()))?;
+        Ok(arr.is_valid(0).then(|| arr.value(0).to_string()))
+    };
+
+    let num_rows = count(0)?.unwrap_or_default();
+    let columns = table
+        .columns
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let idx = 1 + i * 4;
+            let non_null = count(idx)?.unwrap_or_default();
+            Ok(ColumnStatistics {
+                name,
+                null_count: num_rows.saturating_sub(non_null),
+                min_value: value(idx + 1)?,
+                max_value: value(idx + 2)?,
+                distinct_count: count(idx + 3)?,
+            })
+        })
+        .collect::<DataFusionResult<Vec<_>>>()?;
+
+    let analyzed_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default();
+
+    Ok(TableStatistics {
+        num_rows,
+        columns,
+        analyzed_at,
+    })
+}
@@ -0,0 +1,144 @@
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+use catalog::mutator::CatalogMutator;
+use datafusion::arrow::array::UInt64Array;
+use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::error::{DataFusionError, Result as DataFusionResult};
+use datafusion::execution::TaskContext;
+use datafusion::physical_expr::PhysicalSortExpr;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::{
+    DisplayAs,
+    DisplayFormatType,
+    ExecutionPlan,
+    Partitioning,
+    SendableRecordBatchStream,
+    Statistics,
+};
+use datasources::native::access::NativeTableStorage;
+use futures::stream;
+use protogen::metastore::types::options::StorageOptions;
+
+use crate::backup;
+use crate::planner::logical_plan::BACKUP_DATABASE_SCHEMA;
+
+#[derive(Debug, Clone)]
+pub struct BackupDatabaseExec {
+    pub location: String,
+    pub storage_options: StorageOptions,
+}
+
+impl ExecutionPlan for BackupDatabaseExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        BACKUP_DATABASE_SCHEMA.clone()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(1)
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        None
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        Vec::new()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
+        if children.is_empty() {
+            Ok(self)
+        } else {
+            Err(DataFusionError::Plan(
+                "Cannot change children for BackupDatabaseExec".to_string(),
+            ))
+        }
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> DataFusionResult<SendableRecordBatchStream> {
+        if partition != 0 {
+            return Err(DataFusionError::Execution(
+                "BackupDatabaseExec only supports 1 partition".to_string(),
+            ));
+        }
+
+        let mutator = context
+            .session_config()
+            .get_extension::<CatalogMutator>()
+            .expect("context should have catalog mutator");
+        let storage = context
+            .session_config()
+            .get_extension::<NativeTableStorage>()
+            .expect("context should have native table storage");
+
+        let stream = stream::once(backup_database(self.clone(), mutator, storage));
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema(),
+            stream,
+        )))
+    }
+
+    fn statistics(&self) -> DataFusionResult<Statistics> {
+        Ok(Statistics::new_unknown(self.schema().as_ref()))
+    }
+}
+
+impl DisplayAs for BackupDatabaseExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BackupDatabaseExec")
+    }
+}
+
+async fn backup_database(
+    plan: BackupDatabaseExec,
+    mutator: Arc<CatalogMutator>,
+    storage: Arc<NativeTableStorage>,
+) -> DataFusionResult<RecordBatch> {
+    let client = mutator
+        .get_metastore_client()
+        .ok_or_else(|| DataFusionError::Execution("metastore client not configured".to_string()))?;
+
+    // Back up the latest catalog, the state is a consistent snapshot of a
+    // single catalog version.
+    client
+        .refresh_cached_state()
+        .await
+        .map_err(|e| DataFusionError::Execution(format!("failed to refresh catalog: {e}")))?;
+    let state = client
+        .get_cached_state()
+        .await
+        .map_err(|e| DataFusionError::Execution(format!("failed to get catalog: {e}")))?;
+
+    let manifest = async {
+        let dest = backup::open_backup_location(
+            &plan.location,
+            plan.storage_options.inner.clone().into_iter().collect(),
+        )?;
+        backup::backup_database(&state, &storage, dest).await
+    }
+    .await
+    .map_err(|e| DataFusionError::Execution(format!("failed to back up database: {e}")))?;
+
+    Ok(RecordBatch::try_new(
+        BACKUP_DATABASE_SCHEMA.clone(),
+        vec![
+            Arc::new(UInt64Array::from(vec![manifest.catalog_version])),
+            Arc::new(UInt64Array::from(vec![manifest.tables.len() as u64])),
+        ],
+    )?)
+}
@@ -33,6 +33,7 @@ pub struct CreateExternalTableExec {
     pub table_options: TableOptionsV0,
     pub tunnel: Option<String>,
     pub table_schema: Option<Schema>,
+    pub owner: u32,
 }
 
 impl ExecutionPlan for CreateExternalTableExec {
@@ -126,6 +127,7 @@ async fn create_external_table(
                     if_not_exists: plan.if_not_exists,
                     tunnel: plan.tunnel,
                     columns,
+                    owner: plan.owner,
                 },
             )],
         )
@@ -21,6 +21,7 @@ use datafusion::physical_plan::{
 };
 use datasources::native::access::{NativeTable, NativeTableStorage, SaveMode};
 use futures::{stream, StreamExt};
+use protogen::metastore::types::options::{IdentityColumn, TableOptionsInternal};
 use protogen::metastore::types::service;
 use protogen::metastore::types::service::Mutation;
 use sqlbuiltins::builtins::DEFAULT_CATALOG;
@@ -38,6 +39,9 @@ pub struct CreateTableExec {
     pub or_replace: bool,
     pub arrow_schema: SchemaRef,
     pub source: Option<Arc<dyn ExecutionPlan>>,
+    pub partition_columns: Vec<String>,
+    pub identity_columns: Vec<IdentityColumn>,
+    pub owner: u32,
 }
 
 impl ExecutionPlan for CreateTableExec {
@@ -75,6 +79,9 @@ impl ExecutionPlan for CreateTableExec {
             or_replace: self.or_replace,
             arrow_schema: self.arrow_schema.clone(),
             source: children.first().cloned(),
+            partition_columns: self.partition_columns.clone(),
+            identity_columns: self.identity_columns.clone(),
+            owner: self.owner,
         }))
     }
 
@@ -134,9 +141,14 @@ impl CreateTableExec {
                 [Mutation::CreateTable(service::CreateTable {
                     schema: self.tbl_reference.schema.clone().into_owned(),
                     name: self.tbl_reference.name.clone().into_owned(),
-                    options: self.arrow_schema.into(),
+                    options: TableOptionsInternal {
+                        partition_columns: self.partition_columns,
+                        identity_columns: self.identity_columns,
+                        ..TableOptionsInternal::from(self.arrow_schema)
+                    },
                     if_not_exists,
                     or_replace,
+                    owner: self.owner,
                 })],
             )
             .await
@@ -30,6 +30,7 @@ pub struct CreateViewExec {
     pub sql: String,
     pub columns: Vec<String>,
     pub or_replace: bool,
+    pub owner: u32,
 }
 
 impl ExecutionPlan for CreateViewExec {
@@ -114,6 +115,7 @@ async fn create_view(
                 sql: plan.sql,
                 or_replace: plan.or_replace,
                 columns: plan.columns,
+                owner: plan.owner,
             })],
         )
         .await
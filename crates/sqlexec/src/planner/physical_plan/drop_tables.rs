@@ -32,6 +32,11 @@ pub struct DropTablesExec {
     pub tbl_references: Vec<OwnedFullObjectReference>,
     pub tbl_entries: Vec<TableEntry>,
     pub if_exists: bool,
+    /// Previously dropped tables are purged if they expired before this time
+    /// (milliseconds since the epoch).
+    pub purge_expired_before: i64,
+    /// Previously dropped tables expected to be purged.
+    pub purge_entries: Vec<TableEntry>,
 }
 
 impl ExecutionPlan for DropTablesExec {
@@ -107,24 +112,48 @@ async fn drop_tables(
         .get_extension::<CatalogMutator>()
         .expect("context should have catalog mutator");
 
-    let drops = plan.tbl_references.into_iter().map(|r| {
-        Mutation::DropObject(service::DropObject {
-            schema: r.schema.into_owned(),
-            name: r.name.into_owned(),
-            if_exists: plan.if_exists,
+    let mut mutations: Vec<_> = plan
+        .tbl_references
+        .into_iter()
+        .map(|r| {
+            Mutation::DropObject(service::DropObject {
+                schema: r.schema.into_owned(),
+                name: r.name.into_owned(),
+                if_exists: plan.if_exists,
+                // Whether the table is actually retained is up to the
+                // catalog's retention setting.
+                retain: true,
+            })
         })
-    });
+        .collect();
+    if !plan.purge_entries.is_empty() {
+        mutations.push(Mutation::PurgeDroppedTables(service::PurgeDroppedTables {
+            expired_before: plan.purge_expired_before,
+        }));
+    }
 
     // we want to make sure that the catalog is updated before we delete the delta tables
-    mutator
-        .mutate_and_commit(plan.catalog_version, drops)
+    let state = mutator
+        .mutate_and_commit(plan.catalog_version, mutations)
         .await
         .map_err(|e| DataFusionError::Execution(format!("failed to drop tables: {e}")))?;
 
+    let mut to_delete = plan.purge_entries;
+    to_delete.extend(plan.tbl_entries);
+    // Our view of the dropped tables may have been out of date, and tables
+    // dropped just now may be retained. Only delete data for tables that the
+    // catalog no longer knows about at all.
+    to_delete.retain(|ent| {
+        !state.entries.contains_key(&ent.meta.id)
+            && !state
+                .dropped_tables
+                .iter()
+                .any(|dropped| dropped.table.meta.id == ent.meta.id)
+    });
+
     // only after the catalog is updated, we can delete the delta tables
     // TODO: this should be done in the scheduler.
-    let sys_exec =
-        SystemOperationExec::new(DeleteDeltaTablesOperation::new(plan.tbl_entries.clone()).into());
+    let sys_exec = SystemOperationExec::new(DeleteDeltaTablesOperation::new(to_delete).into());
     let _ = sys_exec
         .execute(0, context.clone())?
         .collect::<Vec<_>>()
@@ -111,6 +111,7 @@ async fn drop_views(
                 name: r.name.into_owned(),
                 schema: r.schema.into_owned(),
                 if_exists: plan.if_exists,
+                retain: false,
             })
         })
         .collect();
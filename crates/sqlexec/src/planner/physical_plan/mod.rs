@@ -1,13 +1,18 @@
 pub mod alter_database;
 pub mod alter_table;
 pub mod alter_tunnel_rotate_keys;
+pub mod analyze_table;
+pub mod backup_database;
 pub mod client_recv;
 pub mod client_send;
+pub mod comment_on;
 pub mod copy_to;
 pub mod create_credentials;
 pub mod create_external_database;
 pub mod create_external_table;
+pub mod create_role;
 pub mod create_schema;
+pub mod create_sequence;
 pub mod create_table;
 pub mod create_temp_table;
 pub mod create_tunnel;
@@ -16,18 +21,26 @@ pub mod delete;
 pub mod describe_table;
 pub mod drop_credentials;
 pub mod drop_database;
+pub mod drop_roles;
 pub mod drop_schemas;
+pub mod drop_sequences;
 pub mod drop_tables;
 pub mod drop_temp_tables;
 pub mod drop_tunnel;
 pub mod drop_views;
+pub mod grant_privileges;
 pub mod insert;
+pub mod optimize;
 pub mod remote_exec;
 pub mod remote_scan;
+pub mod restore_table;
+pub mod revoke_privileges;
 pub mod send_recv;
 pub mod set_var;
 pub mod show_var;
+pub mod undrop_table;
 pub mod update;
+pub mod vacuum;
 pub mod values;
 
 use std::sync::Arc;
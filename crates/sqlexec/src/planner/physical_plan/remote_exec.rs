@@ -35,6 +35,8 @@ pub struct RemoteExecutionExec {
     plan: Arc<dyn ExecutionPlan>,
     /// The query text to send for collecting metrics.
     query_text: String,
+    /// The session user to send for the query history.
+    user_name: String,
 }
 
 impl RemoteExecutionExec {
@@ -42,11 +44,13 @@ impl RemoteExecutionExec {
         client: RemoteSessionClient,
         plan: Arc<dyn ExecutionPlan>,
         query_text: String,
+        user_name: String,
     ) -> Self {
         RemoteExecutionExec {
             client,
             plan,
             query_text,
+            user_name,
         }
     }
 }
@@ -80,6 +84,7 @@ impl ExecutionPlan for RemoteExecutionExec {
             client: self.client.clone(),
             plan: children[0].clone(),
             query_text: self.query_text.clone(),
+            user_name: self.user_name.clone(),
         }))
     }
 
@@ -98,6 +103,7 @@ impl ExecutionPlan for RemoteExecutionExec {
             self.client.clone(),
             self.plan.clone(),
             self.query_text.clone(),
+            self.user_name.clone(),
         ))
         .try_flatten();
         Ok(Box::pin(RecordBatchStreamAdapter::new(
@@ -126,9 +132,10 @@ async fn execute_remote(
     mut client: RemoteSessionClient,
     plan: Arc<dyn ExecutionPlan>,
     query_text: String,
+    user_name: String,
 ) -> DataFusionResult<ExecutionResponseBatchStream> {
     let stream = client
-        .physical_plan_execute(plan, query_text)
+        .physical_plan_execute(plan, query_text, user_name)
         .await
         .map_err(|e| {
             DataFusionError::Execution(format!(
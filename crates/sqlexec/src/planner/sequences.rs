@@ -0,0 +1,295 @@
+//! Planning of the functions operating on sequences.
+//!
+//! `nextval`, `currval` and `setval` take the name of the sequence as their
+//! first argument. The sequence is resolved during planning, and the function
+//! is planned as a volatile UDF bound to it.
+//!
+//! `currval` requires `SELECT` on the sequence, `nextval` and `setval` require
+//! `UPDATE`. Like tables, these may also be granted on the sequence's schema.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use catalog::mutator::CatalogMutator;
+use datafusion::arrow::array::{Array, AsArray, Int64Array};
+use datafusion::arrow::datatypes::{DataType, Int64Type};
+use datafusion::error::DataFusionError;
+use datafusion::logical_expr::expr::ScalarFunction;
+use datafusion::logical_expr::{
+    Expr,
+    ReturnTypeFunction,
+    ScalarFunctionImplementation,
+    ScalarUDF,
+    Signature,
+    TypeSignature,
+    Volatility,
+};
+use datafusion::physical_plan::ColumnarValue;
+use datafusion::scalar::ScalarValue;
+use datafusion::sql::TableReference;
+use protogen::metastore::types::catalog::{CatalogEntry, Privilege, SequenceEntry};
+use tokio::runtime::Handle;
+use tokio::task;
+
+use crate::context::local::LocalSessionContext;
+use crate::planner::errors::{PlanError, Result};
+use crate::resolve::{EntryResolver, ResolvedEntry};
+
+/// The most recent value `nextval` returned for each sequence in a session.
+#[derive(Debug, Clone, Default)]
+pub struct SessionSequenceValues {
+    values: Arc<Mutex<HashMap<u32, i64>>>,
+}
+
+impl SessionSequenceValues {
+    fn get(&self, sequence_id: u32) -> Option<i64> {
+        self.values.lock().unwrap().get(&sequence_id).copied()
+    }
+
+    fn set(&self, sequence_id: u32, value: i64) {
+        self.values.lock().unwrap().insert(sequence_id, value);
+    }
+}
+
+/// Plan a call to one of the sequence functions.
+///
+/// Returns `None` if `name` isn't a sequence function.
+pub fn plan_sequence_function(
+    ctx: &LocalSessionContext,
+    resolver: &EntryResolver,
+    name: &str,
+    args: &[Expr],
+) -> Result<Option<Expr>> {
+    if !matches!(name, "nextval" | "currval" | "setval") {
+        return Ok(None);
+    }
+
+    let seq = resolve_sequence(resolver, name, args.first())?;
+    let vars = ctx.get_session_vars();
+    if name != "currval" {
+        if let Some(version) = vars.catalog_as_of_version() {
+            return Err(PlanError::String(format!(
+                "Session is pinned to catalog version {version} and is read-only"
+            )));
+        }
+    }
+
+    let privilege = match name {
+        "currval" => Privilege::Select,
+        _ => Privilege::Update,
+    };
+    if !ctx.get_session_catalog().role_has_privilege(
+        vars.catalog_role().as_deref(),
+        &seq.meta,
+        privilege,
+    ) {
+        return Err(PlanError::PermissionDenied {
+            role: vars.user_name(),
+            action: format!("{privilege} on '{}'", seq.meta.name),
+        });
+    }
+
+    let mutator = ctx.catalog_mutator();
+    let values = ctx.sequence_values().clone();
+    let expr = match name {
+        "nextval" => nextval(seq, mutator, values),
+        "currval" => currval(seq, values),
+        _ => {
+            let args = args[1..].to_vec();
+            if args.is_empty() || args.len() > 2 {
+                return Err(PlanError::String(
+                    "setval expects a sequence name, a value, and optionally is_called".to_string(),
+                ));
+            }
+            setval(seq, mutator, values, args)
+        }
+    };
+
+    Ok(Some(expr))
+}
+
+fn resolve_sequence(
+    resolver: &EntryResolver,
+    func: &str,
+    arg: Option<&Expr>,
+) -> Result<SequenceEntry> {
+    let name = match arg {
+        Some(Expr::Literal(ScalarValue::Utf8(Some(name)))) => name,
+        _ => {
+            return Err(PlanError::String(format!(
+                "{func} expects the name of a sequence as its first argument"
+            )))
+        }
+    };
+
+    match resolver.resolve_entry_from_reference(TableReference::parse_str(name)) {
+        Ok(ResolvedEntry::Entry(CatalogEntry::Sequence(seq))) => Ok(seq),
+        Ok(ResolvedEntry::Entry(ent)) => Err(PlanError::String(format!(
+            "\"{}\" is not a sequence",
+            ent.get_meta().name
+        ))),
+        _ => Err(PlanError::String(format!(
+            "sequence \"{name}\" does not exist"
+        ))),
+    }
+}
+
+/// Build an expression calling a UDF that returns sequence values.
+fn udf_expr(
+    name: &str,
+    signature: Signature,
+    fun: ScalarFunctionImplementation,
+    args: Vec<Expr>,
+) -> Expr {
+    let return_type: ReturnTypeFunction = Arc::new(|_| Ok(Arc::new(DataType::Int64)));
+    let udf = ScalarUDF::new(name, &signature, &return_type, &fun);
+    Expr::ScalarFunction(ScalarFunction::new_udf(Arc::new(udf), args))
+}
+
+fn nextval(
+    seq: SequenceEntry,
+    mutator: Arc<CatalogMutator>,
+    values: SessionSequenceValues,
+) -> Expr {
+    let sequence_id = seq.meta.id;
+    let fun: ScalarFunctionImplementation = Arc::new(move |input| {
+        // The sequence is the only argument and it's already been resolved.
+        // Zero argument functions are instead called with a null array the
+        // length of the batch.
+        let num_rows = match input.first() {
+            Some(ColumnarValue::Array(arr)) => arr.len(),
+            _ => 1,
+        };
+        if num_rows == 0 {
+            return Ok(ColumnarValue::Array(Arc::new(Int64Array::from(
+                Vec::<i64>::new(),
+            ))));
+        }
+
+        // No way around blocking here, expressions aren't async.
+        let mutator = mutator.clone();
+        let next = task::block_in_place(move || {
+            Handle::current().block_on(mutator.advance_sequence(sequence_id, num_rows as u64))
+        })
+        .map_err(|e| DataFusionError::Execution(e.msg))?;
+
+        if let Some(last) = next.last() {
+            values.set(sequence_id, *last);
+        }
+        Ok(ColumnarValue::Array(Arc::new(Int64Array::from(next))))
+    });
+
+    udf_expr(
+        "nextval",
+        Signature::exact(Vec::new(), Volatility::Volatile),
+        fun,
+        Vec::new(),
+    )
+}
+
+fn currval(seq: SequenceEntry, values: SessionSequenceValues) -> Expr {
+    let sequence_id = seq.meta.id;
+    let name = seq.meta.name;
+    let fun: ScalarFunctionImplementation = Arc::new(move |_input| {
+        let value = values.get(sequence_id).ok_or_else(|| {
+            DataFusionError::Execution(format!(
+                "currval of sequence \"{name}\" is not yet defined in this session"
+            ))
+        })?;
+        Ok(ColumnarValue::Scalar(ScalarValue::Int64(Some(value))))
+    });
+
+    // Volatile so that it's not evaluated during planning, `nextval` may be
+    // called before it in the same statement.
+    udf_expr(
+        "currval",
+        Signature::exact(Vec::new(), Volatility::Volatile),
+        fun,
+        Vec::new(),
+    )
+}
+
+fn setval(
+    seq: SequenceEntry,
+    mutator: Arc<CatalogMutator>,
+    values: SessionSequenceValues,
+    args: Vec<Expr>,
+) -> Expr {
+    let sequence_id = seq.meta.id;
+    let name = seq.meta.name;
+    let (min_value, max_value) = (seq.options.min_value, seq.options.max_value);
+    let fun: ScalarFunctionImplementation = Arc::new(move |input| {
+        let is_called = match input.get(1) {
+            Some(ColumnarValue::Scalar(ScalarValue::Boolean(Some(is_called)))) => *is_called,
+            Some(_) => {
+                return Err(DataFusionError::Execution(
+                    "is_called argument to setval must be a constant boolean".to_string(),
+                ))
+            }
+            None => true,
+        };
+
+        // Only the last value of a multi-row call is observable, so it's the
+        // only one applied. Every value is still checked against the bounds.
+        let (targets, scalar) = match &input[0] {
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(value))) => (vec![*value], true),
+            ColumnarValue::Array(arr) => {
+                let arr = arr.as_primitive::<Int64Type>();
+                if arr.null_count() > 0 {
+                    return Err(DataFusionError::Execution(
+                        "setval value must not be null".to_string(),
+                    ));
+                }
+                (arr.values().to_vec(), false)
+            }
+            _ => {
+                return Err(DataFusionError::Execution(
+                    "setval value must not be null".to_string(),
+                ))
+            }
+        };
+
+        if let Some(value) = targets
+            .iter()
+            .find(|value| **value < min_value || **value > max_value)
+        {
+            return Err(DataFusionError::Execution(format!(
+                "value {value} is out of bounds for sequence {name}"
+            )));
+        }
+
+        if let Some(&value) = targets.last() {
+            let mutator = mutator.clone();
+            task::block_in_place(move || {
+                Handle::current().block_on(mutator.set_sequence_value(
+                    sequence_id,
+                    value,
+                    is_called,
+                ))
+            })
+            .map_err(|e| DataFusionError::Execution(e.msg))?;
+            if is_called {
+                values.set(sequence_id, value);
+            }
+        }
+
+        if scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::Int64(Some(targets[0]))))
+        } else {
+            Ok(ColumnarValue::Array(Arc::new(Int64Array::from(targets))))
+        }
+    });
+
+    udf_expr(
+        "setval",
+        Signature::one_of(
+            vec![
+                TypeSignature::Exact(vec![DataType::Int64]),
+                TypeSignature::Exact(vec![DataType::Int64, DataType::Boolean]),
+            ],
+            Volatility::Volatile,
+        ),
+        fun,
+        args,
+    )
+}
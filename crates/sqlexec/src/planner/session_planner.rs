@@ -12,11 +12,13 @@ use datafusion::arrow::datatypes::{
     DECIMAL_DEFAULT_SCALE,
 };
 use datafusion::common::parsers::CompressionTypeVariant;
-use datafusion::common::{OwnedSchemaReference, OwnedTableReference, ToDFSchema};
-use datafusion::logical_expr::{cast, col, LogicalPlanBuilder};
+use datafusion::common::{OwnedSchemaReference, OwnedTableReference, ScalarValue, ToDFSchema};
+use datafusion::logical_expr::expr_fn::{approx_distinct, count, max, min};
+use datafusion::logical_expr::{cast, col, ident, lit, Expr, LogicalPlanBuilder};
 use datafusion::sql::planner::{object_name_to_table_reference, PlannerContext};
 use datafusion::sql::TableReference;
 use datafusion_ext::conversion::convert;
+use datafusion_ext::functions::{FuncParamValue, TableVersion};
 use datafusion_ext::planner::SqlQueryPlanner;
 use datafusion_ext::{AsyncContextProvider, IdentNormalizer};
 use datasources::bigquery::{BigQueryAccessor, BigQueryTableAccess};
@@ -61,6 +63,10 @@ use parser::{
     AlterTableStmtExtension,
     AlterTunnelAction,
     AlterTunnelStmt,
+    AnalyzeStmt,
+    BackupDatabaseStmt,
+    CommentObjectType,
+    CommentOnStmt,
     CopyToSource,
     CopyToStmt,
     CreateCredentialStmt,
@@ -71,11 +77,20 @@ use parser::{
     DropCredentialsStmt,
     DropDatabaseStmt,
     DropTunnelStmt,
+    OptimizeStmt,
+    RestoreTableStmt,
+    RestoreTableTarget,
+    ShowCreateObjectType,
+    ShowCreateStmt,
     StatementWithExtensions,
+    UndropTableStmt,
+    VacuumStmt,
 };
 use protogen::metastore::types::catalog::{
     CatalogEntry,
     DatabaseEntry,
+    EntryType,
+    Privilege,
     RuntimePreference,
     SourceAccessMode,
     TableEntry,
@@ -113,6 +128,9 @@ use protogen::metastore::types::options::{
     DatabaseOptionsSqlite,
     DeltaLakeCatalog,
     DeltaLakeUnityCatalog,
+    IdentityColumn,
+    NativeMaintenancePolicy,
+    SequenceOptions,
     StorageOptions,
     TableOptionsBigQuery,
     TableOptionsCassandra,
@@ -133,8 +151,13 @@ use protogen::metastore::types::options::{
     TunnelOptionsDebug,
     TunnelOptionsInternal,
     TunnelOptionsSsh,
+    MAX_VACUUM_RETAIN_HOURS,
+};
+use protogen::metastore::types::service::{
+    AlterDatabaseOperation,
+    AlterTableOperation,
+    CommentObject,
 };
-use protogen::metastore::types::service::{AlterDatabaseOperation, AlterTableOperation};
 use sqlbuiltins::builtins::{CURRENT_SESSION_SCHEMA, DEFAULT_CATALOG};
 use sqlbuiltins::validation::{
     validate_copyto_dest_creds_support,
@@ -150,16 +173,23 @@ use super::context_builder::PartialContextProvider;
 use super::extension::ExtensionNode;
 use super::physical_plan::remote_scan::ProviderReference;
 use crate::context::local::LocalSessionContext;
+use crate::ddl::{self, DdlOptions};
 use crate::planner::errors::{internal, PlanError, Result};
 use crate::planner::logical_plan::{
     AlterDatabase,
     AlterTable,
     AlterTunnelRotateKeys,
+    AnalyzeTable,
+    AnalyzeTarget,
+    BackupDatabase,
+    CommentOn,
     CopyTo,
     CreateCredentials,
     CreateExternalDatabase,
     CreateExternalTable,
+    CreateRole,
     CreateSchema,
+    CreateSequence,
     CreateTable,
     CreateTempTable,
     CreateTunnel,
@@ -168,17 +198,26 @@ use crate::planner::logical_plan::{
     DescribeTable,
     DropCredentials,
     DropDatabase,
+    DropRoles,
     DropSchemas,
+    DropSequences,
     DropTables,
     DropTunnel,
     DropViews,
     FullObjectReference,
+    GrantPrivileges,
     Insert,
     LogicalPlan,
+    Optimize,
+    OwnedFullObjectReference,
+    RestoreTable,
+    RevokePrivileges,
     SetVariable,
     ShowVariable,
     TransactionPlan,
+    UndropTable,
     Update,
+    Vacuum,
 };
 use crate::planner::preprocess::{preprocess, CastOIDReplacer, EscapedStringToDoubleQuoted};
 use crate::remote::table::StubRemoteTableProvider;
@@ -239,6 +278,27 @@ impl<'a> SessionPlanner<'a> {
             preprocess(inner, &mut EscapedStringToDoubleQuoted)?;
         }
 
+        self.check_writable(&statement)?;
+
+        match &statement {
+            StatementWithExtensions::CreateExternalDatabase(_)
+            | StatementWithExtensions::DropDatabase(_)
+            | StatementWithExtensions::AlterDatabase(_) => {
+                self.check_superuser("manage databases")?
+            }
+            StatementWithExtensions::CreateTunnel(_)
+            | StatementWithExtensions::DropTunnel(_)
+            | StatementWithExtensions::AlterTunnel(_) => self.check_superuser("manage tunnels")?,
+            StatementWithExtensions::CreateCredentials(_)
+            | StatementWithExtensions::DropCredentials(_) => {
+                self.check_superuser("manage credentials")?
+            }
+            StatementWithExtensions::BackupDatabase(_) => {
+                self.check_superuser("back up the database")?
+            }
+            _ => (),
+        }
+
         match statement {
             StatementWithExtensions::Statement(stmt) => self.plan_statement(stmt).await,
             StatementWithExtensions::CreateExternalTable(stmt) => {
@@ -260,6 +320,14 @@ impl<'a> SessionPlanner<'a> {
             }
             StatementWithExtensions::DropCredentials(stmt) => self.plan_drop_credentials(stmt),
             StatementWithExtensions::CopyTo(stmt) => self.plan_copy_to(stmt).await,
+            StatementWithExtensions::Optimize(stmt) => self.plan_optimize(stmt),
+            StatementWithExtensions::Vacuum(stmt) => self.plan_vacuum(stmt),
+            StatementWithExtensions::UndropTable(stmt) => self.plan_undrop_table(stmt),
+            StatementWithExtensions::RestoreTable(stmt) => self.plan_restore_table(stmt),
+            StatementWithExtensions::Analyze(stmt) => self.plan_analyze(stmt).await,
+            StatementWithExtensions::CommentOn(stmt) => self.plan_comment_on(stmt),
+            StatementWithExtensions::ShowCreate(stmt) => self.plan_show_create(stmt),
+            StatementWithExtensions::BackupDatabase(stmt) => self.plan_backup_database(stmt),
         }
     }
 
@@ -1056,14 +1124,20 @@ impl<'a> SessionPlanner<'a> {
             .await?;
 
         let table_name = object_name_to_table_ref(stmt.name)?;
+        let tbl_reference = self.ctx.resolve_table_ref(table_name)?;
+        self.check_schema_create(&tbl_reference)?;
+        if stmt.or_replace {
+            self.check_owner(&tbl_reference, "REPLACE")?;
+        }
 
         let plan = CreateExternalTable {
-            tbl_reference: self.ctx.resolve_table_ref(table_name)?,
+            tbl_reference,
             or_replace: stmt.or_replace,
             if_not_exists: stmt.if_not_exists,
             table_options: external_table_options,
             tunnel,
             schema,
+            owner: self.owner_oid(),
         };
 
         Ok(plan.into_logical_plan())
@@ -1208,6 +1282,8 @@ impl<'a> SessionPlanner<'a> {
                 schema_name,
                 if_not_exists,
             } => {
+                self.check_superuser("create schemas")?;
+
                 // TODO: Schema Authorization
                 let schema_name = match schema_name {
                     ast::SchemaName::Simple(name) => {
@@ -1246,12 +1322,17 @@ impl<'a> SessionPlanner<'a> {
                 columns,
                 query,
                 temporary,
+                partition_by,
                 ..
             } => {
                 validate_object_name(&name)?;
                 let table_name = object_name_to_table_ref(name)?;
+                let partition_columns = match partition_by {
+                    Some(expr) => partition_columns_from_expr(*expr)?,
+                    None => Vec::new(),
+                };
 
-                let (source, arrow_cols) = if let Some(q) = query {
+                let (source, arrow_cols, identity_columns) = if let Some(q) = query {
                     let mut ctx = context_provider;
 
                     let mut planner = SqlQueryPlanner::new(&mut ctx);
@@ -1259,6 +1340,12 @@ impl<'a> SessionPlanner<'a> {
                     let source = planner.query_to_plan(*q).await?;
                     let df_fields = source.schema().fields();
 
+                    if columns.iter().any(is_identity_column) {
+                        return Err(PlanError::String(
+                            "identity columns are not supported for CREATE TABLE AS".to_string(),
+                        ));
+                    }
+
                     let mut columns = columns.into_iter();
                     let mut fields = Vec::with_capacity(df_fields.len());
                     for df_field in df_fields {
@@ -1292,20 +1379,39 @@ impl<'a> SessionPlanner<'a> {
                         .project(project_exprs)?
                         .build()?;
 
-                    (Some(source), fields)
+                    (Some(source), fields, Vec::new())
                 } else {
                     let mut arrow_cols = Vec::with_capacity(columns.len());
+                    let mut identity_columns = Vec::new();
                     for column in columns.into_iter() {
                         validate_ident(&column.name)?;
+                        let (data_type, identity) = column_type_and_identity(&column)?;
                         let name = normalize_ident(column.name);
-                        let data_type = convert_data_type(&column.data_type)?;
+                        if let Some((always, sequence_options)) = identity {
+                            identity_columns.push(IdentityColumn {
+                                name: name.clone(),
+                                always,
+                                sequence_id: 0,
+                                sequence_options,
+                            });
+                        }
                         let field = Field::new(name, data_type, /* nullable = */ true);
                         arrow_cols.push(field);
                     }
-                    (None, arrow_cols)
+                    (None, arrow_cols, identity_columns)
                 };
 
                 if temporary {
+                    if !partition_columns.is_empty() {
+                        return Err(PlanError::String(
+                            "PARTITION BY is not supported for temporary tables".to_string(),
+                        ));
+                    }
+                    if !identity_columns.is_empty() {
+                        return Err(PlanError::String(
+                            "identity columns are not supported for temporary tables".to_string(),
+                        ));
+                    }
                     let table_name = match table_name {
                         TableReference::Bare { table } => table.into_owned(),
                         _ => return Err(internal!("cannot specify schema with temporary tables")),
@@ -1327,19 +1433,69 @@ impl<'a> SessionPlanner<'a> {
 
                     Ok(plan.into_logical_plan())
                 } else {
+                    let tbl_reference = self.ctx.resolve_table_ref(table_name)?;
+                    self.check_schema_create(&tbl_reference)?;
+                    if or_replace {
+                        self.check_owner(&tbl_reference, "REPLACE")?;
+                    }
+                    check_partition_columns(&arrow_cols, &partition_columns)?;
+
                     let df_schema = Schema::new(arrow_cols.clone());
                     let df_schema = df_schema.to_dfschema_ref()?;
                     let create_table = CreateTable {
-                        tbl_reference: self.ctx.resolve_table_ref(table_name)?,
+                        tbl_reference,
                         schema: df_schema,
                         if_not_exists,
                         or_replace,
                         source,
+                        partition_columns,
+                        identity_columns,
+                        owner: self.owner_oid(),
                     };
                     Ok(create_table.into_logical_plan())
                 }
             }
 
+            ast::Statement::CreateSequence {
+                temporary,
+                if_not_exists,
+                name,
+                data_type,
+                sequence_options,
+                owned_by,
+            } => {
+                if temporary {
+                    return Err(PlanError::UnsupportedFeature("temporary sequences"));
+                }
+                if owned_by.is_some() {
+                    return Err(PlanError::UnsupportedFeature("OWNED BY for sequences"));
+                }
+
+                validate_object_name(&name)?;
+                let name = object_name_to_table_ref(name)?;
+                let sequence_reference = self.ctx.resolve_table_ref(name)?;
+                self.check_schema_create(&sequence_reference)?;
+
+                let data_type = match data_type {
+                    Some(typ) => convert_data_type(&typ)?,
+                    None => DataType::Int64,
+                };
+                let (type_min, type_max) = sequence_type_range(&data_type).ok_or_else(|| {
+                    PlanError::String(format!(
+                        "sequence type must be smallint, integer, or bigint, got {data_type}"
+                    ))
+                })?;
+                let options = sequence_options_from_ast(&sequence_options, type_min, type_max)?;
+
+                Ok(CreateSequence {
+                    sequence_reference,
+                    options,
+                    if_not_exists,
+                    owner: self.owner_oid(),
+                }
+                .into_logical_plan())
+            }
+
             // Views
             ast::Statement::CreateView {
                 or_replace,
@@ -1352,6 +1508,11 @@ impl<'a> SessionPlanner<'a> {
             } => {
                 validate_object_name(&name)?;
                 let name = object_name_to_table_ref(name)?;
+                let view_reference = self.ctx.resolve_table_ref(name)?;
+                self.check_schema_create(&view_reference)?;
+                if or_replace {
+                    self.check_owner(&view_reference, "REPLACE")?;
+                }
 
                 if !matches!(options, ast::CreateTableOptions::None) {
                     return Err(PlanError::UnsupportedFeature("view options"));
@@ -1386,10 +1547,11 @@ impl<'a> SessionPlanner<'a> {
                     })
                 } else {
                     Ok(CreateView {
-                        view_reference: self.ctx.resolve_table_ref(name)?,
+                        view_reference,
                         sql: query_string,
                         columns,
                         or_replace,
+                        owner: self.owner_oid(),
                     }
                     .into_logical_plan())
                 }
@@ -1425,6 +1587,12 @@ impl<'a> SessionPlanner<'a> {
                     })
                     .collect::<Result<Vec<_>>>()?;
 
+                // Resolve the target up front so that only INSERT is required
+                // on it.
+                context_provider
+                    .table_provider_for_write(table_name.to_owned_reference(), Privilege::Insert)
+                    .await?;
+
                 let mut planner = SqlQueryPlanner::new(&mut context_provider);
                 let source = planner
                     .insert_to_source_plan(&table_name, &columns, source)
@@ -1443,7 +1611,9 @@ impl<'a> SessionPlanner<'a> {
                 let state = self.ctx.df_ctx().state();
                 let mut ctx_provider = PartialContextProvider::new(self.ctx, &state)?;
 
-                let provider = ctx_provider.table_provider(table_name).await?;
+                let provider = ctx_provider
+                    .table_provider_for_write(table_name, Privilege::Insert)
+                    .await?;
 
                 let (runtime_preference, provider) = match (
                     provider.preference,
@@ -1487,6 +1657,8 @@ impl<'a> SessionPlanner<'a> {
                         validate_object_name(&name)?;
                         let name = object_name_to_table_ref(name)?;
                         let name = self.ctx.resolve_table_ref(name)?;
+                        self.check_schema_create(&name)?;
+                        self.check_owner(&name, "ALTER")?;
 
                         let schema = name.schema.into_owned();
                         let name = name.name.into_owned();
@@ -1520,11 +1692,16 @@ impl<'a> SessionPlanner<'a> {
                 names,
                 ..
             } => {
+                let temp_catalog = self.ctx.get_session_catalog().get_temp_catalog();
                 let mut refs = Vec::with_capacity(names.len());
                 for name in names.into_iter() {
                     validate_object_name(&name)?;
                     let r = object_name_to_table_ref(name)?;
-                    refs.push(self.ctx.resolve_table_ref(r)?);
+                    let r = self.ctx.resolve_table_ref(r)?;
+                    if !temp_catalog.contains_table(&r.name) {
+                        self.check_owner(&r, "DROP")?;
+                    }
+                    refs.push(r);
                 }
 
                 let plan = DropTables {
@@ -1534,6 +1711,38 @@ impl<'a> SessionPlanner<'a> {
                 Ok(plan.into_logical_plan())
             }
 
+            // Drop sequences
+            ast::Statement::Drop {
+                object_type: ObjectType::Sequence,
+                if_exists,
+                names,
+                ..
+            } => {
+                let catalog = self.ctx.get_session_catalog();
+                let mut refs = Vec::with_capacity(names.len());
+                for name in names.into_iter() {
+                    validate_object_name(&name)?;
+                    let r = object_name_to_table_ref(name)?;
+                    let r = self.ctx.resolve_table_ref(r)?;
+
+                    if let Some(ent) = catalog.resolve_entry(&r.database, &r.schema, &r.name) {
+                        if !matches!(ent, CatalogEntry::Sequence(_)) {
+                            return Err(PlanError::String(format!(
+                                "\"{}\" is not a sequence",
+                                r.name
+                            )));
+                        }
+                    }
+                    self.check_owner(&r, "DROP")?;
+                    refs.push(r);
+                }
+                Ok(DropSequences {
+                    if_exists,
+                    sequence_references: refs,
+                }
+                .into_logical_plan())
+            }
+
             // Drop views
             ast::Statement::Drop {
                 object_type: ObjectType::View,
@@ -1545,7 +1754,9 @@ impl<'a> SessionPlanner<'a> {
                 for name in names.into_iter() {
                     validate_object_name(&name)?;
                     let r = object_name_to_table_ref(name)?;
-                    refs.push(self.ctx.resolve_table_ref(r)?);
+                    let r = self.ctx.resolve_table_ref(r)?;
+                    self.check_owner(&r, "DROP")?;
+                    refs.push(r);
                 }
                 Ok(DropViews {
                     if_exists,
@@ -1562,6 +1773,8 @@ impl<'a> SessionPlanner<'a> {
                 names,
                 ..
             } => {
+                self.check_superuser("drop schemas")?;
+
                 let mut refs = Vec::with_capacity(names.len());
                 for name in names.into_iter() {
                     validate_object_name(&name)?;
@@ -1645,6 +1858,12 @@ impl<'a> SessionPlanner<'a> {
                         validate_object_name(&table_name)?;
                         let table_name = object_name_to_table_ref(table_name)?;
 
+                        context_provider
+                            .table_provider_for_write(
+                                table_name.to_owned_reference(),
+                                Privilege::Delete,
+                            )
+                            .await?;
                         let table_source = context_provider
                             .get_table_source(table_name.clone())
                             .await?;
@@ -1673,6 +1892,12 @@ impl<'a> SessionPlanner<'a> {
                 if ent.meta.external {
                     return Err(PlanError::UnsupportedFeature("DELETE with external tables"));
                 }
+                // Filtering on columns reads the table.
+                if let Some(expr) = &where_expr {
+                    if !expr.to_columns()?.is_empty() {
+                        self.check_table_select(&ent)?;
+                    }
+                }
 
                 Ok(Delete {
                     table: ent,
@@ -1700,6 +1925,9 @@ impl<'a> SessionPlanner<'a> {
                 validate_object_name(&table_name)?;
                 let table_name = object_name_to_table_ref(table_name)?;
 
+                context_provider
+                    .table_provider_for_write(table_name.to_owned_reference(), Privilege::Update)
+                    .await?;
                 let table_source = context_provider
                     .get_table_source(table_name.clone())
                     .await?;
@@ -1740,6 +1968,15 @@ impl<'a> SessionPlanner<'a> {
                 if ent.meta.external {
                     return Err(PlanError::UnsupportedFeature("UPDATE with external tables"));
                 }
+                // Filtering on or assigning from columns reads the table.
+                for expr in where_expr
+                    .iter()
+                    .chain(updates.iter().map(|(_, expr)| expr))
+                {
+                    if !expr.to_columns()?.is_empty() {
+                        self.check_table_select(&ent)?;
+                    }
+                }
 
                 Ok(Update {
                     table: ent,
@@ -1749,10 +1986,327 @@ impl<'a> SessionPlanner<'a> {
                 .into_logical_plan())
             }
 
+            ast::Statement::CreateRole {
+                names,
+                if_not_exists,
+                login,
+                password,
+                superuser,
+                in_role,
+                ..
+            } => {
+                self.check_superuser("create roles")?;
+
+                let name = match <[ObjectName; 1]>::try_from(names) {
+                    Ok([name]) => name,
+                    Err(_) => {
+                        return Err(PlanError::InvalidRoleStatement {
+                            msg: "expected exactly one role name",
+                        })
+                    }
+                };
+                validate_object_name(&name)?;
+                let name = object_name_to_role_name(name)?;
+
+                let password = match password {
+                    Some(ast::Password::Password(ast::Expr::Value(
+                        ast::Value::SingleQuotedString(password),
+                    ))) => Some(postgres_protocol::password::scram_sha_256(
+                        password.as_bytes(),
+                    )),
+                    Some(ast::Password::Password(_)) => {
+                        return Err(PlanError::InvalidRoleStatement {
+                            msg: "password must be a string literal",
+                        })
+                    }
+                    Some(ast::Password::NullPassword) | None => None,
+                };
+
+                let member_of = in_role
+                    .into_iter()
+                    .map(|ident| {
+                        validate_ident(&ident)?;
+                        Ok(normalize_ident(ident))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok(CreateRole {
+                    name,
+                    if_not_exists,
+                    login: login.unwrap_or(false),
+                    superuser: superuser.unwrap_or(false),
+                    password,
+                    member_of,
+                }
+                .into_logical_plan())
+            }
+
+            // Drop roles
+            ast::Statement::Drop {
+                object_type: ObjectType::Role,
+                if_exists,
+                names,
+                ..
+            } => {
+                self.check_superuser("drop roles")?;
+
+                let names = names
+                    .into_iter()
+                    .map(|name| {
+                        validate_object_name(&name)?;
+                        object_name_to_role_name(name)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok(DropRoles { names, if_exists }.into_logical_plan())
+            }
+
+            ast::Statement::Grant {
+                privileges,
+                objects,
+                grantees,
+                ..
+            } => {
+                self.check_superuser("grant privileges")?;
+                let (object_ids, privileges) = self.plan_privileges(privileges, objects)?;
+
+                Ok(GrantPrivileges {
+                    grantees: plan_grantees(grantees)?,
+                    object_ids,
+                    privileges,
+                }
+                .into_logical_plan())
+            }
+
+            ast::Statement::Revoke {
+                privileges,
+                objects,
+                grantees,
+                ..
+            } => {
+                self.check_superuser("revoke privileges")?;
+                let (object_ids, privileges) = self.plan_privileges(privileges, objects)?;
+
+                Ok(RevokePrivileges {
+                    grantees: plan_grantees(grantees)?,
+                    object_ids,
+                    privileges,
+                }
+                .into_logical_plan())
+            }
+
             stmt => Err(PlanError::UnsupportedSQLStatement(stmt.to_string())),
         }
     }
 
+    /// Ensure the statement doesn't make changes if the session is pinned to
+    /// an older version of the catalog.
+    fn check_writable(&self, statement: &StatementWithExtensions) -> Result<()> {
+        let version = match self.ctx.get_session_vars().catalog_as_of_version() {
+            Some(version) => version,
+            None => return Ok(()),
+        };
+
+        let read_only = match statement {
+            StatementWithExtensions::Statement(stmt) => match stmt {
+                ast::Statement::Explain { statement, .. } => {
+                    matches!(statement.as_ref(), ast::Statement::Query(_))
+                }
+                ast::Statement::Query(_)
+                | ast::Statement::ExplainTable { .. }
+                | ast::Statement::SetVariable { .. }
+                | ast::Statement::ShowVariable { .. }
+                | ast::Statement::StartTransaction { .. }
+                | ast::Statement::Commit { .. }
+                | ast::Statement::Rollback { .. } => true,
+                _ => false,
+            },
+            StatementWithExtensions::CopyTo(_) | StatementWithExtensions::ShowCreate(_) => true,
+            _ => false,
+        };
+
+        if read_only {
+            Ok(())
+        } else {
+            Err(PlanError::String(format!(
+                "Session is pinned to catalog version {version} and is read-only"
+            )))
+        }
+    }
+
+    /// Ensure the role for this session is a superuser.
+    fn check_superuser(&self, action: &str) -> Result<()> {
+        let vars = self.ctx.get_session_vars();
+        if self
+            .ctx
+            .get_session_catalog()
+            .role_is_superuser(vars.catalog_role().as_deref())
+        {
+            Ok(())
+        } else {
+            Err(PlanError::PermissionDenied {
+                role: vars.user_name(),
+                action: action.to_string(),
+            })
+        }
+    }
+
+    /// Ensure the role for this session is allowed to create (or drop) the
+    /// referenced object in its schema.
+    ///
+    /// Missing schemas are left for execution to error on.
+    fn check_schema_create(&self, reference: &OwnedFullObjectReference) -> Result<()> {
+        let catalog = self.ctx.get_session_catalog();
+        let vars = self.ctx.get_session_vars();
+        let role = vars.catalog_role();
+        match catalog.resolve_schema(&reference.schema) {
+            Some(schema)
+                if !catalog.role_has_privilege(
+                    role.as_deref(),
+                    &schema.meta,
+                    Privilege::Create,
+                ) =>
+            {
+                Err(PlanError::PermissionDenied {
+                    role: vars.user_name(),
+                    action: format!("{} on schema '{}'", Privilege::Create, reference.schema),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Ensure the role for this session owns the referenced object, or is a
+    /// superuser.
+    ///
+    /// Missing objects are left for execution to error on.
+    fn check_owner(&self, reference: &OwnedFullObjectReference, action: &str) -> Result<()> {
+        let catalog = self.ctx.get_session_catalog();
+        let vars = self.ctx.get_session_vars();
+        match catalog.resolve_entry(&reference.database, &reference.schema, &reference.name) {
+            Some(ent) if !catalog.role_owns(vars.catalog_role().as_deref(), ent.get_meta()) => {
+                Err(PlanError::PermissionDenied {
+                    role: vars.user_name(),
+                    action: format!("{action} on '{}'", reference.name),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Ensure the role for this session can read from a table.
+    fn check_table_select(&self, table: &TableEntry) -> Result<()> {
+        let vars = self.ctx.get_session_vars();
+        if self.ctx.get_session_catalog().role_has_privilege(
+            vars.catalog_role().as_deref(),
+            &table.meta,
+            Privilege::Select,
+        ) {
+            Ok(())
+        } else {
+            Err(PlanError::PermissionDenied {
+                role: vars.user_name(),
+                action: format!("{} on '{}'", Privilege::Select, table.meta.name),
+            })
+        }
+    }
+
+    /// Get the OID of the catalog role for this session, to record as the
+    /// owner of newly created objects. Zero if there's no catalog role.
+    fn owner_oid(&self) -> u32 {
+        self.ctx
+            .get_session_vars()
+            .catalog_role()
+            .and_then(|role| {
+                self.ctx
+                    .get_session_catalog()
+                    .resolve_role(&role)
+                    .map(|role| role.meta.id)
+            })
+            .unwrap_or(0)
+    }
+
+    /// Resolve the objects and privileges for a GRANT or REVOKE statement.
+    fn plan_privileges(
+        &self,
+        privileges: ast::Privileges,
+        objects: ast::GrantObjects,
+    ) -> Result<(Vec<u32>, Vec<Privilege>)> {
+        let catalog = self.ctx.get_session_catalog();
+
+        let (object_ids, all) = match objects {
+            ast::GrantObjects::Schemas(names)
+            | ast::GrantObjects::AllTablesInSchema { schemas: names } => {
+                let mut ids = Vec::with_capacity(names.len());
+                for name in names {
+                    validate_object_name(&name)?;
+                    let schema = match object_name_to_schema_ref(name)? {
+                        OwnedSchemaReference::Bare { schema } => schema,
+                        OwnedSchemaReference::Full { schema, .. } => schema,
+                    };
+                    let ent = catalog.resolve_schema(&schema).ok_or_else(|| {
+                        PlanError::String(format!("Schema '{schema}' does not exist"))
+                    })?;
+                    ids.push(ent.meta.id);
+                }
+                (ids, Privilege::ALL.to_vec())
+            }
+            ast::GrantObjects::Tables(names) | ast::GrantObjects::Sequences(names) => {
+                let resolver = EntryResolver::from_context(self.ctx);
+                let mut ids = Vec::with_capacity(names.len());
+                for name in names {
+                    validate_object_name(&name)?;
+                    let reference = object_name_to_table_ref(name)?;
+                    match resolver.resolve_entry_from_reference(reference.clone())? {
+                        ResolvedEntry::Entry(CatalogEntry::Table(ent)) if !ent.meta.is_temp => {
+                            ids.push(ent.meta.id)
+                        }
+                        ResolvedEntry::Entry(CatalogEntry::View(ent)) => ids.push(ent.meta.id),
+                        ResolvedEntry::Entry(CatalogEntry::Sequence(ent)) => ids.push(ent.meta.id),
+                        _ => {
+                            return Err(PlanError::String(format!(
+                                "Privileges can only be granted on tables, views and sequences in the catalog: {reference}"
+                            )))
+                        }
+                    }
+                }
+                (
+                    ids,
+                    vec![
+                        Privilege::Select,
+                        Privilege::Insert,
+                        Privilege::Update,
+                        Privilege::Delete,
+                    ],
+                )
+            }
+            _ => return Err(PlanError::UnsupportedFeature("GRANT on this object type")),
+        };
+
+        let privileges = match privileges {
+            ast::Privileges::All { .. } => all,
+            ast::Privileges::Actions(actions) => actions
+                .into_iter()
+                .map(|action| match action {
+                    ast::Action::Select { columns: None } => Ok(Privilege::Select),
+                    ast::Action::Insert { columns: None } => Ok(Privilege::Insert),
+                    ast::Action::Update { columns: None } => Ok(Privilege::Update),
+                    ast::Action::Delete => Ok(Privilege::Delete),
+                    ast::Action::Create => Ok(Privilege::Create),
+                    ast::Action::Usage => Ok(Privilege::Usage),
+                    ast::Action::Select { .. }
+                    | ast::Action::Insert { .. }
+                    | ast::Action::Update { .. } => {
+                        Err(PlanError::UnsupportedFeature("column privileges"))
+                    }
+                    _ => Err(PlanError::UnsupportedFeature("privilege type")),
+                })
+                .collect::<Result<Vec<_>>>()?,
+        };
+
+        Ok((object_ids, privileges))
+    }
+
     fn plan_drop_database(&self, stmt: DropDatabaseStmt) -> Result<LogicalPlan> {
         let mut names = Vec::with_capacity(stmt.names.len());
         for name in stmt.names.into_iter() {
@@ -1832,6 +2386,9 @@ impl<'a> SessionPlanner<'a> {
                     .map_err(|e| PlanError::String(format!("{e}")))?;
                 AlterDatabaseOperation::SetAccessMode { access_mode }
             }
+            parser::AlterDatabaseOperation::SetDroppedTableRetention { hours } => {
+                AlterDatabaseOperation::SetDroppedTableRetention { hours }
+            }
         };
 
         Ok(AlterDatabase { name, operation }.into_logical_plan())
@@ -1839,8 +2396,10 @@ impl<'a> SessionPlanner<'a> {
 
     fn plan_alter_table_extension(&self, stmt: AlterTableStmtExtension) -> Result<LogicalPlan> {
         validate_object_name(&stmt.name)?;
-        let name = object_name_to_table_ref(stmt.name)?;
-        let name = self.ctx.resolve_table_ref(name)?;
+        let table_ref = object_name_to_table_ref(stmt.name)?;
+        let name = self.ctx.resolve_table_ref(table_ref.clone())?;
+        self.check_schema_create(&name)?;
+        self.check_owner(&name, "ALTER")?;
         let schema = name.schema.into_owned();
         let name = name.name.into_owned();
 
@@ -1850,6 +2409,43 @@ impl<'a> SessionPlanner<'a> {
                     .map_err(|e| PlanError::String(format!("{e}")))?;
                 AlterTableOperation::SetAccessMode { access_mode }
             }
+            parser::AlterTableOperationExtension::SetMaintenance { mut options } => {
+                let table = self.resolve_native_table(table_ref, "SET MAINTENANCE")?;
+
+                let optimize_min_files: usize = options.remove_required("optimize_min_files")?;
+                let zorder_columns: Vec<String> = options
+                    .remove_optional::<Vec<String>>("zorder_by")?
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|col| col.trim().to_string())
+                    .collect();
+                check_native_columns(&table, &zorder_columns)?;
+                let vacuum_retain_hours: Option<usize> =
+                    options.remove_optional("vacuum_retain_hours")?;
+
+                if optimize_min_files == 0 {
+                    return Err(PlanError::InvalidAlterStatement {
+                        msg: "optimize_min_files must be greater than zero",
+                    });
+                }
+                if vacuum_retain_hours.is_some_and(|h| h as u64 > MAX_VACUUM_RETAIN_HOURS) {
+                    return Err(PlanError::InvalidAlterStatement {
+                        msg: "vacuum_retain_hours must not be more than 100 years",
+                    });
+                }
+
+                AlterTableOperation::SetMaintenance {
+                    policy: Some(NativeMaintenancePolicy {
+                        optimize_min_files: optimize_min_files as u64,
+                        zorder_columns,
+                        vacuum_retain_hours: vacuum_retain_hours.map(|h| h as u64),
+                    }),
+                }
+            }
+            parser::AlterTableOperationExtension::ResetMaintenance => {
+                self.resolve_native_table(table_ref, "RESET MAINTENANCE")?;
+                AlterTableOperation::SetMaintenance { policy: None }
+            }
         };
 
         Ok(AlterTable {
@@ -1860,6 +2456,390 @@ impl<'a> SessionPlanner<'a> {
         .into_logical_plan())
     }
 
+    fn plan_optimize(&self, stmt: OptimizeStmt) -> Result<LogicalPlan> {
+        validate_object_name(&stmt.name)?;
+        let table_ref = object_name_to_table_ref(stmt.name)?;
+        let table = self.resolve_native_table(table_ref.clone(), "OPTIMIZE")?;
+        let reference = self.ctx.resolve_table_ref(table_ref)?;
+        self.check_schema_create(&reference)?;
+        self.check_owner(&reference, "OPTIMIZE")?;
+
+        let zorder_columns: Vec<_> = stmt.zorder_by.into_iter().map(normalize_ident).collect();
+        check_native_columns(&table, &zorder_columns)?;
+
+        Ok(Optimize {
+            table,
+            zorder_columns,
+        }
+        .into_logical_plan())
+    }
+
+    fn plan_vacuum(&self, stmt: VacuumStmt) -> Result<LogicalPlan> {
+        validate_object_name(&stmt.name)?;
+        let table_ref = object_name_to_table_ref(stmt.name)?;
+        let table = self.resolve_native_table(table_ref.clone(), "VACUUM")?;
+        let reference = self.ctx.resolve_table_ref(table_ref)?;
+        self.check_schema_create(&reference)?;
+        self.check_owner(&reference, "VACUUM")?;
+
+        if let Some(hours) = stmt.retain_hours {
+            if hours > MAX_VACUUM_RETAIN_HOURS {
+                return Err(PlanError::String(format!(
+                    "RETAIN {hours} HOURS must not be more than {MAX_VACUUM_RETAIN_HOURS} hours (100 years)"
+                )));
+            }
+        }
+
+        Ok(Vacuum {
+            table,
+            retain_hours: stmt.retain_hours,
+            dry_run: stmt.dry_run,
+        }
+        .into_logical_plan())
+    }
+
+    fn plan_backup_database(&self, mut stmt: BackupDatabaseStmt) -> Result<LogicalPlan> {
+        let storage_options = StorageOptions::try_from(&mut stmt.options)?;
+
+        Ok(BackupDatabase {
+            location: stmt.location,
+            storage_options,
+        }
+        .into_logical_plan())
+    }
+
+    fn plan_undrop_table(&self, stmt: UndropTableStmt) -> Result<LogicalPlan> {
+        validate_object_name(&stmt.name)?;
+        let table_ref = object_name_to_table_ref(stmt.name)?;
+        let table_ref = self.ctx.resolve_table_ref(table_ref)?;
+        self.check_schema_create(&table_ref)?;
+
+        Ok(UndropTable {
+            schema: table_ref.schema.into_owned(),
+            name: table_ref.name.into_owned(),
+        }
+        .into_logical_plan())
+    }
+
+    fn plan_comment_on(&self, stmt: CommentOnStmt) -> Result<LogicalPlan> {
+        validate_object_name(&stmt.name)?;
+
+        let object = match stmt.object_type {
+            CommentObjectType::Database => {
+                self.check_superuser("comment on databases")?;
+                let name = stmt.name.0.into_iter().next().map(normalize_ident);
+                CommentObject::Database {
+                    name: name.ok_or_else(|| internal!("missing database name"))?,
+                }
+            }
+            CommentObjectType::Schema => {
+                self.check_superuser("comment on schemas")?;
+                let schema_ref = object_name_to_schema_ref(stmt.name)?;
+                let schema_ref = self.ctx.resolve_schema_ref(schema_ref);
+                CommentObject::Schema {
+                    name: schema_ref.schema.into_owned(),
+                }
+            }
+            CommentObjectType::View => {
+                let view_ref = object_name_to_table_ref(stmt.name)?;
+                let view_ref = self.ctx.resolve_table_ref(view_ref)?;
+                self.check_schema_create(&view_ref)?;
+                CommentObject::View {
+                    schema: view_ref.schema.into_owned(),
+                    name: view_ref.name.into_owned(),
+                }
+            }
+            CommentObjectType::Table | CommentObjectType::Column => {
+                let mut idents = stmt.name.0;
+                let column = if stmt.object_type == CommentObjectType::Column {
+                    idents.pop().map(normalize_ident)
+                } else {
+                    None
+                };
+
+                let table_ref = object_name_to_table_ref(ObjectName(idents))?;
+                let table = EntryResolver::from_context(self.ctx)
+                    .resolve_entry_from_reference(table_ref.clone())?
+                    .try_into_table_entry()?;
+                if table.meta.builtin || table.meta.is_temp {
+                    return Err(PlanError::String(format!(
+                        "COMMENT is not supported for builtin or temporary table '{}'",
+                        table.meta.name
+                    )));
+                }
+
+                let table_ref = self.ctx.resolve_table_ref(table_ref)?;
+                self.check_schema_create(&table_ref)?;
+
+                match column {
+                    Some(column) => {
+                        // Columns of external tables aren't stored in the
+                        // catalog, so only native columns can be checked.
+                        if !table.meta.external {
+                            check_native_columns(&table, std::slice::from_ref(&column))?;
+                        }
+                        CommentObject::Column {
+                            schema: table_ref.schema.into_owned(),
+                            table: table_ref.name.into_owned(),
+                            column,
+                        }
+                    }
+                    None => CommentObject::Table {
+                        schema: table_ref.schema.into_owned(),
+                        name: table_ref.name.into_owned(),
+                    },
+                }
+            }
+        };
+
+        Ok(CommentOn {
+            object,
+            comment: stmt.comment,
+        }
+        .into_logical_plan())
+    }
+
+    fn plan_show_create(&self, stmt: ShowCreateStmt) -> Result<LogicalPlan> {
+        validate_object_name(&stmt.name)?;
+
+        let catalog = self.ctx.get_session_catalog();
+        let (name, ent) = match stmt.object_type {
+            ShowCreateObjectType::Database => {
+                let name = stmt
+                    .name
+                    .0
+                    .into_iter()
+                    .next()
+                    .map(normalize_ident)
+                    .ok_or_else(|| internal!("missing database name"))?;
+                let db = catalog
+                    .resolve_database(&name)
+                    .ok_or_else(|| PlanError::String(format!("Missing database: {name}")))?;
+                (name, CatalogEntry::Database(db.clone()))
+            }
+            ShowCreateObjectType::Table | ShowCreateObjectType::View => {
+                let table_ref = object_name_to_table_ref(stmt.name)?;
+                let ent = match EntryResolver::from_context(self.ctx)
+                    .resolve_entry_from_reference(table_ref.clone())?
+                {
+                    ResolvedEntry::Entry(ent) => ent,
+                    ResolvedEntry::NeedsExternalResolution { .. } => {
+                        return Err(PlanError::String(format!(
+                            "SHOW CREATE is not supported for objects in external databases: {table_ref}"
+                        )))
+                    }
+                };
+
+                let want = match stmt.object_type {
+                    ShowCreateObjectType::View => EntryType::View,
+                    _ => EntryType::Table,
+                };
+                let meta = ent.get_meta();
+                if meta.entry_type != want {
+                    return Err(PlanError::String(format!(
+                        "'{}' is not a {want}",
+                        meta.name
+                    )));
+                }
+
+                let schema = catalog
+                    .get_by_oid(meta.parent)
+                    .map(|schema| schema.get_meta().name.as_str())
+                    .unwrap_or(CURRENT_SESSION_SCHEMA);
+                (format!("{schema}.{}", meta.name), ent)
+            }
+        };
+
+        let stmts = ddl::entry_statements(catalog, &ent, DdlOptions::default())?;
+        let sql = stmts.join(";\n");
+
+        Ok(LogicalPlanBuilder::values(vec![vec![lit(name), lit(sql)]])?
+            .project(vec![
+                col("column1").alias("name"),
+                col("column2").alias("sql"),
+            ])?
+            .build()?
+            .into())
+    }
+
+    fn plan_restore_table(&self, stmt: RestoreTableStmt) -> Result<LogicalPlan> {
+        validate_object_name(&stmt.name)?;
+        let table_ref = object_name_to_table_ref(stmt.name)?;
+        let table = self.resolve_native_table(table_ref.clone(), "RESTORE TABLE")?;
+        let reference = self.ctx.resolve_table_ref(table_ref)?;
+        self.check_schema_create(&reference)?;
+        self.check_owner(&reference, "RESTORE TABLE")?;
+
+        let version = match stmt.target {
+            RestoreTableTarget::Version(version) => TableVersion::Version(version as i64),
+            RestoreTableTarget::Timestamp(ts) => TableVersion::try_from_timestamp(
+                FuncParamValue::Scalar(ScalarValue::Utf8(Some(ts))),
+            )
+            .map_err(|e| PlanError::String(format!("Invalid restore target: {e}")))?,
+        };
+
+        Ok(RestoreTable { table, version }.into_logical_plan())
+    }
+
+    /// Plan `ANALYZE` for a single table or, without a table, for every
+    /// native table the session is allowed to analyze.
+    ///
+    /// Like Postgres, external tables are only analyzed when explicitly named
+    /// since reading them may be expensive or fail.
+    async fn plan_analyze(&self, stmt: AnalyzeStmt) -> Result<LogicalPlan> {
+        let name = match stmt.name {
+            Some(name) => name,
+            None => return self.plan_analyze_all().await,
+        };
+
+        validate_object_name(&name)?;
+        let table_ref = object_name_to_table_ref(name)?;
+
+        let table = EntryResolver::from_context(self.ctx)
+            .resolve_entry_from_reference(table_ref.clone())?
+            .try_into_table_entry()?;
+        if table.meta.builtin || table.meta.is_temp {
+            return Err(PlanError::String(format!(
+                "ANALYZE is not supported for builtin or temporary table '{}'",
+                table.meta.name
+            )));
+        }
+
+        let resolved = self.ctx.resolve_table_ref(table_ref.clone())?;
+        self.check_schema_create(&resolved)?;
+
+        Ok(AnalyzeTable {
+            tables: vec![self.plan_analyze_target(table_ref).await?],
+        }
+        .into_logical_plan())
+    }
+
+    /// Plan `ANALYZE` without a table.
+    ///
+    /// Tables the session can't read, or whose schema it can't create in, are
+    /// skipped rather than erroring.
+    async fn plan_analyze_all(&self) -> Result<LogicalPlan> {
+        let catalog = self.ctx.get_session_catalog();
+        let role = self.ctx.get_session_vars().catalog_role();
+
+        let mut table_refs: Vec<_> = catalog
+            .iter_entries()
+            .filter_map(|ent| match (ent.entry, ent.parent_entry) {
+                (CatalogEntry::Table(table), Some(CatalogEntry::Schema(schema)))
+                    if !ent.builtin
+                        && !table.meta.external
+                        && !table.meta.is_temp
+                        && catalog.role_has_privilege(
+                            role.as_deref(),
+                            &schema.meta,
+                            Privilege::Create,
+                        )
+                        && catalog.role_has_privilege(
+                            role.as_deref(),
+                            &table.meta,
+                            Privilege::Select,
+                        ) =>
+                {
+                    Some(OwnedTableReference::partial(
+                        schema.meta.name.clone(),
+                        table.meta.name.clone(),
+                    ))
+                }
+                _ => None,
+            })
+            .collect();
+        table_refs.sort_by_key(|table_ref| table_ref.to_string());
+
+        let mut tables = Vec::with_capacity(table_refs.len());
+        for table_ref in table_refs {
+            tables.push(self.plan_analyze_target(table_ref).await?);
+        }
+
+        Ok(AnalyzeTable { tables }.into_logical_plan())
+    }
+
+    /// Plan a single row aggregate computing statistics for every column of
+    /// the table.
+    ///
+    /// The output contains the row count, followed by the non-null count,
+    /// min, max and approximate distinct count of each column. Aggregates that
+    /// aren't supported for a column's type are null.
+    async fn plan_analyze_target(&self, table_ref: OwnedTableReference) -> Result<AnalyzeTarget> {
+        let resolved = self.ctx.resolve_table_ref(table_ref.clone())?;
+
+        let query = format!("SELECT * FROM {}", quoted_table_ref(table_ref));
+        let query = match parser::parse_sql(&query)?.pop_front() {
+            Some(StatementWithExtensions::Statement(ast::Statement::Query(q))) => *q,
+            _ => unreachable!(),
+        };
+
+        let state = self.ctx.df_ctx().state();
+        let mut context_provider = PartialContextProvider::new(self.ctx, &state)?;
+        let mut planner = SqlQueryPlanner::new(&mut context_provider);
+        let source = planner.query_to_plan(query).await?;
+
+        let fields: Vec<_> = source.schema().fields().iter().cloned().collect();
+
+        let mut aggs = vec![count(lit(1)).alias("num_rows")];
+        let mut projections = vec![cast(col("num_rows"), DataType::UInt64)];
+        for (idx, field) in fields.iter().enumerate() {
+            let column = ident(field.name());
+            let data_type = field.data_type();
+
+            aggs.push(count(column.clone()).alias(format!("{idx}_count")));
+            projections.push(cast(ident(format!("{idx}_count")), DataType::UInt64));
+
+            if supports_min_max(data_type) {
+                aggs.push(min(column.clone()).alias(format!("{idx}_min")));
+                aggs.push(max(column.clone()).alias(format!("{idx}_max")));
+                projections.push(cast(ident(format!("{idx}_min")), DataType::Utf8));
+                projections.push(cast(ident(format!("{idx}_max")), DataType::Utf8));
+            } else {
+                projections.push(lit(ScalarValue::Utf8(None)));
+                projections.push(lit(ScalarValue::Utf8(None)));
+            }
+
+            if supports_approx_distinct(data_type) {
+                aggs.push(approx_distinct(column).alias(format!("{idx}_distinct")));
+                projections.push(cast(ident(format!("{idx}_distinct")), DataType::UInt64));
+            } else {
+                projections.push(lit(ScalarValue::UInt64(None)));
+            }
+        }
+
+        let source = LogicalPlanBuilder::from(source)
+            .aggregate(Vec::<Expr>::new(), aggs)?
+            .project(projections)?
+            .build()?;
+
+        Ok(AnalyzeTarget {
+            schema: resolved.schema.into_owned(),
+            name: resolved.name.into_owned(),
+            columns: fields.iter().map(|f| f.name().clone()).collect(),
+            source,
+        })
+    }
+
+    /// Resolve a reference to a table whose files we manage.
+    fn resolve_native_table(
+        &self,
+        table_ref: OwnedTableReference,
+        operation: &'static str,
+    ) -> Result<TableEntry> {
+        let table = EntryResolver::from_context(self.ctx)
+            .resolve_entry_from_reference(table_ref)?
+            .try_into_table_entry()?;
+
+        if table.meta.external || table.meta.builtin || table.meta.is_temp {
+            return Err(PlanError::NotNativeTable {
+                operation,
+                table: table.meta.name,
+            });
+        }
+
+        Ok(table)
+    }
+
     async fn plan_copy_to(&self, stmt: CopyToStmt) -> Result<LogicalPlan> {
         let query = match stmt.source {
             CopyToSource::Table(table) => {
@@ -2258,6 +3238,123 @@ async fn validate_and_get_file_type_and_compression(
     Ok((file_type, compression))
 }
 
+/// Ensure every column exists in the native table.
+fn check_native_columns(table: &TableEntry, columns: &[String]) -> Result<()> {
+    let table_columns = table.get_internal_columns().unwrap_or_default();
+    for col in columns {
+        if !table_columns.iter().any(|c| &c.name == col) {
+            return Err(PlanError::String(format!(
+                "Column '{col}' does not exist in table '{}'",
+                table.meta.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Get the column names from a `PARTITION BY` clause, which may be a single
+/// column or a parenthesized list of columns.
+fn partition_columns_from_expr(expr: ast::Expr) -> Result<Vec<String>> {
+    let exprs = match expr {
+        ast::Expr::Tuple(exprs) => exprs,
+        ast::Expr::Nested(expr) => vec![*expr],
+        expr => vec![expr],
+    };
+
+    exprs
+        .into_iter()
+        .map(|expr| match expr {
+            ast::Expr::Identifier(ident) => {
+                validate_ident(&ident)?;
+                Ok(normalize_ident(ident))
+            }
+            other => Err(PlanError::String(format!(
+                "Expected a column name in PARTITION BY, found: {other}"
+            ))),
+        })
+        .collect()
+}
+
+/// Check that a native table can be partitioned by the given columns.
+///
+/// Partition values are stored in file paths, so only simple types can be
+/// used, and at least one column must be left to hold data.
+fn check_partition_columns(fields: &[Field], partition_columns: &[String]) -> Result<()> {
+    if partition_columns.is_empty() {
+        return Ok(());
+    }
+
+    for (idx, col) in partition_columns.iter().enumerate() {
+        if partition_columns[..idx].contains(col) {
+            return Err(PlanError::String(format!(
+                "Column '{col}' specified more than once in PARTITION BY"
+            )));
+        }
+
+        let field = fields
+            .iter()
+            .find(|f| f.name() == col)
+            .ok_or_else(|| PlanError::String(format!("Column '{col}' does not exist")))?;
+
+        if !matches!(
+            field.data_type(),
+            DataType::Boolean
+                | DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::Utf8
+                | DataType::LargeUtf8
+                | DataType::Date32
+        ) {
+            return Err(PlanError::String(format!(
+                "Cannot partition by column '{col}' of type {}",
+                field.data_type()
+            )));
+        }
+    }
+
+    if partition_columns.len() == fields.len() {
+        return Err(PlanError::String(
+            "Cannot partition a table by all of its columns".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// If min and max values of this type can be round tripped through a string
+/// for `ANALYZE`.
+fn supports_min_max(data_type: &DataType) -> bool {
+    data_type.is_numeric()
+        || matches!(
+            data_type,
+            DataType::Boolean
+                | DataType::Utf8
+                | DataType::LargeUtf8
+                | DataType::Date32
+                | DataType::Date64
+                | DataType::Timestamp(_, _)
+                | DataType::Time32(_)
+                | DataType::Time64(_)
+        )
+}
+
+/// If distinct values of this type can be approximated for `ANALYZE`.
+fn supports_approx_distinct(data_type: &DataType) -> bool {
+    data_type.is_integer()
+        || matches!(
+            data_type,
+            DataType::Utf8
+                | DataType::LargeUtf8
+                | DataType::Binary
+                | DataType::LargeBinary
+                | DataType::Date32
+                | DataType::Date64
+                | DataType::Timestamp(_, _)
+        )
+}
+
 /// Resolves an ident (unquoted -> lowercase else case sensitive).
 fn normalize_ident(ident: Ident) -> String {
     let normalizer = IdentNormalizer::new(/* normalize = */ true);
@@ -2281,6 +3378,25 @@ fn quoted_table_ref(table_ref: TableReference<'_>) -> String {
     }
 }
 
+fn object_name_to_role_name(name: ObjectName) -> Result<String> {
+    match <[Ident; 1]>::try_from(name.0) {
+        Ok([ident]) => Ok(normalize_ident(ident)),
+        Err(_) => Err(PlanError::InvalidRoleStatement {
+            msg: "role names cannot be qualified",
+        }),
+    }
+}
+
+fn plan_grantees(grantees: Vec<Ident>) -> Result<Vec<String>> {
+    grantees
+        .into_iter()
+        .map(|ident| {
+            validate_ident(&ident)?;
+            Ok(normalize_ident(ident))
+        })
+        .collect()
+}
+
 fn object_name_to_schema_ref(name: ObjectName) -> Result<OwnedSchemaReference> {
     let r = match object_name_to_table_ref(name)? {
         // Table becomes the schema and schema becomes the catalog.
@@ -2316,6 +3432,156 @@ fn convert_data_type(sql_type: &ast::DataType) -> Result<DataType> {
     }
 }
 
+/// Returns the integer type a `SMALLSERIAL`, `SERIAL` or `BIGSERIAL` column is
+/// stored as.
+fn serial_data_type(sql_type: &ast::DataType) -> Option<DataType> {
+    let name = match sql_type {
+        ast::DataType::Custom(name, modifiers) if name.0.len() == 1 && modifiers.is_empty() => {
+            name.0[0].value.to_lowercase()
+        }
+        _ => return None,
+    };
+    match name.as_str() {
+        "smallserial" | "serial2" => Some(DataType::Int16),
+        "serial" | "serial4" => Some(DataType::Int32),
+        "bigserial" | "serial8" => Some(DataType::Int64),
+        _ => None,
+    }
+}
+
+fn is_identity_column(column: &ast::ColumnDef) -> bool {
+    serial_data_type(&column.data_type).is_some()
+        || column
+            .options
+            .iter()
+            .any(|opt| matches!(opt.option, ColumnOption::Generated { .. }))
+}
+
+/// Get the type of a column, along with whether it's `GENERATED ALWAYS` and
+/// the options for its sequence if it's an identity column.
+///
+/// Like in Postgres, serial types are shorthand for integer columns generated
+/// by default as identity.
+fn column_type_and_identity(
+    column: &ast::ColumnDef,
+) -> Result<(DataType, Option<(bool, SequenceOptions)>)> {
+    let serial_type = serial_data_type(&column.data_type);
+
+    let mut generated = None;
+    for opt in &column.options {
+        if let ColumnOption::Generated {
+            generated_as,
+            sequence_options,
+            generation_expr,
+            ..
+        } = &opt.option
+        {
+            if generation_expr.is_some() {
+                return Err(PlanError::UnsupportedFeature("generated columns"));
+            }
+            let always = matches!(generated_as, ast::GeneratedAs::Always);
+            generated = Some((always, sequence_options.clone().unwrap_or_default()));
+        }
+    }
+
+    let (data_type, always, options) = match (serial_type, generated) {
+        (Some(_), Some(_)) => {
+            return Err(PlanError::String(format!(
+                "multiple identity specifications for column \"{}\"",
+                column.name
+            )))
+        }
+        (Some(data_type), None) => (data_type, false, Vec::new()),
+        (None, Some((always, options))) => (convert_data_type(&column.data_type)?, always, options),
+        (None, None) => return Ok((convert_data_type(&column.data_type)?, None)),
+    };
+
+    let (type_min, type_max) = sequence_type_range(&data_type).ok_or_else(|| {
+        PlanError::String(format!(
+            "identity column type must be smallint, integer, or bigint, got {data_type}"
+        ))
+    })?;
+    let sequence_options = sequence_options_from_ast(&options, type_min, type_max)?;
+
+    Ok((data_type, Some((always, sequence_options))))
+}
+
+/// Range of values a sequence of the given type can hand out.
+fn sequence_type_range(data_type: &DataType) -> Option<(i64, i64)> {
+    match data_type {
+        DataType::Int16 => Some((i16::MIN as i64, i16::MAX as i64)),
+        DataType::Int32 => Some((i32::MIN as i64, i32::MAX as i64)),
+        DataType::Int64 => Some((i64::MIN, i64::MAX)),
+        _ => None,
+    }
+}
+
+/// Convert sequence options from `CREATE SEQUENCE` or an identity column
+/// definition, filling in defaults for a sequence of values in the range
+/// `[type_min, type_max]`.
+fn sequence_options_from_ast(
+    options: &[ast::SequenceOptions],
+    type_min: i64,
+    type_max: i64,
+) -> Result<SequenceOptions> {
+    let mut increment = 1;
+    let mut start = None;
+    let mut min_value = None;
+    let mut max_value = None;
+    let mut cycle = false;
+    let mut cache = None;
+
+    for opt in options {
+        match opt {
+            ast::SequenceOptions::IncrementBy(expr, _) => increment = sequence_option_value(expr)?,
+            ast::SequenceOptions::StartWith(expr, _) => start = Some(sequence_option_value(expr)?),
+            ast::SequenceOptions::MinValue(expr) => {
+                min_value = expr.as_ref().map(sequence_option_value).transpose()?
+            }
+            ast::SequenceOptions::MaxValue(expr) => {
+                max_value = expr.as_ref().map(sequence_option_value).transpose()?
+            }
+            // `Cycle(true)` is `NO CYCLE`.
+            ast::SequenceOptions::Cycle(no_cycle) => cycle = !no_cycle,
+            ast::SequenceOptions::Cache(expr) => cache = Some(sequence_option_value(expr)?),
+        }
+    }
+
+    let mut options = SequenceOptions::with_defaults(
+        increment, start, min_value, max_value, cycle, type_min, type_max,
+    );
+    if let Some(cache) = cache {
+        options.cache = cache;
+    }
+    if options.min_value < type_min || options.max_value > type_max {
+        return Err(PlanError::String(format!(
+            "sequence bounds must be between {type_min} and {type_max}"
+        )));
+    }
+    options.validate().map_err(PlanError::String)?;
+
+    Ok(options)
+}
+
+fn sequence_option_value(expr: &ast::Expr) -> Result<i64> {
+    let value = match expr {
+        ast::Expr::Value(ast::Value::Number(n, _)) => n.parse::<i64>().ok(),
+        ast::Expr::UnaryOp {
+            op: ast::UnaryOperator::Minus,
+            expr,
+        } => match expr.as_ref() {
+            ast::Expr::Value(ast::Value::Number(n, _)) => format!("-{n}").parse::<i64>().ok(),
+            _ => None,
+        },
+        _ => None,
+    };
+    value.ok_or_else(|| {
+        PlanError::String(format!(
+            "sequence option value must be an integer, got {expr}"
+        ))
+    })
+}
+
 // TODO: We already copy this in by way of the `datafusion_ext` crate. Is there
 // a way to ensure we only have a single copy?
 fn convert_simple_data_type(sql_type: &ast::DataType) -> Result<DataType> {
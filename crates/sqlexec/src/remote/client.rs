@@ -25,10 +25,10 @@ use protogen::rpcsrv::types::service::{
 use proxyutil::metadata_constants::{DB_NAME_KEY, ORG_KEY, PASSWORD_KEY, USER_KEY};
 use serde::Deserialize;
 use sqlbuiltins::builtins::{SCHEMA_CURRENT_SESSION, SCHEMA_DEFAULT};
-use tonic::metadata::MetadataMap;
+use tonic::metadata::{AsciiMetadataKey, AsciiMetadataValue, MetadataMap};
 use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint};
 use tonic::{IntoRequest, Streaming};
-use tracing::debug;
+use tracing::{debug, info_span, Instrument};
 use url::Url;
 use uuid::Uuid;
 
@@ -277,9 +277,17 @@ impl RemoteClient {
         let mut request = service::InitializeSessionRequest::from(request).into_request();
         self.append_auth_metadata(request.metadata_mut());
 
-        let resp = self.client.initialize_session(request).await.map_err(|e| {
-            ExecError::RemoteSession(format!("failed to initialize remote session: {e}"))
-        })?;
+        let span = info_span!("remote_rpc", method = "initialize_session");
+        span.in_scope(|| append_trace_context(request.metadata_mut()));
+
+        let resp = self
+            .client
+            .initialize_session(request)
+            .instrument(span)
+            .await
+            .map_err(|e| {
+                ExecError::RemoteSession(format!("failed to initialize remote session: {e}"))
+            })?;
         let resp: InitializeSessionResponse = resp.into_inner().try_into()?;
 
         let remote_sess_client = RemoteSessionClient {
@@ -340,10 +348,14 @@ impl RemoteSessionClient {
         .into_request();
         self.inner.append_auth_metadata(request.metadata_mut());
 
+        let span = info_span!("remote_rpc", method = "fetch_catalog");
+        span.in_scope(|| append_trace_context(request.metadata_mut()));
+
         let resp: FetchCatalogResponse = self
             .inner
             .client
             .fetch_catalog(request)
+            .instrument(span)
             .await
             .map_err(|e| ExecError::RemoteSession(format!("failed to fetch catalog: {e}")))?
             .into_inner()
@@ -382,10 +394,14 @@ impl RemoteSessionClient {
         .into_request();
         self.inner.append_auth_metadata(request.metadata_mut());
 
+        let span = info_span!("remote_rpc", method = "dispatch_access");
+        span.in_scope(|| append_trace_context(request.metadata_mut()));
+
         let resp: TableProviderResponse = self
             .inner
             .client
             .dispatch_access(request)
+            .instrument(span)
             .await
             .map_err(|e| ExecError::RemoteSession(format!("unable to dispatch table access: {e}")))?
             .into_inner()
@@ -398,6 +414,7 @@ impl RemoteSessionClient {
         &mut self,
         physical_plan: Arc<dyn ExecutionPlan>,
         query_text: String,
+        user_name: String,
     ) -> Result<Streaming<service::RecordBatchResponse>> {
         // Encode the physical plan into a protobuf message.
         let physical_plan = {
@@ -415,14 +432,19 @@ impl RemoteSessionClient {
             physical_plan,
             user_id: self.user_id,
             query_text,
+            user_name,
         })
         .into_request();
         self.inner.append_auth_metadata(request.metadata_mut());
 
+        let span = info_span!("remote_rpc", method = "physical_plan_execute");
+        span.in_scope(|| append_trace_context(request.metadata_mut()));
+
         let resp = self
             .inner
             .client
             .physical_plan_execute(request)
+            .instrument(span)
             .await
             .map_err(|e| {
                 ExecError::RemoteSession(format!("error while executing physical plan: {e}"))
@@ -437,11 +459,32 @@ impl RemoteSessionClient {
     ) -> Result<()> {
         let mut req = stream.into_streaming_request();
         self.inner.append_auth_metadata(req.metadata_mut());
-        let _resp = self.inner.client.broadcast_exchange(req).await?;
+
+        let span = info_span!("remote_rpc", method = "broadcast_exchange");
+        span.in_scope(|| append_trace_context(req.metadata_mut()));
+
+        let _resp = self
+            .inner
+            .client
+            .broadcast_exchange(req)
+            .instrument(span)
+            .await?;
         Ok(())
     }
 }
 
+/// Append the trace context of the current span to request metadata, allowing
+/// the remote node to continue the trace.
+fn append_trace_context(metadata: &mut MetadataMap) {
+    for (key, value) in logutil::otlp::current_trace_context() {
+        let key = AsciiMetadataKey::from_bytes(key.as_bytes());
+        let value = AsciiMetadataValue::try_from(value);
+        if let (Ok(key), Ok(value)) = (key, value) {
+            metadata.insert(key, value);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
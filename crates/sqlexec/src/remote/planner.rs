@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use async_trait::async_trait;
 use catalog::session_catalog::SessionCatalog;
@@ -25,11 +26,16 @@ use crate::planner::logical_plan::{
     AlterDatabase,
     AlterTable,
     AlterTunnelRotateKeys,
+    AnalyzeTable,
+    BackupDatabase,
+    CommentOn,
     CopyTo,
     CreateCredentials,
     CreateExternalDatabase,
     CreateExternalTable,
+    CreateRole,
     CreateSchema,
+    CreateSequence,
     CreateTable,
     CreateTempTable,
     CreateTunnel,
@@ -38,25 +44,38 @@ use crate::planner::logical_plan::{
     DescribeTable,
     DropCredentials,
     DropDatabase,
+    DropRoles,
     DropSchemas,
+    DropSequences,
     DropTables,
     DropTunnel,
     DropViews,
+    GrantPrivileges,
     Insert,
+    Optimize,
+    RestoreTable,
+    RevokePrivileges,
     SetVariable,
     ShowVariable,
+    UndropTable,
     Update,
+    Vacuum,
 };
 use crate::planner::physical_plan::alter_database::AlterDatabaseExec;
 use crate::planner::physical_plan::alter_table::AlterTableExec;
 use crate::planner::physical_plan::alter_tunnel_rotate_keys::AlterTunnelRotateKeysExec;
+use crate::planner::physical_plan::analyze_table::{AnalyzeTableExec, AnalyzeTableTarget};
+use crate::planner::physical_plan::backup_database::BackupDatabaseExec;
 use crate::planner::physical_plan::client_recv::ClientExchangeRecvExec;
 use crate::planner::physical_plan::client_send::ClientExchangeSendExec;
+use crate::planner::physical_plan::comment_on::CommentOnExec;
 use crate::planner::physical_plan::copy_to::CopyToExec;
 use crate::planner::physical_plan::create_credentials::CreateCredentialsExec;
 use crate::planner::physical_plan::create_external_database::CreateExternalDatabaseExec;
 use crate::planner::physical_plan::create_external_table::CreateExternalTableExec;
+use crate::planner::physical_plan::create_role::CreateRoleExec;
 use crate::planner::physical_plan::create_schema::CreateSchemaExec;
+use crate::planner::physical_plan::create_sequence::CreateSequenceExec;
 use crate::planner::physical_plan::create_table::CreateTableExec;
 use crate::planner::physical_plan::create_temp_table::CreateTempTableExec;
 use crate::planner::physical_plan::create_tunnel::CreateTunnelExec;
@@ -65,18 +84,26 @@ use crate::planner::physical_plan::delete::DeleteExec;
 use crate::planner::physical_plan::describe_table::DescribeTableExec;
 use crate::planner::physical_plan::drop_credentials::DropCredentialsExec;
 use crate::planner::physical_plan::drop_database::DropDatabaseExec;
+use crate::planner::physical_plan::drop_roles::DropRolesExec;
 use crate::planner::physical_plan::drop_schemas::DropSchemasExec;
+use crate::planner::physical_plan::drop_sequences::DropSequencesExec;
 use crate::planner::physical_plan::drop_tables::DropTablesExec;
 use crate::planner::physical_plan::drop_temp_tables::DropTempTablesExec;
 use crate::planner::physical_plan::drop_tunnel::DropTunnelExec;
 use crate::planner::physical_plan::drop_views::DropViewsExec;
+use crate::planner::physical_plan::grant_privileges::GrantPrivilegesExec;
 use crate::planner::physical_plan::insert::InsertExec;
+use crate::planner::physical_plan::optimize::OptimizeExec;
 use crate::planner::physical_plan::remote_exec::RemoteExecutionExec;
 use crate::planner::physical_plan::remote_scan::ProviderReference;
+use crate::planner::physical_plan::restore_table::RestoreTableExec;
+use crate::planner::physical_plan::revoke_privileges::RevokePrivilegesExec;
 use crate::planner::physical_plan::send_recv::SendRecvJoinExec;
 use crate::planner::physical_plan::set_var::SetVarExec;
 use crate::planner::physical_plan::show_var::ShowVarExec;
+use crate::planner::physical_plan::undrop_table::UndropTableExec;
 use crate::planner::physical_plan::update::UpdateExec;
+use crate::planner::physical_plan::vacuum::VacuumExec;
 
 pub struct DDLExtensionPlanner {
     catalog: SessionCatalog,
@@ -161,6 +188,7 @@ impl ExtensionPlanner for DDLExtensionPlanner {
                     tunnel: lp.tunnel.clone(),
                     table_options: lp.table_options.clone(),
                     table_schema: lp.schema.clone(),
+                    owner: lp.owner,
                 };
                 RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
             }
@@ -182,6 +210,9 @@ impl ExtensionPlanner for DDLExtensionPlanner {
                     or_replace: lp.or_replace,
                     arrow_schema: Arc::new(lp.schema.as_ref().into()),
                     source: physical_inputs.first().cloned(),
+                    partition_columns: lp.partition_columns.clone(),
+                    identity_columns: lp.identity_columns.clone(),
+                    owner: lp.owner,
                 };
                 RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
             }
@@ -214,6 +245,7 @@ impl ExtensionPlanner for DDLExtensionPlanner {
                     sql: lp.sql.clone(),
                     columns: lp.columns.clone(),
                     or_replace: lp.or_replace,
+                    owner: lp.owner,
                 };
                 RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
             }
@@ -268,11 +300,29 @@ impl ExtensionPlanner for DDLExtensionPlanner {
                     }
                     // only remote tables
                     (true, false) => {
+                        // Dropping tables is also when we get rid of tables
+                        // dropped earlier whose retention has expired. The
+                        // expiry is fixed per table when it's dropped.
+                        let now = SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .unwrap_or_default()
+                            .as_millis() as i64;
+                        let purge_entries = self
+                            .catalog
+                            .get_state()
+                            .dropped_tables
+                            .iter()
+                            .filter(|dropped| dropped.expires_at <= now)
+                            .map(|dropped| dropped.table.clone())
+                            .collect();
+
                         let exec = Arc::new(DropTablesExec {
                             catalog_version: self.catalog.version(),
                             tbl_references: drops,
                             tbl_entries,
                             if_exists: plan.if_exists,
+                            purge_expired_before: now,
+                            purge_entries,
                         });
                         RuntimeGroupExec::new(RuntimePreference::Remote, exec)
                     }
@@ -319,6 +369,68 @@ impl ExtensionPlanner for DDLExtensionPlanner {
                 };
                 RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
             }
+            ExtensionType::CreateRole => {
+                let lp = require_downcast_lp::<CreateRole>(node);
+                let exec = CreateRoleExec {
+                    catalog_version: self.catalog.version(),
+                    name: lp.name.clone(),
+                    if_not_exists: lp.if_not_exists,
+                    login: lp.login,
+                    superuser: lp.superuser,
+                    password: lp.password.clone(),
+                    member_of: lp.member_of.clone(),
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
+            ExtensionType::DropRoles => {
+                let lp = require_downcast_lp::<DropRoles>(node);
+                let exec = DropRolesExec {
+                    catalog_version: self.catalog.version(),
+                    names: lp.names.clone(),
+                    if_exists: lp.if_exists,
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
+            ExtensionType::GrantPrivileges => {
+                let lp = require_downcast_lp::<GrantPrivileges>(node);
+                let exec = GrantPrivilegesExec {
+                    catalog_version: self.catalog.version(),
+                    grantees: lp.grantees.clone(),
+                    object_ids: lp.object_ids.clone(),
+                    privileges: lp.privileges.clone(),
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
+            ExtensionType::RevokePrivileges => {
+                let lp = require_downcast_lp::<RevokePrivileges>(node);
+                let exec = RevokePrivilegesExec {
+                    catalog_version: self.catalog.version(),
+                    grantees: lp.grantees.clone(),
+                    object_ids: lp.object_ids.clone(),
+                    privileges: lp.privileges.clone(),
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
+            ExtensionType::CreateSequence => {
+                let lp = require_downcast_lp::<CreateSequence>(node);
+                let exec = CreateSequenceExec {
+                    catalog_version: self.catalog.version(),
+                    sequence_reference: lp.sequence_reference.clone(),
+                    options: lp.options,
+                    if_not_exists: lp.if_not_exists,
+                    owner: lp.owner,
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
+            ExtensionType::DropSequences => {
+                let lp = require_downcast_lp::<DropSequences>(node);
+                let exec = DropSequencesExec {
+                    catalog_version: self.catalog.version(),
+                    sequence_references: lp.sequence_references.clone(),
+                    if_exists: lp.if_exists,
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
             ExtensionType::DropViews => {
                 let lp = require_downcast_lp::<DropViews>(node);
                 // TODO: Fix this.
@@ -394,6 +506,75 @@ impl ExtensionPlanner for DDLExtensionPlanner {
                 };
                 RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
             }
+            ExtensionType::Optimize => {
+                let lp = require_downcast_lp::<Optimize>(node);
+                let exec = OptimizeExec {
+                    table: lp.table.clone(),
+                    zorder_columns: lp.zorder_columns.clone(),
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
+            ExtensionType::Vacuum => {
+                let lp = require_downcast_lp::<Vacuum>(node);
+                let exec = VacuumExec {
+                    table: lp.table.clone(),
+                    retain_hours: lp.retain_hours,
+                    dry_run: lp.dry_run,
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
+            ExtensionType::UndropTable => {
+                let lp = require_downcast_lp::<UndropTable>(node);
+                let exec = UndropTableExec {
+                    catalog_version: self.catalog.version(),
+                    schema: lp.schema.clone(),
+                    name: lp.name.clone(),
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
+            ExtensionType::RestoreTable => {
+                let lp = require_downcast_lp::<RestoreTable>(node);
+                let exec = RestoreTableExec {
+                    table: lp.table.clone(),
+                    version: lp.version,
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
+            ExtensionType::AnalyzeTable => {
+                let lp = require_downcast_lp::<AnalyzeTable>(node);
+                let exec = AnalyzeTableExec {
+                    catalog_version: self.catalog.version(),
+                    tables: lp
+                        .tables
+                        .iter()
+                        .zip(physical_inputs)
+                        .map(|(table, source)| AnalyzeTableTarget {
+                            schema: table.schema.clone(),
+                            name: table.name.clone(),
+                            columns: table.columns.clone(),
+                            source: source.clone(),
+                        })
+                        .collect(),
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
+            ExtensionType::CommentOn => {
+                let lp = require_downcast_lp::<CommentOn>(node);
+                let exec = CommentOnExec {
+                    catalog_version: self.catalog.version(),
+                    object: lp.object.clone(),
+                    comment: lp.comment.clone(),
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
+            ExtensionType::BackupDatabase => {
+                let lp = require_downcast_lp::<BackupDatabase>(node);
+                let exec = BackupDatabaseExec {
+                    location: lp.location.clone(),
+                    storage_options: lp.storage_options.clone(),
+                };
+                RuntimeGroupExec::new(RuntimePreference::Remote, Arc::new(exec))
+            }
         };
 
         Ok(Some(Arc::new(runtime_group_exec)))
@@ -403,6 +584,7 @@ impl ExtensionPlanner for DDLExtensionPlanner {
 pub struct RemotePhysicalPlanner<'a> {
     pub database_id: Uuid,
     pub query_text: &'a str,
+    pub user_name: String,
     pub remote_client: RemoteSessionClient,
     pub catalog: &'a SessionCatalog,
 }
@@ -597,6 +779,7 @@ impl<'a> RemotePhysicalPlanner<'a> {
             self.remote_client.clone(),
             physical,
             self.query_text.to_owned(),
+            self.user_name.clone(),
         ));
 
         Arc::new(SendRecvJoinExec::new(physical, sends))
@@ -155,7 +155,7 @@ impl<'a> EntryResolver<'a> {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
     use std::sync::Arc;
 
     use catalog::session_catalog::ResolveConfig;
@@ -183,6 +183,8 @@ mod tests {
                     builtin: false,
                     external: false,
                     is_temp: false,
+                    comment: None,
+                    owner: 0,
                 },
             }),
             CatalogEntry::Schema(SchemaEntry {
@@ -194,6 +196,8 @@ mod tests {
                     builtin: false,
                     external: false,
                     is_temp: false,
+                    comment: None,
+                    owner: 0,
                 },
             }),
             // Tables
@@ -206,11 +210,15 @@ mod tests {
                     builtin: false,
                     external: false,
                     is_temp: false,
+                    comment: None,
+                    owner: 0,
                 },
                 options: TableOptionsV0::new_internal(Vec::new()),
                 tunnel_id: None,
                 access_mode: SourceAccessMode::ReadWrite,
                 columns: None,
+                statistics: None,
+                column_comments: BTreeMap::new(),
             }),
             // Tables
             CatalogEntry::Table(TableEntry {
@@ -222,11 +230,15 @@ mod tests {
                     builtin: false,
                     external: false,
                     is_temp: false,
+                    comment: None,
+                    owner: 0,
                 },
                 options: TableOptionsV0::new_internal(Vec::new()),
                 tunnel_id: None,
                 access_mode: SourceAccessMode::ReadWrite,
                 columns: None,
+                statistics: None,
+                column_comments: BTreeMap::new(),
             }),
         ];
 
@@ -238,8 +250,10 @@ mod tests {
         CatalogState {
             version: 0,
             entries,
-            deployment: DeploymentMetadata { storage_size: 0 },
+            deployment: DeploymentMetadata::default(),
             catalog_version: 0,
+            dropped_tables: Vec::new(),
+            builtin_comments: HashMap::new(),
         }
     }
 
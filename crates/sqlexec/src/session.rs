@@ -9,6 +9,7 @@ use catalog::mutator::CatalogMutator;
 use catalog::session_catalog::SessionCatalog;
 use datafusion::arrow::datatypes::Schema;
 use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::TableProvider;
 use datafusion::error::{DataFusionError, Result as DataFusionResult};
 use datafusion::logical_expr::LogicalPlan as DfLogicalPlan;
 use datafusion::physical_plan::empty::EmptyExec;
@@ -24,9 +25,11 @@ use datafusion_ext::metrics::AggregatedMetrics;
 use datafusion_ext::session_metrics::{
     BatchStreamWithMetricSender,
     ExecutionStatus,
+    QueryHistory,
     QueryMetrics,
     SessionMetricsHandler,
 };
+use datafusion_ext::traced::TracedExec;
 use datafusion_ext::vars::SessionVars;
 use datasources::native::access::NativeTableStorage;
 use distexec::scheduler::{OutputSink, Scheduler};
@@ -38,6 +41,7 @@ use pgrepr::format::Format;
 use pgrepr::notice::{Notice, NoticeSeverity, SqlState};
 use sqlbuiltins::functions::BuiltinScalarUDF;
 use telemetry::Tracker;
+use tracing::{info_span, Instrument};
 use url::Url;
 use uuid::Uuid;
 
@@ -45,6 +49,7 @@ use crate::context::local::{LocalSessionContext, Portal, PreparedStatement};
 use crate::environment::EnvironmentReader;
 use crate::errors::{ExecError, Result};
 use crate::planner::logical_plan::{LogicalPlan, OperationInfo, TransactionPlan};
+use crate::planner::physical_plan::remote_exec::RemoteExecutionExec;
 use crate::planner::physical_plan::{
     get_count_from_batch,
     get_operation_from_batch,
@@ -117,6 +122,14 @@ pub enum ExecutionResult {
     DropTunnel,
     /// Credentials are dropped.
     DropCredentials,
+    /// A role was created.
+    CreateRole,
+    /// Roles dropped.
+    DropRoles,
+    /// Privileges granted.
+    Grant,
+    /// Privileges revoked.
+    Revoke,
 }
 // this just makes the `prepare_statement` method a bit more ergonomic.
 pub struct PrepareStatementArg {
@@ -237,6 +250,10 @@ impl ExecutionResult {
             ExecutionResult::DropDatabase => "drop_database",
             ExecutionResult::DropTunnel => "drop_tunnel",
             ExecutionResult::DropCredentials => "drop_credentials",
+            ExecutionResult::CreateRole => "create_role",
+            ExecutionResult::DropRoles => "drop_role",
+            ExecutionResult::Grant => "grant",
+            ExecutionResult::Revoke => "revoke",
         }
     }
 
@@ -259,6 +276,10 @@ impl ExecutionResult {
                 | ExecutionResult::DropDatabase
                 | ExecutionResult::DropTunnel
                 | ExecutionResult::DropCredentials
+                | ExecutionResult::CreateRole
+                | ExecutionResult::DropRoles
+                | ExecutionResult::Grant
+                | ExecutionResult::Revoke
         )
     }
 
@@ -298,6 +319,10 @@ impl ExecutionResult {
             "drop_database" => ExecutionResult::DropDatabase,
             "drop_tunnel" => ExecutionResult::DropTunnel,
             "drop_credentials" => ExecutionResult::DropCredentials,
+            "create_role" => ExecutionResult::CreateRole,
+            "drop_role" => ExecutionResult::DropRoles,
+            "grant" => ExecutionResult::Grant,
+            "revoke" => ExecutionResult::Revoke,
             _ => return None,
         })
     }
@@ -355,6 +380,10 @@ impl fmt::Display for ExecutionResult {
             ExecutionResult::DropDatabase => write!(f, "Database(s) dropped"),
             ExecutionResult::DropTunnel => write!(f, "Tunnel(s) dropped"),
             ExecutionResult::DropCredentials => write!(f, "Credentials dropped"),
+            ExecutionResult::CreateRole => write!(f, "Role created"),
+            ExecutionResult::DropRoles => write!(f, "Role(s) dropped"),
+            ExecutionResult::Grant => write!(f, "Privileges granted"),
+            ExecutionResult::Revoke => write!(f, "Privileges revoked"),
         }
     }
 }
@@ -404,14 +433,17 @@ impl Session {
         catalog_mutator: CatalogMutator,
         native_tables: NativeTableStorage,
         tracker: Arc<Tracker>,
+        query_history: Arc<QueryHistory>,
         spill_path: Option<PathBuf>,
         task_scheduler: Scheduler,
     ) -> Result<Session> {
         let metrics_handler = SessionMetricsHandler::new(
             vars.user_id(),
+            vars.user_name(),
             vars.database_id(),
             vars.connection_id(),
             tracker,
+            query_history,
         );
 
         let ctx = LocalSessionContext::new(
@@ -431,6 +463,10 @@ impl Session {
         self.ctx.register_function(udf).await
     }
 
+    pub fn register_session_function(&mut self, udf: Arc<dyn BuiltinScalarUDF>) -> Result<()> {
+        self.ctx.register_session_function(udf)
+    }
+
     pub async fn create_client_session(
         &mut self,
         cloud_url: Option<Url>,
@@ -470,6 +506,36 @@ impl Session {
         self.ctx.register_env_reader(env_reader);
     }
 
+    /// Register a table as a temporary table for this session.
+    ///
+    /// Errors if a temporary table with the same name already exists.
+    pub fn register_temp_table(
+        &self,
+        name: impl Into<String>,
+        table: Arc<dyn TableProvider>,
+    ) -> Result<()> {
+        let name = name.into();
+        let temp = self.get_session_catalog().get_temp_catalog();
+        if temp.contains_table(&name) {
+            return Err(ExecError::DuplicateObjectName(name));
+        }
+        temp.put_temp_table(name, table);
+        Ok(())
+    }
+
+    /// Remove a temporary table from this session.
+    pub fn deregister_temp_table(&self, name: &str) -> Result<()> {
+        let temp = self.get_session_catalog().get_temp_catalog();
+        if !temp.contains_table(name) {
+            return Err(ExecError::MissingObject {
+                typ: "table",
+                name: name.to_string(),
+            });
+        }
+        temp.drop_table(name);
+        Ok(())
+    }
+
     /// Return the DF session context.
     pub fn df_ctx(&self) -> &datafusion::prelude::SessionContext {
         self.ctx.df_ctx()
@@ -482,25 +548,45 @@ impl Session {
         op: &OperationInfo,
     ) -> Result<Arc<dyn ExecutionPlan>> {
         let state = self.ctx.df_ctx().state();
-        let plan = state.optimize(&plan)?;
-        if let Some(client) = self.ctx.exec_client() {
+        let plan = info_span!("optimize").in_scope(|| state.optimize(&plan))?;
+        let span = info_span!("physical_plan");
+        let plan = if let Some(client) = self.ctx.exec_client() {
             let planner = RemotePhysicalPlanner {
                 database_id: self.ctx.get_database_id(),
                 query_text: op.query_text(),
+                user_name: self.ctx.get_session_vars().user_name(),
                 remote_client: client,
                 catalog: self.ctx.get_session_catalog(),
             };
-            let plan = planner.create_physical_plan(&plan, &state).await?;
-            Ok(plan)
+            planner
+                .create_physical_plan(&plan, &state)
+                .instrument(span)
+                .await?
         } else {
             // TODO: Possible to not require a catalog clone here?
             let ddl_planner = DDLExtensionPlanner::new(self.ctx.get_session_catalog().clone());
             let planner =
                 DefaultPhysicalPlanner::with_extension_planners(vec![Arc::new(ddl_planner)]);
-            let plan = planner.create_physical_plan(&plan, &state).await?;
+            planner
+                .create_physical_plan(&plan, &state)
+                .instrument(span)
+                .await?
+        };
 
-            Ok(plan)
+        // Only wrap operators when spans are actually being exported. The
+        // experimental scheduler inspects concrete operator types when
+        // building pipelines, so leave those plans alone.
+        if logutil::otlp::is_enabled()
+            && !self.ctx.get_session_vars().enable_experimental_scheduler()
+        {
+            // Remote execution encodes its child plan, which can't contain
+            // wrapped operators.
+            return Ok(TracedExec::wrap_plan(plan, &|plan| {
+                !plan.as_any().is::<RemoteExecutionExec>()
+            })?);
         }
+
+        Ok(plan)
     }
 
     /// Execute a datafusion physical plan.
@@ -542,7 +628,14 @@ impl Session {
     ) -> Result<()> {
         let stmt: PrepareStatementArg = stmt.try_into()?;
 
-        self.ctx.prepare_statement(name, stmt.stmt, params).await
+        let span = info_span!(
+            "plan",
+            conn_id = %self.ctx.get_session_vars().connection_id(),
+        );
+        self.ctx
+            .prepare_statement(name, stmt.stmt, params)
+            .instrument(span)
+            .await
     }
 
     /// Like 'prepare_statement', but for a portal.
@@ -700,7 +793,14 @@ impl Session {
             ..Default::default()
         };
 
-        let stream = match self.execute_logical_plan(plan, &op).await {
+        let span = info_span!(
+            "execute",
+            conn_id = %self.ctx.get_session_vars().connection_id(),
+            query_id = %Uuid::new_v4(),
+            query_text = op.query_text(),
+        );
+
+        let stream = match self.execute_logical_plan(plan, &op).instrument(span).await {
             Ok((plan, result)) => match result {
                 ExecutionResult::Error(e) => {
                     metrics.execution_status = ExecutionStatus::Fail;
@@ -736,6 +836,7 @@ impl Session {
                             metrics.elapsed_compute_ns = Some(agg_metrics.elapsed_compute_ns);
                             metrics.bytes_read = Some(agg_metrics.bytes_read);
                             metrics.bytes_written = agg_metrics.bytes_written;
+                            metrics.spilled_bytes = Some(agg_metrics.spilled_bytes);
                             self.ctx.get_metrics_handler().push_metric(metrics);
                             write_result
                         }
@@ -0,0 +1,107 @@
+//! Prometheus metrics for the server.
+//!
+//! Metrics are registered in the default prometheus registry and are exported
+//! in the text exposition format with [`gather_text`]. Recording a metric is
+//! cheap, so this is done regardless of whether or not the metrics endpoint is
+//! enabled.
+use once_cell::sync::Lazy;
+use prometheus::{
+    exponential_buckets,
+    register_histogram,
+    register_histogram_vec,
+    register_int_counter,
+    register_int_counter_vec,
+    register_int_gauge,
+    Encoder,
+    Histogram,
+    HistogramVec,
+    IntCounter,
+    IntCounterVec,
+    IntGauge,
+    TextEncoder,
+};
+
+/// Number of currently open sessions.
+///
+/// Set by the server when metrics are gathered.
+pub static ACTIVE_SESSIONS: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!("glaredb_active_sessions", "Number of open sessions.").unwrap()
+});
+
+/// Number of queries executed, by result type and execution status.
+pub static QUERIES_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "glaredb_queries_total",
+        "Number of queries executed.",
+        &["result_type", "status"]
+    )
+    .unwrap()
+});
+
+/// Wall clock duration of queries, by result type.
+pub static QUERY_DURATION_SECONDS: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "glaredb_query_duration_seconds",
+        "Wall clock duration of queries.",
+        &["result_type"],
+        exponential_buckets(0.001, 4.0, 10).unwrap() // 1ms to ~4.4m
+    )
+    .unwrap()
+});
+
+/// Bytes read from data sources.
+pub static BYTES_READ_TOTAL: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!("glaredb_bytes_read_total", "Bytes read from data sources.").unwrap()
+});
+
+/// Bytes written to tables and external sinks.
+pub static BYTES_WRITTEN_TOTAL: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "glaredb_bytes_written_total",
+        "Bytes written to tables and external sinks."
+    )
+    .unwrap()
+});
+
+/// Bytes spilled to disk during query execution.
+pub static SPILLED_BYTES_TOTAL: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "glaredb_spilled_bytes_total",
+        "Bytes spilled to disk during query execution."
+    )
+    .unwrap()
+});
+
+/// Latency of catalog commits to the metastore.
+pub static CATALOG_COMMIT_DURATION_SECONDS: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "glaredb_catalog_commit_duration_seconds",
+        "Latency of catalog mutations and commits to the metastore.",
+        exponential_buckets(0.001, 2.0, 14).unwrap() // 1ms to ~8s
+    )
+    .unwrap()
+});
+
+/// Number of catalog mutations that had to be retried because another session
+/// committed first.
+pub static CATALOG_COMMIT_CONFLICTS_TOTAL: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "glaredb_catalog_commit_conflicts_total",
+        "Catalog mutations retried due to a concurrent commit."
+    )
+    .unwrap()
+});
+
+/// Gather all registered metrics in the prometheus text format.
+pub fn gather_text() -> String {
+    let mut buf = Vec::new();
+    // Encoding to a vec can only fail on invalid metric families, which we
+    // don't produce.
+    TextEncoder::new()
+        .encode(&prometheus::gather(), &mut buf)
+        .expect("encoding metrics should not fail");
+    String::from_utf8(buf).expect("prometheus text format should be utf8")
+}
+
+/// Content type of the output of [`gather_text`].
+pub const TEXT_CONTENT_TYPE: &str = prometheus::TEXT_FORMAT;
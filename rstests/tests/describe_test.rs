@@ -0,0 +1,72 @@
+mod setup;
+
+use predicates::boolean::PredicateBooleanExt;
+
+use crate::setup::make_cli;
+
+#[test]
+/// Objects created in one invocation can be described in another.
+/// ./glaredb -f <DATA_DIR> -q "\dt"
+fn test_describe_tables() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let data_dir = temp_dir.path().to_str().unwrap();
+
+    make_cli()
+        .args([
+            "-f",
+            data_dir,
+            "-q",
+            "create table describe_me (a int, b text); create view describe_view as select 1;",
+        ])
+        .assert()
+        .success();
+
+    make_cli()
+        .args(["-f", data_dir, "--mode", "csv", "-q", "\\dt"])
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("describe_me")
+                .and(predicates::str::contains("describe_view").not()),
+        );
+
+    make_cli()
+        .args(["-f", data_dir, "--mode", "csv", "-q", "\\d"])
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("describe_me,table")
+                .and(predicates::str::contains("describe_view,view")),
+        );
+
+    make_cli()
+        .args(["-f", data_dir, "--mode", "csv", "-q", "\\d describe_*"])
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("describe_me,a,")
+                .and(predicates::str::contains("describe_me,b,")),
+        );
+}
+
+#[test]
+/// ./glaredb -q "\d missing"
+fn test_describe_missing() {
+    make_cli()
+        .args(["-q", "\\d missing"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Did not find any relation named \"missing\".",
+        ));
+}
+
+#[test]
+/// ./glaredb --mode csv -q "\df array_*"
+fn test_describe_functions() {
+    make_cli()
+        .args(["--mode", "csv", "-q", "\\df array_*"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("array_").and(predicates::str::contains("\nabs,").not()));
+}
@@ -64,3 +64,64 @@ fn test_output_mode_ndjson() {
     .trim_start();
     test_output_mode("ndjson", expected);
 }
+
+#[test]
+/// ./glaredb -q <QUERY> --mode markdown
+fn test_output_mode_markdown() {
+    let expected = r#"
+| Int64(1) |
+|---:|
+| 1 |
+"#
+    .trim_start();
+    test_output_mode("markdown", expected);
+}
+
+#[test]
+/// ./glaredb -q <QUERY> --mode html
+fn test_output_mode_html() {
+    let expected = r#"
+<table>
+<tr><th>Int64(1)</th></tr>
+<tr><td>1</td></tr>
+</table>
+"#
+    .trim_start();
+    test_output_mode("html", expected);
+}
+
+#[test]
+/// ./glaredb -q <QUERY> --mode line
+fn test_output_mode_line() {
+    let expected = r#"
+Int64(1) = 1
+"#
+    .trim_start();
+    test_output_mode("line", expected);
+}
+
+fn binary_output(mode: &str) -> Vec<u8> {
+    let mut cmd = make_cli();
+
+    cmd.arg("--mode").arg(mode).arg("-q").arg("select 1;");
+    let output = cmd.output().expect("Failed to run command");
+    assert!(output.status.success());
+
+    output.stdout
+}
+
+#[test]
+/// ./glaredb -q <QUERY> --mode arrow
+fn test_output_mode_arrow() {
+    let stdout = binary_output("arrow");
+    // IPC stream messages start with a continuation marker.
+    assert_eq!(&[0xFF, 0xFF, 0xFF, 0xFF], &stdout[..4]);
+}
+
+#[test]
+/// ./glaredb -q <QUERY> --mode parquet
+fn test_output_mode_parquet() {
+    let stdout = binary_output("parquet");
+    assert!(stdout.starts_with(b"PAR1"));
+    assert!(stdout.ends_with(b"PAR1"));
+}
@@ -0,0 +1,96 @@
+mod setup;
+
+use predicates::boolean::PredicateBooleanExt;
+
+use crate::setup::make_cli;
+
+#[test]
+/// ./glaredb --file <FILE>
+fn test_run_file() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let script = temp_dir.path().join("script.sql");
+    std::fs::write(
+        &script,
+        "\\set tbl script_table\n\\set greeting 'it''s'\ncreate temp table :tbl (a text);\ninsert into :tbl\n  values (:'greeting');\nselect * from :tbl;\n",
+    )
+    .unwrap();
+
+    make_cli()
+        .args(["--mode", "csv", "--file", script.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("it's"));
+}
+
+#[test]
+/// Scripts stop at the first error and exit with a non-zero status.
+/// ./glaredb --file <FILE>
+fn test_run_file_on_error_stop() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let script = temp_dir.path().join("script.sql");
+    std::fs::write(
+        &script,
+        "select 'before';\nselect * from missing_table;\nselect 'after';\n",
+    )
+    .unwrap();
+
+    make_cli()
+        .args(["--mode", "csv", "--file", script.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stdout(predicates::str::contains("before").and(predicates::str::contains("after").not()))
+        .stderr(predicates::str::contains("script.sql:2:"));
+}
+
+#[test]
+/// Errors are printed and execution continues with ON_ERROR_STOP off.
+/// ./glaredb --file <FILE>
+fn test_run_file_continue_on_error() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let script = temp_dir.path().join("script.sql");
+    std::fs::write(
+        &script,
+        "\\set ON_ERROR_STOP off\nselect * from missing_table;\nselect 'after';\n",
+    )
+    .unwrap();
+
+    make_cli()
+        .args(["--mode", "csv", "--file", script.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("after"))
+        .stderr(predicates::str::contains("script.sql:2: Error:"));
+}
+
+#[test]
+/// Include other scripts, and redirect output to a file.
+/// ./glaredb --file <FILE>
+fn test_include_and_output() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let included = temp_dir.path().join("included.sql");
+    std::fs::write(&included, "select 'from include' as a;\n").unwrap();
+
+    let out = temp_dir.path().join("out.csv");
+    let script = temp_dir.path().join("script.sql");
+    std::fs::write(
+        &script,
+        format!(
+            "\\o {}\n\\i {}\n\\o\nselect 'to stdout' as a;\n",
+            out.display(),
+            included.display()
+        ),
+    )
+    .unwrap();
+
+    make_cli()
+        .args(["--mode", "csv", "--file", script.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("to stdout")
+                .and(predicates::str::contains("from include").not()),
+        );
+
+    let contents = std::fs::read_to_string(out).unwrap();
+    assert_eq!("a\nfrom include\n", contents);
+}